@@ -7,10 +7,11 @@ use tracing::{debug, error, instrument};
 use slab_proto::slab::ipc::v1 as pb;
 
 use crate::application::dtos as dto;
+use crate::domain::runtime::CoreError;
 
 use super::{
     GrpcServiceImpl, application_result, extract_request_id, forward, proto_to_status,
-    runtime_to_status,
+    request_deadline, runtime_to_status, within_deadline,
 };
 
 #[tonic::async_trait]
@@ -43,10 +44,13 @@ impl pb::candle_transformers_service_server::CandleTransformersService for GrpcS
         let request_id = extract_request_id(request.metadata());
         tracing::Span::current().record("request_id", &request_id);
 
+        let deadline = request_deadline(request.metadata());
         let dto =
             dto::decode_candle_chat_request(&request.into_inner()).map_err(proto_to_status)?;
         let service = application_result(self.application.candle_llama())?;
-        let stream = application_result(service.chat_stream(dto).await)?;
+        let stream = within_deadline(deadline, service.chat_stream(dto))
+            .await?
+            .take_until(tokio::time::sleep_until(deadline));
 
         let (tx, rx) = mpsc::channel::<Result<pb::CandleChatStreamChunk, Status>>(32);
         tokio::spawn(async move {
@@ -64,6 +68,10 @@ impl pb::candle_transformers_service_server::CandleTransformersService for GrpcS
                     return;
                 }
             }
+            if stream.is_stopped() {
+                debug!("candle llama stream reached the request deadline");
+                let _ = tx.send(Err(runtime_to_status(CoreError::Timeout))).await;
+            }
         });
 
         Ok(Response::new(ReceiverStream::new(rx)))
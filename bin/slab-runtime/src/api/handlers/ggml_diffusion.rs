@@ -1,11 +1,18 @@
+use futures::StreamExt;
+use tokio::sync::mpsc;
+use tokio_stream::wrappers::ReceiverStream;
 use tonic::{Request, Response, Status};
-use tracing::instrument;
+use tracing::{debug, error, instrument};
 
 use slab_proto::slab::ipc::v1 as pb;
 
 use crate::application::dtos as dto;
+use crate::domain::runtime::CoreError;
 
-use super::{GrpcServiceImpl, extract_request_id, forward};
+use super::{
+    GrpcServiceImpl, application_result, extract_request_id, forward, proto_to_status,
+    request_deadline, runtime_to_status, within_deadline,
+};
 
 #[tonic::async_trait]
 impl pb::ggml_diffusion_service_server::GgmlDiffusionService for GrpcServiceImpl {
@@ -27,6 +34,51 @@ impl pb::ggml_diffusion_service_server::GgmlDiffusionService for GrpcServiceImpl
         .await
     }
 
+    type GenerateImageStreamStream =
+        ReceiverStream<Result<pb::GgmlDiffusionGenerateImageStreamChunk, Status>>;
+
+    #[instrument(skip_all, fields(request_id, backend = "ggml.diffusion"))]
+    async fn generate_image_stream(
+        &self,
+        request: Request<pb::GgmlDiffusionGenerateImageRequest>,
+    ) -> Result<Response<Self::GenerateImageStreamStream>, Status> {
+        let request_id = extract_request_id(request.metadata());
+        tracing::Span::current().record("request_id", &request_id);
+
+        let deadline = request_deadline(request.metadata());
+        let dto = dto::decode_ggml_diffusion_generate_image_request(&request.into_inner())
+            .map_err(proto_to_status)?;
+        let service = application_result(self.application.ggml_diffusion())?;
+        let stream = within_deadline(deadline, service.generate_image_stream(dto))
+            .await?
+            .take_until(tokio::time::sleep_until(deadline));
+
+        let (tx, rx) =
+            mpsc::channel::<Result<pb::GgmlDiffusionGenerateImageStreamChunk, Status>>(32);
+        tokio::spawn(async move {
+            tokio::pin!(stream);
+            while let Some(chunk) = stream.next().await {
+                let message = match chunk {
+                    Ok(chunk) => Ok(dto::encode_ggml_diffusion_generate_image_stream_chunk(&chunk)),
+                    Err(error) => {
+                        error!(error = %error, "ggml diffusion stream failed");
+                        Err(runtime_to_status(error))
+                    }
+                };
+                if tx.send(message).await.is_err() {
+                    debug!("ggml diffusion stream receiver dropped");
+                    return;
+                }
+            }
+            if stream.is_stopped() {
+                debug!("ggml diffusion stream reached the request deadline");
+                let _ = tx.send(Err(runtime_to_status(CoreError::Timeout))).await;
+            }
+        });
+
+        Ok(Response::new(ReceiverStream::new(rx)))
+    }
+
     #[instrument(skip_all, fields(request_id, backend = "ggml.diffusion"))]
     async fn generate_video(
         &self,
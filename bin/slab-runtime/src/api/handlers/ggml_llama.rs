@@ -7,10 +7,11 @@ use tracing::{debug, error, instrument};
 use slab_proto::slab::ipc::v1 as pb;
 
 use crate::application::dtos as dto;
+use crate::domain::runtime::CoreError;
 
 use super::{
     GrpcServiceImpl, application_result, extract_request_id, forward, proto_to_status,
-    runtime_to_status,
+    request_deadline, runtime_to_status, within_deadline,
 };
 
 #[tonic::async_trait]
@@ -43,10 +44,13 @@ impl pb::ggml_llama_service_server::GgmlLlamaService for GrpcServiceImpl {
         let request_id = extract_request_id(request.metadata());
         tracing::Span::current().record("request_id", &request_id);
 
+        let deadline = request_deadline(request.metadata());
         let dto =
             dto::decode_ggml_llama_chat_request(&request.into_inner()).map_err(proto_to_status)?;
         let service = application_result(self.application.ggml_llama())?;
-        let stream = application_result(service.chat_stream(dto).await)?;
+        let stream = within_deadline(deadline, service.chat_stream(dto))
+            .await?
+            .take_until(tokio::time::sleep_until(deadline));
 
         let (tx, rx) = mpsc::channel::<Result<pb::GgmlLlamaChatStreamChunk, Status>>(32);
         tokio::spawn(async move {
@@ -64,6 +68,10 @@ impl pb::ggml_llama_service_server::GgmlLlamaService for GrpcServiceImpl {
                     return;
                 }
             }
+            if stream.is_stopped() {
+                debug!("ggml llama stream reached the request deadline");
+                let _ = tx.send(Err(runtime_to_status(CoreError::Timeout))).await;
+            }
         });
 
         Ok(Response::new(ReceiverStream::new(rx)))
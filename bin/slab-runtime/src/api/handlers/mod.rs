@@ -9,14 +9,16 @@
 
 use slab_runtime_core::{RUNTIME_ERROR_CODE_METADATA, RUNTIME_ERROR_DETAIL_METADATA_BIN};
 use std::future::Future;
-use tonic::metadata::MetadataValue;
+use std::time::Duration;
+use tokio::time::Instant;
+use tonic::metadata::{MetadataMap, MetadataValue};
 use tonic::{Request, Response, Status};
 
 use crate::application::{
     dtos as dto,
     services::{RuntimeApplication, RuntimeApplicationError},
 };
-use crate::domain::runtime::CoreError;
+use crate::domain::runtime::{CoreError, with_request_deadline};
 
 mod candle_diffusion;
 mod candle_transformers;
@@ -25,6 +27,12 @@ mod ggml_llama;
 mod ggml_whisper;
 mod onnx;
 
+/// Upper bound on how long any RPC may wait on the runtime.
+///
+/// Clients can ask for less with a `grpc-timeout` deadline; the shorter of the
+/// two bounds every task wait and response stream of the call.
+const MAX_RPC_TIMEOUT: Duration = Duration::from_secs(30 * 60);
+
 #[derive(Clone)]
 pub struct GrpcServiceImpl {
     application: RuntimeApplication,
@@ -60,12 +68,51 @@ where
     Fut: Future<Output = Result<R, RuntimeApplicationError>>,
     Encode: FnOnce(&R) -> O,
 {
+    let deadline = request_deadline(request.metadata());
     let dto = decode(&request.into_inner()).map_err(proto_to_status)?;
     let service = resolve().map_err(application_to_status)?;
-    let response = call(service, dto).await.map_err(application_to_status)?;
+    let response = within_deadline(deadline, call(service, dto)).await?;
     Ok(Response::new(encode(&response)))
 }
 
+/// Runs `call` with `deadline` bounding its task waits, failing with
+/// `DEADLINE_EXCEEDED` if it is still running once the deadline passes.
+async fn within_deadline<R, Fut>(deadline: Instant, call: Fut) -> Result<R, Status>
+where
+    Fut: Future<Output = Result<R, RuntimeApplicationError>>,
+{
+    match tokio::time::timeout_at(deadline, with_request_deadline(deadline, call)).await {
+        Ok(result) => application_result(result),
+        Err(_) => Err(runtime_to_status(CoreError::Timeout)),
+    }
+}
+
+/// The client's `grpc-timeout` deadline, clamped to [`MAX_RPC_TIMEOUT`].
+fn request_deadline(metadata: &MetadataMap) -> Instant {
+    let timeout = metadata
+        .get("grpc-timeout")
+        .and_then(|value| value.to_str().ok())
+        .and_then(parse_grpc_timeout)
+        .map_or(MAX_RPC_TIMEOUT, |timeout| timeout.min(MAX_RPC_TIMEOUT));
+    Instant::now() + timeout
+}
+
+/// Parses a `grpc-timeout` value: at most eight digits followed by a unit.
+fn parse_grpc_timeout(value: &str) -> Option<Duration> {
+    let unit_at = value.len().checked_sub(1).filter(|at| (1..=8).contains(at))?;
+    let (amount, unit) = value.split_at(unit_at);
+    let amount: u64 = amount.parse().ok()?;
+    match unit {
+        "H" => Some(Duration::from_secs(amount * 60 * 60)),
+        "M" => Some(Duration::from_secs(amount * 60)),
+        "S" => Some(Duration::from_secs(amount)),
+        "m" => Some(Duration::from_millis(amount)),
+        "u" => Some(Duration::from_micros(amount)),
+        "n" => Some(Duration::from_nanos(amount)),
+        _ => None,
+    }
+}
+
 fn format_error_chain(err: &dyn std::error::Error) -> String {
     let mut msg = err.to_string();
     let mut source = err.source();
@@ -80,9 +127,9 @@ fn format_error_chain(err: &dyn std::error::Error) -> String {
 pub(super) fn runtime_to_status(err: CoreError) -> Status {
     let msg = format_error_chain(&err);
     let mut status = match &err {
-        CoreError::ModelNotLoaded | CoreError::BackendDisabled { .. } => {
-            Status::failed_precondition(msg)
-        }
+        CoreError::ModelNotLoaded
+        | CoreError::LibraryNotLoaded
+        | CoreError::BackendDisabled { .. } => Status::failed_precondition(msg),
         CoreError::QueueFull { .. }
         | CoreError::OrchestratorQueueFull { .. }
         | CoreError::Busy { .. } => Status::resource_exhausted(msg),
@@ -91,7 +138,9 @@ pub(super) fn runtime_to_status(err: CoreError) -> Status {
         CoreError::Cancelled => Status::cancelled(msg),
         CoreError::BackendShutdown => Status::unavailable(msg),
         CoreError::UnsupportedOperation { .. } => Status::unimplemented(msg),
-        CoreError::InvalidRequestPayload { .. } => Status::invalid_argument(msg),
+        CoreError::InvalidRequestPayload { .. } | CoreError::PreprocessFailed { .. } => {
+            Status::invalid_argument(msg)
+        }
         CoreError::DriverNotRegistered { .. } => Status::failed_precondition(msg),
         CoreError::CpuStageFailed { .. }
         | CoreError::GpuStageFailed { .. }
@@ -131,23 +180,32 @@ pub(super) fn extract_request_id(metadata: &tonic::metadata::MetadataMap) -> Str
 
 #[cfg(test)]
 mod tests {
-    use super::{GrpcServiceImpl, runtime_to_status};
-    use crate::application::services::RuntimeApplication;
+    use std::time::Duration;
+
+    use super::{
+        GrpcServiceImpl, MAX_RPC_TIMEOUT, forward, parse_grpc_timeout, request_deadline,
+        runtime_to_status,
+    };
+    use crate::application::services::{RuntimeApplication, RuntimeApplicationError};
     use crate::domain::models::RuntimeEnabledBackends;
     use crate::domain::runtime::CoreError;
     use crate::domain::runtime::Orchestrator;
+    use crate::domain::runtime::request_time_remaining;
     use crate::domain::services::ExecutionHub;
     use slab_proto::slab::ipc::v1 as pb;
     use slab_runtime_core::{
         RUNTIME_ERROR_CODE_METADATA, RUNTIME_ERROR_DETAIL_METADATA_BIN, backend::ResourceManager,
     };
+    use tokio::time::Instant;
+    use tonic::metadata::{MetadataMap, MetadataValue};
     use tonic::{Code, Request, Status};
 
     fn grpc_service_with_backends(
         backends: impl IntoIterator<Item = &'static str>,
     ) -> GrpcServiceImpl {
         let orchestrator = Orchestrator::start(ResourceManager::new(), 1);
-        let execution = ExecutionHub::new(orchestrator, RuntimeEnabledBackends::new(backends));
+        let execution =
+            ExecutionHub::new(orchestrator, RuntimeEnabledBackends::new(backends), false);
         GrpcServiceImpl::new(RuntimeApplication::new(execution))
     }
 
@@ -194,6 +252,45 @@ mod tests {
         assert!(candle.message().contains("candle.llama"));
     }
 
+    #[test]
+    fn grpc_timeout_header_is_parsed_and_clamped() {
+        assert_eq!(parse_grpc_timeout("250m"), Some(Duration::from_millis(250)));
+        assert_eq!(parse_grpc_timeout("2H"), Some(Duration::from_secs(2 * 60 * 60)));
+        assert_eq!(parse_grpc_timeout("123456789S"), None);
+        assert_eq!(parse_grpc_timeout("10x"), None);
+        assert_eq!(parse_grpc_timeout("S"), None);
+
+        let mut metadata = MetadataMap::new();
+        metadata.insert("grpc-timeout", MetadataValue::from_static("2H"));
+        let remaining = request_deadline(&metadata).saturating_duration_since(Instant::now());
+        assert!(remaining <= MAX_RPC_TIMEOUT);
+        assert!(remaining > MAX_RPC_TIMEOUT - Duration::from_secs(60));
+    }
+
+    #[tokio::test]
+    async fn short_grpc_timeout_fails_the_call_with_deadline_exceeded() {
+        let mut request = Request::new(());
+        request.metadata_mut().insert("grpc-timeout", MetadataValue::from_static("20m"));
+
+        let started = std::time::Instant::now();
+        let result = forward(
+            request,
+            |_| Ok(()),
+            || Ok(()),
+            |_, _| async {
+                assert!(
+                    request_time_remaining().is_some_and(|left| left <= Duration::from_millis(20))
+                );
+                std::future::pending::<Result<(), RuntimeApplicationError>>().await
+            },
+            |_| (),
+        )
+        .await;
+
+        expect_status(result, Code::DeadlineExceeded, "operation timed out");
+        assert!(started.elapsed() < Duration::from_secs(1));
+    }
+
     #[test]
     fn cancelled_error_maps_to_cancelled_status() {
         let status = runtime_to_status(CoreError::Cancelled);
@@ -275,6 +372,19 @@ mod tests {
                 "internal lock poisoned: resource_manager",
             ),
             (CoreError::ModelNotLoaded, Code::FailedPrecondition, "model is not loaded"),
+            (
+                CoreError::LibraryNotLoaded,
+                Code::FailedPrecondition,
+                "backend library is not loaded",
+            ),
+            (
+                CoreError::PreprocessFailed {
+                    tool: "ffmpeg".into(),
+                    message: "invalid data found".into(),
+                },
+                Code::InvalidArgument,
+                "preprocessing with 'ffmpeg' failed",
+            ),
             (
                 CoreError::ResultDecodeFailed {
                     task_kind: "chat".into(),
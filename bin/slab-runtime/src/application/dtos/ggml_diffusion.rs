@@ -2,9 +2,9 @@ use slab_proto::slab::ipc::v1 as pb;
 
 use super::{
     GgmlDiffusionGenerateImageRequest, GgmlDiffusionGenerateImageResponse,
-    GgmlDiffusionGenerateVideoRequest, GgmlDiffusionGenerateVideoResponse,
-    GgmlDiffusionLoadRequest, ProtoConversionError, decode_optional_path, decode_raw_image,
-    encode_raw_image,
+    GgmlDiffusionGenerateImageStreamChunk, GgmlDiffusionGenerateVideoRequest,
+    GgmlDiffusionGenerateVideoResponse, GgmlDiffusionLoadRequest, ProtoConversionError,
+    decode_optional_path, decode_raw_image, encode_raw_image,
 };
 
 pub(crate) fn decode_ggml_diffusion_load_request(
@@ -48,6 +48,7 @@ pub(crate) fn decode_ggml_diffusion_generate_image_request(
         clip_skip: request.clip_skip,
         strength: request.strength,
         eta: request.eta,
+        preview_interval: request.preview_interval,
     })
 }
 
@@ -56,6 +57,20 @@ pub(crate) fn encode_ggml_diffusion_generate_image_response(
 ) -> pb::GgmlDiffusionGenerateImageResponse {
     pb::GgmlDiffusionGenerateImageResponse {
         images: response.images.iter().map(encode_raw_image).collect(),
+        seeds: response.seeds.clone(),
+    }
+}
+
+pub(crate) fn encode_ggml_diffusion_generate_image_stream_chunk(
+    chunk: &GgmlDiffusionGenerateImageStreamChunk,
+) -> pb::GgmlDiffusionGenerateImageStreamChunk {
+    pb::GgmlDiffusionGenerateImageStreamChunk {
+        step: chunk.step,
+        total_steps: chunk.total_steps,
+        preview_png: chunk.preview_png.clone(),
+        images: chunk.images.iter().map(encode_raw_image).collect(),
+        seeds: chunk.seeds.clone(),
+        done: chunk.done,
     }
 }
 
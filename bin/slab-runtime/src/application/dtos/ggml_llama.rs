@@ -16,6 +16,8 @@ pub(crate) fn decode_ggml_llama_load_request(
         chat_template: request.chat_template.clone(),
         gbnf: request.gbnf.clone(),
         flash_attn: request.flash_attn,
+        least_loaded_sessions: request.least_loaded_sessions,
+        stop_on_context_full: request.stop_on_context_full,
     })
 }
 
@@ -30,11 +32,15 @@ pub(crate) fn decode_ggml_llama_chat_request(
         top_k: request.top_k,
         min_p: request.min_p,
         presence_penalty: request.presence_penalty,
+        frequency_penalty: request.frequency_penalty,
         repetition_penalty: request.repetition_penalty,
+        repeat_last_n: request.repeat_last_n,
+        n_keep: request.n_keep,
         session_key: request.session_key.clone(),
         gbnf: request.gbnf.clone(),
         stop_sequences: decode_optional_string_list(request.stop_sequences.as_ref()),
         ignore_eos: request.ignore_eos,
+        seed: request.seed,
         logit_bias_json: request.logit_bias_json.clone(),
         agent_trace: request
             .agent_trace_json
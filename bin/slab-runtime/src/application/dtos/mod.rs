@@ -25,6 +25,7 @@ pub(crate) use candle_transformers::{
 pub(crate) use ggml_diffusion::{
     decode_ggml_diffusion_generate_image_request, decode_ggml_diffusion_generate_video_request,
     decode_ggml_diffusion_load_request, encode_ggml_diffusion_generate_image_response,
+    encode_ggml_diffusion_generate_image_stream_chunk,
     encode_ggml_diffusion_generate_video_response,
 };
 pub(crate) use ggml_llama::{
@@ -47,6 +48,7 @@ pub(crate) struct ModelStatus {
     pub status: String,
     pub context_length: Option<u32>,
     pub training_context_length: Option<u32>,
+    pub version: Option<String>,
 }
 
 #[derive(Clone, Debug, Default, PartialEq)]
@@ -205,6 +207,8 @@ pub(crate) struct GgmlLlamaLoadRequest {
     pub chat_template: Option<String>,
     pub gbnf: Option<String>,
     pub flash_attn: Option<bool>,
+    pub least_loaded_sessions: Option<bool>,
+    pub stop_on_context_full: Option<bool>,
 }
 
 #[derive(Clone, Debug, Default, PartialEq)]
@@ -216,11 +220,15 @@ pub(crate) struct GgmlLlamaChatRequest {
     pub top_k: Option<i32>,
     pub min_p: Option<f32>,
     pub presence_penalty: Option<f32>,
+    pub frequency_penalty: Option<f32>,
     pub repetition_penalty: Option<f32>,
+    pub repeat_last_n: Option<i32>,
+    pub n_keep: Option<u32>,
     pub session_key: Option<String>,
     pub gbnf: Option<String>,
     pub stop_sequences: Option<Vec<String>>,
     pub ignore_eos: Option<bool>,
+    pub seed: Option<u32>,
     pub logit_bias_json: Option<Vec<u8>>,
     pub agent_trace: Option<slab_agent_tracing::AgentTraceContext>,
 }
@@ -260,6 +268,7 @@ pub(crate) struct GgmlWhisperDecodeOptions {
     pub logprob_thold: Option<f32>,
     pub no_speech_thold: Option<f32>,
     pub tdrz_enable: Option<bool>,
+    pub translate: Option<bool>,
 }
 
 #[derive(Clone, Debug, Default, PartialEq)]
@@ -319,11 +328,23 @@ pub(crate) struct GgmlDiffusionGenerateImageRequest {
     pub clip_skip: Option<i32>,
     pub strength: Option<f32>,
     pub eta: Option<f32>,
+    pub preview_interval: Option<u32>,
 }
 
 #[derive(Clone, Debug, Default, PartialEq)]
 pub(crate) struct GgmlDiffusionGenerateImageResponse {
     pub images: Vec<RawImage>,
+    pub seeds: Vec<i64>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub(crate) struct GgmlDiffusionGenerateImageStreamChunk {
+    pub step: Option<i32>,
+    pub total_steps: Option<i32>,
+    pub preview_png: Option<Vec<u8>>,
+    pub images: Vec<RawImage>,
+    pub seeds: Vec<i64>,
+    pub done: Option<bool>,
 }
 
 #[derive(Clone, Debug, Default, PartialEq)]
@@ -454,6 +475,7 @@ pub(crate) fn encode_model_status_response(status: &ModelStatus) -> pb::ModelSta
         status: status.status.clone(),
         context_length: status.context_length,
         training_context_length: status.training_context_length,
+        version: status.version.clone(),
     }
 }
 
@@ -580,6 +602,7 @@ fn decode_ggml_whisper_decode_options(
         logprob_thold: value.logprob_thold,
         no_speech_thold: value.no_speech_thold,
         tdrz_enable: value.tdrz_enable,
+        translate: value.translate,
     }
 }
 
@@ -601,13 +624,17 @@ mod tests {
             top_k: Some(0),
             min_p: Some(0.0),
             presence_penalty: Some(0.0),
+            frequency_penalty: Some(0.0),
             repetition_penalty: Some(0.0),
+            repeat_last_n: Some(0),
+            n_keep: Some(0),
             session_key: Some(String::new()),
             gbnf: Some(String::new()),
             stop_sequences: Some(pb::StringList { values: Vec::new() }),
             ignore_eos: Some(false),
             logit_bias_json: Some(Vec::new()),
             agent_trace_json: None,
+            seed: Some(0),
         })
         .expect("decode should succeed");
 
@@ -618,6 +645,7 @@ mod tests {
         assert_eq!(decoded.top_k, Some(0));
         assert_eq!(decoded.min_p, Some(0.0));
         assert_eq!(decoded.ignore_eos, Some(false));
+        assert_eq!(decoded.seed, Some(0));
         assert_eq!(decoded.stop_sequences, Some(Vec::new()));
         assert_eq!(decoded.logit_bias_json, Some(Vec::new()));
     }
@@ -652,11 +680,25 @@ mod tests {
             status: "loaded".to_owned(),
             context_length: Some(4096),
             training_context_length: None,
+            version: None,
         });
 
         assert_eq!(encoded.backend, "onnx.text");
         assert_eq!(encoded.status, "loaded");
         assert_eq!(encoded.context_length, Some(4096));
         assert_eq!(encoded.training_context_length, None);
+        assert_eq!(encoded.version, None);
+    }
+
+    #[test]
+    fn model_status_encode_preserves_backend_version() {
+        let encoded = encode_model_status_response(&ModelStatus {
+            backend: "ggml.diffusion".to_owned(),
+            status: "loaded".to_owned(),
+            version: Some("master-abc1234".to_owned()),
+            ..Default::default()
+        });
+
+        assert_eq!(encoded.version.as_deref(), Some("master-abc1234"));
     }
 }
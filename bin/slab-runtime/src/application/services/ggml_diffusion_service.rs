@@ -1,3 +1,5 @@
+use futures::stream::BoxStream;
+
 use crate::application::dtos as dto;
 use crate::domain::runtime::CoreError;
 use crate::domain::services::{ExecutionHub, GgmlDiffusionService as DomainGgmlDiffusionService};
@@ -27,9 +29,12 @@ impl GgmlDiffusionService {
         }
 
         let service = DomainGgmlDiffusionService::new(self.execution.clone(), request)?;
-        service.load().await?;
+        let metadata = service.load().await?.unwrap_or_default();
         store_loaded(&self.loaded, service).await;
-        Ok(model_status("ggml.diffusion", "loaded"))
+        Ok(dto::ModelStatus {
+            version: metadata.version,
+            ..model_status("ggml.diffusion", "loaded")
+        })
     }
 
     pub(crate) async fn unload_model(&self) -> Result<dto::ModelStatus, RuntimeApplicationError> {
@@ -48,6 +53,16 @@ impl GgmlDiffusionService {
         clone_loaded(&self.loaded).await?.generate_image(request).await.map_err(Into::into)
     }
 
+    pub(crate) async fn generate_image_stream(
+        &self,
+        request: dto::GgmlDiffusionGenerateImageRequest,
+    ) -> Result<
+        BoxStream<'static, Result<dto::GgmlDiffusionGenerateImageStreamChunk, CoreError>>,
+        RuntimeApplicationError,
+    > {
+        clone_loaded(&self.loaded).await?.generate_image_stream(request).await.map_err(Into::into)
+    }
+
     pub(crate) async fn generate_video(
         &self,
         request: dto::GgmlDiffusionGenerateVideoRequest,
@@ -30,12 +30,16 @@ impl GgmlLlamaService {
 
         let service = DomainGgmlLlamaService::new(self.execution.clone(), request)?;
         let metadata = service.load().await?.unwrap_or_default();
+        if let Err(error) = service.warmup().await {
+            tracing::warn!(%error, "ggml.llama warmup failed; first request will be slower");
+        }
         store_loaded(&self.loaded, service).await;
         Ok(dto::ModelStatus {
             backend: "ggml.llama".to_owned(),
             status: "loaded".to_owned(),
             context_length: metadata.context_length,
             training_context_length: metadata.training_context_length,
+            version: None,
         })
     }
 
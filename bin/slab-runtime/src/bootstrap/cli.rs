@@ -1,4 +1,5 @@
 use std::path::{Path, PathBuf};
+use std::time::Duration;
 
 use anyhow::Context;
 use clap::{Parser, ValueEnum};
@@ -34,6 +35,28 @@ pub struct Cli {
     pub queue_capacity: Option<usize>,
     #[arg(long = "backend-capacity")]
     pub backend_capacity: Option<usize>,
+    /// Concurrency for the llama backend; defaults to `--backend-capacity`.
+    #[arg(long = "llama-capacity")]
+    pub llama_capacity: Option<usize>,
+    /// Concurrency for the whisper backend; defaults to `--backend-capacity`.
+    #[arg(long = "whisper-capacity")]
+    pub whisper_capacity: Option<usize>,
+    /// Concurrency for the diffusion backend; defaults to `--backend-capacity`.
+    #[arg(long = "diffusion-capacity")]
+    pub diffusion_capacity: Option<usize>,
+    /// Fail requests with `RESOURCE_EXHAUSTED` when their backend has no free
+    /// slot instead of queueing them.
+    #[arg(long = "shed-load", action = clap::ArgAction::SetTrue)]
+    pub shed_load: bool,
+    /// Seconds to retain finished task results; `0` disables eviction.
+    #[arg(long = "result-ttl-secs")]
+    pub result_ttl_secs: Option<u64>,
+    /// Maximum number of llama models kept loaded; `0` means unbounded.
+    #[arg(long = "llama-max-loaded-models")]
+    pub llama_max_loaded_models: Option<usize>,
+    /// Directory for persisted llama session KV snapshots; unset keeps them in memory only.
+    #[arg(long = "llama-session-state-dir")]
+    pub llama_session_state_dir: Option<PathBuf>,
     #[arg(long = "lib-dir")]
     pub lib_dir: Option<PathBuf>,
     #[arg(long = "log-file")]
@@ -89,11 +112,21 @@ impl Cli {
             log_json: self.log_json,
             queue_capacity: self.queue_capacity.unwrap_or(64),
             backend_capacity: self.backend_capacity.unwrap_or(4),
+            llama_capacity: self.llama_capacity,
+            whisper_capacity: self.whisper_capacity,
+            diffusion_capacity: self.diffusion_capacity,
+            shed_load: self.shed_load,
+            result_ttl: match self.result_ttl_secs.unwrap_or(3600) {
+                0 => None,
+                secs => Some(Duration::from_secs(secs)),
+            },
             base_lib_path,
             log_file: self.log_file,
             enabled_backends,
             shutdown_on_stdin_close: self.shutdown_on_stdin_close,
             llama_lib_dir,
+            llama_max_loaded_models: self.llama_max_loaded_models.filter(|max| *max > 0),
+            llama_session_state_dir: self.llama_session_state_dir,
             whisper_lib_dir,
             diffusion_lib_dir,
             enable_candle_llama: enabled_backends.candle_llama,
@@ -118,6 +151,78 @@ mod tests {
         assert!(config.enabled_backends.whisper);
         assert!(config.enabled_backends.diffusion);
         assert!(!config.enabled_backends.candle_llama);
+        assert!(!config.shed_load);
+        assert_eq!(config.result_ttl, Some(std::time::Duration::from_secs(3600)));
+    }
+
+    #[test]
+    fn runtime_config_result_ttl_zero_disables_eviction() {
+        let cli = <Cli as Parser>::try_parse_from(["slab-runtime", "--result-ttl-secs", "0"])
+            .expect("parse cli");
+        let config = cli.into_runtime_config().expect("build runtime config");
+
+        assert_eq!(config.result_ttl, None);
+    }
+
+    #[test]
+    fn runtime_config_llama_max_loaded_models_zero_is_unbounded() {
+        let cli = <Cli as Parser>::try_parse_from(["slab-runtime"]).expect("parse cli");
+        assert_eq!(cli.into_runtime_config().expect("config").llama_max_loaded_models, None);
+
+        let cli =
+            <Cli as Parser>::try_parse_from(["slab-runtime", "--llama-max-loaded-models", "0"])
+                .expect("parse cli");
+        assert_eq!(cli.into_runtime_config().expect("config").llama_max_loaded_models, None);
+
+        let cli =
+            <Cli as Parser>::try_parse_from(["slab-runtime", "--llama-max-loaded-models", "2"])
+                .expect("parse cli");
+        assert_eq!(cli.into_runtime_config().expect("config").llama_max_loaded_models, Some(2));
+    }
+
+    #[test]
+    fn runtime_config_enables_load_shedding_on_request() {
+        let cli =
+            <Cli as Parser>::try_parse_from(["slab-runtime", "--shed-load"]).expect("parse cli");
+
+        assert!(cli.into_runtime_config().expect("config").shed_load);
+    }
+
+    #[test]
+    fn runtime_config_keeps_per_backend_capacity_overrides() {
+        let cli = <Cli as Parser>::try_parse_from([
+            "slab-runtime",
+            "--backend-capacity",
+            "3",
+            "--llama-capacity",
+            "8",
+            "--diffusion-capacity",
+            "1",
+        ])
+        .expect("parse cli");
+        let config = cli.into_runtime_config().expect("build runtime config");
+
+        assert_eq!(config.backend_capacity, 3);
+        assert_eq!(config.llama_capacity, Some(8));
+        assert_eq!(config.whisper_capacity, None);
+        assert_eq!(config.diffusion_capacity, Some(1));
+    }
+
+    #[test]
+    fn runtime_config_forwards_llama_session_state_dir() {
+        let cli = <Cli as Parser>::try_parse_from(["slab-runtime"]).expect("parse cli");
+        assert_eq!(cli.into_runtime_config().expect("config").llama_session_state_dir, None);
+
+        let cli = <Cli as Parser>::try_parse_from([
+            "slab-runtime",
+            "--llama-session-state-dir",
+            "/var/slab/sessions",
+        ])
+        .expect("parse cli");
+        assert_eq!(
+            cli.into_runtime_config().expect("config").llama_session_state_dir,
+            Some(std::path::PathBuf::from("/var/slab/sessions"))
+        );
     }
 
     #[test]
@@ -26,14 +26,17 @@ pub async fn run(cli: Cli) -> anyhow::Result<()> {
     telemetry::install_panic_hook();
     telemetry::log_startup(&config);
 
-    let grpc_service = build_grpc_service(Arc::clone(&config))?;
+    let (grpc_service, orchestrator) = build_grpc_service(Arc::clone(&config))?;
     info!(grpc_bind = %config.grpc_bind, "starting slab-runtime gRPC server");
     server::serve_grpc(&config.grpc_bind, config.shutdown_on_stdin_close, grpc_service).await?;
+    orchestrator.shutdown().await;
     info!("slab-runtime stopped");
     Ok(())
 }
 
-fn build_grpc_service(config: Arc<RuntimeConfig>) -> anyhow::Result<GrpcServiceImpl> {
+fn build_grpc_service(
+    config: Arc<RuntimeConfig>,
+) -> anyhow::Result<(GrpcServiceImpl, Orchestrator)> {
     let drivers = backends::RuntimeDriversConfig::from(config.as_ref());
     let worker_count = config.backend_capacity;
     let mut resource_manager = ResourceManager::with_config(ResourceManagerConfig {
@@ -43,10 +46,15 @@ fn build_grpc_service(config: Arc<RuntimeConfig>) -> anyhow::Result<GrpcServiceI
     backends::register_backends(&drivers, &mut resource_manager, worker_count)
         .context("failed to register runtime backends")?;
 
+    let orchestrator = Orchestrator::start(resource_manager, config.queue_capacity);
+    if let Some(ttl) = config.result_ttl {
+        orchestrator.spawn_result_sweeper(ttl);
+    }
     let execution = ExecutionHub::new(
-        Orchestrator::start(resource_manager, config.queue_capacity),
+        orchestrator.clone(),
         RuntimeEnabledBackends::new(backends::service_ids(&drivers)),
+        config.shed_load,
     );
     let application = RuntimeApplication::new(execution);
-    Ok(GrpcServiceImpl::new(application))
+    Ok((GrpcServiceImpl::new(application), orchestrator))
 }
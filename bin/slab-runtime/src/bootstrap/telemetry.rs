@@ -143,6 +143,13 @@ pub(super) fn log_startup(config: &RuntimeConfig) {
     info!(
         queue_capacity = config.queue_capacity,
         backend_capacity = config.backend_capacity,
+        llama_capacity = ?config.llama_capacity,
+        whisper_capacity = ?config.whisper_capacity,
+        diffusion_capacity = ?config.diffusion_capacity,
+        shed_load = config.shed_load,
+        result_ttl = ?config.result_ttl,
+        llama_max_loaded_models = ?config.llama_max_loaded_models,
+        llama_session_state_dir = ?config.llama_session_state_dir,
         "initializing slab-core runtime"
     );
 }
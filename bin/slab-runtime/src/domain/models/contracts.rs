@@ -15,6 +15,21 @@ pub(crate) struct GgmlLlamaLoadConfig {
     pub chat_template: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub gbnf: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub model_id: Option<String>,
+    /// End generation with a context-full error instead of shifting the KV cache.
+    #[serde(default)]
+    pub stop_on_context_full: bool,
+    /// Assign new sessions to the worker with the fewest active sessions instead of round-robin.
+    #[serde(default)]
+    pub least_loaded_sessions: bool,
+}
+
+/// Names the model slot `model.unload` drops; unset means the default slot.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub(crate) struct GgmlLlamaUnloadRequest {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub model_id: Option<String>,
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
@@ -25,6 +40,40 @@ pub(crate) struct GgmlLlamaLoadMetadata {
     pub training_context_length: Option<u32>,
 }
 
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub(crate) struct GgmlLlamaReadiness {
+    pub ready: bool,
+    #[serde(default)]
+    pub model_ids: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub(crate) struct GgmlLlamaTokenizeRequest {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub model_id: Option<String>,
+    pub text: String,
+    /// Add BOS/EOS tokens the way a fresh prompt would be tokenized.
+    #[serde(default)]
+    pub add_special: bool,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub(crate) struct GgmlLlamaTokens {
+    pub tokens: Vec<i32>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub(crate) struct GgmlLlamaDetokenizeRequest {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub model_id: Option<String>,
+    pub tokens: Vec<i32>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub(crate) struct GgmlLlamaDetokenized {
+    pub text: String,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub(crate) struct GgmlWhisperLoadConfig {
     pub model_path: PathBuf,
@@ -65,6 +114,13 @@ pub(crate) struct GgmlDiffusionLoadConfig {
     pub n_threads: Option<i32>,
 }
 
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub(crate) struct GgmlDiffusionLoadMetadata {
+    /// stable-diffusion.cpp build the context was created with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+}
+
 #[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
 pub(crate) struct TextPromptTokensDetails {
     #[serde(default)]
@@ -127,8 +183,14 @@ pub(crate) struct TextGenerationOptions {
     #[serde(default)]
     pub presence_penalty: Option<f32>,
     #[serde(default)]
+    pub frequency_penalty: Option<f32>,
+    #[serde(default)]
     pub repetition_penalty: Option<f32>,
     #[serde(default)]
+    pub repeat_last_n: Option<i32>,
+    #[serde(default)]
+    pub n_keep: Option<u32>,
+    #[serde(default)]
     pub session_key: Option<String>,
     #[serde(default)]
     pub stream: bool,
@@ -136,12 +198,16 @@ pub(crate) struct TextGenerationOptions {
     pub gbnf: Option<String>,
     #[serde(default)]
     pub ignore_eos: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub seed: Option<u32>,
     #[serde(default)]
     pub logit_bias: Option<serde_json::Value>,
     #[serde(default)]
     pub stop_sequences: Vec<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub agent_trace: Option<slab_agent_tracing::AgentTraceContext>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub model_id: Option<String>,
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
@@ -230,6 +296,8 @@ pub(crate) struct AudioTranscriptionDecodeOptions {
     pub no_speech_thold: Option<f32>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub tdrz_enable: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub translate: Option<bool>,
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
@@ -259,6 +327,8 @@ pub(crate) struct GeneratedImage {
     pub width: u32,
     pub height: u32,
     pub channels: u32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub seed: Option<i64>,
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
@@ -293,6 +363,10 @@ pub(crate) struct ImageGenerationRequest {
     pub seed: Option<u64>,
     #[serde(default)]
     pub batch_count: u32,
+    /// Denoising steps between TAESD previews on `inference.stream`; `None`
+    /// disables previews.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub preview_interval: Option<u32>,
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
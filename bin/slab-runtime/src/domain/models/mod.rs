@@ -9,12 +9,15 @@ pub(crate) use backend_payload::{
 pub(crate) use contracts::{
     AudioTranscriptionDecodeOptions, AudioTranscriptionOptions, AudioTranscriptionResponse,
     AudioTranscriptionVadOptions, AudioTranscriptionVadParams, GeneratedImage,
-    GgmlDiffusionLoadConfig, GgmlLlamaLoadConfig, GgmlLlamaLoadMetadata, GgmlWhisperLoadConfig,
-    ImageGenerationRequest, ImageGenerationResponse, OnnxInferenceRequest, OnnxInferenceResponse,
-    OnnxTensor, TextGenerationMetadata, TextGenerationOptions, TextGenerationResponse,
-    TextGenerationStreamEvent, TextGenerationUsage,
+    GgmlDiffusionLoadConfig, GgmlDiffusionLoadMetadata, GgmlLlamaLoadConfig, GgmlLlamaLoadMetadata,
+    GgmlWhisperLoadConfig, ImageGenerationRequest, ImageGenerationResponse, OnnxInferenceRequest,
+    OnnxInferenceResponse, OnnxTensor, TextGenerationMetadata, TextGenerationOptions,
+    TextGenerationResponse, TextGenerationStreamEvent, TextGenerationUsage,
 };
 #[cfg(feature = "ggml")]
-pub(crate) use contracts::{TextPromptTokensDetails, TextStopMetadata};
+pub(crate) use contracts::{
+    GgmlLlamaDetokenizeRequest, GgmlLlamaDetokenized, GgmlLlamaReadiness, GgmlLlamaTokenizeRequest,
+    GgmlLlamaTokens, GgmlLlamaUnloadRequest, TextPromptTokensDetails, TextStopMetadata,
+};
 pub(crate) use enabled_backends::RuntimeEnabledBackends;
 pub(crate) use task::{TaskCodec, TaskHandle};
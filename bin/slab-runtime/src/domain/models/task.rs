@@ -8,6 +8,7 @@ use slab_runtime_core::backend::{RequestRoute, StreamChunk};
 
 use crate::domain::runtime::{
     CoreError, DEFAULT_WAIT_TIMEOUT, Orchestrator, STREAM_INIT_TIMEOUT, TaskId,
+    request_time_remaining,
 };
 
 pub(crate) trait TaskCodec<R, C>: Send + Sync + 'static {
@@ -54,8 +55,10 @@ where
         self.orchestrator.purge_task(self.task_id).await;
     }
 
+    /// Waits for the result until the deadline of the RPC being served, or
+    /// [`DEFAULT_WAIT_TIMEOUT`] outside of one.
     pub async fn result(&self) -> Result<R, CoreError> {
-        self.result_timeout(DEFAULT_WAIT_TIMEOUT).await
+        self.result_timeout(request_time_remaining().unwrap_or(DEFAULT_WAIT_TIMEOUT)).await
     }
 
     pub async fn result_timeout(&self, timeout: std::time::Duration) -> Result<R, CoreError> {
@@ -63,8 +66,12 @@ where
         self.codec.decode_result(payload)
     }
 
+    /// Waits for the stream to open, for at most [`STREAM_INIT_TIMEOUT`] and
+    /// never past the deadline of the RPC being served.
     pub async fn take_stream(&self) -> Result<BoxStream<'static, Result<C, CoreError>>, CoreError> {
-        self.take_stream_timeout(STREAM_INIT_TIMEOUT).await
+        let timeout = request_time_remaining()
+            .map_or(STREAM_INIT_TIMEOUT, |remaining| remaining.min(STREAM_INIT_TIMEOUT));
+        self.take_stream_timeout(timeout).await
     }
 
     pub async fn take_stream_timeout(
@@ -73,17 +80,58 @@ where
     ) -> Result<BoxStream<'static, Result<C, CoreError>>, CoreError> {
         let handle = self.orchestrator.wait_stream(self.task_id, timeout).await?;
         let codec = Arc::clone(&self.codec);
+        let guard = CancelOnDrop::new(self.orchestrator.clone(), self.task_id);
 
-        Ok(stream::unfold((handle, codec), |(mut rx, codec)| async move {
+        Ok(stream::unfold((handle, codec, guard), |(mut rx, codec, mut guard)| async move {
             match rx.recv().await {
                 Some(chunk) => match codec.decode_chunk(chunk) {
-                    Ok(Some(decoded)) => Some((Ok(decoded), (rx, codec))),
-                    Ok(None) => None,
-                    Err(error) => Some((Err(error), (rx, codec))),
+                    Ok(Some(decoded)) => Some((Ok(decoded), (rx, codec, guard))),
+                    Ok(None) => {
+                        guard.disarm();
+                        None
+                    }
+                    Err(error) => Some((Err(error), (rx, codec, guard))),
                 },
-                None => None,
+                None => {
+                    guard.disarm();
+                    None
+                }
             }
         })
         .boxed())
     }
 }
+
+/// Cancels the backing task when a stream is dropped before it completes,
+/// e.g. because the client on the other end of an SSE response went away.
+struct CancelOnDrop {
+    orchestrator: Orchestrator,
+    task_id: TaskId,
+    armed: bool,
+}
+
+impl CancelOnDrop {
+    fn new(orchestrator: Orchestrator, task_id: TaskId) -> Self {
+        Self { orchestrator, task_id, armed: true }
+    }
+
+    fn disarm(&mut self) {
+        self.armed = false;
+    }
+}
+
+impl Drop for CancelOnDrop {
+    fn drop(&mut self) {
+        if !self.armed {
+            return;
+        }
+        let Ok(runtime) = tokio::runtime::Handle::try_current() else {
+            return;
+        };
+        let orchestrator = self.orchestrator.clone();
+        let task_id = self.task_id;
+        runtime.spawn(async move {
+            orchestrator.cancel(task_id).await;
+        });
+    }
+}
@@ -1,3 +1,4 @@
+use slab_runtime_core::backend::{BackendError, BackendErrorKind};
 use thiserror::Error;
 
 use super::types::TaskId;
@@ -49,6 +50,12 @@ pub enum RuntimeError {
     #[error("model is not loaded")]
     ModelNotLoaded,
 
+    #[error("backend library is not loaded")]
+    LibraryNotLoaded,
+
+    #[error("preprocessing with '{tool}' failed: {message}")]
+    PreprocessFailed { tool: String, message: String },
+
     #[error("result decode failed for '{task_kind}': {message}")]
     ResultDecodeFailed { task_kind: String, message: String },
 
@@ -102,6 +109,22 @@ impl From<std::io::Error> for RuntimeError {
 }
 
 impl RuntimeError {
+    /// Classify an error reply from a backend worker by its typed kind, so
+    /// readiness and argument failures surface as dedicated variants instead of
+    /// a generic [`RuntimeError::GpuStageFailed`].
+    pub fn from_backend_reply(stage_name: impl Into<String>, error: BackendError) -> Self {
+        match error.kind {
+            BackendErrorKind::ModelNotLoaded => Self::ModelNotLoaded,
+            BackendErrorKind::LibraryNotLoaded => Self::LibraryNotLoaded,
+            BackendErrorKind::InvalidArgument => {
+                Self::InvalidRequestPayload { message: error.message }
+            }
+            BackendErrorKind::Failed => {
+                Self::GpuStageFailed { stage_name: stage_name.into(), message: error.message }
+            }
+        }
+    }
+
     pub fn runtime_code(&self) -> &'static str {
         match self {
             Self::QueueFull { .. } => "runtime_queue_full",
@@ -119,6 +142,8 @@ impl RuntimeError {
             Self::BackendDisabled { .. } => "runtime_backend_disabled",
             Self::InternalPoisoned { .. } => "runtime_internal_poisoned",
             Self::ModelNotLoaded => "runtime_model_not_loaded",
+            Self::LibraryNotLoaded => "runtime_library_not_loaded",
+            Self::PreprocessFailed { .. } => "runtime_preprocess_failed",
             Self::ResultDecodeFailed { .. } => "runtime_result_decode_failed",
             Self::EngineIo(_) => "runtime_engine_io",
             Self::GGMLEngine { .. } => "runtime_ggml_engine",
@@ -147,11 +172,17 @@ impl RuntimeError {
                 "stage_name": stage_name,
                 "message": message,
             }),
-            Self::BackendShutdown | Self::Timeout | Self::Cancelled | Self::ModelNotLoaded => {
-                serde_json::json!({
-                    "message": self.to_string(),
-                })
-            }
+            Self::BackendShutdown
+            | Self::Timeout
+            | Self::Cancelled
+            | Self::ModelNotLoaded
+            | Self::LibraryNotLoaded => serde_json::json!({
+                "message": self.to_string(),
+            }),
+            Self::PreprocessFailed { tool, message } => serde_json::json!({
+                "tool": tool,
+                "message": message,
+            }),
             Self::OrchestratorQueueFull { capacity } => serde_json::json!({
                 "capacity": capacity,
                 "message": self.to_string(),
@@ -195,6 +226,8 @@ impl RuntimeError {
 
 #[cfg(test)]
 mod tests {
+    use slab_runtime_core::backend::{BackendError, BackendErrorKind};
+
     use super::RuntimeError;
 
     #[test]
@@ -219,4 +252,39 @@ mod tests {
                 if component == "candle.llama" && message == "tensor mismatch"
         ));
     }
+
+    #[test]
+    fn backend_replies_classify_by_error_kind() {
+        let model = RuntimeError::from_backend_reply(
+            "ggml.whisper",
+            BackendError::new(BackendErrorKind::ModelNotLoaded, "call model.load first"),
+        );
+        let library = RuntimeError::from_backend_reply(
+            "model.load",
+            BackendError::new(BackendErrorKind::LibraryNotLoaded, "engine not initialized"),
+        );
+        let invalid = RuntimeError::from_backend_reply(
+            "ggml.llama",
+            BackendError::new(BackendErrorKind::InvalidArgument, "token id 99 is out of range"),
+        );
+        // Only the kind decides the variant, never the wording.
+        let other = RuntimeError::from_backend_reply(
+            "ggml.llama",
+            BackendError::from("decode failed: model not loaded"),
+        );
+
+        assert!(matches!(model, RuntimeError::ModelNotLoaded));
+        assert_eq!(model.runtime_code(), "runtime_model_not_loaded");
+        assert!(matches!(library, RuntimeError::LibraryNotLoaded));
+        assert_eq!(library.runtime_code(), "runtime_library_not_loaded");
+        assert!(matches!(
+            invalid,
+            RuntimeError::InvalidRequestPayload { message } if message.contains("out of range")
+        ));
+        assert!(matches!(
+            other,
+            RuntimeError::GpuStageFailed { stage_name, message }
+                if stage_name == "ggml.llama" && message == "decode failed: model not loaded"
+        ));
+    }
 }
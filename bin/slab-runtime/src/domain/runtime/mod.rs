@@ -6,7 +6,10 @@ mod storage;
 mod types;
 
 pub(crate) use error::RuntimeError as CoreError;
-pub(crate) use orchestrator::{DEFAULT_WAIT_TIMEOUT, Orchestrator, STREAM_INIT_TIMEOUT};
+pub(crate) use orchestrator::{
+    DEFAULT_WAIT_TIMEOUT, Orchestrator, STREAM_INIT_TIMEOUT, request_time_remaining,
+    with_request_deadline,
+};
 pub(crate) use pipeline::PipelineBuilder;
 pub(crate) use stage::CpuStage;
 pub(crate) use types::TaskId;
@@ -1,31 +1,56 @@
+use std::collections::HashMap;
 use std::time::Duration;
 
 use slab_runtime_core::Payload;
 use slab_runtime_core::backend::{
-    BackendReply, BackendRequest, ManagementEvent, ResourceManager, StreamHandle,
+    BackendReply, BackendRequest, InferenceLease, InferenceReservation, ManagementEvent, Priority,
+    ResourceManager, StreamHandle,
 };
 use tokio::sync::mpsc;
-use tracing::info;
+use tokio::time::Instant;
+use tracing::{info, warn};
 
 use super::error::RuntimeError as CoreError;
 use super::stage::Stage;
 use super::storage::ResultStorage;
-use super::types::{StageStatus, TaskId, TaskStatus};
+use super::types::{StageStatus, TaskId, TaskStatus, TaskStatusView};
 
 pub const DEFAULT_WAIT_TIMEOUT: Duration = Duration::from_secs(300);
 pub const STREAM_INIT_TIMEOUT: Duration = Duration::from_secs(30);
 
+tokio::task_local! {
+    /// Deadline of the RPC currently being served, set by the gRPC handlers.
+    static REQUEST_DEADLINE: Instant;
+}
+
+/// Runs `fut` with `deadline` bounding every task wait it performs.
+pub(crate) async fn with_request_deadline<F: Future>(deadline: Instant, fut: F) -> F::Output {
+    REQUEST_DEADLINE.scope(deadline, fut).await
+}
+
+/// Time left before the deadline of the RPC being served, if there is one.
+pub(crate) fn request_time_remaining() -> Option<Duration> {
+    REQUEST_DEADLINE.try_with(|deadline| deadline.saturating_duration_since(Instant::now())).ok()
+}
+
 #[cfg(not(test))]
 const GPU_ACQUIRE_TIMEOUT: Duration = Duration::from_secs(30);
 
 #[cfg(test)]
 const GPU_ACQUIRE_TIMEOUT: Duration = Duration::from_millis(200);
 
+/// Longest gap between result eviction sweeps; shorter TTLs sweep more often.
+const RESULT_SWEEP_INTERVAL: Duration = Duration::from_secs(30);
+
 #[derive(Debug)]
 pub enum OrchestratorCommand {
     Submit {
         stages: Vec<Stage>,
         initial_payload: Payload,
+        priority: Priority,
+        metadata: HashMap<String, String>,
+        /// Quota already taken for the first GPU stage by a fail-fast submit.
+        reservation: Option<InferenceReservation>,
         reply_tx: tokio::sync::oneshot::Sender<TaskId>,
     },
 }
@@ -64,9 +89,7 @@ impl Orchestrator {
         match reply {
             BackendReply::Ack => Ok(None),
             BackendReply::Value(payload) => Ok(Some(payload)),
-            BackendReply::Error(message) => {
-                Err(CoreError::GpuStageFailed { stage_name: op_name.to_owned(), message })
-            }
+            BackendReply::Error(error) => Err(CoreError::from_backend_reply(op_name, error)),
             BackendReply::Stream(_) => Err(CoreError::GpuStageFailed {
                 stage_name: op_name.to_owned(),
                 message: "unexpected stream reply on management call".into(),
@@ -88,6 +111,44 @@ impl Orchestrator {
         orchestrator
     }
 
+    /// Drain every registered backend and stop its workers.
+    ///
+    /// Waits for in-flight requests to release their leases; tasks submitted
+    /// afterwards fail because no backend is registered any more.
+    pub async fn shutdown(&self) {
+        // Whatever is still queued or running is abandoned with the backends;
+        // leave a trace of it so the lost work can be attributed.
+        for task in self.list_tasks(|status| !status.is_terminal()).await {
+            warn!(
+                task_id = task.task_id,
+                status = ?task.status,
+                stages = ?task.stage_statuses,
+                metadata = ?task.metadata,
+                "task still in flight at shutdown"
+            );
+        }
+        let backends = self.resource_manager.shutdown().await;
+        info!(?backends, "runtime backends shut down");
+    }
+
+    /// Periodically evict terminal task records older than `ttl` so completed
+    /// results do not accumulate for the lifetime of the process.
+    pub fn spawn_result_sweeper(&self, ttl: Duration) {
+        let storage = self.storage.clone();
+        let period = ttl.min(RESULT_SWEEP_INTERVAL).max(Duration::from_millis(100));
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(period);
+            interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            loop {
+                interval.tick().await;
+                let evicted = storage.evict_expired(ttl, std::time::Instant::now()).await;
+                if evicted > 0 {
+                    tracing::debug!(evicted, ?ttl, "evicted expired task results");
+                }
+            }
+        });
+    }
+
     async fn run_loop(
         mut rx: mpsc::Receiver<OrchestratorCommand>,
         storage: ResultStorage,
@@ -95,15 +156,30 @@ impl Orchestrator {
     ) {
         while let Some(command) = rx.recv().await {
             match command {
-                OrchestratorCommand::Submit { stages, initial_payload, reply_tx } => {
-                    let task_id = storage.create_task(stages.len()).await;
+                OrchestratorCommand::Submit {
+                    stages,
+                    initial_payload,
+                    priority,
+                    metadata,
+                    reservation,
+                    reply_tx,
+                } => {
+                    let task_id = storage.create_task_with_metadata(stages.len(), metadata).await;
                     let _ = reply_tx.send(task_id);
 
                     let task_storage = storage.clone();
                     let task_rm = rm.clone();
                     tokio::spawn(async move {
-                        Self::execute_task(task_id, stages, initial_payload, task_storage, task_rm)
-                            .await;
+                        Self::execute_task(
+                            task_id,
+                            stages,
+                            initial_payload,
+                            priority,
+                            reservation,
+                            task_storage,
+                            task_rm,
+                        )
+                        .await;
                     });
                 }
             }
@@ -114,6 +190,8 @@ impl Orchestrator {
         task_id: TaskId,
         stages: Vec<Stage>,
         initial_payload: Payload,
+        priority: Priority,
+        mut reservation: Option<InferenceReservation>,
         storage: ResultStorage,
         rm: ResourceManager,
     ) {
@@ -148,9 +226,13 @@ impl Orchestrator {
                     }
                 },
                 Stage::Gpu(gpu_stage) => {
-                    let lease = match rm
-                        .acquire_inference_lease(&gpu_stage.backend_id, GPU_ACQUIRE_TIMEOUT)
-                        .await
+                    let lease = match Self::acquire_stage_lease(
+                        &rm,
+                        &gpu_stage.backend_id,
+                        priority,
+                        reservation.take(),
+                    )
+                    .await
                     {
                         Ok(lease) => lease,
                         Err(error) => {
@@ -162,7 +244,7 @@ impl Orchestrator {
                         }
                     };
 
-                    let result = gpu_stage.run(payload, cancel_rx.clone(), &rm).await;
+                    let result = gpu_stage.run(payload, cancel_rx.clone(), priority, &rm).await;
                     drop(lease);
 
                     match result {
@@ -178,9 +260,13 @@ impl Orchestrator {
                     }
                 }
                 Stage::GpuStream(stream_stage) => {
-                    let lease = match rm
-                        .acquire_inference_lease(&stream_stage.backend_id, GPU_ACQUIRE_TIMEOUT)
-                        .await
+                    let lease = match Self::acquire_stage_lease(
+                        &rm,
+                        &stream_stage.backend_id,
+                        priority,
+                        reservation.take(),
+                    )
+                    .await
                     {
                         Ok(lease) => lease,
                         Err(error) => {
@@ -192,7 +278,7 @@ impl Orchestrator {
                         }
                     };
 
-                    let result = stream_stage.run(payload, cancel_rx.clone(), &rm).await;
+                    let result = stream_stage.run(payload, cancel_rx.clone(), priority, &rm).await;
                     drop(lease);
 
                     match result {
@@ -216,24 +302,77 @@ impl Orchestrator {
         info!(task_id, "task succeeded");
     }
 
+    /// Use the quota reserved at submit time, or wait for a lease like any
+    /// other queued request.
+    async fn acquire_stage_lease(
+        rm: &ResourceManager,
+        backend_id: &str,
+        priority: Priority,
+        reservation: Option<InferenceReservation>,
+    ) -> Result<InferenceLease, slab_runtime_core::CoreError> {
+        match reservation {
+            Some(reservation) => Ok(reservation.into_lease().await),
+            None => {
+                rm.acquire_inference_lease_with_priority(backend_id, priority, GPU_ACQUIRE_TIMEOUT)
+                    .await
+            }
+        }
+    }
+
+    /// Take a free admission slot on the backend of the first GPU stage, failing
+    /// with [`CoreError::Busy`] instead of queueing when it has none. Later GPU
+    /// stages queue for their lease as usual.
+    pub fn reserve_admission(
+        &self,
+        stages: &[Stage],
+    ) -> Result<Option<InferenceReservation>, CoreError> {
+        let backend_id = stages.iter().find_map(|stage| match stage {
+            Stage::Cpu(_) => None,
+            Stage::Gpu(stage) => Some(&stage.backend_id),
+            Stage::GpuStream(stage) => Some(&stage.backend_id),
+        });
+        match backend_id {
+            Some(backend_id) => Ok(Some(self.resource_manager.try_reserve_inference(backend_id)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Enqueue a task. Without `submit_timeout` a full orchestrator queue is
+    /// rejected immediately; otherwise the enqueue waits up to that long.
     pub async fn submit(
         &self,
         stages: Vec<Stage>,
         initial_payload: Payload,
+        priority: Priority,
+        metadata: HashMap<String, String>,
+        reservation: Option<InferenceReservation>,
+        submit_timeout: Option<Duration>,
     ) -> Result<TaskId, CoreError> {
         let (reply_tx, reply_rx) = tokio::sync::oneshot::channel();
-        self.storage
-            .submit_tx()
-            .try_send(OrchestratorCommand::Submit { stages, initial_payload, reply_tx })
-            .map_err(|error| {
-                let capacity = self.storage.submit_tx().max_capacity();
-                match error {
-                    mpsc::error::TrySendError::Full(_) => {
+        let command = OrchestratorCommand::Submit {
+            stages,
+            initial_payload,
+            priority,
+            metadata,
+            reservation,
+            reply_tx,
+        };
+        let submit_tx = self.storage.submit_tx();
+        let capacity = submit_tx.max_capacity();
+        match submit_timeout {
+            None => submit_tx.try_send(command).map_err(|error| match error {
+                mpsc::error::TrySendError::Full(_) => CoreError::OrchestratorQueueFull { capacity },
+                mpsc::error::TrySendError::Closed(_) => CoreError::BackendShutdown,
+            })?,
+            Some(timeout) => {
+                submit_tx.send_timeout(command, timeout).await.map_err(|error| match error {
+                    mpsc::error::SendTimeoutError::Timeout(_) => {
                         CoreError::OrchestratorQueueFull { capacity }
                     }
-                    mpsc::error::TrySendError::Closed(_) => CoreError::BackendShutdown,
-                }
-            })?;
+                    mpsc::error::SendTimeoutError::Closed(_) => CoreError::BackendShutdown,
+                })?
+            }
+        }
 
         reply_rx.await.map_err(|_| CoreError::BackendShutdown)
     }
@@ -245,6 +384,20 @@ impl Orchestrator {
         self.storage.remove_task(task_id).await;
     }
 
+    /// Signal cancellation and record the task as cancelled.
+    ///
+    /// Unlike [`Orchestrator::cancel_and_purge`] the record is kept so the
+    /// cancellation stays observable until the result sweeper evicts it. A task
+    /// that already finished keeps its final status.
+    pub async fn cancel(&self, task_id: TaskId) {
+        if !self.storage.mark_cancelled(task_id).await {
+            return;
+        }
+        if let Some(cancel_tx) = self.storage.get_cancel_tx(task_id).await {
+            let _ = cancel_tx.send(true);
+        }
+    }
+
     pub async fn load_model_backend_with_reply(
         &self,
         backend_id: &str,
@@ -274,6 +427,12 @@ impl Orchestrator {
         self.storage.get_status(task_id).await.ok_or(CoreError::TaskNotFound { task_id })
     }
 
+    /// List tracked tasks whose status matches `include`, e.g. `|_| true` for
+    /// all of them or `|status| !status.is_terminal()` for in-flight work.
+    pub async fn list_tasks(&self, include: impl Fn(&TaskStatus) -> bool) -> Vec<TaskStatusView> {
+        self.storage.list(include).await
+    }
+
     pub async fn purge_task(&self, task_id: TaskId) {
         self.storage.remove_task(task_id).await;
     }
@@ -324,6 +483,10 @@ impl Orchestrator {
                 stage_name: "result".into(),
                 message: "task result has already been consumed".into(),
             }),
+            TaskStatus::Evicted => Err(CoreError::GpuStageFailed {
+                stage_name: "result".into(),
+                message: "task result has been evicted after its retention period".into(),
+            }),
             TaskStatus::Failed { error } => Err(error),
             TaskStatus::Cancelled => Err(CoreError::Cancelled),
             TaskStatus::SucceededStreaming => Err(CoreError::GpuStageFailed {
@@ -350,6 +513,13 @@ impl Orchestrator {
                             message: "non-streaming task has no stream".into(),
                         });
                     }
+                    TaskStatus::Evicted => {
+                        return Err(CoreError::GpuStageFailed {
+                            stage_name: "stream".into(),
+                            message: "task result has been evicted after its retention period"
+                                .into(),
+                        });
+                    }
                     TaskStatus::Failed { error } => return Err(error),
                     TaskStatus::Cancelled => return Err(CoreError::Cancelled),
                     _ => tokio::time::sleep(Duration::from_millis(5)).await,
@@ -373,11 +543,33 @@ impl Orchestrator {
 
 #[cfg(test)]
 mod tests {
+    use std::sync::Arc;
+
     use slab_runtime_core::Payload;
-    use slab_runtime_core::backend::StreamChunk;
+    use slab_runtime_core::backend::{RequestRoute, StreamChunk};
     use tokio::sync::mpsc;
 
     use super::*;
+    use crate::domain::models::{TaskCodec, TaskHandle};
+
+    struct TokenCodec;
+
+    impl TaskCodec<Payload, String> for TokenCodec {
+        fn route(&self) -> RequestRoute {
+            RequestRoute::InferenceStream
+        }
+
+        fn decode_result(&self, payload: Payload) -> Result<Payload, CoreError> {
+            Ok(payload)
+        }
+
+        fn decode_chunk(&self, chunk: StreamChunk) -> Result<Option<String>, CoreError> {
+            match chunk {
+                StreamChunk::Token(token) => Ok(Some(token)),
+                _ => Ok(None),
+            }
+        }
+    }
 
     fn orchestrator_with_storage() -> (Orchestrator, ResultStorage) {
         let (submit_tx, _submit_rx) = mpsc::channel::<OrchestratorCommand>(1);
@@ -410,6 +602,224 @@ mod tests {
         ));
     }
 
+    #[tokio::test]
+    async fn high_priority_task_runs_before_queued_low_priority_task() {
+        use std::sync::{Arc, Mutex};
+
+        use slab_runtime_core::backend::{BackendOp, BackendReply, ResourceManagerConfig};
+
+        use crate::domain::runtime::PipelineBuilder;
+
+        let order = Arc::new(Mutex::new(Vec::new()));
+        let mut rm = ResourceManager::with_config(ResourceManagerConfig {
+            backend_capacity: 1,
+            ..ResourceManagerConfig::default()
+        });
+        let backend_order = Arc::clone(&order);
+        rm.register_backend("echo", move |shared_rx, _control_tx| {
+            tokio::spawn(async move {
+                while let Ok(request) = shared_rx.recv_async().await {
+                    let text = request.input.to_str().expect("text input").to_owned();
+                    backend_order.lock().expect("order lock").push(text);
+                    let _ = request.reply_tx.send(BackendReply::value(request.input));
+                }
+            });
+        });
+        let orchestrator = Orchestrator::start(rm.clone(), 8);
+
+        let blocker = rm
+            .acquire_inference_lease("echo", Duration::from_secs(1))
+            .await
+            .expect("blocking lease");
+        let mut task_ids = Vec::new();
+        for (text, priority) in [("low", Priority::Low), ("high", Priority::High)] {
+            let task_id = PipelineBuilder::new(orchestrator.clone(), Payload::from(text))
+                .priority(priority)
+                .gpu("echo", "echo", BackendOp::new("inference", Payload::None))
+                .run()
+                .await
+                .expect("submit task");
+            task_ids.push(task_id);
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        }
+        drop(blocker);
+
+        for task_id in task_ids {
+            orchestrator.wait_result(task_id, Duration::from_secs(1)).await.expect("task result");
+        }
+        assert_eq!(*order.lock().expect("order lock"), vec!["high".to_owned(), "low".to_owned()]);
+    }
+
+    #[tokio::test]
+    async fn try_run_returns_busy_when_backend_is_saturated() {
+        use slab_runtime_core::backend::{BackendOp, ResourceManagerConfig};
+
+        use crate::domain::runtime::PipelineBuilder;
+
+        let mut rm = ResourceManager::with_config(ResourceManagerConfig {
+            backend_capacity: 1,
+            ..ResourceManagerConfig::default()
+        });
+        rm.register_backend("echo", |shared_rx, _control_tx| {
+            tokio::spawn(async move {
+                while let Ok(request) = shared_rx.recv_async().await {
+                    let _ = request.reply_tx.send(BackendReply::value(request.input));
+                }
+            });
+        });
+        let orchestrator = Orchestrator::start(rm.clone(), 8);
+        let pipeline = || {
+            PipelineBuilder::new(orchestrator.clone(), Payload::from("hello")).gpu(
+                "echo",
+                "echo",
+                BackendOp::new("inference", Payload::None),
+            )
+        };
+
+        let blocker = rm.acquire_inference_lease("echo", Duration::from_secs(1)).await.unwrap();
+        let error = tokio::time::timeout(Duration::from_millis(100), pipeline().try_run())
+            .await
+            .expect("try_run must not block on a saturated backend")
+            .expect_err("saturated backend should be rejected");
+        assert!(matches!(error, CoreError::Busy { ref backend_id } if backend_id == "echo"));
+        drop(blocker);
+
+        let task_id = pipeline().try_run().await.expect("free backend should accept the task");
+        orchestrator.wait_result(task_id, Duration::from_secs(1)).await.expect("task result");
+    }
+
+    #[tokio::test]
+    async fn try_run_holds_the_slot_while_earlier_cpu_stages_run() {
+        use slab_runtime_core::backend::{BackendOp, ResourceManagerConfig};
+
+        use crate::domain::runtime::{CpuStage, PipelineBuilder};
+
+        let mut rm = ResourceManager::with_config(ResourceManagerConfig {
+            backend_capacity: 1,
+            ..ResourceManagerConfig::default()
+        });
+        rm.register_backend("echo", |shared_rx, _control_tx| {
+            tokio::spawn(async move {
+                while let Ok(request) = shared_rx.recv_async().await {
+                    let _ = request.reply_tx.send(BackendReply::value(request.input));
+                }
+            });
+        });
+        let orchestrator = Orchestrator::start(rm.clone(), 8);
+        let pipeline = || {
+            let decode = CpuStage::new("decode", |payload| {
+                std::thread::sleep(Duration::from_millis(100));
+                Ok(payload)
+            });
+            PipelineBuilder::new(orchestrator.clone(), Payload::from("hello"))
+                .cpu_stage(decode)
+                .gpu("echo", "echo", BackendOp::new("inference", Payload::None))
+        };
+
+        let first = pipeline().try_run().await.expect("first task takes the free slot");
+        let error = pipeline().try_run().await.expect_err("slot is reserved by the first task");
+        assert!(matches!(error, CoreError::Busy { ref backend_id } if backend_id == "echo"));
+
+        orchestrator.wait_result(first, Duration::from_secs(1)).await.expect("task result");
+        assert_eq!(rm.backend_stats("echo").expect("stats").in_flight, 0);
+    }
+
+    #[tokio::test]
+    async fn submitted_metadata_is_reported_with_the_task() {
+        use crate::domain::runtime::PipelineBuilder;
+
+        let orchestrator = Orchestrator::start(ResourceManager::new(), 8);
+        let task_id = PipelineBuilder::new(orchestrator.clone(), Payload::None)
+            .metadata("tenant", "acme")
+            .metadata("user_id", "42")
+            .run()
+            .await
+            .expect("submit task");
+
+        let tasks = orchestrator.list_tasks(|_| true).await;
+        assert_eq!(tasks.len(), 1);
+        assert_eq!(tasks[0].task_id, task_id);
+        let metadata = &tasks[0].metadata;
+        assert_eq!(metadata.len(), 2);
+        assert_eq!(metadata.get("tenant").map(String::as_str), Some("acme"));
+        assert_eq!(metadata.get("user_id").map(String::as_str), Some("42"));
+    }
+
+    #[tokio::test]
+    async fn list_tasks_reports_submitted_tasks_and_applies_the_filter() {
+        let (submit_tx, _submit_rx) = mpsc::channel::<OrchestratorCommand>(1);
+        let storage = ResultStorage::new(submit_tx);
+        let orchestrator =
+            Orchestrator { storage: storage.clone(), resource_manager: ResourceManager::new() };
+        let running = storage.create_task(1).await;
+        storage.set_status(running, TaskStatus::Running).await;
+        let done = storage.create_task(0).await;
+        storage.set_status(done, TaskStatus::Succeeded { result: Payload::None }).await;
+
+        let all = orchestrator.list_tasks(|_| true).await;
+        assert_eq!(all.iter().map(|task| task.task_id).collect::<Vec<_>>(), [running, done]);
+        assert_eq!(all[0].stage_statuses.len(), 1);
+
+        let in_flight = orchestrator.list_tasks(|status| !status.is_terminal()).await;
+        assert_eq!(in_flight.len(), 1);
+        assert_eq!(in_flight[0].task_id, running);
+        assert!(matches!(in_flight[0].status, TaskStatus::Running));
+    }
+
+    #[tokio::test]
+    async fn submit_timeout_bounds_wait_for_orchestrator_queue() {
+        use crate::domain::runtime::PipelineBuilder;
+
+        // No run loop drains this channel, so the single slot stays taken.
+        let (submit_tx, _submit_rx) = mpsc::channel::<OrchestratorCommand>(1);
+        let storage = ResultStorage::new(submit_tx.clone());
+        let orchestrator = Orchestrator { storage, resource_manager: ResourceManager::new() };
+        let (reply_tx, _reply_rx) = tokio::sync::oneshot::channel();
+        submit_tx
+            .try_send(OrchestratorCommand::Submit {
+                stages: Vec::new(),
+                initial_payload: Payload::None,
+                priority: Priority::default(),
+                metadata: HashMap::new(),
+                reservation: None,
+                reply_tx,
+            })
+            .expect("fill queue");
+
+        let started = std::time::Instant::now();
+        let error = PipelineBuilder::new(orchestrator, Payload::None)
+            .submit_timeout(Duration::from_millis(30))
+            .run()
+            .await
+            .expect_err("full queue should time out");
+
+        assert!(matches!(error, CoreError::OrchestratorQueueFull { capacity: 1 }));
+        assert!(started.elapsed() >= Duration::from_millis(30));
+    }
+
+    #[tokio::test]
+    async fn cpu_stage_timeout_fails_task_with_timeout() {
+        use crate::domain::runtime::{CpuStage, PipelineBuilder};
+
+        let orchestrator = Orchestrator::start(ResourceManager::new(), 8);
+        let sleepy = CpuStage::new("sleepy", |payload| {
+            std::thread::sleep(Duration::from_millis(300));
+            Ok(payload)
+        });
+
+        let task_id = PipelineBuilder::new(orchestrator.clone(), Payload::from("audio"))
+            .cpu_stage_timeout(sleepy, Duration::from_millis(20))
+            .run()
+            .await
+            .expect("submit task");
+        let status = orchestrator
+            .wait_terminal(task_id, Duration::from_secs(1))
+            .await
+            .expect("task should reach a terminal status");
+
+        assert!(matches!(status, TaskStatus::Failed { error: CoreError::Timeout }));
+    }
+
     #[tokio::test]
     async fn wait_result_rejects_streaming_tasks() {
         let (orchestrator, storage) = orchestrator_with_storage();
@@ -485,6 +895,89 @@ mod tests {
         assert!(matches!(stream.recv().await, Some(StreamChunk::Done)));
     }
 
+    #[tokio::test]
+    async fn task_handle_waits_stop_at_the_request_deadline() {
+        let (orchestrator, storage) = orchestrator_with_storage();
+        let task_id = storage.create_task(0).await;
+        let handle = TaskHandle::new(orchestrator.clone(), task_id, Arc::new(TokenCodec));
+
+        let started = std::time::Instant::now();
+        let deadline = Instant::now() + Duration::from_millis(20);
+        let err = with_request_deadline(deadline, handle.result())
+            .await
+            .expect_err("pending task should time out at the request deadline");
+
+        assert!(matches!(err, CoreError::Timeout));
+        assert!(started.elapsed() < Duration::from_secs(1));
+        assert!(request_time_remaining().is_none());
+    }
+
+    #[tokio::test]
+    async fn dropping_task_stream_cancels_backing_task() {
+        use futures::StreamExt;
+
+        let (orchestrator, storage) = orchestrator_with_storage();
+        let task_id = storage.create_task(0).await;
+        let mut cancel_rx =
+            storage.get_cancel_tx(task_id).await.expect("cancel sender").subscribe();
+        let (tx, rx) = mpsc::channel(4);
+        storage.set_stream_handle(task_id, rx).await;
+        storage.set_status(task_id, TaskStatus::SucceededStreaming).await;
+        tx.send(StreamChunk::Token("hello".into())).await.expect("send first chunk");
+        tx.send(StreamChunk::Token("world".into())).await.expect("send second chunk");
+
+        let handle = TaskHandle::new(orchestrator.clone(), task_id, Arc::new(TokenCodec));
+        let mut stream =
+            handle.take_stream_timeout(Duration::from_millis(10)).await.expect("stream");
+        assert_eq!(stream.next().await.expect("first chunk").expect("token"), "hello");
+        drop(stream);
+
+        tokio::time::timeout(Duration::from_secs(1), cancel_rx.wait_for(|cancelled| *cancelled))
+            .await
+            .expect("cancel signal should be sent")
+            .expect("cancel channel open");
+        tokio::time::timeout(Duration::from_secs(1), async {
+            while !matches!(orchestrator.get_status(task_id).await, Ok(TaskStatus::Cancelled)) {
+                tokio::time::sleep(Duration::from_millis(5)).await;
+            }
+        })
+        .await
+        .expect("task should transition to cancelled");
+    }
+
+    #[tokio::test]
+    async fn cancel_keeps_the_status_of_finished_tasks() {
+        let (orchestrator, storage) = orchestrator_with_storage();
+        let succeeded_task = storage.create_task(0).await;
+        storage
+            .set_status(succeeded_task, TaskStatus::Succeeded { result: Payload::from("done") })
+            .await;
+        let failed_task = storage.create_task(0).await;
+        storage.set_status(failed_task, TaskStatus::Failed { error: CoreError::Timeout }).await;
+        let consumed_task = storage.create_task(0).await;
+        storage.set_status(consumed_task, TaskStatus::ResultConsumed).await;
+        let running_task = storage.create_task(0).await;
+        storage.set_status(running_task, TaskStatus::Running).await;
+
+        for task_id in [succeeded_task, failed_task, consumed_task, running_task] {
+            orchestrator.cancel(task_id).await;
+        }
+
+        assert!(matches!(
+            orchestrator.get_status(succeeded_task).await,
+            Ok(TaskStatus::Succeeded { .. })
+        ));
+        assert!(matches!(
+            orchestrator.get_status(failed_task).await,
+            Ok(TaskStatus::Failed { error: CoreError::Timeout })
+        ));
+        assert!(matches!(
+            orchestrator.get_status(consumed_task).await,
+            Ok(TaskStatus::ResultConsumed)
+        ));
+        assert!(matches!(orchestrator.get_status(running_task).await, Ok(TaskStatus::Cancelled)));
+    }
+
     #[tokio::test]
     async fn wait_stream_rejects_non_streaming_tasks() {
         let (orchestrator, storage) = orchestrator_with_storage();
@@ -508,6 +1001,24 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn wait_stream_reports_evicted_tasks() {
+        let (orchestrator, storage) = orchestrator_with_storage();
+        let task_id = storage.create_task(0).await;
+        storage.set_status(task_id, TaskStatus::Evicted).await;
+
+        let err = orchestrator
+            .wait_stream(task_id, Duration::from_millis(10))
+            .await
+            .expect_err("evicted task should not produce a stream");
+
+        assert!(matches!(
+            err,
+            CoreError::GpuStageFailed { stage_name, message }
+                if stage_name == "stream" && message.contains("evicted")
+        ));
+    }
+
     #[tokio::test]
     async fn wait_stream_propagates_failed_and_cancelled_statuses() {
         let (orchestrator, storage) = orchestrator_with_storage();
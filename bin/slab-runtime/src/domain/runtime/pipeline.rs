@@ -1,7 +1,9 @@
+use std::collections::HashMap;
 use std::marker::PhantomData;
+use std::time::Duration;
 
 use slab_runtime_core::Payload;
-use slab_runtime_core::backend::BackendOp;
+use slab_runtime_core::backend::{BackendOp, InferenceReservation, Priority};
 
 use super::error::RuntimeError as CoreError;
 use super::orchestrator::Orchestrator;
@@ -16,12 +18,63 @@ pub struct PipelineBuilder<S = NoStream> {
     orchestrator: Orchestrator,
     stages: Vec<Stage>,
     initial_payload: Payload,
+    priority: Priority,
+    metadata: HashMap<String, String>,
+    submit_timeout: Option<Duration>,
     _state: PhantomData<S>,
 }
 
+impl<S> PipelineBuilder<S> {
+    /// Admission priority used when the task's GPU stages compete for backend capacity.
+    pub fn priority(mut self, priority: Priority) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// Attach a key/value tag to the task, reported with it by
+    /// [`Orchestrator::list_tasks`]; a repeated key overwrites the old value.
+    pub fn metadata(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.metadata.insert(key.into(), value.into());
+        self
+    }
+
+    /// Wait up to `timeout` for room in the orchestrator queue instead of
+    /// failing with [`CoreError::OrchestratorQueueFull`] as soon as it is full.
+    pub fn submit_timeout(mut self, timeout: Duration) -> Self {
+        self.submit_timeout = Some(timeout);
+        self
+    }
+
+    async fn submit(self, reservation: Option<InferenceReservation>) -> Result<TaskId, CoreError> {
+        self.orchestrator
+            .submit(
+                self.stages,
+                self.initial_payload,
+                self.priority,
+                self.metadata,
+                reservation,
+                self.submit_timeout,
+            )
+            .await
+    }
+
+    async fn try_submit(self) -> Result<TaskId, CoreError> {
+        let reservation = self.orchestrator.reserve_admission(&self.stages)?;
+        self.submit(reservation).await
+    }
+}
+
 impl PipelineBuilder<NoStream> {
     pub fn new(orchestrator: Orchestrator, initial_payload: Payload) -> Self {
-        Self { orchestrator, stages: Vec::new(), initial_payload, _state: PhantomData }
+        Self {
+            orchestrator,
+            stages: Vec::new(),
+            initial_payload,
+            priority: Priority::default(),
+            metadata: HashMap::new(),
+            submit_timeout: None,
+            _state: PhantomData,
+        }
     }
 
     pub fn cpu_stage(mut self, stage: CpuStage) -> Self {
@@ -29,6 +82,12 @@ impl PipelineBuilder<NoStream> {
         self
     }
 
+    /// Append `stage`, failing the task with [`CoreError::Timeout`] if it runs
+    /// longer than `timeout`.
+    pub fn cpu_stage_timeout(self, stage: CpuStage, timeout: Duration) -> Self {
+        self.cpu_stage(stage.with_timeout(timeout))
+    }
+
     pub fn gpu(
         mut self,
         name: impl Into<String>,
@@ -58,17 +117,33 @@ impl PipelineBuilder<NoStream> {
             orchestrator: self.orchestrator,
             stages: self.stages,
             initial_payload: self.initial_payload,
+            priority: self.priority,
+            metadata: self.metadata,
+            submit_timeout: self.submit_timeout,
             _state: PhantomData,
         }
     }
 
     pub async fn run(self) -> Result<TaskId, CoreError> {
-        self.orchestrator.submit(self.stages, self.initial_payload).await
+        self.submit(None).await
+    }
+
+    /// Like [`Self::run`], but fail with [`CoreError::Busy`] instead of
+    /// queueing when the first GPU stage's backend has no free capacity. The
+    /// reserved slot is held until that stage runs.
+    pub async fn try_run(self) -> Result<TaskId, CoreError> {
+        self.try_submit().await
     }
 }
 
 impl PipelineBuilder<HasStream> {
     pub async fn run_stream(self) -> Result<TaskId, CoreError> {
-        self.orchestrator.submit(self.stages, self.initial_payload).await
+        self.submit(None).await
+    }
+
+    /// Like [`Self::run_stream`], but fail with [`CoreError::Busy`] instead of
+    /// queueing when the backend has no free capacity.
+    pub async fn try_run_stream(self) -> Result<TaskId, CoreError> {
+        self.try_submit().await
     }
 }
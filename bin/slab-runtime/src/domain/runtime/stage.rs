@@ -1,8 +1,9 @@
 use std::sync::Arc;
+use std::time::Duration;
 
 use slab_runtime_core::Payload;
 use slab_runtime_core::backend::{
-    BackendOp, BackendReply, BackendRequest, ResourceManager, StreamHandle,
+    BackendOp, BackendReply, BackendRequest, Priority, ResourceManager, StreamHandle,
 };
 
 use super::error::RuntimeError as CoreError;
@@ -20,11 +21,16 @@ pub enum Stage {
 pub struct CpuStage {
     pub name: String,
     pub work: CpuFn,
+    /// Upper bound on the stage's wall-clock time; `None` waits indefinitely.
+    pub timeout: Option<Duration>,
 }
 
 impl std::fmt::Debug for CpuStage {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CpuStage").field("name", &self.name).finish()
+        f.debug_struct("CpuStage")
+            .field("name", &self.name)
+            .field("timeout", &self.timeout)
+            .finish()
     }
 }
 
@@ -33,21 +39,37 @@ impl CpuStage {
         name: impl Into<String>,
         work: impl Fn(Payload) -> Result<Payload, CoreError> + Send + Sync + 'static,
     ) -> Self {
-        Self { name: name.into(), work: Arc::new(work) }
+        Self { name: name.into(), work: Arc::new(work), timeout: None }
+    }
+
+    /// Fail the stage with [`CoreError::Timeout`] if `work` has not finished
+    /// within `timeout`.
+    ///
+    /// The blocking closure itself cannot be interrupted; it keeps running on
+    /// the blocking pool but its result is discarded.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
     }
 
     pub async fn run(&self, input: Payload) -> Result<Payload, CoreError> {
         let work = Arc::clone(&self.work);
         let name = self.name.clone();
-        let result = tokio::task::spawn_blocking(move || work(input)).await.map_err(|_| {
-            CoreError::CpuStageFailed {
-                stage_name: name.clone(),
-                message: "spawn_blocking task panicked".into(),
-            }
+        let handle = tokio::task::spawn_blocking(move || work(input));
+        let joined = match self.timeout {
+            Some(timeout) => tokio::time::timeout(timeout, handle).await.map_err(|_| {
+                tracing::warn!(stage_name = %name, ?timeout, "cpu stage timed out");
+                CoreError::Timeout
+            })?,
+            None => handle.await,
+        };
+        let result = joined.map_err(|_| CoreError::CpuStageFailed {
+            stage_name: name.clone(),
+            message: "spawn_blocking task panicked".into(),
         })?;
 
         result.map_err(|error| match error {
-            CoreError::CpuStageFailed { .. } => error,
+            CoreError::CpuStageFailed { .. } | CoreError::PreprocessFailed { .. } => error,
             other => CoreError::CpuStageFailed { stage_name: name, message: other.to_string() },
         })
     }
@@ -65,11 +87,13 @@ impl GpuStage {
         &self,
         input: Payload,
         cancel_rx: tokio::sync::watch::Receiver<bool>,
+        priority: Priority,
         rm: &ResourceManager,
     ) -> Result<Payload, CoreError> {
         let (reply_tx, reply_rx) = tokio::sync::oneshot::channel();
         let ingress_tx = rm.ingress_tx(&self.backend_id)?;
-        let req = BackendRequest::inference(self.op.clone(), input, cancel_rx, reply_tx);
+        let req = BackendRequest::inference(self.op.clone(), input, cancel_rx, reply_tx)
+            .with_priority(priority);
 
         ingress_tx.try_send(req).map_err(|error| {
             let capacity = ingress_tx.capacity().unwrap_or(0);
@@ -88,8 +112,8 @@ impl GpuStage {
                 stage_name: self.name.clone(),
                 message: "unexpected ack reply on non-management stage".into(),
             }),
-            BackendReply::Error(message) => {
-                Err(CoreError::GpuStageFailed { stage_name: self.name.clone(), message })
+            BackendReply::Error(error) => {
+                Err(CoreError::from_backend_reply(self.name.clone(), error))
             }
             BackendReply::Stream(_) => Err(CoreError::GpuStageFailed {
                 stage_name: self.name.clone(),
@@ -111,11 +135,13 @@ impl GpuStreamStage {
         &self,
         input: Payload,
         cancel_rx: tokio::sync::watch::Receiver<bool>,
+        priority: Priority,
         rm: &ResourceManager,
     ) -> Result<StreamHandle, CoreError> {
         let (reply_tx, reply_rx) = tokio::sync::oneshot::channel();
         let ingress_tx = rm.ingress_tx(&self.backend_id)?;
-        let req = BackendRequest::inference(self.op.clone(), input, cancel_rx, reply_tx);
+        let req = BackendRequest::inference(self.op.clone(), input, cancel_rx, reply_tx)
+            .with_priority(priority);
 
         ingress_tx.try_send(req).map_err(|error| {
             let capacity = ingress_tx.capacity().unwrap_or(0);
@@ -134,8 +160,8 @@ impl GpuStreamStage {
                 stage_name: self.name.clone(),
                 message: "unexpected ack reply on streaming stage".into(),
             }),
-            BackendReply::Error(message) => {
-                Err(CoreError::GpuStageFailed { stage_name: self.name.clone(), message })
+            BackendReply::Error(error) => {
+                Err(CoreError::from_backend_reply(self.name.clone(), error))
             }
             BackendReply::Value(_) => Err(CoreError::GpuStageFailed {
                 stage_name: self.name.clone(),
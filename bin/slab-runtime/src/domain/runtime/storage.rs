@@ -1,12 +1,13 @@
 use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 use slab_runtime_core::Payload;
 use slab_runtime_core::backend::StreamHandle;
 use tokio::sync::{RwLock, mpsc};
 
 use super::orchestrator::OrchestratorCommand;
-use super::types::{StageStatus, TaskId, TaskStatus};
+use super::types::{StageStatus, TaskId, TaskStatus, TaskStatusView};
 
 #[derive(Debug)]
 pub struct TaskRecord {
@@ -14,6 +15,10 @@ pub struct TaskRecord {
     pub stage_statuses: Vec<StageStatus>,
     pub stream_handle: Option<StreamHandle>,
     pub cancel_tx: Arc<tokio::sync::watch::Sender<bool>>,
+    /// When the task first reached a terminal status; drives result eviction.
+    pub finished_at: Option<Instant>,
+    /// Caller-supplied tags (tenant, user id, ...) attached at submission.
+    pub metadata: HashMap<String, String>,
 }
 
 #[derive(Debug, Clone)]
@@ -33,6 +38,14 @@ impl ResultStorage {
     }
 
     pub async fn create_task(&self, num_stages: usize) -> TaskId {
+        self.create_task_with_metadata(num_stages, HashMap::new()).await
+    }
+
+    pub async fn create_task_with_metadata(
+        &self,
+        num_stages: usize,
+        metadata: HashMap<String, String>,
+    ) -> TaskId {
         let task_id = self.next_id.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
         let (cancel_tx, _cancel_rx) = tokio::sync::watch::channel(false);
 
@@ -41,6 +54,8 @@ impl ResultStorage {
             stage_statuses: vec![StageStatus::Pending; num_stages],
             stream_handle: None,
             cancel_tx: Arc::new(cancel_tx),
+            finished_at: None,
+            metadata,
         };
 
         self.inner.write().await.insert(task_id, record);
@@ -49,10 +64,32 @@ impl ResultStorage {
 
     pub async fn set_status(&self, task_id: TaskId, status: TaskStatus) {
         if let Some(record) = self.inner.write().await.get_mut(&task_id) {
+            if status.is_terminal() && record.finished_at.is_none() {
+                record.finished_at = Some(Instant::now());
+            }
             record.status = status;
         }
     }
 
+    /// Record `Cancelled` unless the task already finished. A streaming task
+    /// is still producing chunks, so it counts as unfinished. Returns whether
+    /// the status changed.
+    pub async fn mark_cancelled(&self, task_id: TaskId) -> bool {
+        let mut guard = self.inner.write().await;
+        let Some(record) = guard.get_mut(&task_id) else {
+            return false;
+        };
+        if !matches!(
+            record.status,
+            TaskStatus::Pending | TaskStatus::Running | TaskStatus::SucceededStreaming
+        ) {
+            return false;
+        }
+        record.status = TaskStatus::Cancelled;
+        record.finished_at.get_or_insert_with(Instant::now);
+        true
+    }
+
     pub async fn set_stage_status(&self, task_id: TaskId, stage_index: usize, status: StageStatus) {
         if let Some(record) = self.inner.write().await.get_mut(&task_id)
             && let Some(stage) = record.stage_statuses.get_mut(stage_index)
@@ -80,6 +117,25 @@ impl ResultStorage {
         Some(record.status.clone())
     }
 
+    /// Snapshot every task whose status matches `include`, ordered by id.
+    pub async fn list(&self, include: impl Fn(&TaskStatus) -> bool) -> Vec<TaskStatusView> {
+        let mut tasks: Vec<TaskStatusView> = self
+            .inner
+            .read()
+            .await
+            .iter()
+            .filter(|(_, record)| include(&record.status))
+            .map(|(&task_id, record)| TaskStatusView {
+                task_id,
+                status: record.status.clone(),
+                stage_statuses: record.stage_statuses.clone(),
+                metadata: record.metadata.clone(),
+            })
+            .collect();
+        tasks.sort_by_key(|task| task.task_id);
+        tasks
+    }
+
     pub async fn take_result(&self, task_id: TaskId) -> Option<Payload> {
         let mut guard = self.inner.write().await;
         let record = guard.get_mut(&task_id)?;
@@ -99,7 +155,77 @@ impl ResultStorage {
         self.inner.write().await.remove(&task_id);
     }
 
+    /// Evict terminal records that finished at least `ttl` before `now`.
+    ///
+    /// Evicted records keep a [`TaskStatus::Evicted`] tombstone so callers can
+    /// tell eviction apart from an unknown task; the tombstone itself is
+    /// removed after another `ttl`. Returns the number of records touched.
+    pub async fn evict_expired(&self, ttl: Duration, now: Instant) -> usize {
+        let mut guard = self.inner.write().await;
+        let mut touched = 0;
+        guard.retain(|_, record| {
+            let Some(finished_at) = record.finished_at else {
+                return true;
+            };
+            if now.saturating_duration_since(finished_at) < ttl {
+                return true;
+            }
+
+            touched += 1;
+            if matches!(record.status, TaskStatus::Evicted) {
+                return false;
+            }
+            record.status = TaskStatus::Evicted;
+            record.stage_statuses.clear();
+            record.stream_handle = None;
+            record.finished_at = Some(now);
+            true
+        });
+        touched
+    }
+
     pub fn submit_tx(&self) -> mpsc::Sender<OrchestratorCommand> {
         self.submit_tx.clone()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn storage() -> ResultStorage {
+        let (submit_tx, _submit_rx) = mpsc::channel(1);
+        ResultStorage::new(submit_tx)
+    }
+
+    #[tokio::test]
+    async fn evict_expired_drops_result_after_ttl() {
+        let storage = storage();
+        let ttl = Duration::from_secs(60);
+        let task_id = storage.create_task(1).await;
+        storage.set_status(task_id, TaskStatus::Succeeded { result: Payload::from("done") }).await;
+
+        let now = Instant::now();
+        assert_eq!(storage.evict_expired(ttl, now).await, 0);
+        assert!(matches!(storage.get_status(task_id).await, Some(TaskStatus::Succeeded { .. })));
+
+        let later = now + ttl + Duration::from_millis(1);
+        assert_eq!(storage.evict_expired(ttl, later).await, 1);
+        assert!(matches!(storage.get_status(task_id).await, Some(TaskStatus::Evicted)));
+        assert!(storage.take_result(task_id).await.is_none());
+
+        storage.evict_expired(ttl, later + ttl).await;
+        assert!(storage.get_status(task_id).await.is_none());
+    }
+
+    #[tokio::test]
+    async fn evict_expired_keeps_running_tasks() {
+        let storage = storage();
+        let task_id = storage.create_task(1).await;
+        storage.set_status(task_id, TaskStatus::Running).await;
+
+        let far_future = Instant::now() + Duration::from_secs(3600);
+        assert_eq!(storage.evict_expired(Duration::from_secs(1), far_future).await, 0);
+        assert!(matches!(storage.get_status(task_id).await, Some(TaskStatus::Running)));
+    }
+}
@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use slab_runtime_core::Payload;
 
 use super::error::RuntimeError;
@@ -13,6 +15,8 @@ pub enum TaskStatus {
     SucceededStreaming,
     Failed { error: RuntimeError },
     Cancelled,
+    // The terminal record outlived the result TTL and its payload was dropped.
+    Evicted,
 }
 
 impl TaskStatus {
@@ -24,10 +28,20 @@ impl TaskStatus {
                 | TaskStatus::SucceededStreaming
                 | TaskStatus::Failed { .. }
                 | TaskStatus::Cancelled
+                | TaskStatus::Evicted
         )
     }
 }
 
+/// Point-in-time view of one task, as returned by task listings.
+#[derive(Debug, Clone)]
+pub struct TaskStatusView {
+    pub task_id: TaskId,
+    pub status: TaskStatus,
+    pub stage_statuses: Vec<StageStatus>,
+    pub metadata: HashMap<String, String>,
+}
+
 #[derive(Debug, Clone)]
 pub enum StageStatus {
     Pending,
@@ -1,4 +1,5 @@
 use std::sync::Arc;
+use std::time::Duration;
 
 use serde::de::DeserializeOwned;
 use slab_runtime_core::Payload;
@@ -150,7 +151,6 @@ impl DriverRuntime {
         self.submit(route, Payload::typed(input), preprocess_stages, Payload::None).await
     }
 
-    #[allow(dead_code)]
     pub(crate) async fn submit_preprocessed_without_options(
         &self,
         route: RequestRoute,
@@ -272,6 +272,13 @@ impl TaskCodec<Payload, StreamChunk> for RawPayloadTaskCodec {
     }
 }
 
+/// Upper bound for each preprocess stage (e.g. the ffmpeg audio decode) so a
+/// stuck child process fails the task instead of holding it in `Running` forever.
+const PREPROCESS_STAGE_TIMEOUT: Duration = Duration::from_secs(10 * 60);
+
+/// How long a queued submit may wait for room in the orchestrator queue.
+const SUBMIT_TIMEOUT: Duration = Duration::from_secs(5);
+
 async fn submit_invocation(
     execution: &ExecutionHub,
     backend_id: &str,
@@ -282,15 +289,25 @@ async fn submit_invocation(
 ) -> Result<u64, CoreError> {
     let op = BackendOp { name: route.as_str().to_owned(), options: op_options };
 
-    let mut builder = PipelineBuilder::new(execution.orchestrator(), initial_payload);
+    let mut builder = PipelineBuilder::new(execution.orchestrator(), initial_payload)
+        .metadata("backend", backend_id)
+        .metadata("route", route.as_str());
     for stage in preprocess_stages {
-        builder = builder.cpu_stage(stage);
+        builder = builder.cpu_stage_timeout(stage, PREPROCESS_STAGE_TIMEOUT);
+    }
+
+    // Load shedding fails fast on a full orchestrator queue as well.
+    let shed_load = execution.shed_load();
+    if !shed_load {
+        builder = builder.submit_timeout(SUBMIT_TIMEOUT);
     }
 
     if matches!(route, RequestRoute::InferenceStream) {
-        builder.gpu_stream(route.as_str(), backend_id.to_owned(), op).run_stream().await
+        let builder = builder.gpu_stream(route.as_str(), backend_id.to_owned(), op);
+        if shed_load { builder.try_run_stream().await } else { builder.run_stream().await }
     } else {
-        builder.gpu(route.as_str(), backend_id.to_owned(), op).run().await
+        let builder = builder.gpu(route.as_str(), backend_id.to_owned(), op);
+        if shed_load { builder.try_run().await } else { builder.run().await }
     }
 }
 
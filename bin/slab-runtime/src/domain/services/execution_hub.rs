@@ -12,14 +12,17 @@ pub struct ExecutionHub {
 pub(crate) struct ExecutionState {
     pub orchestrator: Orchestrator,
     pub enabled_backends: RuntimeEnabledBackends,
+    /// Reject requests whose backend is saturated instead of queueing them.
+    pub shed_load: bool,
 }
 
 impl ExecutionHub {
     pub(crate) fn new(
         orchestrator: Orchestrator,
         enabled_backends: RuntimeEnabledBackends,
+        shed_load: bool,
     ) -> Self {
-        Self { inner: Arc::new(ExecutionState { orchestrator, enabled_backends }) }
+        Self { inner: Arc::new(ExecutionState { orchestrator, enabled_backends, shed_load }) }
     }
 
     pub(crate) fn orchestrator(&self) -> Orchestrator {
@@ -29,6 +32,10 @@ impl ExecutionHub {
     pub(crate) fn enabled_backends(&self) -> &RuntimeEnabledBackends {
         &self.inner.enabled_backends
     }
+
+    pub(crate) fn shed_load(&self) -> bool {
+        self.inner.shed_load
+    }
 }
 
 impl std::fmt::Debug for ExecutionHub {
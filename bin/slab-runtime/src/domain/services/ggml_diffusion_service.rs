@@ -1,22 +1,27 @@
 use std::str::FromStr;
 
+use futures::StreamExt;
+use futures::stream::BoxStream;
 use slab_diffusion::{
     GuidanceParams as DiffusionGuidanceParams, SampleMethod as DiffusionSampleMethod,
     SampleParams as DiffusionSampleParams, Scheduler as DiffusionScheduler, SlgParams,
 };
 use slab_runtime_core::backend::RequestRoute;
+use tokio::sync::mpsc;
+use tokio_stream::wrappers::ReceiverStream;
 
 use crate::application::dtos as dto;
 use crate::domain::models::{
-    GgmlDiffusionLoadConfig, ImageGenerationRequest, ImageGenerationResponse,
+    GgmlDiffusionLoadConfig, GgmlDiffusionLoadMetadata, ImageGenerationRequest,
+    ImageGenerationResponse,
 };
 use crate::domain::runtime::CoreError;
 
 use super::ExecutionHub;
 use super::driver_runtime::DriverRuntime;
 use super::helpers::{
-    contract_image_to_raw_image, invalid_model, raw_image_to_generated_image, required_path,
-    required_string,
+    ImageStreamDecoder, contract_image_to_raw_image, invalid_model, raw_image_to_generated_image,
+    required_path, required_string,
 };
 
 #[derive(Clone, Debug)]
@@ -58,8 +63,8 @@ impl GgmlDiffusionService {
         })
     }
 
-    pub(crate) async fn load(&self) -> Result<(), CoreError> {
-        self.runtime.load().await
+    pub(crate) async fn load(&self) -> Result<Option<GgmlDiffusionLoadMetadata>, CoreError> {
+        self.runtime.load_with_result().await
     }
 
     pub(crate) async fn unload(&self) -> Result<(), CoreError> {
@@ -80,9 +85,63 @@ impl GgmlDiffusionService {
             .await?;
         Ok(dto::GgmlDiffusionGenerateImageResponse {
             images: response.images.iter().map(contract_image_to_raw_image).collect(),
+            seeds: response.images.iter().filter_map(|image| image.seed).collect(),
         })
     }
 
+    pub(crate) async fn generate_image_stream(
+        &self,
+        request: dto::GgmlDiffusionGenerateImageRequest,
+    ) -> Result<
+        BoxStream<'static, Result<dto::GgmlDiffusionGenerateImageStreamChunk, CoreError>>,
+        CoreError,
+    > {
+        let handle = self
+            .runtime
+            .submit_without_options(
+                RequestRoute::InferenceStream,
+                build_image_request(request)?,
+                Vec::new(),
+            )
+            .await?;
+        let raw_stream = match handle.take_stream().await {
+            Ok(stream) => stream,
+            Err(error) => {
+                handle.cancel_and_purge().await;
+                return Err(error);
+            }
+        };
+
+        let (tx, rx) =
+            mpsc::channel::<Result<dto::GgmlDiffusionGenerateImageStreamChunk, CoreError>>(32);
+        tokio::spawn(async move {
+            tokio::pin!(raw_stream);
+            let mut decoder = ImageStreamDecoder::default();
+            while let Some(chunk) = raw_stream.next().await {
+                let next = match chunk {
+                    Ok(chunk) => decoder.decode(chunk, "ggml_diffusion").await.transpose(),
+                    Err(error) => Some(Err(error)),
+                };
+                let Some(next) = next else {
+                    continue;
+                };
+                let failed = next.is_err();
+                if tx.send(next).await.is_err() {
+                    handle.cancel_and_purge().await;
+                    return;
+                }
+                if failed {
+                    handle.purge().await;
+                    return;
+                }
+            }
+            let _ = tx.send(decoder.finish("ggml_diffusion")).await;
+            handle.purge().await;
+        });
+
+        Ok(ReceiverStream::new(rx).boxed())
+    }
+
     pub(crate) async fn generate_video(
         &self,
         request: dto::GgmlDiffusionGenerateVideoRequest,
@@ -189,6 +248,7 @@ fn build_image_request(
             })
             .transpose()?,
         batch_count: request.count.unwrap_or(1),
+        preview_interval: request.preview_interval,
     })
 }
 
@@ -285,6 +345,7 @@ fn build_video_as_image_request(
             })
             .transpose()?,
         batch_count: video_frames,
+        preview_interval: None,
     })
 }
 
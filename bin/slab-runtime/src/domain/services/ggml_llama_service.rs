@@ -43,6 +43,9 @@ impl GgmlLlamaService {
             flash_attn,
             chat_template: request.chat_template,
             gbnf: request.gbnf,
+            model_id: None,
+            stop_on_context_full: request.stop_on_context_full.unwrap_or(false),
+            least_loaded_sessions: request.least_loaded_sessions.unwrap_or(false),
         };
 
         Ok(Self {
@@ -54,6 +57,17 @@ impl GgmlLlamaService {
         self.runtime.load_with_result().await
     }
 
+    /// Decode a single BOS token per worker so the first real request does
+    /// not pay for kernel compilation and KV cache allocation.
+    pub(crate) async fn warmup(&self) -> Result<(), CoreError> {
+        self.runtime
+            .submit_preprocessed_without_options(RequestRoute::Warmup, Vec::new())
+            .await?
+            .result()
+            .await
+            .map(|_| ())
+    }
+
     pub(crate) async fn unload(&self) -> Result<(), CoreError> {
         self.runtime.unload().await
     }
@@ -151,12 +165,17 @@ fn build_inference_params(
         top_k: request.top_k,
         min_p: request.min_p,
         repetition_penalty: request.repetition_penalty,
+        repeat_last_n: request.repeat_last_n,
+        n_keep: request.n_keep,
         presence_penalty: request.presence_penalty,
+        frequency_penalty: request.frequency_penalty,
         ignore_eos: request.ignore_eos.unwrap_or(false),
+        seed: request.seed,
         logit_bias,
         stop_sequences: request.stop_sequences.unwrap_or_default(),
         agent_trace: request.agent_trace,
         stream: false,
+        model_id: None,
     })
 }
 
@@ -172,6 +191,7 @@ mod tests {
             max_tokens: Some(32),
             stop_sequences: Some(vec!["</think>".to_owned(), "###".to_owned()]),
             ignore_eos: Some(true),
+            seed: Some(7),
             logit_bias_json: Some(br#"{"42":false,"hello":1.5}"#.to_vec()),
             ..Default::default()
         })
@@ -179,6 +199,7 @@ mod tests {
 
         assert_eq!(options.max_tokens, Some(32));
         assert!(options.ignore_eos);
+        assert_eq!(options.seed, Some(7));
         assert_eq!(options.stop_sequences, vec!["</think>".to_owned(), "###".to_owned()]);
         assert_eq!(options.logit_bias, Some(serde_json::json!({ "42": false, "hello": 1.5 })));
     }
@@ -183,6 +183,7 @@ fn build_transcription_options(
             logprob_thold: decode.logprob_thold,
             no_speech_thold: decode.no_speech_thold,
             tdrz_enable: decode.tdrz_enable,
+            translate: decode.translate,
         });
     }
 
@@ -208,7 +208,7 @@ pub(crate) fn raw_image_to_generated_image(
         message: "raw image channels are required".to_owned(),
     })?;
 
-    Ok(GeneratedImage { data: image.data.clone(), width, height, channels })
+    Ok(GeneratedImage { data: image.data.clone(), width, height, channels, seed: None })
 }
 
 pub(crate) fn contract_image_to_raw_image(image: &GeneratedImage) -> dto::RawImage {
@@ -220,6 +220,144 @@ pub(crate) fn contract_image_to_raw_image(image: &GeneratedImage) -> dto::RawIma
     }
 }
 
+/// Postprocess stage turning a [`Payload::Image`] into PNG-encoded bytes.
+pub(crate) fn png_encode_stage() -> CpuStage {
+    CpuStage::new("image.encode.png", |payload| {
+        let Payload::Image { width, height, channels, data } = payload else {
+            return Err(CoreError::CpuStageFailed {
+                stage_name: "image.encode.png".to_owned(),
+                message: format!("expected an image payload, got {payload:?}"),
+            });
+        };
+        let color = match channels {
+            1 => image::ExtendedColorType::L8,
+            3 => image::ExtendedColorType::Rgb8,
+            4 => image::ExtendedColorType::Rgba8,
+            other => {
+                return Err(CoreError::CpuStageFailed {
+                    stage_name: "image.encode.png".to_owned(),
+                    message: format!("unsupported channel count {other}"),
+                });
+            }
+        };
+        let mut png = std::io::Cursor::new(Vec::new());
+        image::write_buffer_with_format(
+            &mut png,
+            &data,
+            width,
+            height,
+            color,
+            image::ImageFormat::Png,
+        )
+        .map_err(|error| CoreError::CpuStageFailed {
+            stage_name: "image.encode.png".to_owned(),
+            message: error.to_string(),
+        })?;
+        Ok(Payload::from(png.into_inner()))
+    })
+}
+
+/// Folds a diffusion `inference.stream` into stream chunks. `Image` chunks ahead
+/// of the worker's `{"seeds": [...]}` header are TAESD previews, forwarded as
+/// they arrive after [`png_encode_stage`]; the ones after it are the final
+/// images, returned together by [`ImageStreamDecoder::finish`].
+#[derive(Debug, Default)]
+pub(crate) struct ImageStreamDecoder {
+    seeds: Option<Vec<i64>>,
+    images: Vec<dto::RawImage>,
+}
+
+impl ImageStreamDecoder {
+    pub(crate) async fn decode(
+        &mut self,
+        chunk: StreamChunk,
+        task_kind: &'static str,
+    ) -> Result<Option<dto::GgmlDiffusionGenerateImageStreamChunk>, CoreError> {
+        match chunk {
+            StreamChunk::Token(progress) => {
+                let progress: Value = serde_json::from_str(&progress).map_err(|error| {
+                    CoreError::ResultDecodeFailed {
+                        task_kind: task_kind.to_owned(),
+                        message: format!("invalid progress chunk: {error}"),
+                    }
+                })?;
+                let step = |key: &str| {
+                    progress.get(key).and_then(Value::as_i64).and_then(|v| i32::try_from(v).ok())
+                };
+                Ok(Some(dto::GgmlDiffusionGenerateImageStreamChunk {
+                    step: step("step"),
+                    total_steps: step("total"),
+                    done: Some(false),
+                    ..Default::default()
+                }))
+            }
+            StreamChunk::Json(header) => {
+                let seeds = header
+                    .get("seeds")
+                    .and_then(Value::as_array)
+                    .map(|seeds| {
+                        seeds
+                            .iter()
+                            .filter_map(Value::as_u64)
+                            .filter_map(|seed| i64::try_from(seed).ok())
+                            .collect()
+                    })
+                    .unwrap_or_default();
+                self.seeds = Some(seeds);
+                Ok(None)
+            }
+            StreamChunk::Image(payload) if self.seeds.is_none() => {
+                let Payload::Bytes(png) = png_encode_stage().run(payload).await? else {
+                    unreachable!("png_encode_stage always emits bytes");
+                };
+                Ok(Some(dto::GgmlDiffusionGenerateImageStreamChunk {
+                    preview_png: Some(png.to_vec()),
+                    done: Some(false),
+                    ..Default::default()
+                }))
+            }
+            StreamChunk::Image(payload) => {
+                let Payload::Image { width, height, channels, data } = payload else {
+                    return Err(CoreError::ResultDecodeFailed {
+                        task_kind: task_kind.to_owned(),
+                        message: format!("expected an image payload, got {payload:?}"),
+                    });
+                };
+                self.images.push(dto::RawImage {
+                    data: data.to_vec(),
+                    width: Some(width),
+                    height: Some(height),
+                    channels: Some(channels),
+                });
+                Ok(None)
+            }
+            StreamChunk::Done => Ok(None),
+            StreamChunk::Error(message) => {
+                Err(CoreError::ResultDecodeFailed { task_kind: task_kind.to_owned(), message })
+            }
+        }
+    }
+
+    /// Final chunk carrying the generated images once the stream completed.
+    pub(crate) fn finish(
+        self,
+        task_kind: &'static str,
+    ) -> Result<dto::GgmlDiffusionGenerateImageStreamChunk, CoreError> {
+        let Some(seeds) = self.seeds else {
+            return Err(CoreError::ResultDecodeFailed {
+                task_kind: task_kind.to_owned(),
+                message: "image stream ended before the final images".to_owned(),
+            });
+        };
+        Ok(dto::GgmlDiffusionGenerateImageStreamChunk {
+            images: self.images,
+            seeds,
+            done: Some(true),
+            ..Default::default()
+        })
+    }
+}
+
 pub(crate) fn audio_decode_stage(path: PathBuf) -> CpuStage {
     CpuStage::new("audio.decode.pcm", move |_| decode_audio_path(&path).map(Payload::F32))
 }
@@ -366,18 +504,21 @@ fn decode_audio_path(path: &Path) -> Result<Arc<[f32]>, CoreError> {
 
     if !output.status.success() {
         let stderr = String::from_utf8_lossy(&output.stderr);
-        return Err(CoreError::EngineIo(format!(
-            "ffmpeg failed with status {}: {}",
-            output.status.code().unwrap_or(-1),
-            stderr.trim()
-        )));
+        return Err(CoreError::PreprocessFailed {
+            tool: "ffmpeg".to_owned(),
+            message: format!(
+                "exited with status {}: {}",
+                output.status.code().unwrap_or(-1),
+                stderr.trim()
+            ),
+        });
     }
 
     if output.stdout.len() % std::mem::size_of::<f32>() != 0 {
-        return Err(CoreError::EngineIo(format!(
-            "PCM output length {} is not aligned to f32",
-            output.stdout.len()
-        )));
+        return Err(CoreError::PreprocessFailed {
+            tool: "ffmpeg".to_owned(),
+            message: format!("PCM output length {} is not aligned to f32", output.stdout.len()),
+        });
     }
 
     let samples: Vec<f32> = cast_slice::<u8, f32>(&output.stdout).to_vec();
@@ -437,6 +578,25 @@ mod tests {
         TextGenerationUsage, TextPromptTokensDetails,
     };
 
+    #[tokio::test]
+    async fn png_encode_stage_round_trips_image_payload_dimensions() {
+        let pixels: Vec<u8> = (0..2 * 3 * 3).map(|value| value as u8 * 10).collect();
+        let image =
+            Payload::Image { width: 2, height: 3, channels: 3, data: Arc::from(pixels.clone()) };
+
+        let encoded = png_encode_stage().run(image).await.expect("png encode");
+        let Payload::Bytes(png) = encoded else {
+            panic!("png stage should emit bytes, got {encoded:?}");
+        };
+        let decoded = image::load_from_memory(&png).expect("valid png").to_rgb8();
+
+        assert_eq!(decoded.dimensions(), (2, 3));
+        assert_eq!(decoded.into_raw(), pixels);
+
+        let error = png_encode_stage().run(Payload::from("text")).await.expect_err("not an image");
+        assert!(error.to_string().contains("expected an image payload"), "{error}");
+    }
+
     #[test]
     fn decode_text_response_prefers_typed_contract() {
         let payload = Payload::typed(TextGenerationResponse {
@@ -464,6 +624,47 @@ mod tests {
         assert_eq!(response.reasoning_content.as_deref(), Some("thinking"));
     }
 
+    #[tokio::test]
+    async fn image_stream_decoder_separates_previews_from_final_images() {
+        fn image(side: u32) -> StreamChunk {
+            StreamChunk::Image(Payload::Image {
+                width: side,
+                height: side,
+                channels: 3,
+                data: Arc::from(vec![0; (side * side * 3) as usize]),
+            })
+        }
+
+        let mut decoder = ImageStreamDecoder::default();
+        let progress = decoder
+            .decode(StreamChunk::Token(r#"{"step":1,"total":2}"#.to_owned()), "ggml_diffusion")
+            .await
+            .unwrap()
+            .expect("progress chunk");
+        assert_eq!((progress.step, progress.total_steps), (Some(1), Some(2)));
+
+        let preview =
+            decoder.decode(image(1), "ggml_diffusion").await.unwrap().expect("preview chunk");
+        let png = preview.preview_png.expect("preview should be PNG-encoded");
+        assert_eq!(image::load_from_memory(&png).expect("valid png").dimensions(), (1, 1));
+
+        let header = StreamChunk::Json(serde_json::json!({ "seeds": [42] }));
+        assert!(decoder.decode(header, "ggml_diffusion").await.unwrap().is_none());
+        assert!(decoder.decode(image(8), "ggml_diffusion").await.unwrap().is_none());
+
+        let last = decoder.finish("ggml_diffusion").expect("final chunk");
+        assert_eq!(last.done, Some(true));
+        assert_eq!(last.seeds, [42]);
+        assert_eq!(last.images.len(), 1);
+        assert_eq!(last.images[0].width, Some(8));
+    }
+
+    #[test]
+    fn image_stream_decoder_rejects_streams_without_final_images() {
+        let error = ImageStreamDecoder::default().finish("ggml_diffusion").expect_err("no header");
+        assert!(error.to_string().contains("ended before the final images"), "{error}");
+    }
+
     #[test]
     fn decode_text_stream_chunk_reads_contract_event() {
         let chunk = StreamChunk::Json(
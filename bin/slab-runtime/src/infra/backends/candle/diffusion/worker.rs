@@ -132,7 +132,7 @@ impl CandleDiffusionWorker {
                 self.emit_peer_unload_generation(seq_id);
                 Ok(())
             }
-            None => Err(CandleDiffusionWorkerError::unload("model not loaded")),
+            None => Err(CandleDiffusionWorkerError::model_not_loaded("nothing to unload")),
         }
     }
 
@@ -141,9 +141,7 @@ impl CandleDiffusionWorker {
         raw: ImageGenerationRequest,
     ) -> Result<Typed<ImageGenerationResponse>, CandleDiffusionWorkerError> {
         let engine = self.engine.as_mut().ok_or_else(|| {
-            CandleDiffusionWorkerError::inference(
-                "candle.diffusion backend not ready: model not loaded",
-            )
+            CandleDiffusionWorkerError::model_not_loaded("candle.diffusion backend not ready")
         })?;
 
         let (request, count) = build_image_request(&raw)?;
@@ -280,6 +278,7 @@ fn map_generated_image(image: SlabGeneratedImage) -> GeneratedImage {
         height: image.height,
         channels: image.channels,
         data: image.data,
+        seed: None,
     }
 }
 
@@ -130,7 +130,7 @@ impl CandleLlamaWorker {
                 engine.unload_model();
                 Ok(())
             }
-            None => Err(CandleLlamaWorkerError::unload("model not loaded")),
+            None => Err(CandleLlamaWorkerError::model_not_loaded("nothing to unload")),
         }
     }
 
@@ -210,7 +210,7 @@ impl CandleLlamaWorker {
         self.engine
             .as_ref()
             .cloned()
-            .ok_or_else(|| CandleLlamaWorkerError::inference("model not loaded"))
+            .ok_or_else(|| CandleLlamaWorkerError::model_not_loaded("call model.load first"))
     }
 }
 
@@ -15,6 +15,12 @@ pub(crate) enum CandleWhisperWorkerError {
     Sync { message: String },
     #[error("internal error: {message}")]
     Internal { message: String },
+    #[error("invalid argument: {message}")]
+    InvalidArgument { message: String },
+    #[error("model not loaded: {message}")]
+    ModelNotLoaded { message: String },
+    #[error("library not loaded: {message}")]
+    LibraryNotLoaded { message: String },
 }
 
 #[allow(dead_code)]
@@ -43,3 +49,5 @@ impl CandleWhisperWorkerError {
         Self::Internal { message: message.into() }
     }
 }
+
+slab_runtime_core::impl_backend_error_kinds!(CandleWhisperWorkerError);
@@ -130,7 +130,7 @@ impl CandleWhisperWorker {
                 self.emit_peer_unload_generation(seq_id);
                 Ok(())
             }
-            None => Err(CandleWhisperWorkerError::unload("model not loaded")),
+            None => Err(CandleWhisperWorkerError::model_not_loaded("nothing to unload")),
         }
     }
 
@@ -140,8 +140,8 @@ impl CandleWhisperWorker {
         options: AudioTranscriptionOptions,
     ) -> Result<Typed<AudioTranscriptionResponse>, CandleWhisperWorkerError> {
         let engine = self.engine.as_mut().ok_or_else(|| {
-            CandleWhisperWorkerError::inference(
-                "candle.whisper backend not ready: model not loaded. Call model.load first",
+            CandleWhisperWorkerError::model_not_loaded(
+                "candle.whisper backend not ready. Call model.load first",
             )
         })?;
 
@@ -205,7 +205,11 @@ fn map_transcription_request(
         samples: samples.to_vec(),
         language: options.language,
         detect_language: options.detect_language.unwrap_or(false),
-        task: WhisperTask::Transcribe,
+        task: if decode.as_ref().and_then(|value| value.translate).unwrap_or(false) {
+            WhisperTask::Translate
+        } else {
+            WhisperTask::Transcribe
+        },
         timestamps,
         prompt: options.prompt,
         max_tokens: decode
@@ -4,7 +4,7 @@ use crate::domain::models::{
 use crate::infra::backends::ggml;
 use slab_diffusion::{
     Context, ContextParams, Diffusion, DiffusionError, GuidanceParams, Image, ImgParams,
-    SampleMethod, SampleParams, Scheduler, SlgParams,
+    SampleMethod, SampleParams, Scheduler, SeededImage, SlgParams,
 };
 use slab_utils::loader::load_library_from_dir;
 use std::path::{Path, PathBuf};
@@ -47,8 +47,8 @@ pub enum GGMLDiffusionEngineError {
 #[derive(Debug)]
 pub struct GGMLDiffusionEngine {
     instance: Arc<Diffusion>,
-    // Owned per-engine context; not shared across instances.
-    ctx: Option<Context>,
+    // Per-engine context; only shared with engines produced by `fork_loaded`.
+    ctx: Option<Arc<Context>>,
 }
 
 // # Safety
@@ -60,7 +60,7 @@ pub struct GGMLDiffusionEngine {
 //    handle that holds a read-only table of function pointers loaded once at startup.
 //    This function pointer table is never mutated, making concurrent reads safe.
 //
-// 2. **`ctx: Option<Context>`** - According to upstream stable-diffusion.cpp
+// 2. **`ctx: Option<Arc<Context>>`** - According to upstream stable-diffusion.cpp
 //    documentation, each thread should have its own `Context` instance. However,
 //    in this wrapper, the context is protected by the engine's internal locking
 //    mechanisms, and the `Context` type itself provides internal synchronization
@@ -94,6 +94,16 @@ impl GGMLDiffusionEngine {
         })
     }
 
+    /// stable-diffusion.cpp version string, falling back to the commit hash
+    /// when the library was built without a release tag.
+    pub fn library_version(&self) -> Option<String> {
+        [self.instance.get_version(), self.instance.get_commit()]
+            .into_iter()
+            .map(str::trim)
+            .find(|value| !value.is_empty())
+            .map(str::to_owned)
+    }
+
     /// Create (or replace) the Stable Diffusion inference context.
     ///
     /// Loading the model files specified in `params` may take several seconds.
@@ -105,7 +115,7 @@ impl GGMLDiffusionEngine {
             .instance
             .new_context(params)
             .map_err(|source| GGMLDiffusionEngineError::CreateContext { source })?;
-        self.ctx = Some(ctx);
+        self.ctx = Some(Arc::new(ctx));
 
         Ok(())
     }
@@ -136,8 +146,9 @@ impl GGMLDiffusionEngine {
 
     /// Generate one or more images from the supplied parameters.
     ///
-    /// The returned `Vec` contains exactly `params.batch_count` images.
-    pub fn generate_image(&self, params: ImgParams) -> Result<Vec<Image>, ggml::EngineError> {
+    /// The returned `Vec` contains exactly `params.batch_count` images, each
+    /// paired with the seed it was sampled from.
+    pub fn generate_image(&self, params: ImgParams) -> Result<Vec<SeededImage>, ggml::EngineError> {
         info!(
             prompt_len = params.prompt.as_ref().map_or(0, |prompt| prompt.len()),
             width = params.width,
@@ -149,7 +160,23 @@ impl GGMLDiffusionEngine {
         );
         let ctx = self.ctx.as_ref().ok_or(GGMLDiffusionEngineError::ContextNotInitialized)?;
 
-        ctx.generate_image(params)
+        ctx.generate_image_with_seed(params)
+            .map_err(|source| GGMLDiffusionEngineError::InferenceFailed { source }.into())
+    }
+
+    /// Generate images like [`Self::generate_image`], reporting every
+    /// denoising step to `on_progress(step, steps)` from the calling thread.
+    pub fn generate_image_with_progress<F>(
+        &self,
+        params: ImgParams,
+        on_progress: F,
+    ) -> Result<Vec<SeededImage>, ggml::EngineError>
+    where
+        F: FnMut(i32, i32),
+    {
+        let ctx = self.ctx.as_ref().ok_or(GGMLDiffusionEngineError::ContextNotInitialized)?;
+
+        ctx.generate_image_with_seed_and_progress(params, on_progress)
             .map_err(|source| GGMLDiffusionEngineError::InferenceFailed { source }.into())
     }
 
@@ -165,6 +192,57 @@ impl GGMLDiffusionEngine {
         })
     }
 
+    pub(crate) fn generate_image_from_request_with_progress<F>(
+        &self,
+        request: ImageGenerationRequest,
+        on_progress: F,
+    ) -> Result<ImageGenerationResponse, ggml::EngineError>
+    where
+        F: FnMut(i32, i32),
+    {
+        let params = image_params_from_request(request)
+            .map_err(|source| GGMLDiffusionEngineError::InferenceFailed { source })?;
+        let images = self.generate_image_with_progress(params, on_progress)?;
+        Ok(ImageGenerationResponse {
+            images: images.into_iter().map(raw_image_to_contract_image).collect(),
+        })
+    }
+
+    /// Returns `true` when the loaded context can decode TAESD previews.
+    pub fn has_preview(&self) -> bool {
+        self.ctx.as_ref().is_some_and(|ctx| ctx.has_taesd())
+    }
+
+    pub(crate) fn generate_image_from_request_with_preview<P, V>(
+        &self,
+        request: ImageGenerationRequest,
+        interval: i32,
+        on_progress: P,
+        mut on_preview: V,
+    ) -> Result<ImageGenerationResponse, ggml::EngineError>
+    where
+        P: FnMut(i32, i32),
+        V: FnMut(GeneratedImage),
+    {
+        let ctx = self.ctx.as_ref().ok_or(GGMLDiffusionEngineError::ContextNotInitialized)?;
+        let params = image_params_from_request(request)
+            .map_err(|source| GGMLDiffusionEngineError::InferenceFailed { source })?;
+        let images = ctx
+            .generate_image_with_preview(params, interval, on_progress, |_, image| {
+                on_preview(GeneratedImage {
+                    width: image.width,
+                    height: image.height,
+                    channels: image.channel,
+                    data: image.data,
+                    seed: None,
+                })
+            })
+            .map_err(|source| GGMLDiffusionEngineError::InferenceFailed { source })?;
+        Ok(ImageGenerationResponse {
+            images: images.into_iter().map(raw_image_to_contract_image).collect(),
+        })
+    }
+
     /// Unload the current context and release its resources.
     pub fn unload(&mut self) {
         info!("unloading context...");
@@ -185,6 +263,17 @@ impl GGMLDiffusionEngine {
     pub fn fork_library(&self) -> Self {
         Self { instance: Arc::clone(&self.instance), ctx: None }
     }
+
+    /// Create a new engine that shares both the library handle and the loaded
+    /// model context, or `None` when no model is loaded.
+    ///
+    /// Used by streaming generation, which keeps running after the worker has
+    /// returned the stream handle. The context stays alive until both engines
+    /// release it, so an unload during generation cannot free it underneath.
+    pub fn fork_loaded(&self) -> Option<Self> {
+        let ctx = Arc::clone(self.ctx.as_ref()?);
+        Some(Self { instance: Arc::clone(&self.instance), ctx: Some(ctx) })
+    }
 }
 
 fn image_params_from_request(request: ImageGenerationRequest) -> Result<ImgParams, DiffusionError> {
@@ -244,12 +333,14 @@ fn contract_image_to_raw_image(image: GeneratedImage) -> Image {
     Image { width: image.width, height: image.height, channel: image.channels, data: image.data }
 }
 
-fn raw_image_to_contract_image(image: Image) -> GeneratedImage {
+fn raw_image_to_contract_image(seeded: SeededImage) -> GeneratedImage {
+    let SeededImage { seed, image } = seeded;
     GeneratedImage {
         width: image.width,
         height: image.height,
         channels: image.channel,
         data: image.data,
+        seed: Some(seed),
     }
 }
 
@@ -303,7 +394,7 @@ mod test {
         let images = ds.generate_image(image_params).expect("generate_image failed");
 
         assert_eq!(images.len(), 1);
-        assert!(!images[0].data.is_empty());
+        assert!(!images[0].image.data.is_empty());
 
         let out = test_data_path.join("diffusion_test.png");
         println!("Generated image saved to {out:?}");
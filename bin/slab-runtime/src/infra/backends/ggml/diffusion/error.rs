@@ -17,6 +17,12 @@ pub(crate) enum GGMLDiffusionWorkerError {
     Sync { message: String },
     #[error("internal error: {message}")]
     Internal { message: String },
+    #[error("invalid argument: {message}")]
+    InvalidArgument { message: String },
+    #[error("model not loaded: {message}")]
+    ModelNotLoaded { message: String },
+    #[error("library not loaded: {message}")]
+    LibraryNotLoaded { message: String },
 }
 
 #[allow(dead_code)]
@@ -45,3 +51,5 @@ impl GGMLDiffusionWorkerError {
         Self::Internal { message: message.into() }
     }
 }
+
+slab_runtime_core::impl_backend_error_kinds!(GGMLDiffusionWorkerError);
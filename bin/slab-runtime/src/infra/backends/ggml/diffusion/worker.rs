@@ -4,11 +4,26 @@
 //!
 //! # Supported ops
 //!
-//! | Op string           | Event variant    | Description                                       |
-//! |---------------------|------------------|---------------------------------------------------|
-//! | `"model.load"`      | `LoadModel`      | Load a model from the engine.                     |
-//! | `"model.unload"`    | `UnloadModel`    | Drop the model handle; call model.load to restore. |
-//! | `"inference.image"` | `InferenceImage` | Image generation from typed diffusion params.     |
+//! | Op string            | Event variant     | Description                                        |
+//! |----------------------|-------------------|----------------------------------------------------|
+//! | `"model.load"`       | `LoadModel`       | Load a model from the engine.                      |
+//! | `"model.unload"`     | `UnloadModel`     | Drop the model handle; call model.load to restore. |
+//! | `"inference.image"`  | `InferenceImage`  | Image generation from typed diffusion params.      |
+//! | `"inference.stream"` | `InferenceStream` | Image generation with per-step progress chunks.    |
+//!
+//! ### `inference.stream` chunks
+//! One `Token` per denoising step carrying `{"step": n, "total": m}` JSON, then
+//! a `Json` header `{"seeds": [...]}`, then one `Image` chunk per generated
+//! image carrying a `Payload::Image` (raw pixels plus `width`/`height`/
+//! `channels`), then `Done`. When the request sets `preview_interval`, the
+//! model must have been loaded with a TAESD (`taesd_path`); low-resolution
+//! `Image` previews of the denoised latent are then interleaved with the step
+//! tokens every `preview_interval` steps, always ahead of the header. Once the consumer drops the stream no further chunks are produced,
+//! and a request whose stream was dropped while it waited in the queue is
+//! skipped entirely. stable-diffusion.cpp's progress callback cannot abort
+//! sampling, so a generation that has already started runs to completion and
+//! its output is discarded.
+//!
 //! ### `model.load` input payload
 //! Uses a typed runtime-owned `GgmlDiffusionLoadConfig` payload inside `slab-runtime`.
 
@@ -17,11 +32,19 @@ use std::time::Instant;
 use super::engine::GGMLDiffusionEngine;
 use super::error::GGMLDiffusionWorkerError;
 use crate::domain::models::{
-    GgmlDiffusionLoadConfig, ImageGenerationRequest, ImageGenerationResponse,
+    GeneratedImage, GgmlDiffusionLoadConfig, GgmlDiffusionLoadMetadata, ImageGenerationRequest,
+    ImageGenerationResponse,
 };
 use slab_runtime_core::Payload;
-use slab_runtime_core::backend::{BroadcastSeq, ControlOpId, Input, PeerControlBus, Typed};
+use slab_runtime_core::backend::{
+    BroadcastSeq, ControlOpId, Input, PeerControlBus, StreamChunk, StreamHandle, Typed,
+};
 use slab_runtime_macros::backend_handler;
+use tokio::sync::mpsc;
+
+/// Progress chunks are dropped rather than blocking the sampler once this many
+/// are waiting for the consumer.
+const PROGRESS_STREAM_CAPACITY: usize = 64;
 
 // ── Configurations ────────────────────────────────────────────────────────────
 
@@ -59,7 +82,7 @@ impl DiffusionWorker {
         &mut self,
         config: Input<GgmlDiffusionLoadConfig>,
         seq: BroadcastSeq,
-    ) -> Result<(), GGMLDiffusionWorkerError> {
+    ) -> Result<Typed<GgmlDiffusionLoadMetadata>, GGMLDiffusionWorkerError> {
         self.handle_load_model(config.0, seq.0).await
     }
 
@@ -76,18 +99,26 @@ impl DiffusionWorker {
         self.handle_inference_image(image_params.0).await
     }
 
+    #[on_event(InferenceStream)]
+    async fn on_inference_stream(
+        &mut self,
+        image_params: Input<ImageGenerationRequest>,
+    ) -> Result<StreamHandle, GGMLDiffusionWorkerError> {
+        self.handle_inference_stream(image_params.0).await
+    }
+
     // ── model.load ────────────────────────────────────────────────────────────
 
     async fn handle_load_model(
         &mut self,
         config: GgmlDiffusionLoadConfig,
         seq_id: u64,
-    ) -> Result<(), GGMLDiffusionWorkerError> {
+    ) -> Result<Typed<GgmlDiffusionLoadMetadata>, GGMLDiffusionWorkerError> {
         let worker_id = self.peer_sender_id();
         let engine = match self.engine.as_mut() {
             Some(e) => e,
             None => {
-                return Err(GGMLDiffusionWorkerError::load("engine not initialized"));
+                return Err(GGMLDiffusionWorkerError::library_not_loaded("engine not initialized"));
             }
         };
         let config_payload = Payload::typed(config.clone());
@@ -109,6 +140,7 @@ impl DiffusionWorker {
         // Diffusion workers run on dedicated OS threads, so call the engine directly
         // and keep the native context pinned to that thread.
         let result = engine.new_context_from_config(config.clone());
+        let version = engine.library_version();
 
         match result {
             Ok(()) => {
@@ -121,7 +153,7 @@ impl DiffusionWorker {
                     elapsed_ms = started_at.elapsed().as_millis(),
                     "diffusion model.load completed"
                 );
-                Ok(())
+                Ok(Typed(GgmlDiffusionLoadMetadata { version }))
             }
             Err(e) => {
                 tracing::error!(
@@ -147,7 +179,7 @@ impl DiffusionWorker {
                 self.emit_peer_unload_generation(seq_id);
                 Ok(())
             }
-            None => Err(GGMLDiffusionWorkerError::unload("engine not initialized")),
+            None => Err(GGMLDiffusionWorkerError::library_not_loaded("engine not initialized")),
         }
     }
 
@@ -160,7 +192,7 @@ impl DiffusionWorker {
         let engine = match self.engine.as_ref() {
             Some(e) => e,
             None => {
-                return Err(GGMLDiffusionWorkerError::inference("engine not initialized"));
+                return Err(GGMLDiffusionWorkerError::library_not_loaded("engine not initialized"));
             }
         };
 
@@ -172,6 +204,43 @@ impl DiffusionWorker {
         }
     }
 
+    // ── inference.stream ──────────────────────────────────────────────────────
+
+    async fn handle_inference_stream(
+        &mut self,
+        image_params: ImageGenerationRequest,
+    ) -> Result<StreamHandle, GGMLDiffusionWorkerError> {
+        let engine =
+            self.engine.as_ref().and_then(GGMLDiffusionEngine::fork_loaded).ok_or_else(|| {
+                GGMLDiffusionWorkerError::model_not_loaded("call model.load first")
+            })?;
+
+        if let Some(interval) = image_params.preview_interval {
+            if !engine.has_preview() {
+                return Err(GGMLDiffusionWorkerError::invalid_argument(
+                    "preview_interval requires a model loaded with taesd_path",
+                ));
+            }
+            let interval = i32::try_from(interval.max(1)).unwrap_or(i32::MAX);
+            return Ok(spawn_preview_stream(move |on_progress, on_preview| {
+                engine
+                    .generate_image_from_request_with_preview(
+                        image_params,
+                        interval,
+                        on_progress,
+                        on_preview,
+                    )
+                    .map_err(|error| error.to_string())
+            }));
+        }
+
+        Ok(spawn_progress_stream(move |on_progress| {
+            engine
+                .generate_image_from_request_with_progress(image_params, on_progress)
+                .map_err(|error| error.to_string())
+        }))
+    }
+
     #[on_peer_control(LoadModel)]
     async fn on_peer_load_model(
         &mut self,
@@ -228,6 +297,95 @@ impl DiffusionWorker {
     }
 }
 
+/// Run `generate` on the worker's runtime and stream its progress and images.
+///
+/// Diffusion workers own a current-thread runtime on a dedicated OS thread, so
+/// the spawned task keeps the native context on that thread; generation blocks
+/// it until sampling finishes, exactly like the unary `inference.image` path.
+///
+/// stable-diffusion.cpp cannot abort sampling from its progress callback, so
+/// once `generate` has started a dropped stream only silences the remaining
+/// chunks; the generation itself runs to completion.
+fn spawn_progress_stream<F>(generate: F) -> StreamHandle
+where
+    F: FnOnce(&mut dyn FnMut(i32, i32)) -> Result<ImageGenerationResponse, String> + Send + 'static,
+{
+    spawn_preview_stream(move |on_progress, _| generate(on_progress))
+}
+
+/// Like [`spawn_progress_stream`], additionally handing `generate` a callback
+/// that streams intermediate preview images ahead of the final ones.
+fn spawn_preview_stream<F>(generate: F) -> StreamHandle
+where
+    F: FnOnce(
+            &mut dyn FnMut(i32, i32),
+            &mut dyn FnMut(GeneratedImage),
+        ) -> Result<ImageGenerationResponse, String>
+        + Send
+        + 'static,
+{
+    let (tx, rx) = mpsc::channel::<StreamChunk>(PROGRESS_STREAM_CAPACITY);
+    tokio::spawn(async move {
+        if tx.is_closed() {
+            tracing::debug!("diffusion stream dropped before generation started; skipping");
+            return;
+        }
+
+        let result = {
+            let mut report = |step: i32, total: i32| {
+                if tx.is_closed() {
+                    return;
+                }
+                let progress = serde_json::json!({ "step": step, "total": total });
+                let _ = tx.try_send(StreamChunk::Token(progress.to_string()));
+            };
+            let mut preview = |image: GeneratedImage| {
+                if tx.is_closed() {
+                    return;
+                }
+                let _ = tx.try_send(StreamChunk::Image(image_payload(image)));
+            };
+            generate(&mut report, &mut preview)
+        };
+
+        if tx.is_closed() {
+            tracing::debug!("diffusion stream dropped during generation; discarding output");
+            return;
+        }
+
+        match result {
+            Ok(response) => {
+                let seeds: Vec<u64> =
+                    response.images.iter().filter_map(|image| image.seed).collect();
+                let header = serde_json::json!({ "seeds": seeds });
+                if tx.send(StreamChunk::Json(header)).await.is_err() {
+                    return;
+                }
+                for image in response.images {
+                    if tx.send(StreamChunk::Image(image_payload(image))).await.is_err() {
+                        return;
+                    }
+                }
+                let _ = tx.send(StreamChunk::Done).await;
+            }
+            Err(error) => {
+                tracing::error!(error = %error, "diffusion streaming generation failed");
+                let _ = tx.send(StreamChunk::Error(error)).await;
+            }
+        }
+    });
+    rx
+}
+
+fn image_payload(image: GeneratedImage) -> Payload {
+    Payload::Image {
+        width: image.width,
+        height: image.height,
+        channels: image.channels,
+        data: image.data.into(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::path::PathBuf;
@@ -269,4 +427,111 @@ mod tests {
         assert_eq!(config.vae_device.as_deref(), Some("cpu"));
         assert_eq!(config.n_threads, Some(8));
     }
+
+    #[tokio::test]
+    async fn progress_stream_reports_each_step_before_image() {
+        const STEPS: i32 = 20;
+        let mut stream = spawn_progress_stream(|on_progress| {
+            for step in 1..=STEPS {
+                on_progress(step, STEPS);
+            }
+            Ok(ImageGenerationResponse {
+                images: vec![GeneratedImage {
+                    data: vec![0; 2 * 2 * 3],
+                    width: 2,
+                    height: 2,
+                    channels: 3,
+                    seed: None,
+                }],
+            })
+        });
+
+        let mut progress = Vec::new();
+        let image = loop {
+            match stream.recv().await.expect("stream should yield an image") {
+                StreamChunk::Token(token) => {
+                    progress.push(serde_json::from_str::<serde_json::Value>(&token).unwrap())
+                }
+                StreamChunk::Json(header) => assert_eq!(header, serde_json::json!({ "seeds": [] })),
+                StreamChunk::Image(image) => break image,
+                other => panic!("unexpected chunk before image: {other:?}"),
+            }
+        };
+
+        assert_eq!(progress.len(), STEPS as usize);
+        assert_eq!(progress.last().unwrap(), &serde_json::json!({ "step": STEPS, "total": STEPS }));
+        let Payload::Image { width, height, channels, data } = image else {
+            panic!("image chunk should carry an image payload, got {image:?}");
+        };
+        assert_eq!((width, height, channels), (2, 2, 3));
+        assert_eq!(data.len(), 12);
+        assert!(matches!(stream.recv().await, Some(StreamChunk::Done)));
+    }
+
+    #[tokio::test]
+    async fn progress_stream_skips_generation_when_dropped_before_start() {
+        let (ran_tx, ran_rx) = std::sync::mpsc::channel();
+        let stream = spawn_progress_stream(move |_| {
+            let _ = ran_tx.send(());
+            Ok(ImageGenerationResponse { images: Vec::new() })
+        });
+        drop(stream);
+        tokio::task::yield_now().await;
+
+        assert!(ran_rx.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn preview_stream_emits_previews_before_the_final_image() {
+        fn image(side: u32) -> GeneratedImage {
+            GeneratedImage {
+                data: vec![0; (side * side * 3) as usize],
+                width: side,
+                height: side,
+                channels: 3,
+                seed: None,
+            }
+        }
+
+        let mut stream = spawn_preview_stream(|on_progress, on_preview| {
+            for step in 1..=2 {
+                on_progress(step, 2);
+                on_preview(image(1));
+            }
+            Ok(ImageGenerationResponse { images: vec![image(8)] })
+        });
+
+        let mut previews = Vec::new();
+        let mut images = Vec::new();
+        let mut header_seen = false;
+        loop {
+            match stream.recv().await.expect("stream should finish with Done") {
+                StreamChunk::Token(_) => {}
+                StreamChunk::Json(_) => header_seen = true,
+                StreamChunk::Image(Payload::Image { width, .. }) if header_seen => {
+                    images.push(width)
+                }
+                StreamChunk::Image(Payload::Image { width, .. }) => previews.push(width),
+                StreamChunk::Done => break,
+                other => panic!("unexpected chunk: {other:?}"),
+            }
+        }
+
+        assert_eq!(previews, [1, 1]);
+        assert_eq!(images, [8]);
+    }
+
+    #[tokio::test]
+    async fn progress_stream_surfaces_generation_error() {
+        let mut stream = spawn_progress_stream(|on_progress| {
+            on_progress(1, 4);
+            Err("sampler failed".to_owned())
+        });
+
+        assert!(matches!(stream.recv().await, Some(StreamChunk::Token(_))));
+        match stream.recv().await {
+            Some(StreamChunk::Error(message)) => assert_eq!(message, "sampler failed"),
+            other => panic!("expected error chunk, got {other:?}"),
+        }
+    }
 }
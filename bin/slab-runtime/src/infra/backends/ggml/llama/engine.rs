@@ -1,16 +1,17 @@
 use crate::infra::backends::ggml;
 use slab_agent_tracing::record_json_from_context;
 use slab_llama::{
-    Llama, LlamaContextParams, LlamaInferenceOutput, LlamaLogitBias, LlamaModel, LlamaModelParams,
-    LlamaRuntime, LlamaSamplingOptions, LlamaSessionSnapshot, LlamaStopInfo,
+    Llama, LlamaContextOverflow, LlamaContextParams, LlamaInferenceOutput, LlamaLogitBias,
+    LlamaModel, LlamaModelParams, LlamaRuntime, LlamaRuntimeOptions, LlamaSamplingOptions,
+    LlamaSessionAssignment, LlamaSessionSnapshot, LlamaStopInfo,
 };
 use slab_runtime_core::backend::{
     StreamChunk as BaseStreamChunk, StreamHandle as BaseStreamHandle,
 };
 use slab_utils::loader::load_library_from_dir;
 use std::collections::HashMap;
-use std::path::Path;
-use std::sync::{Arc, Mutex, MutexGuard, RwLock};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, MutexGuard, OnceLock, RwLock};
 use std::time::{Duration, Instant};
 use tokio::sync::{mpsc, watch};
 use tracing::{info, warn};
@@ -20,6 +21,7 @@ use crate::domain::models::{
     TextGenerationUsage, TextPromptTokensDetails, TextStopMetadata,
 };
 
+use super::session_store::{self, PersistedSession, PersistedSessionHeader, SnapshotWriter};
 use super::{GGMLLlamaEngineError, SessionId, StreamChunk, StreamHandle};
 
 #[derive(Debug, Clone)]
@@ -33,7 +35,11 @@ pub(crate) struct LlamaDispatchRequest {
     pub top_k: Option<i32>,
     pub min_p: Option<f32>,
     pub repetition_penalty: Option<f32>,
+    pub repeat_last_n: Option<i32>,
+    pub n_keep: Option<u32>,
     pub presence_penalty: Option<f32>,
+    pub frequency_penalty: Option<f32>,
+    pub seed: Option<u32>,
     pub ignore_eos: bool,
     pub logit_bias: Option<serde_json::Value>,
     pub stop_sequences: Vec<String>,
@@ -51,6 +57,9 @@ pub(crate) struct LlamaDispatchOutput {
 const THINK_OPEN_MARKER: &str = "<think";
 const THINK_CLOSE_TAG: &str = "</think>";
 const SESSION_BINDING_BUSY_TTL: Duration = Duration::from_secs(10 * 60);
+/// How long a managed session must stay untouched before its snapshot is
+/// written to disk.
+const SESSION_PERSIST_IDLE: Duration = Duration::from_secs(10);
 
 #[derive(Debug, Clone, Default, PartialEq, Eq)]
 struct ParsedThinkingOutput {
@@ -242,7 +251,11 @@ fn llama_request_payload(request: &LlamaDispatchRequest) -> serde_json::Value {
         "top_k": request.top_k,
         "min_p": request.min_p,
         "repetition_penalty": request.repetition_penalty,
+        "repeat_last_n": request.repeat_last_n,
+        "n_keep": request.n_keep,
         "presence_penalty": request.presence_penalty,
+        "frequency_penalty": request.frequency_penalty,
+        "seed": request.seed,
         "ignore_eos": request.ignore_eos,
         "logit_bias": request.logit_bias,
         "stop_sequences": request.stop_sequences,
@@ -334,12 +347,47 @@ fn plan_session_reuse(
     }
 }
 
+/// Rebuild a ready binding from disk.
+///
+/// KV state only fits a context with the same model and per-sequence length,
+/// so a snapshot without a recorded `n_ctx` is rejected too.
+fn binding_from_persisted(
+    persisted: PersistedSession,
+    model_path: &str,
+    n_ctx: u32,
+) -> Result<SessionBinding, GGMLLlamaEngineError> {
+    if persisted.header.model_path != model_path {
+        return Err(GGMLLlamaEngineError::PersistedSessionModelMismatch {
+            stored: persisted.header.model_path,
+            current: model_path.to_owned(),
+        });
+    }
+    if persisted.header.n_ctx == 0 || persisted.header.n_ctx != n_ctx {
+        return Err(GGMLLlamaEngineError::PersistedSessionContextMismatch {
+            stored: persisted.header.n_ctx,
+            current: n_ctx,
+        });
+    }
+    Ok(SessionBinding::Ready {
+        snapshot: LlamaSessionSnapshot {
+            worker_id: persisted.header.worker_id,
+            n_past: persisted.header.n_past,
+            state: Arc::from(persisted.state),
+        },
+        cached_prompt: persisted.header.cached_prompt,
+        grammar: persisted.header.grammar,
+    })
+}
+
 #[derive(Debug)]
 pub struct GGMLLlamaEngine {
     instance: Arc<Llama>,
     inference_engine: RwLock<Option<LlamaRuntime>>,
     loaded_model: RwLock<Option<Arc<LlamaModel>>>,
+    loaded_model_path: RwLock<Option<String>>,
     session_bindings: Mutex<HashMap<String, SessionBinding>>,
+    session_state_dir: OnceLock<PathBuf>,
+    snapshot_writer: SnapshotWriter,
 }
 
 // # Safety
@@ -384,8 +432,6 @@ impl GGMLLlamaEngine {
                 }
             })?;
 
-            llama.backend_init();
-
             // SAFETY: `Llama` wraps `Arc<slab_llama_sys::LlamaLib>` — a dlopen2-generated
             // handle that holds a read-only table of function pointers loaded once at startup.
             // After `Llama::new` returns the function pointer table is never mutated, making
@@ -399,11 +445,60 @@ impl GGMLLlamaEngine {
                 instance: Arc::new(llama),
                 inference_engine: RwLock::new(None),
                 loaded_model: RwLock::new(None),
+                loaded_model_path: RwLock::new(None),
                 session_bindings: Mutex::new(HashMap::new()),
+                session_state_dir: OnceLock::new(),
+                snapshot_writer: SnapshotWriter::default(),
             }))
         })
     }
 
+    /// Create an empty engine that shares this engine's library handle.
+    ///
+    /// Used to keep several models loaded side by side without re-opening the
+    /// dynamic library or re-initialising the backend.
+    pub fn fork_library(&self) -> Arc<Self> {
+        #[allow(clippy::arc_with_non_send_sync)]
+        Arc::new(Self {
+            instance: Arc::clone(&self.instance),
+            inference_engine: RwLock::new(None),
+            loaded_model: RwLock::new(None),
+            loaded_model_path: RwLock::new(None),
+            session_bindings: Mutex::new(HashMap::new()),
+            session_state_dir: self.session_state_dir.clone(),
+            snapshot_writer: self.snapshot_writer.clone(),
+        })
+    }
+
+    /// Persist managed session snapshots under `dir` so they survive a restart.
+    ///
+    /// Only the first call takes effect; forked engines inherit the directory.
+    pub fn set_session_state_dir(&self, dir: PathBuf) {
+        let _ = self.session_state_dir.set(dir);
+    }
+
+    /// Whether any session is mid-request (within the busy TTL).
+    pub fn has_busy_sessions(&self) -> bool {
+        self.session_bindings.lock().map_or(true, |bindings| {
+            bindings.values().any(|binding| {
+                matches!(binding, SessionBinding::Busy { started_at, .. }
+                    if started_at.elapsed() < SESSION_BINDING_BUSY_TTL)
+            })
+        })
+    }
+
+    /// Size in bytes of the loaded model's tensors, if a model is loaded.
+    pub fn loaded_model_size(&self) -> Option<u64> {
+        self.loaded_model.read().ok()?.as_ref().map(|model| model.model_size())
+    }
+
+    fn set_loaded_model_path(&self, path: Option<String>) -> Result<(), GGMLLlamaEngineError> {
+        *self.loaded_model_path.write().map_err(|_| GGMLLlamaEngineError::LockPoisoned {
+            operation: "lock loaded llama model path",
+        })? = path;
+        Ok(())
+    }
+
     fn lock_session_bindings(
         &self,
     ) -> Result<MutexGuard<'_, HashMap<String, SessionBinding>>, GGMLLlamaEngineError> {
@@ -414,13 +509,20 @@ impl GGMLLlamaEngine {
 
     /// Load a model and start a multi-worker inference engine.
     ///
-    /// Any previously loaded model/engine are replaced.
+    /// `options` picks context-overflow handling and how new sessions are
+    /// assigned to workers.
+    ///
+    /// Any previously loaded model/engine are replaced: the old runtime handle
+    /// is dropped before the new model loads, so its worker threads exit and
+    /// free their contexts once in-flight requests finish. Each of the
+    /// `num_workers` new workers owns its own `LlamaContext`.
     pub fn load_model_with_workers<P: AsRef<Path>>(
         &self,
         path_to_model: P,
         model_params: LlamaModelParams,
         ctx_params: LlamaContextParams,
         num_workers: usize,
+        options: LlamaRuntimeOptions,
     ) -> Result<GgmlLlamaLoadMetadata, ggml::EngineError> {
         if num_workers == 0 {
             return Err(GGMLLlamaEngineError::InvalidWorkerCount { num_workers }.into());
@@ -434,6 +536,7 @@ impl GGMLLlamaEngine {
             GGMLLlamaEngineError::LockPoisoned { operation: "lock loaded llama model state" }
         })?;
         *model_write_lock = None;
+        self.set_loaded_model_path(None)?;
         self.lock_session_bindings()?.clear();
 
         let path =
@@ -446,13 +549,16 @@ impl GGMLLlamaEngine {
         let training_context_length =
             u32::try_from(model.n_ctx_train()).ok().filter(|value| *value > 0);
 
-        let engine = LlamaRuntime::start(num_workers, Arc::clone(&model), ctx_params)
-            .map_err(GGMLLlamaEngineError::from)?;
+        let engine =
+            LlamaRuntime::start_with_options(num_workers, Arc::clone(&model), ctx_params, options)
+                .map_err(GGMLLlamaEngineError::from)?;
+        info!(model_path = path, num_workers, "llama inference runtime started");
         let loaded_context_length = engine.context_length();
         let context_length = (loaded_context_length > 0).then_some(loaded_context_length);
 
         *write_lock = Some(engine);
         *model_write_lock = Some(model);
+        self.set_loaded_model_path(Some(path.to_owned()))?;
         Ok(GgmlLlamaLoadMetadata { context_length, training_context_length })
     }
 
@@ -480,9 +586,26 @@ impl GGMLLlamaEngine {
             LlamaModelParams::default(),
             ctx_params,
             config.engine_workers,
+            LlamaRuntimeOptions {
+                overflow: if config.stop_on_context_full {
+                    LlamaContextOverflow::Stop
+                } else {
+                    LlamaContextOverflow::Shift
+                },
+                assignment: if config.least_loaded_sessions {
+                    LlamaSessionAssignment::LeastLoaded
+                } else {
+                    LlamaSessionAssignment::RoundRobin
+                },
+            },
         )
     }
 
+    /// Number of inference workers in the running runtime, if a model is loaded.
+    pub fn num_workers(&self) -> Option<usize> {
+        self.inference_engine.read().ok()?.as_ref().map(LlamaRuntime::num_workers)
+    }
+
     fn require_engine(&self) -> Result<LlamaRuntime, ggml::EngineError> {
         let read_lock: std::sync::RwLockReadGuard<'_, Option<LlamaRuntime>> =
             self.inference_engine.read().map_err(|_| GGMLLlamaEngineError::LockPoisoned {
@@ -492,7 +615,7 @@ impl GGMLLlamaEngine {
         Ok(engine.clone())
     }
 
-    fn require_model(&self) -> Result<Arc<LlamaModel>, ggml::EngineError> {
+    fn require_model(&self) -> Result<Arc<LlamaModel>, GGMLLlamaEngineError> {
         let read_lock = self.loaded_model.read().map_err(|_| {
             GGMLLlamaEngineError::LockPoisoned { operation: "read loaded llama model state" }
         })?;
@@ -592,10 +715,19 @@ impl GGMLLlamaEngine {
             });
         };
 
+        let restored = if self.lock_session_bindings()?.contains_key(&key) {
+            None
+        } else {
+            self.restore_persisted_binding(&key).await
+        };
+
         let plan;
 
         {
             let mut bindings = self.lock_session_bindings()?;
+            if let Some(binding) = restored {
+                bindings.entry(key.clone()).or_insert(binding);
+            }
             plan =
                 plan_session_reuse(&key, bindings.get(&key), &full_prompt, request.gbnf.as_deref())
                     .map_err(ggml::EngineError::from)?;
@@ -623,9 +755,13 @@ impl GGMLLlamaEngine {
             top_k: request.top_k,
             min_p: request.min_p,
             repetition_penalty: request.repetition_penalty,
+            frequency_penalty: request.frequency_penalty,
             presence_penalty: request.presence_penalty,
+            repeat_last_n: request.repeat_last_n,
+            seed: request.seed,
             ignore_eos: request.ignore_eos,
             logit_bias: logit_bias.to_vec(),
+            n_keep: request.n_keep.map_or(0, |n_keep| n_keep as usize),
         };
 
         let (sid, delta_prompt, cached_tokens) = match plan {
@@ -652,16 +788,18 @@ impl GGMLLlamaEngine {
         Ok(PreparedSession { key: Some(key), sid, delta_prompt, full_prompt, cached_tokens })
     }
 
+    /// Build usage counts for a finished generation.
+    ///
+    /// `completion_tokens` is the number of tokens the runtime sampled, so text
+    /// trimmed at a stop sequence is still billed for what was generated.
     fn build_usage(
         &self,
         prompt: &str,
-        generated: &str,
+        completion_tokens: u32,
         cached_tokens: u32,
     ) -> Option<TextGenerationUsage> {
         let model = self.require_model().ok()?;
         let prompt_tokens = u32::try_from(model.tokenize(prompt, false, true).ok()?.len()).ok()?;
-        let completion_tokens =
-            u32::try_from(model.tokenize(generated, false, true).ok()?.len()).ok()?;
         let cached_tokens = cached_tokens.min(prompt_tokens);
 
         Some(TextGenerationUsage {
@@ -701,11 +839,93 @@ impl GGMLLlamaEngine {
         let mut cached_prompt = String::with_capacity(full_prompt.len() + generated.len());
         cached_prompt.push_str(full_prompt);
         cached_prompt.push_str(generated);
+        self.persist_session_binding(&key, &snapshot, &cached_prompt, gbnf.as_deref());
         self.lock_session_bindings()?
             .insert(key, SessionBinding::Ready { snapshot, cached_prompt, grammar: gbnf });
         Ok(())
     }
 
+    /// Load the on-disk snapshot for `key`, if any, as a ready binding.
+    ///
+    /// Snapshots written for a different model or context length are discarded.
+    async fn restore_persisted_binding(&self, key: &str) -> Option<SessionBinding> {
+        let dir = self.session_state_dir.get()?.clone();
+        let model_path = self.loaded_model_path.read().ok()?.clone()?;
+        let n_ctx = self.require_engine().ok()?.context_length();
+        let file_key = key.to_owned();
+        let loaded =
+            tokio::task::spawn_blocking(move || session_store::load(&dir, &file_key)).await;
+
+        let persisted = match loaded {
+            Ok(Ok(Some(persisted))) => persisted,
+            Ok(Ok(None)) => return None,
+            Ok(Err(error)) => {
+                warn!(session_key = key, %error, "failed to read persisted llama session");
+                self.remove_persisted_session(key);
+                return None;
+            }
+            Err(error) => {
+                warn!(session_key = key, %error, "persisted llama session read task failed");
+                return None;
+            }
+        };
+        let n_past = persisted.header.n_past;
+        let binding = match binding_from_persisted(persisted, &model_path, n_ctx) {
+            Ok(binding) => binding,
+            Err(error) => {
+                warn!(session_key = key, %error, "discarding incompatible persisted llama session");
+                self.remove_persisted_session(key);
+                return None;
+            }
+        };
+        info!(session_key = key, n_past, "restored persisted llama session snapshot");
+        Some(binding)
+    }
+
+    /// Schedule a snapshot write for `key` once the session has been idle for
+    /// [`SESSION_PERSIST_IDLE`]; a later commit for the same key supersedes it.
+    fn persist_session_binding(
+        &self,
+        key: &str,
+        snapshot: &LlamaSessionSnapshot,
+        cached_prompt: &str,
+        grammar: Option<&str>,
+    ) {
+        let Some(dir) = self.session_state_dir.get().cloned() else {
+            return;
+        };
+        let Some(model_path) = self.loaded_model_path.read().ok().and_then(|path| path.clone())
+        else {
+            return;
+        };
+        let Ok(engine) = self.require_engine() else {
+            return;
+        };
+        let header = PersistedSessionHeader::new(
+            model_path,
+            engine.context_length(),
+            snapshot.worker_id,
+            snapshot.n_past,
+            cached_prompt.to_owned(),
+            grammar.map(str::to_owned),
+        );
+        self.snapshot_writer.schedule(
+            dir,
+            key.to_owned(),
+            header,
+            Arc::clone(&snapshot.state),
+            SESSION_PERSIST_IDLE,
+        );
+    }
+
+    fn remove_persisted_session(&self, key: &str) {
+        if let Some(dir) = self.session_state_dir.get()
+            && let Err(error) = self.snapshot_writer.remove(dir, key)
+        {
+            warn!(session_key = key, %error, "failed to remove persisted llama session");
+        }
+    }
+
     async fn drop_managed_session(&self, key: Option<String>, sid: Option<SessionId>) {
         if let Some(key) = key {
             match self.lock_session_bindings() {
@@ -772,8 +992,11 @@ impl GGMLLlamaEngine {
                         }),
                     );
                 }
-                let usage =
-                    self.build_usage(&prepared.full_prompt, &trimmed_text, prepared.cached_tokens);
+                let usage = self.build_usage(
+                    &prepared.full_prompt,
+                    output.completion_tokens,
+                    prepared.cached_tokens,
+                );
                 let finish_reason = if stop_matched {
                     Some("stop".to_owned())
                 } else {
@@ -876,6 +1099,7 @@ impl GGMLLlamaEngine {
                 gbnf,
                 request.ignore_eos,
                 &logit_bias,
+                cancel_rx.clone(),
             )
             .await
         {
@@ -892,6 +1116,9 @@ impl GGMLLlamaEngine {
             let PreparedSession { key, full_prompt, cached_tokens, .. } = prepared;
             let gbnf = commit_gbnf;
             let mut generated = String::new();
+            // Sampled tokens; the runtime's count in the stop chunk replaces
+            // this piece count when the generation runs to completion.
+            let mut completion_tokens: u32 = 0;
             let mut completed = false;
             let mut forward_failed = false;
             let mut stream_error = false;
@@ -943,6 +1170,7 @@ impl GGMLLlamaEngine {
 
                         match chunk {
                             StreamChunk::Token(text) => {
+                                completion_tokens = completion_tokens.saturating_add(1);
                                 if let Some(trace_context) = agent_trace.as_ref() {
                                     record_json_from_context(
                                         trace_context,
@@ -1068,6 +1296,7 @@ impl GGMLLlamaEngine {
                                 break;
                             }
                             StreamChunk::Stop(stop) => {
+                                completion_tokens = stop.completion_tokens;
                                 terminal_finish_reason = Some(stop.finish_reason.clone());
                                 terminal_metadata = stop_info_to_metadata(&stop);
                                 if let Some(trace_context) = agent_trace.as_ref() {
@@ -1160,7 +1389,8 @@ impl GGMLLlamaEngine {
                 && !forward_failed
                 && !stream_error
                 && !cancelled
-                && let Some(usage) = engine.build_usage(&full_prompt, &generated, cached_tokens)
+                && let Some(usage) =
+                    engine.build_usage(&full_prompt, completion_tokens, cached_tokens)
             {
                 if let Some(trace_context) = agent_trace.as_ref() {
                     record_json_from_context(
@@ -1307,6 +1537,21 @@ impl GGMLLlamaEngine {
             .map_err(Into::into)
     }
 
+    /// Start streaming generation that the llama worker stops once `cancel_rx` flips.
+    pub async fn generate_stream_with_cancel(
+        &self,
+        session_id: SessionId,
+        max_new_tokens: usize,
+        cancel_rx: watch::Receiver<bool>,
+    ) -> Result<StreamHandle, ggml::EngineError> {
+        let engine = self.require_engine()?;
+        engine
+            .generate_stream_with_cancel(session_id, max_new_tokens, cancel_rx)
+            .await
+            .map_err(GGMLLlamaEngineError::from)
+            .map_err(Into::into)
+    }
+
     /// End a session and release its KV entries.
     pub async fn end_session(&self, session_id: SessionId) -> Result<(), ggml::EngineError> {
         let engine = self.require_engine()?;
@@ -1380,23 +1625,7 @@ impl GGMLLlamaEngine {
                 return Err(error);
             }
         };
-        let mut output = String::new();
-        let mut terminal_stop: Option<LlamaStopInfo> = None;
-        let mut stream_error: Option<GGMLLlamaEngineError> = None;
-
-        while let Some(chunk) = stream.recv().await {
-            match chunk {
-                StreamChunk::Token(piece) => output.push_str(&piece),
-                StreamChunk::Stop(stop) => {
-                    terminal_stop = Some(stop);
-                }
-                StreamChunk::Done => break,
-                StreamChunk::Error(message) => {
-                    stream_error = Some(GGMLLlamaEngineError::InferenceStreamError { message });
-                    break;
-                }
-            }
-        }
+        let (output, stream_error) = collect_inference_output(&mut stream).await;
 
         if should_end {
             let end_result = self.end_session(sid).await;
@@ -1409,7 +1638,7 @@ impl GGMLLlamaEngine {
             return Err(error.into());
         }
 
-        Ok(LlamaInferenceOutput { text: output, stop: terminal_stop })
+        Ok(output)
     }
 
     /// Generate text from a prompt as an async stream.
@@ -1424,6 +1653,10 @@ impl GGMLLlamaEngine {
     /// management).  `gbnf`, `ignore_eos`, and `logit_bias` are ignored when
     /// `session_id` is `Some` because the session's sampler was already built
     /// at creation time.
+    ///
+    /// `cancel_rx` is forwarded to the decode loop so a cancelled request stops
+    /// generating at the next batch step instead of running to `max_tokens`.
+    #[allow(clippy::too_many_arguments)]
     pub async fn inference_stream(
         &self,
         prompt: &str,
@@ -1432,6 +1665,7 @@ impl GGMLLlamaEngine {
         gbnf: Option<String>,
         ignore_eos: bool,
         logit_bias: &[LlamaLogitBias],
+        cancel_rx: watch::Receiver<bool>,
     ) -> Result<(StreamHandle, SessionId), ggml::EngineError> {
         let sid = match session_id {
             Some(sid) => sid,
@@ -1453,7 +1687,7 @@ impl GGMLLlamaEngine {
             return Err(error);
         }
 
-        let stream = match self.generate_stream(sid, max_tokens).await {
+        let stream = match self.generate_stream_with_cancel(sid, max_tokens, cancel_rx).await {
             Ok(stream) => stream,
             Err(error) => {
                 if session_id.is_none() {
@@ -1477,16 +1711,114 @@ impl GGMLLlamaEngine {
             GGMLLlamaEngineError::LockPoisoned { operation: "lock loaded llama model state" }
         })?;
         *model_write_lock = None;
+        self.set_loaded_model_path(None)?;
         self.lock_session_bindings()?.clear();
         Ok(())
     }
 
+    /// Decode the BOS token once on every inference worker so kernels are
+    /// compiled and KV buffers allocated before the first real request.
+    pub async fn warmup(&self) -> Result<(), ggml::EngineError> {
+        let num_workers = self.num_workers().ok_or(GGMLLlamaEngineError::ModelNotLoaded)?;
+        let model = self.require_model()?;
+        let bos = model
+            .token_bos()
+            .and_then(|bos| model.token_to_piece(bos, true).ok())
+            .unwrap_or_default();
+        let prompt = if bos.is_empty() { " ".to_owned() } else { bos };
+
+        // Hold every warmup session open until all are placed: round-robin
+        // and least-loaded assignment then both put one on each worker.
+        let mut sessions = Vec::with_capacity(num_workers);
+        let mut result = Ok(());
+        for _ in 0..num_workers {
+            match self.create_session_with_options(LlamaSamplingOptions::default()).await {
+                Ok(sid) => sessions.push(sid),
+                Err(error) => {
+                    result = Err(error);
+                    break;
+                }
+            }
+        }
+        if result.is_ok() {
+            for &sid in &sessions {
+                if let Err(error) = self.inference(&prompt, 1, Some(sid), None, false, &[]).await {
+                    result = Err(error);
+                    break;
+                }
+            }
+        }
+        for sid in sessions {
+            let end_result = self.end_session(sid).await;
+            if result.is_ok() {
+                result = end_result;
+            }
+        }
+        result
+    }
+
+    /// Tokenize `text` with the loaded model's vocabulary; no context or
+    /// inference worker is involved.
+    pub fn tokenize(&self, text: &str, add_special: bool) -> Result<Vec<i32>, ggml::EngineError> {
+        let model = self.require_model()?;
+        Ok(model.tokenize(text, add_special, true).map_err(|source| {
+            GGMLLlamaEngineError::Tokenize { operation: "tokenize text", source }
+        })?)
+    }
+
+    /// Turn token ids back into text with the loaded model's vocabulary.
+    ///
+    /// Ids outside the vocabulary fail with a [`slab_llama::LlamaError::TokenOutOfRange`]
+    /// source before reaching llama.cpp.
+    pub fn detokenize(&self, tokens: &[i32]) -> Result<String, GGMLLlamaEngineError> {
+        let model = self.require_model()?;
+        model.detokenize(tokens, false, true).map_err(|source| GGMLLlamaEngineError::Tokenize {
+            operation: "detokenize tokens",
+            source,
+        })
+    }
+
     /// Unload the current model and stop all inference workers.
+    ///
+    /// Dropping the last runtime handle closes the worker channels, so each
+    /// worker thread frees its context and model reference once any in-flight
+    /// stream finishes. Later requests fail with [`GGMLLlamaEngineError::ModelNotLoaded`].
     pub fn unload(&self) -> Result<(), ggml::EngineError> {
         Ok(self.do_unload()?)
     }
 }
 
+/// Drain a generation stream into text, stop info and the sampled token count.
+///
+/// Pieces are counted until the stop chunk arrives with the runtime's exact
+/// count; a piece can hold more than one token when UTF-8 bytes were buffered.
+async fn collect_inference_output(
+    stream: &mut StreamHandle,
+) -> (LlamaInferenceOutput, Option<GGMLLlamaEngineError>) {
+    let mut output = LlamaInferenceOutput::default();
+    let mut stream_error = None;
+
+    while let Some(chunk) = stream.recv().await {
+        match chunk {
+            StreamChunk::Token(piece) => {
+                output.text.push_str(&piece);
+                output.completion_tokens = output.completion_tokens.saturating_add(1);
+            }
+            StreamChunk::Stop(stop) => {
+                output.completion_tokens = stop.completion_tokens;
+                output.stop = Some(stop);
+            }
+            StreamChunk::Done => break,
+            StreamChunk::Error(message) => {
+                stream_error = Some(GGMLLlamaEngineError::InferenceStreamError { message });
+                break;
+            }
+        }
+    }
+
+    (output, stream_error)
+}
+
 async fn forward_thinking_delta(
     stream_tx: &mpsc::Sender<BaseStreamChunk>,
     delta: ThinkingDelta,
@@ -1514,11 +1846,19 @@ fn reasoning_event_payload(reasoning: String) -> serde_json::Value {
 #[cfg(test)]
 mod tests {
     use super::{
-        ParsedThinkingOutput, SESSION_BINDING_BUSY_TTL, SessionBinding, SessionReusePlan,
-        ThinkingDelta, ThinkingStreamState, parse_generated_thinking_output, parse_thinking_output,
-        plan_session_reuse,
+        GGMLLlamaEngine, GGMLLlamaEngineError, ParsedThinkingOutput, SESSION_BINDING_BUSY_TTL,
+        SessionBinding, SessionReusePlan, StreamChunk, ThinkingDelta, ThinkingStreamState,
+        binding_from_persisted, collect_inference_output, parse_generated_thinking_output,
+        parse_thinking_output, plan_session_reuse,
     };
-    use slab_llama::LlamaSessionSnapshot;
+    use crate::infra::backends::ggml::llama::session_store::{
+        PersistedSession, PersistedSessionHeader,
+    };
+    use slab_llama::{
+        LlamaContextParams, LlamaModelParams, LlamaRuntimeOptions, LlamaSamplingOptions,
+        LlamaSessionAssignment, LlamaSessionSnapshot, LlamaStopInfo,
+    };
+    use std::path::PathBuf;
     use std::sync::Arc;
     use std::time::{Duration, Instant};
 
@@ -1526,6 +1866,76 @@ mod tests {
         LlamaSessionSnapshot { worker_id: 1, n_past: 12, state: Arc::from([1_u8, 2, 3, 4]) }
     }
 
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    #[ignore = "requires local llama runtime libraries and a GGUF model"]
+    async fn reload_restarts_runtime_with_requested_worker_count() {
+        let llama_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../testdata/llama");
+        let engine = GGMLLlamaEngine::from_path(&llama_dir).expect("llama runtime libraries");
+        let model_path = llama_dir.join("model.gguf");
+        let load = |num_workers| {
+            engine.load_model_with_workers(
+                &model_path,
+                LlamaModelParams::default(),
+                LlamaContextParams::default(),
+                num_workers,
+                LlamaRuntimeOptions::default(),
+            )
+        };
+
+        load(1).expect("load with one worker");
+        assert_eq!(engine.num_workers(), Some(1));
+
+        load(2).expect("reload with two workers");
+        assert_eq!(engine.num_workers(), Some(2));
+
+        let (first, second) = tokio::join!(
+            engine.inference("Hello", 8, None, None, false, &[]),
+            engine.inference("Bonjour", 8, None, None, false, &[]),
+        );
+        assert!(!first.expect("first session").text.is_empty());
+        assert!(!second.expect("second session").text.is_empty());
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    #[ignore = "requires local llama runtime libraries and a GGUF model"]
+    async fn held_sessions_land_on_distinct_workers_with_least_loaded_assignment() {
+        let llama_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../testdata/llama");
+        let engine = GGMLLlamaEngine::from_path(&llama_dir).expect("llama runtime libraries");
+        engine
+            .load_model_with_workers(
+                llama_dir.join("model.gguf"),
+                LlamaModelParams::default(),
+                LlamaContextParams::default(),
+                2,
+                LlamaRuntimeOptions {
+                    assignment: LlamaSessionAssignment::LeastLoaded,
+                    ..LlamaRuntimeOptions::default()
+                },
+            )
+            .expect("load with two workers");
+
+        // Sessions held open together are spread one per worker instead of
+        // all landing on the first idle worker; warmup relies on this.
+        let first = engine
+            .create_session_with_options(LlamaSamplingOptions::default())
+            .await
+            .expect("first session");
+        let second = engine
+            .create_session_with_options(LlamaSamplingOptions::default())
+            .await
+            .expect("second session");
+        let workers = [
+            engine.snapshot_session(first).await.expect("first snapshot").worker_id,
+            engine.snapshot_session(second).await.expect("second snapshot").worker_id,
+        ];
+        assert_ne!(workers[0], workers[1]);
+        engine.end_session(first).await.expect("end first");
+        engine.end_session(second).await.expect("end second");
+
+        // Smoke check only: warmup does not report which workers it reached.
+        engine.warmup().await.expect("warmup");
+    }
+
     #[test]
     fn plan_session_reuse_creates_fresh_when_no_binding_exists() {
         let plan = plan_session_reuse("chat-1", None, "hello", None).expect("plan should succeed");
@@ -1594,6 +2004,68 @@ mod tests {
         }
     }
 
+    #[test]
+    fn persisted_binding_only_decodes_new_turn_after_restart() {
+        let first_turn = "<|user|>hi<|assistant|>hello";
+        let persisted = PersistedSession {
+            header: PersistedSessionHeader::new(
+                "/models/a.gguf".to_owned(),
+                2048,
+                0,
+                9,
+                first_turn.to_owned(),
+                None,
+            ),
+            state: vec![7, 7, 7],
+        };
+
+        assert!(binding_from_persisted(persisted.clone(), "/models/b.gguf", 2048).is_err());
+
+        let binding =
+            binding_from_persisted(persisted, "/models/a.gguf", 2048).expect("same model restores");
+        let second_turn = format!("{first_turn}<|user|>again<|assistant|>");
+        let plan = plan_session_reuse("chat-1", Some(&binding), &second_turn, None)
+            .expect("plan should succeed");
+
+        match plan {
+            SessionReusePlan::RestoreSnapshot { snapshot, delta_prompt, cached_tokens } => {
+                assert_eq!(snapshot.state.as_ref(), &[7, 7, 7]);
+                assert_eq!(delta_prompt, "<|user|>again<|assistant|>");
+                assert_eq!(cached_tokens, 9);
+            }
+            SessionReusePlan::CreateFresh { .. } => panic!("expected snapshot restore plan"),
+        }
+    }
+
+    #[test]
+    fn persisted_binding_rejects_snapshot_from_a_different_context_length() {
+        let persisted = |n_ctx| PersistedSession {
+            header: PersistedSessionHeader::new(
+                "/models/a.gguf".to_owned(),
+                n_ctx,
+                0,
+                9,
+                "<|user|>hi".to_owned(),
+                None,
+            ),
+            state: vec![7, 7, 7],
+        };
+
+        let error = binding_from_persisted(persisted(2048), "/models/a.gguf", 8192)
+            .expect_err("larger context must not reuse the snapshot");
+        assert!(matches!(
+            error,
+            GGMLLlamaEngineError::PersistedSessionContextMismatch { stored: 2048, current: 8192 }
+        ));
+        assert!(error.to_string().contains("n_ctx 2048"));
+
+        assert!(matches!(
+            binding_from_persisted(persisted(0), "/models/a.gguf", 8192),
+            Err(GGMLLlamaEngineError::PersistedSessionContextMismatch { stored: 0, .. })
+        ));
+        assert!(binding_from_persisted(persisted(8192), "/models/a.gguf", 8192).is_ok());
+    }
+
     #[test]
     fn plan_session_reuse_invalidates_snapshot_on_grammar_change() {
         let binding = SessionBinding::Ready {
@@ -1615,6 +2087,42 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn collect_inference_output_counts_sampled_tokens() {
+        let pieces = ["Hel", "lo", ",", " world"];
+        let piece_stream = || async move {
+            let (tx, rx) = tokio::sync::mpsc::channel(8);
+            for piece in pieces {
+                tx.send(StreamChunk::Token(piece.to_owned())).await.expect("send piece");
+            }
+            (tx, rx)
+        };
+
+        // Without a stop chunk the streamed pieces are the best count available.
+        let (tx, mut rx) = piece_stream().await;
+        drop(tx);
+        let (partial, _) = collect_inference_output(&mut rx).await;
+        assert_eq!(partial.completion_tokens as usize, pieces.len());
+
+        // The runtime's sampled count wins, e.g. when a piece held two tokens.
+        let (tx, mut rx) = piece_stream().await;
+        tx.send(StreamChunk::Stop(LlamaStopInfo {
+            finish_reason: "stop".to_owned(),
+            completion_tokens: 5,
+            ..LlamaStopInfo::default()
+        }))
+        .await
+        .expect("send stop");
+        tx.send(StreamChunk::Done).await.expect("send done");
+
+        let (output, error) = collect_inference_output(&mut rx).await;
+
+        assert!(error.is_none());
+        assert_eq!(output.text, "Hello, world");
+        assert_eq!(output.completion_tokens, 5);
+        assert_eq!(output.stop.map(|stop| stop.finish_reason), Some("stop".to_owned()));
+    }
+
     #[test]
     fn parse_thinking_output_extracts_reasoning_block() {
         let parsed = parse_thinking_output("<think>step one</think>\n\nfinal answer", true);
@@ -33,9 +33,24 @@ pub enum GGMLLlamaEngineError {
         source: LlamaError,
     },
 
+    #[error("Failed to {operation} with the loaded llama model")]
+    Tokenize {
+        operation: &'static str,
+        #[source]
+        source: LlamaError,
+    },
+
     #[error("Session key '{key}' is already active")]
     SessionKeyBusy { key: String },
 
+    #[error("Persisted session was saved for model '{stored}', but '{current}' is loaded")]
+    PersistedSessionModelMismatch { stored: String, current: String },
+
+    #[error(
+        "Persisted session was saved with n_ctx {stored}, but the loaded context has n_ctx {current}"
+    )]
+    PersistedSessionContextMismatch { stored: u32, current: u32 },
+
     #[error(transparent)]
     Runtime(#[from] LlamaRuntimeError),
 
@@ -58,6 +73,12 @@ pub(crate) enum GGMLLlamaWorkerError {
     Sync { message: String },
     #[error("internal error: {message}")]
     Internal { message: String },
+    #[error("invalid argument: {message}")]
+    InvalidArgument { message: String },
+    #[error("model not loaded: {message}")]
+    ModelNotLoaded { message: String },
+    #[error("library not loaded: {message}")]
+    LibraryNotLoaded { message: String },
 }
 
 #[allow(dead_code)]
@@ -86,3 +107,5 @@ impl GGMLLlamaWorkerError {
         Self::Internal { message: message.into() }
     }
 }
+
+slab_runtime_core::impl_backend_error_kinds!(GGMLLlamaWorkerError);
@@ -1,5 +1,6 @@
 pub(crate) mod engine;
 mod error;
+mod session_store;
 mod worker;
 
 pub use engine::GGMLLlamaEngine;
@@ -0,0 +1,313 @@
+//! On-disk persistence for managed llama session snapshots.
+//!
+//! Each session key maps to one file holding a small JSON header followed by
+//! the raw `llama_state_seq_get_data` bytes, so a restarted runtime can restore
+//! the KV cache instead of re-decoding the whole chat history. Writes go
+//! through [`SnapshotWriter`], which only lands a snapshot once its session has
+//! gone idle.
+
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use slab_utils::hash::sha256_hex_bytes;
+use tracing::warn;
+
+const SESSION_FILE_EXTENSION: &str = "llama-session";
+const SESSION_FILE_VERSION: u32 = 1;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct PersistedSessionHeader {
+    pub version: u32,
+    pub model_path: String,
+    /// Per-sequence context length the state was captured with.
+    pub n_ctx: u32,
+    pub worker_id: usize,
+    pub n_past: i32,
+    pub cached_prompt: String,
+    pub grammar: Option<String>,
+}
+
+impl PersistedSessionHeader {
+    pub(crate) fn new(
+        model_path: String,
+        n_ctx: u32,
+        worker_id: usize,
+        n_past: i32,
+        cached_prompt: String,
+        grammar: Option<String>,
+    ) -> Self {
+        Self {
+            version: SESSION_FILE_VERSION,
+            model_path,
+            n_ctx,
+            worker_id,
+            n_past,
+            cached_prompt,
+            grammar,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct PersistedSession {
+    pub header: PersistedSessionHeader,
+    pub state: Vec<u8>,
+}
+
+/// Session keys are caller-controlled, so the file is named after their
+/// SHA-256 digest: it stays inside `dir` and has a fixed length.
+pub(crate) fn session_file_path(dir: &Path, key: &str) -> PathBuf {
+    dir.join(format!("{}.{SESSION_FILE_EXTENSION}", sha256_hex_bytes(key.as_bytes())))
+}
+
+pub(crate) fn save(
+    dir: &Path,
+    key: &str,
+    header: &PersistedSessionHeader,
+    state: &[u8],
+) -> io::Result<()> {
+    fs::create_dir_all(dir)?;
+    let header = serde_json::to_vec(header).map_err(io::Error::other)?;
+    let header_len = u32::try_from(header.len()).map_err(io::Error::other)?;
+
+    let mut bytes = Vec::with_capacity(4 + header.len() + state.len());
+    bytes.extend_from_slice(&header_len.to_le_bytes());
+    bytes.extend_from_slice(&header);
+    bytes.extend_from_slice(state);
+
+    // Write to a sibling file first so a crash never leaves a truncated snapshot.
+    let path = session_file_path(dir, key);
+    let tmp_path = path.with_extension(format!("{SESSION_FILE_EXTENSION}.tmp"));
+    fs::write(&tmp_path, bytes)?;
+    fs::rename(&tmp_path, &path)
+}
+
+/// Returns `Ok(None)` when no snapshot exists for `key`.
+pub(crate) fn load(dir: &Path, key: &str) -> io::Result<Option<PersistedSession>> {
+    let bytes = match fs::read(session_file_path(dir, key)) {
+        Ok(bytes) => bytes,
+        Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(error) => return Err(error),
+    };
+
+    let invalid = |message: &str| io::Error::new(io::ErrorKind::InvalidData, message.to_owned());
+    let (len_bytes, rest) =
+        bytes.split_first_chunk::<4>().ok_or_else(|| invalid("session file is truncated"))?;
+    let header_len = u32::from_le_bytes(*len_bytes) as usize;
+    if rest.len() < header_len {
+        return Err(invalid("session file header is truncated"));
+    }
+    let (header, state) = rest.split_at(header_len);
+    let header: PersistedSessionHeader =
+        serde_json::from_slice(header).map_err(|error| invalid(&error.to_string()))?;
+    if header.version != SESSION_FILE_VERSION {
+        return Err(invalid("unsupported session file version"));
+    }
+
+    Ok(Some(PersistedSession { header, state: state.to_vec() }))
+}
+
+pub(crate) fn remove(dir: &Path, key: &str) -> io::Result<()> {
+    match fs::remove_file(session_file_path(dir, key)) {
+        Err(error) if error.kind() != io::ErrorKind::NotFound => Err(error),
+        _ => Ok(()),
+    }
+}
+
+#[derive(Debug, Default)]
+struct PendingWrites {
+    next_generation: u64,
+    by_key: HashMap<String, u64>,
+}
+
+/// Debounces snapshot writes per session key.
+///
+/// Every commit schedules a write after `delay`; a newer schedule or a
+/// [`SnapshotWriter::remove`] for the same key supersedes it, so a busy chat
+/// only hits the disk once it pauses. Clones share the pending state.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct SnapshotWriter {
+    pending: Arc<Mutex<PendingWrites>>,
+}
+
+impl SnapshotWriter {
+    /// Write `header` and `state` for `key` unless a newer write or removal
+    /// is requested within `delay`.
+    pub(crate) fn schedule(
+        &self,
+        dir: PathBuf,
+        key: String,
+        header: PersistedSessionHeader,
+        state: Arc<[u8]>,
+        delay: Duration,
+    ) -> tokio::task::JoinHandle<()> {
+        let generation = {
+            let mut pending = self.lock();
+            pending.next_generation += 1;
+            let generation = pending.next_generation;
+            pending.by_key.insert(key.clone(), generation);
+            generation
+        };
+        let pending = Arc::clone(&self.pending);
+        tokio::spawn(async move {
+            tokio::time::sleep(delay).await;
+            let written = tokio::task::spawn_blocking(move || {
+                // Held across the write so a concurrent removal cannot be undone.
+                let mut pending = pending.lock().unwrap_or_else(|error| error.into_inner());
+                if pending.by_key.get(&key) != Some(&generation) {
+                    return Ok(());
+                }
+                pending.by_key.remove(&key);
+                save(&dir, &key, &header, &state).map_err(|error| (key, error))
+            })
+            .await;
+            match written {
+                Ok(Ok(())) => {}
+                Ok(Err((key, error))) => {
+                    warn!(session_key = key, %error, "failed to persist llama session snapshot")
+                }
+                Err(error) => warn!(%error, "llama session snapshot write task failed"),
+            }
+        })
+    }
+
+    /// Cancel any pending write for `key` and delete its snapshot.
+    pub(crate) fn remove(&self, dir: &Path, key: &str) -> io::Result<()> {
+        let mut pending = self.lock();
+        pending.by_key.remove(key);
+        remove(dir, key)
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, PendingWrites> {
+        self.pending.lock().unwrap_or_else(|error| error.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scratch_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir()
+            .join(format!("slab-llama-session-store-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn save_then_load_round_trips_header_and_state() {
+        let dir = scratch_dir("roundtrip");
+        let header = PersistedSessionHeader::new(
+            "/models/qwen.gguf".to_owned(),
+            4096,
+            1,
+            42,
+            "<|user|>hi<|assistant|>hello".to_owned(),
+            Some("root ::= \"a\"".to_owned()),
+        );
+
+        save(&dir, "chat-1", &header, &[0, 1, 2, 255]).expect("save session");
+        let loaded = load(&dir, "chat-1").expect("load session").expect("session exists");
+
+        assert_eq!(loaded, PersistedSession { header, state: vec![0, 1, 2, 255] });
+        assert!(load(&dir, "chat-2").expect("load missing").is_none());
+
+        remove(&dir, "chat-1").expect("remove session");
+        assert!(load(&dir, "chat-1").expect("load removed").is_none());
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn headers_without_n_ctx_are_rejected() {
+        let header = serde_json::from_value::<PersistedSessionHeader>(serde_json::json!({
+            "version": SESSION_FILE_VERSION,
+            "model_path": "/models/qwen.gguf",
+            "worker_id": 0,
+            "n_past": 3,
+            "cached_prompt": "hi",
+            "grammar": null,
+        }));
+
+        assert!(header.is_err());
+    }
+
+    #[test]
+    fn session_file_path_stays_inside_directory() {
+        let dir = Path::new("/var/slab/sessions");
+        let path = session_file_path(dir, "../../etc/passwd");
+
+        assert_eq!(path.parent(), Some(dir));
+        assert_eq!(path.extension().and_then(|ext| ext.to_str()), Some(SESSION_FILE_EXTENSION));
+        let stem = path.file_stem().and_then(|stem| stem.to_str()).expect("file stem");
+        assert_eq!(stem, sha256_hex_bytes(b"../../etc/passwd"));
+
+        let long_key = "k".repeat(4096);
+        let long_path = session_file_path(dir, &long_key);
+        assert_eq!(long_path.file_name().map(|name| name.len()), path.file_name().map(|n| n.len()));
+    }
+
+    #[tokio::test]
+    async fn snapshot_writer_keeps_only_the_latest_write_per_key() {
+        let dir = scratch_dir("debounce");
+        let writer = SnapshotWriter::default();
+        let header = |n_past| {
+            PersistedSessionHeader::new(
+                "/models/a.gguf".to_owned(),
+                2048,
+                0,
+                n_past,
+                String::new(),
+                None,
+            )
+        };
+
+        let stale = writer.schedule(
+            dir.clone(),
+            "chat".to_owned(),
+            header(1),
+            Arc::from([1_u8]),
+            Duration::from_millis(50),
+        );
+        let latest = writer.schedule(
+            dir.clone(),
+            "chat".to_owned(),
+            header(2),
+            Arc::from([2_u8]),
+            Duration::ZERO,
+        );
+        latest.await.expect("latest write");
+        stale.await.expect("stale write");
+
+        let loaded = load(&dir, "chat").expect("load session").expect("session exists");
+        assert_eq!(loaded.header.n_past, 2);
+        assert_eq!(loaded.state, vec![2]);
+
+        let cancelled = writer.schedule(
+            dir.clone(),
+            "other".to_owned(),
+            header(3),
+            Arc::from([3_u8]),
+            Duration::from_millis(20),
+        );
+        writer.remove(&dir, "other").expect("remove pending session");
+        cancelled.await.expect("cancelled write");
+        assert!(load(&dir, "other").expect("load cancelled").is_none());
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn load_rejects_truncated_files() {
+        let dir = scratch_dir("truncated");
+        fs::create_dir_all(&dir).expect("create dir");
+        fs::write(session_file_path(&dir, "chat"), [16, 0, 0, 0, b'{']).expect("write file");
+
+        let error = load(&dir, "chat").expect_err("truncated file must fail");
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
@@ -8,31 +8,58 @@
 //!
 //! | Op string            | Event variant    | Description                                    |
 //! |----------------------|------------------|------------------------------------------------|
-//! | `"model.load"`       | `LoadModel`      | Load a GGUF model from the engine.             |
-//! | `"model.unload"`     | `UnloadModel`    | Drop the model handle; call model.load to restore. |
+//! | `"model.load"`       | `LoadModel`      | Load a GGUF model into a model slot.           |
+//! | `"model.unload"`     | `UnloadModel`    | Drop one model slot; call model.load to restore. |
 //! | `"inference"`        | `Inference`      | Unary text generation; input is UTF-8 prompt.  |
 //! | `"inference.stream"` | `InferenceStream`| Streaming text generation.                     |
+//! | `"is_ready"`         | `IsReady`        | Report whether the default model slot is loaded. |
+//! | `"model.warmup"`     | `Warmup`         | Decode one BOS token per worker on every loaded model. |
+//! | `"tokenize"`         | `Tokenize`       | Tokenize text with a loaded model's vocabulary. |
+//! | `"detokenize"`       | `Detokenize`     | Turn token ids back into text.                 |
 //!
 //! ### `model.load` input payload
-//! Uses a typed runtime-owned `GgmlLlamaLoadConfig` payload.
+//! Uses a typed runtime-owned `GgmlLlamaLoadConfig` payload. Its optional
+//! `model_id` names the slot the model is loaded into, so several models (e.g.
+//! a draft model next to a large one) can stay resident at once. Loading into
+//! an occupied slot replaces that slot's model only. When the worker is capped
+//! by `max_loaded_models`, loading into a new slot evicts the
+//! least-recently-used idle model once the new model has loaded, so a failed
+//! load leaves the loaded models in service; if every loaded model is serving
+//! a request the load is rejected.
+//!
+//! ### `model.unload` input payload
+//! Empty to unload the default slot, or a typed `GgmlLlamaUnloadRequest` whose
+//! `model_id` names the slot to drop. Other loaded models stay in service.
 //!
 //! ### `inference` / `inference.stream` options payload
 //! Uses a typed runtime-owned `TextGenerationOptions` payload. Grammar and chat
 //! message normalization are resolved before the backend receives the request.
+//! `model_id` selects the loaded model; an unknown id fails the request instead
+//! of falling back to another model.
+//!
+//! ### `tokenize` / `detokenize` input payload
+//! Uses typed `GgmlLlamaTokenizeRequest` / `GgmlLlamaDetokenizeRequest`
+//! payloads. They only need the model's vocabulary, so they never touch an
+//! inference context or wait behind running generations.
 //!
 //! Runtime and peer control hooks are also routed through typed extractor
 //! arguments, but remain fire-and-forget because the control bus has no reply
 //! channel.
 
+use std::collections::{HashMap, VecDeque};
 use std::sync::Arc;
 
 use tokio::sync::broadcast;
 
 use super::engine::{GGMLLlamaEngine, LlamaDispatchOutput, LlamaDispatchRequest};
-use super::error::GGMLLlamaWorkerError;
+use super::error::{GGMLLlamaEngineError, GGMLLlamaWorkerError};
 use crate::domain::models::{
-    GgmlLlamaLoadConfig, GgmlLlamaLoadMetadata, TextGenerationOptions, TextGenerationResponse,
+    GgmlLlamaDetokenizeRequest, GgmlLlamaDetokenized, GgmlLlamaLoadConfig, GgmlLlamaLoadMetadata,
+    GgmlLlamaReadiness, GgmlLlamaTokenizeRequest, GgmlLlamaTokens, GgmlLlamaUnloadRequest,
+    TextGenerationOptions, TextGenerationResponse,
 };
+use slab_llama::LlamaError;
+use slab_runtime_core::Payload;
 use slab_runtime_core::backend::{
     CancelRx, ControlOpId, Input, Options, StreamHandle, Typed, WorkerCommand,
 };
@@ -41,7 +68,11 @@ use slab_runtime_macros::backend_handler;
 
 // ── Configurations ────────────────────────────────────────────────────────────
 
+/// Slot used when a request does not carry a `model_id`.
+const DEFAULT_MODEL_ID: &str = "default";
+
 struct InferenceOptions {
+    model_id: Option<String>,
     max_tokens: usize,
     session_key: Option<String>,
     gbnf: Option<String>,
@@ -50,7 +81,11 @@ struct InferenceOptions {
     top_k: Option<i32>,
     min_p: Option<f32>,
     repetition_penalty: Option<f32>,
+    repeat_last_n: Option<i32>,
+    n_keep: Option<u32>,
     presence_penalty: Option<f32>,
+    frequency_penalty: Option<f32>,
+    seed: Option<u32>,
     ignore_eos: bool,
     logit_bias: Option<serde_json::Value>,
     stop_sequences: Vec<String>,
@@ -60,6 +95,7 @@ struct InferenceOptions {
 impl InferenceOptions {
     fn from_options(params: TextGenerationOptions) -> Self {
         Self {
+            model_id: params.model_id,
             max_tokens: params
                 .max_tokens
                 .and_then(|value| usize::try_from(value).ok())
@@ -72,7 +108,11 @@ impl InferenceOptions {
             top_k: params.top_k,
             min_p: params.min_p,
             repetition_penalty: params.repetition_penalty,
+            repeat_last_n: params.repeat_last_n,
+            n_keep: params.n_keep,
             presence_penalty: params.presence_penalty,
+            frequency_penalty: params.frequency_penalty,
+            seed: params.seed,
             ignore_eos: params.ignore_eos,
             logit_bias: params.logit_bias,
             stop_sequences: params.stop_sequences,
@@ -81,20 +121,125 @@ impl InferenceOptions {
     }
 }
 
+// ── Model slots ───────────────────────────────────────────────────────────────
+
+/// Loaded models keyed by `model_id`, tracked in least-recently-used order.
+struct ModelSlots<E> {
+    entries: HashMap<String, E>,
+    /// Model ids ordered from least to most recently used.
+    recency: VecDeque<String>,
+    max_loaded: Option<usize>,
+}
+
+impl<E: Clone> ModelSlots<E> {
+    fn new(max_loaded: Option<usize>) -> Self {
+        Self { entries: HashMap::new(), recency: VecDeque::new(), max_loaded }
+    }
+
+    fn get(&mut self, id: &str) -> Option<E> {
+        let entry = self.entries.get(id)?.clone();
+        self.touch(id);
+        Some(entry)
+    }
+
+    fn insert(&mut self, id: String, entry: E) {
+        self.touch(&id);
+        self.entries.insert(id, entry);
+    }
+
+    fn touch(&mut self, id: &str) {
+        self.recency.retain(|existing| existing != id);
+        self.recency.push_back(id.to_owned());
+    }
+
+    fn remove(&mut self, id: &str) -> Option<E> {
+        self.recency.retain(|existing| existing != id);
+        self.entries.remove(id)
+    }
+
+    /// Load `id` into its current entry, or into a fresh one from `fresh`.
+    ///
+    /// When a new entry would exceed `max_loaded`, the least-recently-used idle
+    /// entry is evicted, but only after `load` succeeds, so a failed load
+    /// leaves every loaded model in service. Busy entries are never evicted.
+    fn load<T>(
+        &mut self,
+        id: &str,
+        is_busy: impl Fn(&E) -> bool,
+        fresh: impl FnOnce() -> E,
+        load: impl FnOnce(&E) -> Result<T, GGMLLlamaWorkerError>,
+    ) -> Result<(T, Option<(String, E)>), GGMLLlamaWorkerError> {
+        let (entry, victim) = match self.get(id) {
+            Some(entry) => (entry, None),
+            None => {
+                let victim = self.eviction_victim(id, is_busy)?;
+                (fresh(), victim)
+            }
+        };
+
+        let loaded = load(&entry)?;
+        let evicted = victim.and_then(|victim| self.remove(&victim).map(|entry| (victim, entry)));
+        self.insert(id.to_owned(), entry);
+        Ok((loaded, evicted))
+    }
+
+    /// The least-recently-used idle entry to evict before a new `id` fits
+    /// under `max_loaded`, if one has to go.
+    fn eviction_victim(
+        &self,
+        id: &str,
+        is_busy: impl Fn(&E) -> bool,
+    ) -> Result<Option<String>, GGMLLlamaWorkerError> {
+        let Some(max_loaded) = self.max_loaded else {
+            return Ok(None);
+        };
+        if self.entries.len() < max_loaded {
+            return Ok(None);
+        }
+
+        let victim = self
+            .recency
+            .iter()
+            .find(|candidate| self.entries.get(*candidate).is_some_and(|entry| !is_busy(entry)));
+        match victim {
+            Some(victim) => Ok(Some(victim.clone())),
+            None => Err(GGMLLlamaWorkerError::load(format!(
+                "cannot load llama model '{id}': all {max_loaded} loaded models are serving requests"
+            ))),
+        }
+    }
+
+    fn drain(&mut self) -> impl Iterator<Item = E> + '_ {
+        self.recency.clear();
+        self.entries.drain().map(|(_, entry)| entry)
+    }
+}
+
+/// A model is busy while a stream still holds its engine or a session is
+/// mid-request.
+fn is_model_busy(engine: &Arc<GGMLLlamaEngine>) -> bool {
+    Arc::strong_count(engine) > 1 || engine.has_busy_sessions()
+}
+
 // ── Worker ────────────────────────────────────────────────────────────────────
 
 struct LlamaWorker {
-    /// The engine: wraps both the library handle and inference workers.
-    /// - `None` → library not loaded.
-    /// - `Some(e)` where `e.inference_engine` is None → lib loaded, no model.
-    /// - `Some(e)` where `e.inference_engine` is Some → lib + model loaded.
+    /// The library handle; `None` means the library is not loaded. Models are
+    /// never loaded into it directly, each slot forks its own engine from it.
     engine: Option<Arc<GGMLLlamaEngine>>,
+    /// Loaded models keyed by `model_id`.
+    models: ModelSlots<Arc<GGMLLlamaEngine>>,
 }
 
 #[backend_handler]
 impl LlamaWorker {
     fn new(engine: Option<Arc<GGMLLlamaEngine>>) -> Self {
-        Self { engine }
+        Self { engine, models: ModelSlots::new(None) }
+    }
+
+    fn with_max_loaded_models(mut self, max_loaded_models: Option<usize>) -> Self {
+        self.models.max_loaded = max_loaded_models;
+        self
     }
 
     #[on_event(LoadModel)]
@@ -106,8 +251,18 @@ impl LlamaWorker {
     }
 
     #[on_event(UnloadModel)]
-    async fn on_unload_model(&mut self) -> Result<(), GGMLLlamaWorkerError> {
-        self.handle_unload_model().await
+    async fn on_unload_model(&mut self, input: Payload) -> Result<(), GGMLLlamaWorkerError> {
+        // The runtime sends no input when it unloads the default slot.
+        let model_id = match input {
+            Payload::None => None,
+            input => {
+                input
+                    .to_typed::<GgmlLlamaUnloadRequest>()
+                    .map_err(GGMLLlamaWorkerError::contract)?
+                    .model_id
+            }
+        };
+        self.handle_unload_model(model_id.as_deref()).await
     }
 
     #[on_event(Inference)]
@@ -131,12 +286,88 @@ impl LlamaWorker {
         self.handle_inference_stream(prompt, options, cancel).await
     }
 
+    #[on_event(IsReady)]
+    async fn on_is_ready(&mut self) -> Result<Typed<GgmlLlamaReadiness>, GGMLLlamaWorkerError> {
+        Ok(Typed(self.readiness()))
+    }
+
+    #[on_event(Warmup)]
+    async fn on_warmup(&mut self) -> Result<Typed<GgmlLlamaReadiness>, GGMLLlamaWorkerError> {
+        let engines: Vec<_> = self.models.entries.values().map(Arc::clone).collect();
+        for engine in engines {
+            engine
+                .warmup()
+                .await
+                .map_err(|error| GGMLLlamaWorkerError::inference(error.to_string()))?;
+        }
+        Ok(Typed(self.readiness()))
+    }
+
+    #[on_event(Tokenize)]
+    async fn on_tokenize(
+        &mut self,
+        request: Input<GgmlLlamaTokenizeRequest>,
+    ) -> Result<Typed<GgmlLlamaTokens>, GGMLLlamaWorkerError> {
+        let GgmlLlamaTokenizeRequest { model_id, text, add_special } = request.0;
+        let engine = self.model_engine(model_id.as_deref())?;
+        let tokens = engine
+            .tokenize(&text, add_special)
+            .map_err(|error| GGMLLlamaWorkerError::inference(error.to_string()))?;
+        Ok(Typed(GgmlLlamaTokens { tokens }))
+    }
+
+    #[on_event(Detokenize)]
+    async fn on_detokenize(
+        &mut self,
+        request: Input<GgmlLlamaDetokenizeRequest>,
+    ) -> Result<Typed<GgmlLlamaDetokenized>, GGMLLlamaWorkerError> {
+        let GgmlLlamaDetokenizeRequest { model_id, tokens } = request.0;
+        let engine = self.model_engine(model_id.as_deref())?;
+        let text = engine.detokenize(&tokens).map_err(|error| match error {
+            // Unknown token ids are the caller's mistake, not an engine failure.
+            GGMLLlamaEngineError::Tokenize {
+                source: source @ LlamaError::TokenOutOfRange { .. },
+                ..
+            } => GGMLLlamaWorkerError::invalid_argument(source.to_string()),
+            other => GGMLLlamaWorkerError::inference(other.to_string()),
+        })?;
+        Ok(Typed(GgmlLlamaDetokenized { text }))
+    }
+
+    /// Ready means requests without a `model_id` can be served.
+    fn readiness(&self) -> GgmlLlamaReadiness {
+        let mut model_ids: Vec<String> = self.models.entries.keys().cloned().collect();
+        model_ids.sort();
+        GgmlLlamaReadiness { ready: self.models.entries.contains_key(DEFAULT_MODEL_ID), model_ids }
+    }
+
     fn cleanup_runtime_state(&mut self) {
-        if let Some(engine) = self.engine.as_ref() {
+        for engine in self.models.drain() {
             let _ = engine.unload();
         }
     }
 
+    /// Resolve the engine serving `model_id`, defaulting to [`DEFAULT_MODEL_ID`].
+    fn model_engine(
+        &mut self,
+        model_id: Option<&str>,
+    ) -> Result<Arc<GGMLLlamaEngine>, GGMLLlamaWorkerError> {
+        if self.engine.is_none() {
+            return Err(GGMLLlamaWorkerError::library_not_loaded("engine not initialized"));
+        }
+        match model_id {
+            None => self
+                .models
+                .get(DEFAULT_MODEL_ID)
+                .ok_or_else(|| GGMLLlamaWorkerError::model_not_loaded("call model.load first")),
+            Some(id) => self.models.get(id).ok_or_else(|| {
+                GGMLLlamaWorkerError::model_not_loaded(format!(
+                    "unknown llama model_id '{id}'; load it with model.load first"
+                ))
+            }),
+        }
+    }
+
     #[on_runtime_control(GlobalUnload)]
     #[on_runtime_control(GlobalLoad)]
     async fn apply_runtime_control(
@@ -162,34 +393,64 @@ impl LlamaWorker {
         &mut self,
         config: GgmlLlamaLoadConfig,
     ) -> Result<Typed<GgmlLlamaLoadMetadata>, GGMLLlamaWorkerError> {
-        let engine = match self.engine.as_ref() {
+        let library = match self.engine.as_ref() {
             Some(e) => Arc::clone(e),
             None => {
-                return Err(GGMLLlamaWorkerError::load("engine not initialized"));
+                return Err(GGMLLlamaWorkerError::library_not_loaded("engine not initialized"));
             }
         };
 
         if config.engine_workers == 0 {
             return Err(GGMLLlamaWorkerError::contract("engine_workers must be > 0"));
         }
-
-        // Model loading is CPU/blocking; use block_in_place to avoid stalling
-        // the async runtime without the Send constraint of spawn_blocking.
-        let result = tokio::task::block_in_place(|| engine.load_model_from_config(&config));
-
-        result.map(Typed).map_err(|error| GGMLLlamaWorkerError::load(error.to_string()))
+        let model_id = match config.model_id.as_deref().map(str::trim) {
+            Some("") => {
+                return Err(GGMLLlamaWorkerError::contract("model_id must not be empty"));
+            }
+            Some(id) => id.to_owned(),
+            None => DEFAULT_MODEL_ID.to_owned(),
+        };
+        let (metadata, evicted) = self.models.load(
+            &model_id,
+            is_model_busy,
+            || library.fork_library(),
+            |engine| {
+                // Model loading is CPU/blocking; use block_in_place to avoid stalling
+                // the async runtime without the Send constraint of spawn_blocking.
+                tokio::task::block_in_place(|| engine.load_model_from_config(&config))
+                    .map_err(|error| GGMLLlamaWorkerError::load(error.to_string()))
+            },
+        )?;
+        if let Some((evicted_id, evicted)) = evicted {
+            tracing::info!(
+                model_id = %evicted_id,
+                model_size = ?evicted.loaded_model_size(),
+                "evicted least-recently-used llama model"
+            );
+            if let Err(error) = evicted.unload() {
+                tracing::warn!(model_id = %evicted_id, %error, "failed to unload evicted llama model");
+            }
+        }
+        Ok(Typed(metadata))
     }
 
     // ── model.unload ──────────────────────────────────────────────────────────
 
-    async fn handle_unload_model(&mut self) -> Result<(), GGMLLlamaWorkerError> {
-        let engine = match self.engine.as_ref() {
-            Some(e) => Arc::clone(e),
-            None => {
-                return Err(GGMLLlamaWorkerError::unload("engine not initialized"));
-            }
-        };
+    /// Unload `model_id` (the default slot when `None`), leaving other loaded
+    /// models in service. Unloading a model that is not loaded is a no-op.
+    async fn handle_unload_model(
+        &mut self,
+        model_id: Option<&str>,
+    ) -> Result<(), GGMLLlamaWorkerError> {
+        if self.engine.is_none() {
+            return Err(GGMLLlamaWorkerError::library_not_loaded("engine not initialized"));
+        }
 
+        let model_id = model_id.unwrap_or(DEFAULT_MODEL_ID);
+        let Some(engine) = self.models.remove(model_id) else {
+            tracing::debug!(model_id, "llama model already unloaded");
+            return Ok(());
+        };
         engine.unload().map_err(|error| GGMLLlamaWorkerError::unload(error.to_string()))
     }
 
@@ -201,6 +462,7 @@ impl LlamaWorker {
         options: InferenceOptions,
     ) -> Result<Typed<TextGenerationResponse>, GGMLLlamaWorkerError> {
         let InferenceOptions {
+            model_id,
             max_tokens,
             session_key,
             gbnf,
@@ -209,17 +471,17 @@ impl LlamaWorker {
             top_k,
             min_p,
             repetition_penalty,
+            repeat_last_n,
+            n_keep,
             presence_penalty,
+            frequency_penalty,
+            seed,
             ignore_eos,
             logit_bias,
             stop_sequences,
             agent_trace,
         } = options;
-        let engine = self
-            .engine
-            .as_ref()
-            .map(Arc::clone)
-            .ok_or_else(|| GGMLLlamaWorkerError::inference("model not loaded"))?;
+        let engine = self.model_engine(model_id.as_deref())?;
         let request = LlamaDispatchRequest {
             prompt,
             max_tokens,
@@ -230,7 +492,11 @@ impl LlamaWorker {
             top_k,
             min_p,
             repetition_penalty,
+            repeat_last_n,
+            n_keep,
             presence_penalty,
+            frequency_penalty,
+            seed,
             ignore_eos,
             logit_bias,
             stop_sequences,
@@ -252,6 +518,7 @@ impl LlamaWorker {
         cancel: CancelRx,
     ) -> Result<StreamHandle, GGMLLlamaWorkerError> {
         let InferenceOptions {
+            model_id,
             max_tokens,
             session_key,
             gbnf,
@@ -260,17 +527,17 @@ impl LlamaWorker {
             top_k,
             min_p,
             repetition_penalty,
+            repeat_last_n,
+            n_keep,
             presence_penalty,
+            frequency_penalty,
+            seed,
             ignore_eos,
             logit_bias,
             stop_sequences,
             agent_trace,
         } = options;
-        let engine = self
-            .engine
-            .as_ref()
-            .map(Arc::clone)
-            .ok_or_else(|| GGMLLlamaWorkerError::inference("model not loaded"))?;
+        let engine = self.model_engine(model_id.as_deref())?;
         let request = LlamaDispatchRequest {
             prompt,
             max_tokens,
@@ -281,7 +548,11 @@ impl LlamaWorker {
             top_k,
             min_p,
             repetition_penalty,
+            repeat_last_n,
+            n_keep,
             presence_penalty,
+            frequency_penalty,
+            seed,
             ignore_eos,
             logit_bias,
             stop_sequences,
@@ -305,16 +576,21 @@ pub fn spawn_backend_with_engine(
     shared_ingress_rx: SharedIngressRx,
     control_tx: broadcast::Sender<WorkerCommand>,
     engine: Option<Arc<GGMLLlamaEngine>>,
+    max_loaded_models: Option<usize>,
 ) {
-    let worker = LlamaWorker::new(engine);
+    let worker = LlamaWorker::new(engine).with_max_loaded_models(max_loaded_models);
     spawn_runtime_worker(shared_ingress_rx, control_tx.subscribe(), 0, worker);
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{InferenceOptions, LlamaWorker};
-    use crate::domain::models::TextGenerationOptions;
-    use slab_runtime_core::backend::ControlOpId;
+    use std::sync::Arc;
+
+    use super::{GGMLLlamaWorkerError, InferenceOptions, LlamaWorker, ModelSlots};
+    use crate::domain::models::{
+        GgmlLlamaDetokenizeRequest, GgmlLlamaTokenizeRequest, TextGenerationOptions,
+    };
+    use slab_runtime_core::backend::{ControlOpId, Input};
 
     // ── infer_add_assistant_prompt ────────────────────────────────────────────
 
@@ -329,6 +605,16 @@ mod tests {
         assert!(worker.engine.is_none(), "global unload should remain safe without an engine");
     }
 
+    #[test]
+    fn requests_without_a_library_report_library_not_loaded() {
+        let mut worker = LlamaWorker::new(None);
+
+        for model_id in [None, Some("chat")] {
+            let error = worker.model_engine(model_id).expect_err("no library is loaded");
+            assert!(matches!(error, GGMLLlamaWorkerError::LibraryNotLoaded { .. }), "{error:?}");
+        }
+    }
+
     #[tokio::test]
     async fn runtime_global_load_is_safe_without_engine() {
         let mut worker = LlamaWorker::new(None);
@@ -350,4 +636,213 @@ mod tests {
         assert!(options.ignore_eos);
         assert_eq!(options.logit_bias, Some(serde_json::json!({ "42": false })));
     }
+
+    #[test]
+    fn inference_options_carry_the_sampler_seed() {
+        let params: TextGenerationOptions =
+            serde_json::from_value(serde_json::json!({ "seed": 1234 })).expect("options");
+        assert_eq!(InferenceOptions::from_options(params).seed, Some(1234));
+
+        let unseeded = InferenceOptions::from_options(TextGenerationOptions::default());
+        assert_eq!(unseeded.seed, None);
+    }
+
+    #[test]
+    fn inference_options_parse_sampling_fields_and_ignore_unknown_ones() {
+        let params: TextGenerationOptions = serde_json::from_value(serde_json::json!({
+            "temperature": 0.0,
+            "top_p": 0.5,
+            "frequency_penalty": 1.0,
+            "repeat_last_n": 32,
+            "n_keep": 16,
+            "mirostat": 2,
+        }))
+        .expect("sampling input should decode");
+        let options = InferenceOptions::from_options(params);
+
+        assert_eq!(options.temperature, Some(0.0));
+        assert_eq!(options.top_p, Some(0.5));
+        assert_eq!(options.frequency_penalty, Some(1.0));
+        assert_eq!(options.repeat_last_n, Some(32));
+        assert_eq!(options.n_keep, Some(16));
+        assert_eq!(options.top_k, None);
+        assert_eq!(options.max_tokens, 256);
+
+        let defaults = InferenceOptions::from_options(
+            serde_json::from_value(serde_json::json!({})).expect("empty input should decode"),
+        );
+        assert_eq!(defaults.temperature, None);
+        assert_eq!(defaults.top_p, None);
+    }
+
+    #[tokio::test]
+    async fn is_ready_reports_not_ready_without_model() {
+        let mut worker = LlamaWorker::new(None);
+
+        let readiness = worker.on_is_ready().await.expect("readiness").0;
+        assert!(!readiness.ready);
+        assert!(readiness.model_ids.is_empty());
+    }
+
+    #[tokio::test]
+    async fn inference_after_cleanup_reports_model_not_loaded() {
+        let mut worker = LlamaWorker::new(None);
+        worker.cleanup_runtime_state();
+
+        let options = InferenceOptions::from_options(TextGenerationOptions::default());
+        let error = worker
+            .handle_inference_stream(
+                "hello".to_owned(),
+                options,
+                slab_runtime_core::backend::CancelRx(tokio::sync::watch::channel(false).1),
+            )
+            .await
+            .expect_err("no model after unload");
+
+        assert!(error.to_string().contains("model not loaded"), "{error}");
+    }
+
+    #[tokio::test]
+    async fn unload_without_library_reports_engine_not_initialized() {
+        let mut worker = LlamaWorker::new(None);
+
+        let error = worker.handle_unload_model(None).await.expect_err("no library");
+
+        assert!(error.to_string().contains("engine not initialized"), "{error}");
+    }
+
+    #[tokio::test]
+    async fn warmup_without_models_is_a_no_op() {
+        let mut worker = LlamaWorker::new(None);
+
+        let readiness = worker.on_warmup().await.expect("warmup").0;
+
+        assert!(!readiness.ready);
+    }
+
+    #[tokio::test]
+    async fn inference_with_unknown_model_id_is_rejected() {
+        let mut worker = LlamaWorker::new(None);
+        let options = InferenceOptions::from_options(TextGenerationOptions {
+            model_id: Some("draft".to_owned()),
+            ..Default::default()
+        });
+
+        let error = worker
+            .handle_inference("hello".to_owned(), options)
+            .await
+            .expect_err("unknown model_id should fail");
+
+        assert!(matches!(error, GGMLLlamaWorkerError::ModelNotLoaded { .. }), "{error:?}");
+        assert!(error.to_string().contains("unknown llama model_id 'draft'"), "{error}");
+    }
+
+    #[tokio::test]
+    async fn inference_without_model_id_reports_model_not_loaded() {
+        let mut worker = LlamaWorker::new(None);
+        let options = InferenceOptions::from_options(TextGenerationOptions::default());
+
+        let error = worker
+            .handle_inference("hello".to_owned(), options)
+            .await
+            .expect_err("default slot is empty");
+
+        assert!(error.to_string().contains("model not loaded"), "{error}");
+    }
+
+    #[tokio::test]
+    async fn tokenize_ops_need_a_loaded_model() {
+        let mut worker = LlamaWorker::new(None);
+
+        let error = worker
+            .on_tokenize(Input(GgmlLlamaTokenizeRequest {
+                text: "hello".to_owned(),
+                ..Default::default()
+            }))
+            .await
+            .expect_err("default slot is empty");
+        assert!(error.to_string().contains("model not loaded"), "{error}");
+
+        let error = worker
+            .on_detokenize(Input(GgmlLlamaDetokenizeRequest {
+                model_id: Some("draft".to_owned()),
+                tokens: vec![1, 2, 3],
+            }))
+            .await
+            .expect_err("unknown model_id should fail");
+        assert!(error.to_string().contains("unknown llama model_id 'draft'"), "{error}");
+    }
+
+    fn load_ok(slots: &mut ModelSlots<Arc<()>>, id: &str) -> Option<String> {
+        let ((), evicted) = slots
+            .load(id, |entry| Arc::strong_count(entry) > 1, || Arc::new(()), |_| Ok(()))
+            .expect("room for model");
+        evicted.map(|(id, _)| id)
+    }
+
+    #[test]
+    fn model_slots_evict_least_recently_used_when_full() {
+        let mut slots = ModelSlots::new(Some(2));
+        assert_eq!(load_ok(&mut slots, "first"), None);
+        assert_eq!(load_ok(&mut slots, "second"), None);
+        assert_eq!(load_ok(&mut slots, "third").as_deref(), Some("first"));
+
+        assert!(slots.get("first").is_none());
+        assert!(slots.get("second").is_some());
+        assert!(slots.get("third").is_some());
+
+        // Reloading a resident model replaces it in place without evicting.
+        assert_eq!(load_ok(&mut slots, "second"), None);
+        assert!(slots.get("third").is_some());
+    }
+
+    #[test]
+    fn model_slots_keep_serving_old_models_when_a_load_fails() {
+        let mut slots = ModelSlots::new(Some(2));
+        load_ok(&mut slots, "first");
+        load_ok(&mut slots, "second");
+
+        let error = slots
+            .load(
+                "third",
+                |_: &Arc<()>| false,
+                || Arc::new(()),
+                |_| Err::<(), _>(GGMLLlamaWorkerError::load("bad gguf")),
+            )
+            .expect_err("load fails");
+
+        assert!(matches!(error, GGMLLlamaWorkerError::Load { .. }), "{error:?}");
+        assert!(slots.get("first").is_some(), "the eviction victim must still be served");
+        assert!(slots.get("second").is_some());
+        assert!(slots.get("third").is_none());
+    }
+
+    #[test]
+    fn model_slots_skip_busy_models_and_reject_when_all_busy() {
+        let mut slots = ModelSlots::new(Some(2));
+        let busy = Arc::new(());
+        let _in_flight = Arc::clone(&busy);
+        slots.insert("busy".to_owned(), busy);
+        slots.insert("idle".to_owned(), Arc::new(()));
+
+        assert_eq!(load_ok(&mut slots, "next").as_deref(), Some("idle"));
+        let _next_in_flight = slots.get("next");
+
+        let error = slots
+            .load("last", |entry| Arc::strong_count(entry) > 1, || Arc::new(()), |_| Ok(()))
+            .expect_err("all models are busy");
+        assert!(error.to_string().contains("serving requests"), "{error}");
+        assert!(slots.get("last").is_none());
+    }
+
+    #[test]
+    fn model_slots_remove_only_the_named_model() {
+        let mut slots = ModelSlots::new(None);
+        load_ok(&mut slots, "draft");
+        load_ok(&mut slots, "default");
+
+        assert!(slots.remove("draft").is_some());
+        assert!(slots.remove("draft").is_none());
+        assert!(slots.get("default").is_some());
+    }
 }
@@ -10,6 +10,7 @@ use std::sync::Arc;
 use slab_runtime_core::CoreError;
 use slab_runtime_core::backend::{ResourceManager, spawn_dedicated_workers, spawn_workers};
 use thiserror::Error;
+use tracing::warn;
 
 use crate::infra::backends::ggml::diffusion::{DiffusionWorker, GGMLDiffusionEngine};
 use crate::infra::backends::ggml::llama::{
@@ -71,8 +72,14 @@ impl From<llama::GGMLLlamaEngineError> for slab_runtime_core::CoreError {
 #[derive(Debug, Clone, Default)]
 pub struct GgmlBackendConfig {
     pub llama_lib_dir: Option<PathBuf>,
+    pub llama_max_loaded_models: Option<usize>,
+    pub llama_session_state_dir: Option<PathBuf>,
     pub whisper_lib_dir: Option<PathBuf>,
     pub diffusion_lib_dir: Option<PathBuf>,
+    /// Per-backend capacity overrides; `None` uses the shared worker count.
+    pub llama_capacity: Option<usize>,
+    pub whisper_capacity: Option<usize>,
+    pub diffusion_capacity: Option<usize>,
 }
 
 pub fn service_ids(config: &GgmlBackendConfig) -> Vec<&'static str> {
@@ -99,45 +106,98 @@ pub fn register(
     worker_count: usize,
 ) -> Result<(), CoreError> {
     if let Some(path) = config.llama_lib_dir.as_deref() {
-        let llama_engine = load_llama_engine(path)?;
-        resource_manager.register_backend("ggml.llama", move |shared_rx, control_tx| {
-            spawn_ggml_llama_backend(shared_rx, control_tx, Some(Arc::clone(&llama_engine)));
-        });
+        let llama_engine = available_engine("ggml.llama", load_llama_engine(path));
+        if let (Some(engine), Some(dir)) =
+            (llama_engine.as_ref(), config.llama_session_state_dir.clone())
+        {
+            engine.set_session_state_dir(dir);
+        }
+        let max_loaded_models = config.llama_max_loaded_models;
+        let capacity = config.llama_capacity.unwrap_or(worker_count);
+        resource_manager.register_backend_with_capacity(
+            "ggml.llama",
+            capacity,
+            move |shared_rx, control_tx| {
+                spawn_ggml_llama_backend(
+                    shared_rx,
+                    control_tx,
+                    llama_engine.clone(),
+                    max_loaded_models,
+                );
+            },
+        );
     }
 
     if let Some(path) = config.whisper_lib_dir.as_deref() {
-        let whisper_engine = load_whisper_engine(path)?;
-        resource_manager.register_backend("ggml.whisper", move |shared_rx, control_tx| {
-            let count = worker_count.max(1);
-            let mut worker_engines: Vec<Option<GGMLWhisperEngine>> =
-                (1..count).map(|_| Some(whisper_engine.fork_library())).collect();
-            worker_engines.insert(0, Some(whisper_engine));
-            let mut worker_engines = worker_engines.into_iter();
-            spawn_workers(shared_rx, control_tx, count, move |peer_bus| {
-                let worker_engine = worker_engines.next().unwrap_or(None);
-                WhisperWorker::new(worker_engine, peer_bus)
-            });
-        });
+        let whisper_engine = available_engine("ggml.whisper", load_whisper_engine(path));
+        let capacity = config.whisper_capacity.unwrap_or(worker_count);
+        resource_manager.register_backend_with_capacity(
+            "ggml.whisper",
+            capacity,
+            move |shared_rx, control_tx| {
+                let count = capacity.max(1);
+                let mut worker_engines =
+                    worker_engines(whisper_engine, count, GGMLWhisperEngine::fork_library)
+                        .into_iter();
+                spawn_workers(shared_rx, control_tx, count, move |peer_bus| {
+                    let worker_engine = worker_engines.next().unwrap_or(None);
+                    WhisperWorker::new(worker_engine, peer_bus)
+                });
+            },
+        );
     }
 
     if let Some(path) = config.diffusion_lib_dir.as_deref() {
-        let diffusion_engine = load_diffusion_engine(path)?;
-        resource_manager.register_backend("ggml.diffusion", move |shared_rx, control_tx| {
-            let count = worker_count.max(1);
-            let mut worker_engines: Vec<Option<GGMLDiffusionEngine>> =
-                (1..count).map(|_| Some(diffusion_engine.fork_library())).collect();
-            worker_engines.insert(0, Some(diffusion_engine));
-            let mut worker_engines = worker_engines.into_iter();
-            spawn_dedicated_workers(shared_rx, control_tx, count, move |peer_bus| {
-                let worker_engine = worker_engines.next().unwrap_or(None);
-                DiffusionWorker::new(worker_engine, peer_bus)
-            });
-        });
+        let diffusion_engine = available_engine("ggml.diffusion", load_diffusion_engine(path));
+        let capacity = config.diffusion_capacity.unwrap_or(worker_count);
+        resource_manager.register_backend_with_capacity(
+            "ggml.diffusion",
+            capacity,
+            move |shared_rx, control_tx| {
+                let count = capacity.max(1);
+                let mut worker_engines =
+                    worker_engines(diffusion_engine, count, GGMLDiffusionEngine::fork_library)
+                        .into_iter();
+                spawn_dedicated_workers(shared_rx, control_tx, count, move |peer_bus| {
+                    let worker_engine = worker_engines.next().unwrap_or(None);
+                    DiffusionWorker::new(worker_engine, peer_bus)
+                });
+            },
+        );
     }
 
     Ok(())
 }
 
+/// A backend whose library fails to load is still registered without an
+/// engine, so its workers answer every request with "engine not initialized"
+/// (surfaced as `LibraryNotLoaded`) instead of failing runtime startup.
+fn available_engine<E>(backend_id: &str, loaded: Result<E, CoreError>) -> Option<E> {
+    loaded
+        .inspect_err(|error| {
+            warn!(
+                backend_id,
+                error = %error,
+                "backend library failed to load; registering backend as unavailable"
+            );
+        })
+        .ok()
+}
+
+/// One engine per worker, each sharing the library loaded for `engine`.
+fn worker_engines<E>(
+    engine: Option<E>,
+    count: usize,
+    fork_library: impl Fn(&E) -> E,
+) -> Vec<Option<E>> {
+    let Some(engine) = engine else {
+        return Vec::new();
+    };
+    let mut engines: Vec<Option<E>> = (1..count).map(|_| Some(fork_library(&engine))).collect();
+    engines.insert(0, Some(engine));
+    engines
+}
+
 fn load_llama_engine(path: &Path) -> Result<Arc<GGMLLlamaEngine>, CoreError> {
     GGMLLlamaEngine::from_path(path)
 }
@@ -154,6 +214,91 @@ fn load_diffusion_engine(path: &Path) -> Result<GGMLDiffusionEngine, CoreError>
 mod tests {
     use super::*;
 
+    #[tokio::test(flavor = "multi_thread")]
+    async fn backend_with_missing_library_registers_as_unavailable() {
+        use crate::domain::models::GgmlWhisperLoadConfig;
+        use crate::domain::runtime::{CoreError as RuntimeError, Orchestrator};
+        use slab_runtime_core::Payload;
+
+        let mut resource_manager = ResourceManager::new();
+        register(
+            &GgmlBackendConfig {
+                whisper_lib_dir: Some(PathBuf::from("/nonexistent/slab/libs")),
+                ..GgmlBackendConfig::default()
+            },
+            &mut resource_manager,
+            2,
+        )
+        .expect("a missing library should not fail registration");
+        assert_eq!(resource_manager.backend_ids(), ["ggml.whisper"]);
+
+        let orchestrator = Orchestrator::start(resource_manager, 4);
+        let error = orchestrator
+            .load_model_backend_with_reply(
+                "ggml.whisper",
+                Payload::typed(GgmlWhisperLoadConfig {
+                    model_path: PathBuf::from("model.bin"),
+                    flash_attn: None,
+                }),
+            )
+            .await
+            .expect_err("unavailable backend should reject model.load");
+        assert!(matches!(error, RuntimeError::LibraryNotLoaded), "{error:?}");
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    #[ignore = "requires local llama runtime libraries and a GGUF model"]
+    async fn llama_serves_while_missing_whisper_library_is_unavailable() {
+        use crate::domain::models::{GgmlLlamaLoadConfig, GgmlWhisperLoadConfig};
+        use crate::domain::runtime::{CoreError as RuntimeError, Orchestrator};
+        use slab_runtime_core::Payload;
+
+        let llama_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../testdata/llama");
+        let mut resource_manager = ResourceManager::new();
+        register(
+            &GgmlBackendConfig {
+                llama_lib_dir: Some(llama_dir.clone()),
+                whisper_lib_dir: Some(PathBuf::from("/nonexistent/slab/libs")),
+                ..GgmlBackendConfig::default()
+            },
+            &mut resource_manager,
+            1,
+        )
+        .expect("a missing whisper library should not fail registration");
+        assert_eq!(resource_manager.backend_ids(), ["ggml.llama", "ggml.whisper"]);
+
+        let orchestrator = Orchestrator::start(resource_manager, 4);
+        orchestrator
+            .load_model_backend_with_reply(
+                "ggml.llama",
+                Payload::typed(GgmlLlamaLoadConfig {
+                    model_path: llama_dir.join("model.gguf"),
+                    engine_workers: 1,
+                    context_length: None,
+                    flash_attn: false,
+                    chat_template: None,
+                    gbnf: None,
+                    model_id: None,
+                    stop_on_context_full: false,
+                    least_loaded_sessions: false,
+                }),
+            )
+            .await
+            .expect("llama should load while whisper is unavailable");
+
+        let error = orchestrator
+            .load_model_backend_with_reply(
+                "ggml.whisper",
+                Payload::typed(GgmlWhisperLoadConfig {
+                    model_path: PathBuf::from("model.bin"),
+                    flash_attn: None,
+                }),
+            )
+            .await
+            .expect_err("unavailable backend should reject model.load");
+        assert!(matches!(error, RuntimeError::LibraryNotLoaded), "{error:?}");
+    }
+
     #[test]
     fn llama_session_busy_maps_to_core_busy() {
         let error = llama::GGMLLlamaEngineError::SessionKeyBusy { key: "chat-1".to_owned() };
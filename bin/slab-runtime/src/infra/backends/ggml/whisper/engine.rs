@@ -116,6 +116,13 @@ impl GGMLWhisperEngine {
             .instance
             .new_context(params)
             .map_err(|source| GGMLWhisperEngineError::CreateContext { model_path, source })?;
+        info!(
+            model_type = ctx.model_type_str(),
+            n_mels = ctx.n_mels(),
+            n_audio_ctx = ctx.n_audio_ctx(),
+            multilingual = ctx.is_multilingual(),
+            "whisper model loaded"
+        );
         self.ctx = Some(ctx);
         Ok(())
     }
@@ -160,19 +167,47 @@ impl GGMLWhisperEngine {
         let srt_entries: Vec<SubtitleEntry> = state
             .as_iter()
             .map(|segment| {
-                SubtitleEntry {
-                    timespan: TimeSpan::new(
-                        // 从厘秒转换为毫秒
-                        TimePoint::from_msecs(segment.start_timestamp() * 10),
-                        TimePoint::from_msecs(segment.end_timestamp() * 10),
-                    ),
-                    line: Some(segment.to_string().trim().to_string()),
-                }
+                subtitle_entry(
+                    segment.start_timestamp(),
+                    segment.end_timestamp(),
+                    &segment.to_string(),
+                )
             })
             .collect();
         Ok(srt_entries)
     }
 
+    /// Run Whisper inference, handing each segment to `on_entry` as soon as
+    /// whisper.cpp finalizes it instead of collecting the whole transcript.
+    ///
+    /// # Errors
+    /// Same as [`Self::inference`].
+    pub fn inference_with_segment_callback<F>(
+        &self,
+        audio_data: &[f32],
+        params: &FullParams,
+        mut on_entry: F,
+    ) -> Result<(), ggml::EngineError>
+    where
+        F: FnMut(SubtitleEntry),
+    {
+        let ctx = self.ctx.as_ref().ok_or(GGMLWhisperEngineError::ContextNotInitialized)?;
+
+        let mut state = ctx
+            .create_state()
+            .map_err(|source| GGMLWhisperEngineError::CreateInferenceState { source })?;
+        state
+            .full_with_segment_callback(params.clone(), audio_data, |segment| {
+                on_entry(subtitle_entry(
+                    segment.start_timestamp,
+                    segment.end_timestamp,
+                    &segment.text,
+                ))
+            })
+            .map_err(|source| GGMLWhisperEngineError::InferenceFailed { source })?;
+        Ok(())
+    }
+
     pub(crate) fn inference_with_options(
         &self,
         audio_data: &[f32],
@@ -181,6 +216,23 @@ impl GGMLWhisperEngine {
         self.inference(audio_data, &full_params_from_options(options))
     }
 
+    /// Streaming variant of [`Self::inference_with_options`]; whisper.cpp polls
+    /// `should_abort` while decoding and stops early once it returns `true`.
+    pub(crate) fn inference_stream_with_options<F>(
+        &self,
+        audio_data: &[f32],
+        options: &AudioTranscriptionOptions,
+        should_abort: impl FnMut() -> bool + Send + 'static,
+        on_entry: F,
+    ) -> Result<(), ggml::EngineError>
+    where
+        F: FnMut(SubtitleEntry),
+    {
+        let mut params = full_params_from_options(options);
+        params.set_abort_callback(should_abort);
+        self.inference_with_segment_callback(audio_data, &params, on_entry)
+    }
+
     // unload the model. free ctx
     pub fn unload(&mut self) {
         self.ctx = None;
@@ -200,6 +252,28 @@ impl GGMLWhisperEngine {
     pub fn fork_library(&self) -> Self {
         Self { instance: Arc::clone(&self.instance), ctx: None }
     }
+
+    /// Create a new engine that shares both the library handle and the loaded
+    /// model context, or `None` when no model is loaded.
+    ///
+    /// Used by streaming inference, which runs on a blocking thread after the
+    /// worker has already returned the stream handle. Each inference creates
+    /// its own `WhisperState`, so sharing the context is safe.
+    pub fn fork_loaded(&self) -> Option<Self> {
+        let ctx = self.ctx.clone()?;
+        Some(Self { instance: Arc::clone(&self.instance), ctx: Some(ctx) })
+    }
+}
+
+fn subtitle_entry(start_cs: i64, end_cs: i64, text: &str) -> SubtitleEntry {
+    SubtitleEntry {
+        timespan: TimeSpan::new(
+            // 从厘秒转换为毫秒
+            TimePoint::from_msecs(start_cs * 10),
+            TimePoint::from_msecs(end_cs * 10),
+        ),
+        line: Some(text.trim().to_string()),
+    }
 }
 
 fn full_params_from_options(options: &AudioTranscriptionOptions) -> FullParams {
@@ -219,7 +293,6 @@ fn full_params_from_options(options: &AudioTranscriptionOptions) -> FullParams {
         params.split_on_word = decode.split_on_word;
         params.suppress_nst = decode.suppress_nst;
         params.thold_pt = decode.word_thold;
-        params.max_len = decode.max_len;
         params.max_tokens = decode.max_tokens;
         params.temperature = decode.temperature;
         params.temperature_inc = decode.temperature_inc;
@@ -227,6 +300,10 @@ fn full_params_from_options(options: &AudioTranscriptionOptions) -> FullParams {
         params.logprob_thold = decode.logprob_thold;
         params.no_speech_thold = decode.no_speech_thold;
         params.tdrz_enable = decode.tdrz_enable;
+        params.translate = decode.translate;
+        if let Some(max_len) = decode.max_len {
+            params.set_max_segment_len(max_len);
+        }
     }
 
     if let Some(vad) = options.vad.as_ref() {
@@ -248,6 +325,7 @@ fn full_params_from_options(options: &AudioTranscriptionOptions) -> FullParams {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::models::AudioTranscriptionDecodeOptions;
 
     #[test]
     fn detect_language_is_forwarded_verbatim() {
@@ -260,6 +338,33 @@ mod tests {
         assert_eq!(params.detect_language, Some(true));
     }
 
+    #[test]
+    fn translate_is_forwarded_from_decode_options() {
+        let params = full_params_from_options(&AudioTranscriptionOptions {
+            decode: Some(AudioTranscriptionDecodeOptions {
+                translate: Some(true),
+                ..Default::default()
+            }),
+            ..Default::default()
+        });
+
+        assert_eq!(params.translate, Some(true));
+    }
+
+    #[test]
+    fn max_len_turns_on_token_timestamps_for_segment_wrapping() {
+        let params = full_params_from_options(&AudioTranscriptionOptions {
+            decode: Some(AudioTranscriptionDecodeOptions {
+                max_len: Some(42),
+                ..Default::default()
+            }),
+            ..Default::default()
+        });
+
+        assert_eq!(params.max_len, Some(42));
+        assert_eq!(params.token_timestamps, Some(true));
+    }
+
     #[test]
     fn language_is_forwarded_verbatim() {
         let params = full_params_from_options(&AudioTranscriptionOptions {
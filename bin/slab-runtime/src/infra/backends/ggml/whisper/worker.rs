@@ -4,15 +4,18 @@
 //!
 //! # Supported ops
 //!
-//! | Op string          | Event variant    | Description                                        |
-//! |--------------------|------------------|----------------------------------------------------|
-//! | `"model.load"`     | `LoadModel`      | Load a model from the engine.                      |
-//! | `"model.unload"`   | `UnloadModel`    | Drop the model handle; call model.load to restore. |
-//! | `"inference"`      | `Inference`      | Transcribe audio; input is packed `f32` PCM.       |
+//! | Op string            | Event variant     | Description                                        |
+//! |----------------------|-------------------|----------------------------------------------------|
+//! | `"model.load"`       | `LoadModel`       | Load a model from the engine.                      |
+//! | `"model.unload"`     | `UnloadModel`     | Drop the model handle; call model.load to restore. |
+//! | `"inference"`        | `Inference`       | Transcribe audio; input is packed `f32` PCM.       |
+//! | `"inference.stream"` | `InferenceStream` | Transcribe audio, one `Token` chunk per segment.   |
 //!
 //! ### `model.load` input payload
 //! Expects typed runtime-owned `GgmlWhisperLoadConfig` payloads.
 
+use std::sync::Arc;
+
 use super::engine::GGMLWhisperEngine;
 use super::error::GGMLWhisperWorkerError;
 use crate::domain::models::{
@@ -20,9 +23,12 @@ use crate::domain::models::{
 };
 use slab_runtime_core::Payload;
 use slab_runtime_core::backend::{
-    BroadcastSeq, ControlOpId, Input, Options, PeerControlBus, Typed,
+    BroadcastSeq, CancelRx, ControlOpId, Input, Options, PeerControlBus, StreamChunk, StreamHandle,
+    Typed,
 };
 use slab_runtime_macros::backend_handler;
+use slab_subtitle::SubtitleEntry;
+use tokio::sync::{mpsc, watch};
 
 // ── Worker ────────────────────────────────────────────────────────────────────
 
@@ -76,6 +82,16 @@ impl WhisperWorker {
         self.handle_inference(input, options.0).await
     }
 
+    #[on_event(InferenceStream)]
+    async fn on_inference_stream(
+        &mut self,
+        input: Payload,
+        options: Options<AudioTranscriptionOptions>,
+        cancel: CancelRx,
+    ) -> Result<StreamHandle, GGMLWhisperWorkerError> {
+        self.handle_inference_stream(input, options.0, cancel).await
+    }
+
     // ── model.load ────────────────────────────────────────────────────────────
 
     async fn handle_load_model(
@@ -86,7 +102,7 @@ impl WhisperWorker {
         let engine = match self.engine.as_mut() {
             Some(e) => e,
             None => {
-                return Err(GGMLWhisperWorkerError::load("engine not initialized"));
+                return Err(GGMLWhisperWorkerError::library_not_loaded("engine not initialized"));
             }
         };
         let model_payload = Payload::typed(params.clone());
@@ -116,7 +132,7 @@ impl WhisperWorker {
                 self.emit_peer_unload_generation(seq_id);
                 Ok(())
             }
-            None => Err(GGMLWhisperWorkerError::unload("engine not initialized")),
+            None => Err(GGMLWhisperWorkerError::library_not_loaded("engine not initialized")),
         }
     }
 
@@ -130,26 +146,11 @@ impl WhisperWorker {
         let engine = match self.engine.as_ref() {
             Some(e) => e,
             None => {
-                return Err(GGMLWhisperWorkerError::inference(
-                    "whisper backend not ready: model not loaded. Call model.load first",
-                ));
+                return Err(GGMLWhisperWorkerError::library_not_loaded("engine not initialized"));
             }
         };
 
-        let samples = match input.to_f32_arc() {
-            Ok(b) => b,
-            Err(e) => {
-                return Err(GGMLWhisperWorkerError::contract(format!(
-                    "invalid input for whisper inference: expected f32 PCM audio samples, got: {e}"
-                )));
-            }
-        };
-
-        if samples.is_empty() {
-            return Err(GGMLWhisperWorkerError::contract(
-                "invalid input for whisper inference: audio samples are empty",
-            ));
-        }
+        let samples = audio_samples(&input)?;
 
         // Whisper inference is CPU/GPU-bound; use block_in_place so the engine
         // context stays on this thread without needing an additional spawn_blocking.
@@ -171,6 +172,7 @@ impl WhisperWorker {
                 || decode.logprob_thold.is_some()
                 || decode.no_speech_thold.is_some()
                 || decode.tdrz_enable.is_some()
+                || decode.translate.is_some()
         }) || params.language.is_some()
             || params.prompt.is_some();
         let result = tokio::task::block_in_place(|| {
@@ -191,23 +193,50 @@ impl WhisperWorker {
             }
             Ok(entries) => {
                 tracing::debug!(segment_count = entries.len(), "whisper inference succeeded");
-                let mut out = String::new();
-                for entry in entries {
-                    if let Some(line) = entry.line {
-                        let ts = entry.timespan;
-                        out.push_str(&format!(
-                            "{} --> {}: {}\n",
-                            ts.start.msecs(),
-                            ts.end.msecs(),
-                            line
-                        ));
-                    }
-                }
+                let out: String = entries.iter().filter_map(transcript_line).collect();
                 Ok(Typed(AudioTranscriptionResponse { text: out }))
             }
         }
     }
 
+    // ── inference.stream ──────────────────────────────────────────────────────
+
+    async fn handle_inference_stream(
+        &mut self,
+        input: Payload,
+        params: AudioTranscriptionOptions,
+        cancel: CancelRx,
+    ) -> Result<StreamHandle, GGMLWhisperWorkerError> {
+        let engine = self
+            .engine
+            .as_ref()
+            .ok_or_else(|| GGMLWhisperWorkerError::library_not_loaded("engine not initialized"))?
+            .fork_loaded()
+            .ok_or_else(|| {
+                GGMLWhisperWorkerError::model_not_loaded(
+                    "whisper backend not ready. Call model.load first",
+                )
+            })?;
+        let samples = audio_samples(&input)?;
+
+        tracing::debug!(
+            sample_count = samples.len(),
+            duration_sec = samples.len() as f64 / 16000.0,
+            "starting whisper streaming inference"
+        );
+        let abort_rx = cancel.0.clone();
+        Ok(spawn_segment_stream(cancel.0, move |on_entry| {
+            engine
+                .inference_stream_with_options(
+                    &samples,
+                    &params,
+                    move || *abort_rx.borrow(),
+                    on_entry,
+                )
+                .map_err(|e| e.to_string())
+        }))
+    }
+
     #[on_peer_control(LoadModel)]
     async fn on_peer_load_model(
         &mut self,
@@ -265,12 +294,67 @@ impl WhisperWorker {
     }
 }
 
+fn audio_samples(input: &Payload) -> Result<Arc<[f32]>, GGMLWhisperWorkerError> {
+    let samples = input.to_f32_arc().map_err(|e| {
+        GGMLWhisperWorkerError::contract(format!(
+            "invalid input for whisper inference: expected f32 PCM audio samples, got: {e}"
+        ))
+    })?;
+
+    if samples.is_empty() {
+        return Err(GGMLWhisperWorkerError::contract(
+            "invalid input for whisper inference: audio samples are empty",
+        ));
+    }
+    Ok(samples)
+}
+
+/// Render one transcript line as `"{start_ms} --> {end_ms}: {text}\n"`.
+fn transcript_line(entry: &SubtitleEntry) -> Option<String> {
+    let line = entry.line.as_ref()?;
+    let ts = &entry.timespan;
+    Some(format!("{} --> {}: {}\n", ts.start.msecs(), ts.end.msecs(), line))
+}
+
+/// Run `transcribe` on a blocking thread and forward every finalized segment
+/// as a [`StreamChunk::Token`], terminated by `Done` or `Error`.
+///
+/// Segments produced after `cancel_rx` fires are dropped. The stream still
+/// closes with a terminal chunk once the native decode returns, which is
+/// usually an `Error` when the decode was aborted through the same signal.
+fn spawn_segment_stream<F>(cancel_rx: watch::Receiver<bool>, transcribe: F) -> StreamHandle
+where
+    F: FnOnce(&mut dyn FnMut(SubtitleEntry)) -> Result<(), String> + Send + 'static,
+{
+    let (tx, rx) = mpsc::channel::<StreamChunk>(64);
+    tokio::task::spawn_blocking(move || {
+        let mut forward = |entry: SubtitleEntry| {
+            if *cancel_rx.borrow() {
+                return;
+            }
+            if let Some(line) = transcript_line(&entry) {
+                let _ = tx.blocking_send(StreamChunk::Token(line));
+            }
+        };
+        let terminal = match transcribe(&mut forward) {
+            Ok(()) => StreamChunk::Done,
+            Err(error) => {
+                tracing::error!(error = %error, "whisper streaming inference failed");
+                StreamChunk::Error(format!("whisper inference failed: {error}"))
+            }
+        };
+        let _ = tx.blocking_send(terminal);
+    });
+    rx
+}
+
 #[cfg(test)]
 mod tests {
     use std::path::PathBuf;
 
     use super::*;
     use slab_runtime_core::backend::DeploymentSnapshot;
+    use slab_subtitle::timetypes::{TimePoint, TimeSpan};
 
     #[test]
     fn deployment_snapshot_reads_typed_whisper_model_config() {
@@ -289,4 +373,50 @@ mod tests {
         assert_eq!(config.model_path, PathBuf::from("model.bin"));
         assert_eq!(config.flash_attn, Some(true));
     }
+
+    fn segment(start_ms: i64, end_ms: i64, text: &str) -> SubtitleEntry {
+        SubtitleEntry {
+            timespan: TimeSpan::new(TimePoint::from_msecs(start_ms), TimePoint::from_msecs(end_ms)),
+            line: Some(text.to_owned()),
+        }
+    }
+
+    #[tokio::test]
+    async fn segment_stream_emits_token_per_segment_before_done() {
+        let (_cancel_tx, cancel_rx) = watch::channel(false);
+        let mut stream = spawn_segment_stream(cancel_rx, |on_entry| {
+            on_entry(segment(0, 1200, "first sentence"));
+            on_entry(segment(1200, 2500, "second sentence"));
+            on_entry(segment(2500, 4000, "third sentence"));
+            Ok(())
+        });
+
+        let mut tokens = Vec::new();
+        loop {
+            match stream.recv().await.expect("stream should end with a terminal chunk") {
+                StreamChunk::Token(token) => tokens.push(token),
+                StreamChunk::Done => break,
+                other => panic!("unexpected chunk: {other:?}"),
+            }
+        }
+
+        assert!(tokens.len() >= 2);
+        assert_eq!(tokens[0], "0 --> 1200: first sentence\n");
+        assert_eq!(tokens[2], "2500 --> 4000: third sentence\n");
+    }
+
+    #[tokio::test]
+    async fn segment_stream_reports_failure_as_error_chunk() {
+        let (cancel_tx, cancel_rx) = watch::channel(false);
+        let mut stream = spawn_segment_stream(cancel_rx, move |on_entry| {
+            cancel_tx.send(true).expect("cancel receiver should be alive");
+            on_entry(segment(0, 500, "dropped after cancel"));
+            Err("decode failed".to_owned())
+        });
+
+        match stream.recv().await {
+            Some(StreamChunk::Error(message)) => assert!(message.contains("decode failed")),
+            other => panic!("expected error chunk, got {other:?}"),
+        }
+    }
 }
@@ -18,9 +18,19 @@ pub(crate) struct RuntimeDriversConfig {
     #[cfg_attr(not(feature = "ggml"), allow(dead_code))]
     pub llama_lib_dir: Option<PathBuf>,
     #[cfg_attr(not(feature = "ggml"), allow(dead_code))]
+    pub llama_max_loaded_models: Option<usize>,
+    #[cfg_attr(not(feature = "ggml"), allow(dead_code))]
+    pub llama_session_state_dir: Option<PathBuf>,
+    #[cfg_attr(not(feature = "ggml"), allow(dead_code))]
     pub whisper_lib_dir: Option<PathBuf>,
     #[cfg_attr(not(feature = "ggml"), allow(dead_code))]
     pub diffusion_lib_dir: Option<PathBuf>,
+    #[cfg_attr(not(feature = "ggml"), allow(dead_code))]
+    pub llama_capacity: Option<usize>,
+    #[cfg_attr(not(feature = "ggml"), allow(dead_code))]
+    pub whisper_capacity: Option<usize>,
+    #[cfg_attr(not(feature = "ggml"), allow(dead_code))]
+    pub diffusion_capacity: Option<usize>,
     #[cfg_attr(not(feature = "onnx"), allow(dead_code))]
     pub onnx_enabled: bool,
     pub enable_candle_llama: bool,
@@ -32,8 +42,13 @@ impl From<&RuntimeConfig> for RuntimeDriversConfig {
     fn from(value: &RuntimeConfig) -> Self {
         Self {
             llama_lib_dir: value.llama_lib_dir.clone(),
+            llama_max_loaded_models: value.llama_max_loaded_models,
+            llama_session_state_dir: value.llama_session_state_dir.clone(),
             whisper_lib_dir: value.whisper_lib_dir.clone(),
             diffusion_lib_dir: value.diffusion_lib_dir.clone(),
+            llama_capacity: value.llama_capacity,
+            whisper_capacity: value.whisper_capacity,
+            diffusion_capacity: value.diffusion_capacity,
             onnx_enabled: value.onnx_enabled,
             enable_candle_llama: value.enable_candle_llama,
             enable_candle_whisper: value.enable_candle_whisper,
@@ -49,8 +64,13 @@ pub(crate) fn service_ids(_config: &RuntimeDriversConfig) -> Vec<&'static str> {
     #[cfg(feature = "ggml")]
     service_ids.extend(ggml::service_ids(&ggml::GgmlBackendConfig {
         llama_lib_dir: _config.llama_lib_dir.clone(),
+        llama_max_loaded_models: _config.llama_max_loaded_models,
+        llama_session_state_dir: _config.llama_session_state_dir.clone(),
         whisper_lib_dir: _config.whisper_lib_dir.clone(),
         diffusion_lib_dir: _config.diffusion_lib_dir.clone(),
+        llama_capacity: _config.llama_capacity,
+        whisper_capacity: _config.whisper_capacity,
+        diffusion_capacity: _config.diffusion_capacity,
     }));
 
     #[cfg(feature = "candle")]
@@ -76,8 +96,13 @@ pub(crate) fn register_backends(
     ggml::register(
         &ggml::GgmlBackendConfig {
             llama_lib_dir: _config.llama_lib_dir.clone(),
+            llama_max_loaded_models: _config.llama_max_loaded_models,
+            llama_session_state_dir: _config.llama_session_state_dir.clone(),
             whisper_lib_dir: _config.whisper_lib_dir.clone(),
             diffusion_lib_dir: _config.diffusion_lib_dir.clone(),
+            llama_capacity: _config.llama_capacity,
+            whisper_capacity: _config.whisper_capacity,
+            diffusion_capacity: _config.diffusion_capacity,
         },
         _resource_manager,
         _worker_count,
@@ -17,7 +17,7 @@
 use tracing::warn;
 
 use super::engine::OnnxEngine;
-use super::error::OnnxWorkerError;
+use super::error::{OnnxEngineError, OnnxWorkerError};
 use crate::domain::models::{OnnxInferenceRequest, OnnxInferenceResponse, OnnxLoadConfig};
 use slab_runtime_core::backend::{BroadcastSeq, ControlOpId, Input, PeerControlBus, Typed};
 use slab_runtime_macros::backend_handler;
@@ -150,6 +150,9 @@ impl OnnxWorker {
 
         match result {
             Ok(output) => Ok(Typed(output)),
+            Err(OnnxEngineError::SessionNotLoaded) => {
+                Err(OnnxWorkerError::model_not_loaded("call model.load first"))
+            }
             Err(e) => {
                 warn!(error = %e, "ONNX inference error");
                 Err(OnnxWorkerError::inference(e.to_string()))
@@ -1,4 +1,5 @@
 use std::path::{Path, PathBuf};
+use std::time::Duration;
 
 #[derive(Debug, Clone)]
 pub struct RuntimeConfig {
@@ -7,11 +8,23 @@ pub struct RuntimeConfig {
     pub log_json: bool,
     pub queue_capacity: usize,
     pub backend_capacity: usize,
+    /// Per-backend concurrency overrides; `None` falls back to `backend_capacity`.
+    pub llama_capacity: Option<usize>,
+    pub whisper_capacity: Option<usize>,
+    pub diffusion_capacity: Option<usize>,
+    /// Reject requests for a saturated backend instead of queueing them.
+    pub shed_load: bool,
+    /// How long finished task records are retained; `None` keeps them forever.
+    pub result_ttl: Option<Duration>,
     pub base_lib_path: PathBuf,
     pub log_file: Option<PathBuf>,
     pub enabled_backends: CliEnabledBackends,
     pub shutdown_on_stdin_close: bool,
     pub llama_lib_dir: Option<PathBuf>,
+    /// Upper bound on simultaneously loaded llama models; `None` is unbounded.
+    pub llama_max_loaded_models: Option<usize>,
+    /// Where managed llama session snapshots are persisted; `None` disables persistence.
+    pub llama_session_state_dir: Option<PathBuf>,
     pub whisper_lib_dir: Option<PathBuf>,
     pub diffusion_lib_dir: Option<PathBuf>,
     pub enable_candle_llama: bool,
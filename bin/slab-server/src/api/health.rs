@@ -1,5 +1,7 @@
 //! Health / heartbeat endpoint.
 
+use axum::extract::State;
+use axum::http::StatusCode;
 use axum::routing::get;
 use axum::{Json, Router};
 use serde::Serialize;
@@ -7,9 +9,13 @@ use std::sync::Arc;
 use utoipa::{OpenApi, ToSchema};
 
 use slab_app_core::context::AppState;
+use slab_app_core::domain::services::BackendService;
+use slab_app_core::schemas::backend::BackendStatusResponse;
+
+use crate::error::ServerError;
 
 #[derive(OpenApi)]
-#[openapi(paths(get_health), components(schemas(HealthResponse)))]
+#[openapi(paths(get_health, get_ready), components(schemas(HealthResponse, ReadinessResponse)))]
 pub struct HealthApi;
 
 #[derive(Debug, Clone, Serialize, ToSchema)]
@@ -18,9 +24,19 @@ pub struct HealthResponse {
     pub version: String,
 }
 
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct ReadinessResponse {
+    /// `"ready"` or `"not_ready"`.
+    pub status: String,
+    /// Status of every enabled runtime backend.
+    pub backends: Vec<BackendStatusResponse>,
+    /// Backends that currently hold a loaded model.
+    pub loaded_backends: Vec<String>,
+}
+
 /// Register health-check routes.
 pub fn router() -> Router<Arc<AppState>> {
-    Router::new().route("/health", get(get_health))
+    Router::new().route("/health", get(get_health)).route("/health/ready", get(get_ready))
 }
 
 /// Heartbeat endpoint.
@@ -39,6 +55,39 @@ pub async fn get_health() -> Json<HealthResponse> {
     Json(HealthResponse { status: "ok".to_owned(), version: env!("CARGO_PKG_VERSION").to_owned() })
 }
 
+/// Readiness probe.
+///
+/// Returns HTTP 200 once every enabled runtime backend is ready and at least
+/// one model is loaded, and 503 otherwise. `/health` stays a pure liveness
+/// probe.
+#[utoipa::path(
+    get,
+    path = "/health/ready",
+    tag = "health",
+    responses(
+        (status = 200, description = "All enabled backends are ready and a model is loaded", body = ReadinessResponse),
+        (status = 503, description = "A backend is not ready or no model is loaded", body = ReadinessResponse)
+    )
+)]
+pub async fn get_ready(
+    State(service): State<BackendService>,
+) -> Result<(StatusCode, Json<ReadinessResponse>), ServerError> {
+    let readiness = service.readiness().await?;
+    let (code, status) = if readiness.ready {
+        (StatusCode::OK, "ready")
+    } else {
+        (StatusCode::SERVICE_UNAVAILABLE, "not_ready")
+    };
+    Ok((
+        code,
+        Json(ReadinessResponse {
+            status: status.to_owned(),
+            backends: readiness.backends.into_iter().map(Into::into).collect(),
+            loaded_backends: readiness.loaded_backends,
+        }),
+    ))
+}
+
 //  Tests
 
 #[cfg(test)]
@@ -56,4 +105,52 @@ mod test {
         let Json(body) = get_health().await;
         assert!(!body.version.is_empty());
     }
+
+    #[tokio::test]
+    async fn readiness_is_503_until_a_model_is_loaded() {
+        use crate::api::test_support::TestServer;
+        use slab_app_core::model_auto_unload::ModelReplayPlan;
+        use slab_types::{GgmlWhisperLoadConfig, RuntimeBackendId, RuntimeBackendLoadSpec};
+
+        let server = TestServer::new().await;
+
+        let before_load = server.get("/health/ready").await;
+        assert_eq!(before_load.status, StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(before_load.body["status"], "not_ready");
+        assert_eq!(before_load.body["loaded_backends"], serde_json::json!([]));
+
+        server
+            .state
+            .context
+            .model_state
+            .auto_unload()
+            .notify_model_loaded(ModelReplayPlan {
+                backend_id: RuntimeBackendId::GgmlWhisper,
+                model_id: None,
+                load_spec: RuntimeBackendLoadSpec::GgmlWhisper(GgmlWhisperLoadConfig {
+                    model_path: "whisper.bin".into(),
+                    flash_attn: false,
+                }),
+            })
+            .await;
+
+        let ready = server.get("/health/ready").await;
+        assert_eq!(ready.status, StatusCode::OK);
+        assert_eq!(ready.body["status"], "ready");
+        assert_eq!(ready.body["loaded_backends"], serde_json::json!(["ggml.whisper"]));
+
+        server
+            .state
+            .context
+            .model_state
+            .runtime_status()
+            .mark_unavailable(RuntimeBackendId::GgmlLlama, "runtime exited".to_owned());
+
+        let not_ready = server.get("/health/ready").await;
+        assert_eq!(not_ready.status, StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(not_ready.body["status"], "not_ready");
+        assert_eq!(not_ready.body["backends"][0]["backend"], "ggml.llama");
+        assert_eq!(not_ready.body["backends"][0]["status"], "unavailable");
+        assert_eq!(server.get("/health").await.status, StatusCode::OK);
+    }
 }
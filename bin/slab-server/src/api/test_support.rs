@@ -34,6 +34,7 @@ pub(crate) struct TestServerOptions {
     pub(crate) bind_address: Option<String>,
     pub(crate) admin_api_token: Option<String>,
     pub(crate) workspace_root: Option<std::path::PathBuf>,
+    pub(crate) max_completion_tokens: Option<u32>,
 }
 
 pub(crate) struct TestResponse {
@@ -90,6 +91,7 @@ impl TestServer {
             cloud_http_trace: false,
             queue_capacity: 64,
             backend_capacity: 4,
+            max_completion_tokens: options.max_completion_tokens,
             enable_swagger: false,
             cors_allowed_origins: None,
             admin_api_token: options.admin_api_token,
@@ -157,6 +159,10 @@ impl TestServer {
         self.send_json(Method::PUT, uri, Some(body), None).await
     }
 
+    pub(crate) async fn delete(&self, uri: &str) -> TestResponse {
+        self.send_json(Method::DELETE, uri, None, None).await
+    }
+
     pub(crate) async fn get_with_token(&self, uri: &str, token: &str) -> TestResponse {
         self.send_json(Method::GET, uri, None, Some(token)).await
     }
@@ -1,27 +1,45 @@
 use std::sync::Arc;
 
-use axum::extract::{Path, State};
+use axum::extract::{DefaultBodyLimit, Multipart, Path, State};
 use axum::http::StatusCode;
 use axum::routing::post;
 use axum::{Json, Router};
-use utoipa::OpenApi;
+use utoipa::{OpenApi, ToSchema};
 
 use crate::api::v1::audio::schema::{
-    AudioTranscriptionRequest, AudioTranscriptionTaskResponse, TranscribeDecodeRequest,
-    TranscribeVadRequest,
+    AudioTranscriptionBatchItemResponse, AudioTranscriptionRequest, AudioTranscriptionTaskResponse,
+    TranscribeDecodeRequest, TranscribeVadRequest,
 };
 use crate::api::v1::tasks::schema::OperationAcceptedResponse;
 use crate::api::v1::tasks::schema::TimedTextSegmentResponse;
 use crate::api::validation::ValidatedJson;
 use crate::error::ServerError;
 use slab_app_core::context::AppState;
-use slab_app_core::domain::services::AudioService;
+use slab_app_core::domain::models::AudioTranscriptionBatchCommand;
+use slab_app_core::domain::services::{AudioService, MAX_AUDIO_UPLOAD_BATCH_BYTES};
+
+/// Request body cap for batch uploads: the aggregate file limit plus room for
+/// multipart framing and text fields.
+const AUDIO_BATCH_BODY_LIMIT_BYTES: usize = MAX_AUDIO_UPLOAD_BATCH_BYTES + 1024 * 1024;
+
+#[allow(dead_code)]
+#[derive(ToSchema)]
+struct AudioTranscriptionBatchMultipartRequest {
+    /// One or more audio files, each sent as a `file` field.
+    #[schema(value_type = Vec<String>, format = Binary)]
+    file: Vec<Vec<u8>>,
+    model_id: Option<String>,
+    language: Option<String>,
+    prompt: Option<String>,
+}
 
 #[derive(OpenApi)]
 #[openapi(
-    paths(transcribe, list_audio_transcriptions, get_audio_transcription),
+    paths(transcribe, transcribe_batch, list_audio_transcriptions, get_audio_transcription),
     components(schemas(
         AudioTranscriptionRequest,
+        AudioTranscriptionBatchMultipartRequest,
+        AudioTranscriptionBatchItemResponse,
         AudioTranscriptionTaskResponse,
         TranscribeVadRequest,
         TranscribeDecodeRequest,
@@ -34,6 +52,10 @@ pub struct AudioApi;
 pub fn router() -> Router<Arc<AppState>> {
     Router::new()
         .route("/audio/transcriptions", post(transcribe).get(list_audio_transcriptions))
+        .route(
+            "/audio/transcriptions/batch",
+            post(transcribe_batch).layer(DefaultBodyLimit::max(AUDIO_BATCH_BODY_LIMIT_BYTES)),
+        )
         .route("/audio/transcriptions/{id}", axum::routing::get(get_audio_transcription))
 }
 
@@ -56,6 +78,77 @@ async fn transcribe(
     Ok((StatusCode::ACCEPTED, Json(response.into())))
 }
 
+#[utoipa::path(
+    post,
+    path = "/v1/audio/transcriptions/batch",
+    tag = "audio",
+    request_body(
+        content = AudioTranscriptionBatchMultipartRequest,
+        content_type = "multipart/form-data",
+        description = "Upload several audio files as repeated `file` fields; one task is created per file."
+    ),
+    responses(
+        (status = 202, description = "Tasks accepted", body = [AudioTranscriptionBatchItemResponse]),
+        (status = 400, description = "Bad request (missing, empty or oversized files)"),
+        (status = 500, description = "Backend error"),
+    )
+)]
+async fn transcribe_batch(
+    State(service): State<AudioService>,
+    multipart: Multipart,
+) -> Result<(StatusCode, Json<Vec<AudioTranscriptionBatchItemResponse>>), ServerError> {
+    let mut command = AudioTranscriptionBatchCommand::default();
+    if let Err(error) = read_transcription_batch(&service, multipart, &mut command).await {
+        service.discard_uploads(&command.files).await;
+        return Err(error);
+    }
+    let accepted = service.transcribe_uploads(command).await?;
+    Ok((StatusCode::ACCEPTED, Json(accepted.into_iter().map(Into::into).collect())))
+}
+
+/// Streams each `file` field to a staged file so large batches are never held
+/// in memory. Files staged before an error are left in `command` for cleanup.
+async fn read_transcription_batch(
+    service: &AudioService,
+    mut multipart: Multipart,
+    command: &mut AudioTranscriptionBatchCommand,
+) -> Result<(), ServerError> {
+    while let Some(mut field) = multipart.next_field().await.map_err(|error| {
+        ServerError::BadRequest(format!("failed to read multipart field: {error}"))
+    })? {
+        let Some(name) = field.name().map(str::to_owned) else {
+            continue;
+        };
+        if name == "file" {
+            let filename = field
+                .file_name()
+                .map(str::to_owned)
+                .unwrap_or_else(|| format!("audio-{}", command.files.len() + 1));
+            let mut upload = service.stage_upload(filename.clone()).await?;
+            while let Some(chunk) = field.chunk().await.map_err(|error| {
+                ServerError::BadRequest(format!("failed to read audio file '{filename}': {error}"))
+            })? {
+                upload.write(&chunk).await?;
+            }
+            command.files.push(upload.finish().await?);
+            continue;
+        }
+
+        let value = field.text().await.map_err(|error| {
+            ServerError::BadRequest(format!("failed to read multipart field `{name}`: {error}"))
+        })?;
+        let value = Some(value.trim().to_owned()).filter(|value| !value.is_empty());
+        match name.as_str() {
+            "model_id" => command.model_id = value,
+            "language" => command.language = value,
+            "prompt" => command.prompt = value,
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
+
 #[utoipa::path(
     get,
     path = "/v1/audio/transcriptions",
@@ -88,3 +181,65 @@ async fn get_audio_transcription(
 ) -> Result<Json<AudioTranscriptionTaskResponse>, ServerError> {
     Ok(Json(service.get_transcription_task(&id).await?.into()))
 }
+
+#[cfg(test)]
+mod tests {
+    use axum::body::Body;
+    use axum::http::{Method, Request, StatusCode, header};
+
+    use crate::api::test_support::{TestServer, response_json};
+
+    fn batch_request(parts: &[(&str, Option<&str>, &str)]) -> Request<Body> {
+        let boundary = "slab-test-boundary";
+        let mut body = String::new();
+        for (name, filename, content) in parts {
+            body.push_str(&format!("--{boundary}\r\n"));
+            match filename {
+                Some(filename) => body.push_str(&format!(
+                    "Content-Disposition: form-data; name=\"{name}\"; filename=\"{filename}\"\r\n\
+                     Content-Type: application/octet-stream\r\n"
+                )),
+                None => {
+                    body.push_str(&format!("Content-Disposition: form-data; name=\"{name}\"\r\n"))
+                }
+            }
+            body.push_str(&format!("\r\n{content}\r\n"));
+        }
+        body.push_str(&format!("--{boundary}--\r\n"));
+
+        Request::builder()
+            .method(Method::POST)
+            .uri("/v1/audio/transcriptions/batch")
+            .header(header::CONTENT_TYPE, format!("multipart/form-data; boundary={boundary}"))
+            .body(Body::from(body))
+            .expect("multipart request")
+    }
+
+    #[tokio::test]
+    async fn transcribe_batch_requires_at_least_one_file() {
+        let server = TestServer::new().await;
+
+        let request = batch_request(&[("language", None, "en")]);
+        let response = response_json(server.raw(request).await).await;
+
+        assert_eq!(response.status, StatusCode::BAD_REQUEST);
+        assert!(
+            response.body["message"]
+                .as_str()
+                .unwrap_or_default()
+                .contains("at least one audio file")
+        );
+    }
+
+    #[tokio::test]
+    async fn transcribe_batch_rejects_empty_file_before_backend_lookup() {
+        let server = TestServer::new().await;
+
+        let request =
+            batch_request(&[("file", Some("a.wav"), "RIFF"), ("file", Some("b.wav"), "")]);
+        let response = response_json(server.raw(request).await).await;
+
+        assert_eq!(response.status, StatusCode::BAD_REQUEST);
+        assert!(response.body["message"].as_str().unwrap_or_default().contains("'b.wav' is empty"));
+    }
+}
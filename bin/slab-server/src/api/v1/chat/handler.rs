@@ -2,33 +2,42 @@ use std::convert::Infallible;
 use std::sync::Arc;
 
 use axum::extract::State;
-use axum::http::{HeaderName, HeaderValue, StatusCode};
+use axum::extract::ws::rejection::WebSocketUpgradeRejection;
+use axum::extract::ws::{Message, WebSocket, WebSocketUpgrade};
+use axum::http::{HeaderName, HeaderValue, StatusCode, header};
 use axum::response::sse::{Event, Sse};
 use axum::response::{IntoResponse, Response};
 use axum::routing::{get, post};
 use axum::{Json, Router};
-use futures::StreamExt;
+use futures::stream::BoxStream;
+use futures::{SinkExt, StreamExt};
+use serde::Serialize;
 use utoipa::OpenApi;
 
 use crate::api::v1::chat::schema::{
     ChatChoice, ChatCompletionRequest, ChatCompletionResponse, ChatCompletionUsage,
-    ChatContentPart, ChatMessage as OpenAiMessage, ChatMessageContent, ChatModelCapabilities,
-    ChatModelOption, ChatModelSource, ChatPromptTokensDetails, ChatReasoningEffort,
-    ChatResponseFormat, ChatResponseFormatType, ChatResponseJsonSchema, ChatStreamOptions,
-    ChatThinkingConfig, ChatThinkingType, ChatToolCall, ChatToolFunction, ChatVerbosity,
+    ChatContentPart, ChatFunctionDefinition, ChatMessage as OpenAiMessage, ChatMessageContent,
+    ChatModelCapabilities, ChatModelOption, ChatModelSource, ChatNamedToolChoice,
+    ChatNamedToolChoiceFunction, ChatPromptTokensDetails, ChatReasoningEffort, ChatResponseFormat,
+    ChatResponseFormatType, ChatResponseJsonSchema, ChatSocketClientMessage,
+    ChatSocketServerMessage, ChatStreamOptions, ChatThinkingConfig, ChatThinkingType, ChatTool,
+    ChatToolCall, ChatToolChoice, ChatToolChoiceMode, ChatToolFunction, ChatVerbosity,
     CompletionChoice, CompletionRequest, CompletionResponse, OpenAiError, OpenAiErrorResponse,
     StopSequences,
 };
-use crate::api::validation::ValidatedJson;
+use crate::api::validation::{ValidatedJson, validate};
 use crate::error::{ServerError, message_i18n, message_i18n_with_detail};
 use slab_app_core::context::AppState;
-use slab_app_core::domain::models::{ChatCompletionOutput, ChatStreamChunk, TextCompletionOutput};
-use slab_app_core::domain::services::{ChatService, ModelService};
+use slab_app_core::domain::models::{
+    ChatCompletionOutput, ChatStreamChunk, ChatStreamEvent, CreateSessionCommand,
+    TextCompletionOutput,
+};
+use slab_app_core::domain::services::{ChatService, ModelService, SessionService};
 use slab_types::ServerI18nKey;
 
 #[derive(OpenApi)]
 #[openapi(
-    paths(chat_completions, completions, list_chat_models),
+    paths(chat_completions, chat_socket, completions, list_chat_models),
     components(schemas(
         ChatCompletionRequest,
         ChatCompletionResponse,
@@ -53,8 +62,16 @@ use slab_types::ServerI18nKey;
         ChatResponseJsonSchema,
         ChatStreamOptions,
         StopSequences,
+        ChatTool,
         ChatToolCall,
         ChatToolFunction,
+        ChatFunctionDefinition,
+        ChatToolChoice,
+        ChatToolChoiceMode,
+        ChatNamedToolChoice,
+        ChatNamedToolChoiceFunction,
+        ChatSocketClientMessage,
+        ChatSocketServerMessage,
         OpenAiErrorResponse
     ))
 )]
@@ -64,6 +81,7 @@ pub fn router() -> Router<Arc<AppState>> {
     Router::new()
         .route("/chat/models", get(list_chat_models))
         .route("/chat/completions", post(chat_completions))
+        .route("/chat/ws", get(chat_socket))
         .route("/completions", post(completions))
 }
 
@@ -114,14 +132,16 @@ async fn chat_completions(
         Ok(req) => req,
         Err(error) => return openai_error_response(error),
     };
+    let clamped_to = service.max_tokens_clamp(req.max_tokens);
 
-    match service.create_chat_completion(req.into()).await {
+    let response = match service.create_chat_completion(req.into()).await {
         Ok(ChatCompletionOutput::Json(response)) => {
             Json(ChatCompletionResponse::from(response)).into_response()
         }
         Ok(ChatCompletionOutput::Stream(stream)) => sse_response(stream),
         Err(error) => openai_error_response(error.into()),
-    }
+    };
+    with_max_tokens_warning(response, clamped_to)
 }
 
 #[utoipa::path(
@@ -143,19 +163,270 @@ async fn completions(
         Ok(req) => req,
         Err(error) => return openai_error_response(error),
     };
+    let clamped_to = service.max_tokens_clamp(req.max_tokens);
 
-    match service.create_text_completion(req.into()).await {
+    let response = match service.create_text_completion(req.into()).await {
         Ok(TextCompletionOutput::Json(response)) => {
             Json(CompletionResponse::from(response)).into_response()
         }
         Ok(TextCompletionOutput::Stream(stream)) => sse_response(stream),
         Err(error) => openai_error_response(error.into()),
+    };
+    with_max_tokens_warning(response, clamped_to)
+}
+
+/// Tell the client its `max_tokens` was lowered to the server cap.
+fn with_max_tokens_warning(mut response: Response, clamped_to: Option<u32>) -> Response {
+    if let Some(cap) = clamped_to
+        && let Ok(value) = HeaderValue::from_str(&format!("299 - \"max_tokens clamped to {cap}\""))
+    {
+        response.headers_mut().insert(header::WARNING, value);
     }
+    response
+}
+
+#[utoipa::path(
+    get,
+    path = "/v1/chat/ws",
+    tag = "chat",
+    responses(
+        (status = 101, description = "WebSocket upgrade for bidirectional chat on a persistent session"),
+        (status = 400, description = "Bad request"),
+    )
+)]
+async fn chat_socket(
+    State(chat): State<ChatService>,
+    State(sessions): State<SessionService>,
+    ws: Result<WebSocketUpgrade, WebSocketUpgradeRejection>,
+) -> Result<Response, ServerError> {
+    let ws = ws.map_err(|_| {
+        ServerError::BadRequest("GET /v1/chat/ws requires a websocket upgrade".into())
+    })?;
+    Ok(ws.on_upgrade(move |socket| chat_socket_session(socket, chat, sessions)).into_response())
 }
 
-fn sse_response(stream: futures::stream::BoxStream<'static, ChatStreamChunk>) -> Response {
+/// Owns the chat session for the lifetime of one socket; closing the socket
+/// deletes it.
+async fn chat_socket_session(socket: WebSocket, chat: ChatService, sessions: SessionService) {
+    let session = match sessions
+        .create_session(CreateSessionCommand { name: Some("WebSocket chat".to_owned()) })
+        .await
+    {
+        Ok(session) => session,
+        Err(error) => {
+            tracing::warn!(error = %error, "failed to create chat websocket session");
+            return;
+        }
+    };
+
+    if let Err(error) = run_chat_socket(socket, &chat, &session.id).await {
+        tracing::warn!(session_id = %session.id, error = %error, "chat websocket ended");
+    }
+    if let Err(error) = sessions.delete_session(&session.id).await {
+        tracing::warn!(session_id = %session.id, error = %error, "failed to delete chat websocket session");
+    }
+}
+
+async fn run_chat_socket(
+    socket: WebSocket,
+    chat: &ChatService,
+    session_id: &str,
+) -> Result<(), String> {
+    let (mut sender, mut receiver) = socket.split();
+    send_socket_message(
+        &mut sender,
+        &ChatSocketServerMessage::Session { session_id: session_id.to_owned() },
+    )
+    .await?;
+
+    while let Some(message) = receiver.next().await {
+        let message = message.map_err(|error| format!("websocket receive failed: {error}"))?;
+        let payload = match message {
+            Message::Text(payload) => payload,
+            Message::Close(_) => break,
+            _ => continue,
+        };
+        let command = match parse_chat_socket_message(&payload) {
+            Ok(command) => command,
+            Err(error) => {
+                send_socket_message(&mut sender, &socket_error_message(error, None)).await?;
+                continue;
+            }
+        };
+        let request_id = command.request_id().map(str::to_owned);
+        // Nothing is streaming between replies, so a cancel frame is a no-op.
+        let Some(request) = command.into_completion_request(session_id) else {
+            continue;
+        };
+        let request = match validate(request) {
+            Ok(request) => request,
+            Err(error) => {
+                send_socket_message(&mut sender, &socket_error_message(error, request_id)).await?;
+                continue;
+            }
+        };
+
+        match chat.create_chat_completion(request.into()).await {
+            Ok(ChatCompletionOutput::Stream(stream)) => {
+                if !forward_chat_stream(stream, &mut sender, &mut receiver, request_id).await? {
+                    break;
+                }
+            }
+            Ok(ChatCompletionOutput::Json(response)) => {
+                let response = ChatCompletionResponse::from(response);
+                let choice = response.choices.into_iter().next();
+                let finish_reason = choice.as_ref().and_then(|choice| choice.finish_reason.clone());
+                if let Some(content) = choice
+                    .map(|choice| choice.message.rendered_text())
+                    .filter(|content| !content.is_empty())
+                {
+                    send_socket_message(
+                        &mut sender,
+                        &ChatSocketServerMessage::Token { request_id: request_id.clone(), content },
+                    )
+                    .await?;
+                }
+                send_socket_message(
+                    &mut sender,
+                    &ChatSocketServerMessage::Done { request_id, finish_reason },
+                )
+                .await?;
+            }
+            Err(error) => {
+                send_socket_message(&mut sender, &socket_error_message(error.into(), request_id))
+                    .await?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Forward one reply stream to the socket while still reading client frames,
+/// so closing the socket or sending `chat.cancel` stops generation by dropping
+/// the stream. Returns `false` once the client has closed the socket.
+async fn forward_chat_stream<S, R, E>(
+    mut stream: BoxStream<'static, ChatStreamChunk>,
+    sender: &mut S,
+    receiver: &mut R,
+    request_id: Option<String>,
+) -> Result<bool, String>
+where
+    S: futures::Sink<Message> + Unpin,
+    S::Error: std::fmt::Display,
+    R: futures::Stream<Item = Result<Message, E>> + Unpin,
+    E: std::fmt::Display,
+{
+    let mut finish_reason = None;
+    loop {
+        tokio::select! {
+            chunk = stream.next() => {
+                let Some(chunk) = chunk else {
+                    return Ok(true);
+                };
+                if let Some(frame) =
+                    chat_socket_frame(chunk.event, request_id.as_deref(), &mut finish_reason)
+                {
+                    send_socket_message(sender, &frame).await?;
+                }
+            }
+            message = receiver.next() => {
+                let payload = match message {
+                    None | Some(Ok(Message::Close(_))) => return Ok(false),
+                    Some(Err(error)) => return Err(format!("websocket receive failed: {error}")),
+                    Some(Ok(Message::Text(payload))) => payload,
+                    Some(Ok(_)) => continue,
+                };
+                match parse_chat_socket_message(&payload) {
+                    Ok(ChatSocketClientMessage::Cancel { .. }) => {
+                        send_socket_message(
+                            sender,
+                            &ChatSocketServerMessage::Done {
+                                request_id,
+                                finish_reason: Some("cancelled".to_owned()),
+                            },
+                        )
+                        .await?;
+                        return Ok(true);
+                    }
+                    Ok(command @ ChatSocketClientMessage::Input { .. }) => {
+                        let error = ServerError::Conflict(
+                            "a reply is already streaming on this socket".into(),
+                        );
+                        let request_id = command.request_id().map(str::to_owned);
+                        send_socket_message(sender, &socket_error_message(error, request_id))
+                            .await?;
+                    }
+                    Err(error) => {
+                        send_socket_message(sender, &socket_error_message(error, None)).await?;
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn parse_chat_socket_message(payload: &str) -> Result<ChatSocketClientMessage, ServerError> {
+    serde_json::from_str::<ChatSocketClientMessage>(payload)
+        .map_err(|error| ServerError::BadRequest(format!("invalid chat socket message: {error}")))
+}
+
+/// Map one typed stream event onto the socket frame it produces, if any.
+fn chat_socket_frame(
+    event: ChatStreamEvent,
+    request_id: Option<&str>,
+    finish_reason: &mut Option<String>,
+) -> Option<ChatSocketServerMessage> {
+    let request_id = request_id.map(str::to_owned);
+    match event {
+        ChatStreamEvent::Content(content) if !content.is_empty() => {
+            Some(ChatSocketServerMessage::Token { request_id, content })
+        }
+        ChatStreamEvent::Finish(reason) => {
+            *finish_reason = Some(reason);
+            None
+        }
+        ChatStreamEvent::Error(message) => Some(ChatSocketServerMessage::Error {
+            request_id,
+            code: "runtime_error".to_owned(),
+            message,
+            i18n: None,
+        }),
+        ChatStreamEvent::Done => {
+            Some(ChatSocketServerMessage::Done { request_id, finish_reason: finish_reason.take() })
+        }
+        _ => None,
+    }
+}
+
+fn socket_error_message(error: ServerError, request_id: Option<String>) -> ChatSocketServerMessage {
+    let (code, message, i18n) = error.agent_code_message();
+    ChatSocketServerMessage::Error { request_id, code, message, i18n: Some(i18n) }
+}
+
+async fn send_socket_message<S>(
+    sender: &mut S,
+    message: &ChatSocketServerMessage,
+) -> Result<(), String>
+where
+    S: futures::Sink<Message> + Unpin,
+    S::Error: std::fmt::Display,
+{
+    sender
+        .send(Message::Text(serialize_socket_message(message).into()))
+        .await
+        .map_err(|error| format!("websocket send failed: {error}"))
+}
+
+fn serialize_socket_message<T: Serialize>(value: &T) -> String {
+    serde_json::to_string(value).unwrap_or_else(|_| {
+        r#"{"type":"chat.error","code":"serialization_failed","message":"failed to serialize chat message"}"#.to_owned()
+    })
+}
+
+fn sse_response(stream: BoxStream<'static, ChatStreamChunk>) -> Response {
     let event_stream =
-        stream.map(|chunk| -> Result<Event, Infallible> { Ok(Event::default().data(chunk)) });
+        stream.map(|chunk| -> Result<Event, Infallible> { Ok(Event::default().data(chunk.data)) });
     Sse::new(event_stream).into_response()
 }
 
@@ -260,11 +531,285 @@ fn openai_error_response(error: ServerError) -> Response {
 
 #[cfg(test)]
 mod tests {
+    use std::convert::Infallible;
+    use std::time::Duration;
+
     use axum::body::to_bytes;
+    use axum::extract::ws::Message;
     use axum::http::StatusCode;
+    use futures::{SinkExt, StreamExt};
     use serde_json::Value;
+    use slab_app_core::domain::models::{ChatStreamChunk, ChatStreamEvent};
+    use tokio_tungstenite::tungstenite::{self, Message as TungsteniteMessage};
+
+    use super::{
+        ServerError, chat_socket_frame, forward_chat_stream, openai_error_response,
+        parse_chat_socket_message, sse_response,
+    };
+    use crate::api::test_support::{TestServer, TestServerOptions};
 
-    use super::{ServerError, openai_error_response};
+    fn chunk(event: ChatStreamEvent, data: &str) -> ChatStreamChunk {
+        ChatStreamChunk { event, data: data.to_owned() }
+    }
+
+    fn sent_frames(messages: Vec<Message>) -> Vec<Value> {
+        messages
+            .into_iter()
+            .map(|message| match message {
+                Message::Text(text) => serde_json::from_str(text.as_str()).expect("json frame"),
+                other => panic!("unexpected socket message: {other:?}"),
+            })
+            .collect()
+    }
+
+    async fn next_socket_frame<S>(socket: &mut S) -> Value
+    where
+        S: futures::Stream<Item = Result<TungsteniteMessage, tungstenite::Error>> + Unpin,
+    {
+        let message = tokio::time::timeout(Duration::from_secs(5), socket.next())
+            .await
+            .expect("socket frame in time")
+            .expect("open socket")
+            .expect("socket frame");
+        serde_json::from_str(message.to_text().expect("text frame")).expect("json frame")
+    }
+
+    #[tokio::test]
+    async fn sse_response_emits_each_chunk_as_event_and_ends_with_done() {
+        let chunks = vec![
+            chunk(
+                ChatStreamEvent::Content("Hel".to_owned()),
+                r#"{"object":"chat.completion.chunk","choices":[{"delta":{"content":"Hel"}}]}"#,
+            ),
+            chunk(
+                ChatStreamEvent::Content("lo".to_owned()),
+                r#"{"object":"chat.completion.chunk","choices":[{"delta":{"content":"lo"}}]}"#,
+            ),
+            ChatStreamChunk::done(),
+        ];
+        let response = sse_response(futures::stream::iter(chunks.clone()).boxed());
+
+        assert_eq!(
+            response.headers().get(axum::http::header::CONTENT_TYPE).expect("content type"),
+            "text/event-stream"
+        );
+        let body = to_bytes(response.into_body(), usize::MAX).await.expect("read body");
+        let body = String::from_utf8(body.to_vec()).expect("utf8 body");
+        let events: Vec<&str> = body
+            .split("\n\n")
+            .filter(|event| !event.is_empty())
+            .map(|event| event.strip_prefix("data: ").expect("data event"))
+            .collect();
+
+        let expected: Vec<&str> = chunks.iter().map(|chunk| chunk.data.as_str()).collect();
+        assert_eq!(events, expected);
+        assert_eq!(events.last().copied(), Some("[DONE]"));
+    }
+
+    #[test]
+    fn chat_socket_input_streams_tokens_then_done_on_the_socket_session() {
+        let command = parse_chat_socket_message(
+            r#"{"type":"chat.input","request_id":"r1","model":"local","content":"hi"}"#,
+        )
+        .expect("valid frame");
+        assert_eq!(command.request_id(), Some("r1"));
+        let request = command.into_completion_request("session-1").expect("input frame");
+        assert_eq!(request.id.as_deref(), Some("session-1"));
+        assert!(request.stream);
+        assert_eq!(request.messages.len(), 1);
+        assert_eq!(request.messages[0].rendered_text(), "hi");
+
+        let events = [
+            ChatStreamEvent::Role,
+            ChatStreamEvent::Content("Hel".to_owned()),
+            ChatStreamEvent::Reasoning("hmm".to_owned()),
+            ChatStreamEvent::Content("lo".to_owned()),
+            ChatStreamEvent::Finish("stop".to_owned()),
+            ChatStreamEvent::Done,
+        ];
+        let mut finish_reason = None;
+        let frames: Vec<Value> = events
+            .into_iter()
+            .filter_map(|event| chat_socket_frame(event, Some("r1"), &mut finish_reason))
+            .map(|frame| serde_json::to_value(frame).expect("serialize frame"))
+            .collect();
+
+        assert_eq!(
+            frames,
+            vec![
+                serde_json::json!({"type":"chat.token","request_id":"r1","content":"Hel"}),
+                serde_json::json!({"type":"chat.token","request_id":"r1","content":"lo"}),
+                serde_json::json!({"type":"chat.done","request_id":"r1","finish_reason":"stop"}),
+            ]
+        );
+    }
+
+    #[test]
+    fn chat_socket_rejects_unknown_frames_and_forwards_stream_errors() {
+        let error = parse_chat_socket_message(r#"{"type":"chat.bogus"}"#).expect_err("bad frame");
+        assert!(matches!(error, ServerError::BadRequest(_)));
+
+        let cancel =
+            parse_chat_socket_message(r#"{"type":"chat.cancel"}"#).expect("valid cancel frame");
+        assert!(cancel.into_completion_request("session-1").is_none());
+
+        let frame = chat_socket_frame(ChatStreamEvent::Error("boom".to_owned()), None, &mut None)
+            .expect("error frame");
+        let frame = serde_json::to_value(frame).expect("serialize frame");
+        assert_eq!(frame["type"], "chat.error");
+        assert_eq!(frame["code"], "runtime_error");
+        assert_eq!(frame["message"], "boom");
+    }
+
+    #[tokio::test]
+    async fn chat_socket_cancel_stops_an_unfinished_reply() {
+        let generation = futures::stream::pending::<ChatStreamChunk>().boxed();
+        let (mut sender, sent) = futures::channel::mpsc::unbounded::<Message>();
+        let mut receiver = futures::stream::iter([
+            Ok::<_, Infallible>(Message::Text(
+                r#"{"type":"chat.input","request_id":"r2","content":"again"}"#.into(),
+            )),
+            Ok(Message::Text(r#"{"type":"chat.cancel","request_id":"r1"}"#.into())),
+        ])
+        .chain(futures::stream::pending());
+
+        let open = tokio::time::timeout(
+            Duration::from_secs(5),
+            forward_chat_stream(generation, &mut sender, &mut receiver, Some("r1".to_owned())),
+        )
+        .await
+        .expect("cancel ends the reply")
+        .expect("forward reply");
+        drop(sender);
+
+        assert!(open);
+        let frames = sent_frames(sent.collect().await);
+        assert_eq!(frames.len(), 2);
+        assert_eq!(frames[0]["type"], "chat.error");
+        assert_eq!(frames[0]["request_id"], "r2");
+        assert_eq!(
+            frames[1],
+            serde_json::json!({"type":"chat.done","request_id":"r1","finish_reason":"cancelled"})
+        );
+    }
+
+    #[tokio::test]
+    async fn chat_socket_close_stops_an_unfinished_reply() {
+        let generation = futures::stream::iter([chunk(ChatStreamEvent::Role, "{}")])
+            .chain(futures::stream::pending())
+            .boxed();
+        let (mut sender, sent) = futures::channel::mpsc::unbounded::<Message>();
+        let mut receiver = futures::stream::iter([Ok::<_, Infallible>(Message::Close(None))])
+            .chain(futures::stream::pending());
+
+        let open = tokio::time::timeout(
+            Duration::from_secs(5),
+            forward_chat_stream(generation, &mut sender, &mut receiver, None),
+        )
+        .await
+        .expect("close ends the reply")
+        .expect("forward reply");
+        drop(sender);
+
+        assert!(!open);
+        assert!(sent_frames(sent.collect().await).is_empty());
+    }
+
+    #[tokio::test]
+    async fn completions_above_the_server_cap_carry_a_warning_header() {
+        let server = TestServer::new_with(TestServerOptions {
+            max_completion_tokens: Some(32),
+            ..Default::default()
+        })
+        .await;
+        let post = |uri: &str, body: Value| {
+            axum::http::Request::post(uri)
+                .header(axum::http::header::CONTENT_TYPE, "application/json")
+                .body(axum::body::Body::from(body.to_string()))
+                .expect("test request")
+        };
+        let chat = |max_tokens: u32| {
+            post(
+                "/v1/chat/completions",
+                serde_json::json!({
+                    "model": "missing-model",
+                    "messages": [{ "role": "user", "content": "hi" }],
+                    "max_tokens": max_tokens,
+                }),
+            )
+        };
+
+        let clamped = server.raw(chat(100)).await;
+        assert_eq!(
+            clamped.headers().get(axum::http::header::WARNING).expect("warning header"),
+            "299 - \"max_tokens clamped to 32\""
+        );
+        let within_cap = server.raw(chat(5)).await;
+        assert!(within_cap.headers().get(axum::http::header::WARNING).is_none());
+
+        let text = server
+            .raw(post(
+                "/v1/completions",
+                serde_json::json!({ "model": "missing-model", "prompt": "hi", "max_tokens": 64 }),
+            ))
+            .await;
+        assert_eq!(
+            text.headers().get(axum::http::header::WARNING).expect("warning header"),
+            "299 - \"max_tokens clamped to 32\""
+        );
+    }
+
+    #[tokio::test]
+    async fn chat_socket_upgrade_serves_frames_and_close_deletes_the_session() {
+        let server = TestServer::new().await;
+        let listener =
+            tokio::net::TcpListener::bind("127.0.0.1:0").await.expect("bind test listener");
+        let address = listener.local_addr().expect("listener address");
+        let app = server.app();
+        tokio::spawn(async move { axum::serve(listener, app).await });
+
+        let (mut socket, response) =
+            tokio_tungstenite::connect_async(format!("ws://{address}/v1/chat/ws"))
+                .await
+                .expect("websocket upgrade");
+        assert_eq!(response.status(), StatusCode::SWITCHING_PROTOCOLS);
+
+        let session = next_socket_frame(&mut socket).await;
+        assert_eq!(session["type"], "chat.session");
+        let session_id = session["session_id"].as_str().expect("session id").to_owned();
+        let sessions = server.get("/v1/sessions").await;
+        assert!(
+            sessions.body.as_array().expect("session list").iter().any(|s| s["id"] == session_id)
+        );
+
+        socket
+            .send(TungsteniteMessage::text(
+                r#"{"type":"chat.input","request_id":"r1","content":"hi","max_tokens":0}"#,
+            ))
+            .await
+            .expect("send input frame");
+        let error = next_socket_frame(&mut socket).await;
+        assert_eq!(error["type"], "chat.error");
+        assert_eq!(error["request_id"], "r1");
+
+        socket.close(None).await.expect("close socket");
+        let mut deleted = false;
+        for _ in 0..50 {
+            let sessions = server.get("/v1/sessions").await;
+            if !sessions
+                .body
+                .as_array()
+                .expect("session list")
+                .iter()
+                .any(|s| s["id"] == session_id)
+            {
+                deleted = true;
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        }
+        assert!(deleted, "closing the socket should delete its session");
+    }
 
     #[tokio::test]
     async fn openai_error_response_includes_nested_message_i18n() {
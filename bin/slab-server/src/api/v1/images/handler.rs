@@ -1,15 +1,15 @@
 use std::sync::Arc;
 
-use axum::extract::{Path, State};
+use axum::extract::{DefaultBodyLimit, Multipart, Path, State};
 use axum::http::StatusCode;
 use axum::http::header::{CACHE_CONTROL, CONTENT_TYPE};
 use axum::response::IntoResponse;
 use axum::routing::post;
 use axum::{Json, Router};
-use utoipa::OpenApi;
+use utoipa::{OpenApi, ToSchema};
 
 use crate::api::v1::images::schema::{
-    ImageGenerationRequest, ImageGenerationTaskResponse, ImageMode,
+    ImageEditUpload, ImageGenerationRequest, ImageGenerationTaskResponse, ImageMode,
 };
 use crate::api::v1::tasks::schema::OperationAcceptedResponse;
 use crate::api::validation::ValidatedJson;
@@ -17,17 +17,39 @@ use crate::error::ServerError;
 use slab_app_core::context::AppState;
 use slab_app_core::domain::services::ImageService;
 
+/// Upload cap for `/v1/images/edits`: the 20 MiB image limit plus room for text fields.
+const IMAGE_EDIT_BODY_LIMIT_BYTES: usize = 21 * 1024 * 1024;
+
+#[allow(dead_code)]
+#[derive(ToSchema)]
+struct ImageEditMultipartRequest {
+    /// PNG or JPEG image to edit.
+    #[schema(value_type = String, format = Binary)]
+    image: Vec<u8>,
+    prompt: String,
+    model: String,
+    model_id: Option<String>,
+    negative_prompt: Option<String>,
+    n: Option<u32>,
+    strength: Option<f32>,
+    steps: Option<i32>,
+    seed: Option<i64>,
+}
+
 #[derive(OpenApi)]
 #[openapi(
     paths(
         generate_images,
+        edit_images,
         list_image_generations,
         get_image_generation,
         get_image_generation_artifact,
+        get_image_generation_preview,
         get_image_generation_reference
     ),
     components(schemas(
         ImageGenerationRequest,
+        ImageEditMultipartRequest,
         ImageGenerationTaskResponse,
         ImageMode,
         OperationAcceptedResponse
@@ -38,11 +60,16 @@ pub struct ImagesApi;
 pub fn router() -> Router<Arc<AppState>> {
     Router::new()
         .route("/images/generations", post(generate_images).get(list_image_generations))
+        .route(
+            "/images/edits",
+            post(edit_images).layer(DefaultBodyLimit::max(IMAGE_EDIT_BODY_LIMIT_BYTES)),
+        )
         .route("/images/generations/{id}", axum::routing::get(get_image_generation))
         .route(
             "/images/generations/{id}/artifacts/{index}",
             axum::routing::get(get_image_generation_artifact),
         )
+        .route("/images/generations/{id}/preview", axum::routing::get(get_image_generation_preview))
         .route(
             "/images/generations/{id}/reference",
             axum::routing::get(get_image_generation_reference),
@@ -68,6 +95,78 @@ async fn generate_images(
     Ok((StatusCode::ACCEPTED, Json(response.into())))
 }
 
+#[utoipa::path(
+    post,
+    path = "/v1/images/edits",
+    tag = "images",
+    request_body(
+        content = ImageEditMultipartRequest,
+        content_type = "multipart/form-data",
+        description = "Edit an uploaded image (img2img). The `image` file field is required."
+    ),
+    responses(
+        (status = 202, description = "Task accepted", body = OperationAcceptedResponse),
+        (status = 400, description = "Bad request (invalid parameters or image)"),
+        (status = 500, description = "Backend error"),
+    )
+)]
+async fn edit_images(
+    State(service): State<ImageService>,
+    multipart: Multipart,
+) -> Result<(StatusCode, Json<OperationAcceptedResponse>), ServerError> {
+    let upload = read_image_edit_upload(multipart).await?;
+    let response = service.generate_images(upload.try_into()?).await?;
+    Ok((StatusCode::ACCEPTED, Json(response.into())))
+}
+
+async fn read_image_edit_upload(mut multipart: Multipart) -> Result<ImageEditUpload, ServerError> {
+    let mut upload = ImageEditUpload::default();
+    while let Some(field) = multipart.next_field().await.map_err(|error| {
+        ServerError::BadRequest(format!("failed to read multipart field: {error}"))
+    })? {
+        let Some(name) = field.name().map(str::to_owned) else {
+            continue;
+        };
+        if name == "image" {
+            upload.image = field
+                .bytes()
+                .await
+                .map_err(|error| {
+                    ServerError::BadRequest(format!("failed to read image bytes: {error}"))
+                })?
+                .to_vec();
+            continue;
+        }
+
+        let value = field.text().await.map_err(|error| {
+            ServerError::BadRequest(format!("failed to read multipart field `{name}`: {error}"))
+        })?;
+        match name.as_str() {
+            "prompt" => upload.prompt = value,
+            "model" => upload.model = value,
+            "model_id" => upload.model_id = Some(value),
+            "negative_prompt" => upload.negative_prompt = Some(value),
+            "n" => upload.n = Some(parse_field(&name, &value)?),
+            "strength" => upload.strength = Some(parse_field(&name, &value)?),
+            "steps" => upload.steps = Some(parse_field(&name, &value)?),
+            "seed" => upload.seed = Some(parse_field(&name, &value)?),
+            _ => {}
+        }
+    }
+
+    if upload.image.is_empty() {
+        return Err(ServerError::BadRequest("multipart body must contain an image field".into()));
+    }
+    Ok(upload)
+}
+
+fn parse_field<T: std::str::FromStr>(name: &str, value: &str) -> Result<T, ServerError> {
+    value
+        .trim()
+        .parse()
+        .map_err(|_| ServerError::BadRequest(format!("invalid value for `{name}`: {value}")))
+}
+
 #[utoipa::path(
     get,
     path = "/v1/images/generations",
@@ -123,6 +222,25 @@ async fn get_image_generation_artifact(
     Ok((StatusCode::OK, [(CONTENT_TYPE, "image/png"), (CACHE_CONTROL, "no-store")], bytes))
 }
 
+#[utoipa::path(
+    get,
+    path = "/v1/images/generations/{id}/preview",
+    tag = "images",
+    params(("id" = String, Path, description = "Image generation task ID")),
+    responses(
+        (status = 200, description = "Latest preview image bytes"),
+        (status = 404, description = "Task or preview not found"),
+        (status = 500, description = "Backend error"),
+    )
+)]
+async fn get_image_generation_preview(
+    State(service): State<ImageService>,
+    Path(id): Path<String>,
+) -> Result<impl IntoResponse, ServerError> {
+    let bytes = service.read_preview_image(&id).await?;
+    Ok((StatusCode::OK, [(CONTENT_TYPE, "image/png"), (CACHE_CONTROL, "no-store")], bytes))
+}
+
 #[utoipa::path(
     get,
     path = "/v1/images/generations/{id}/reference",
@@ -88,16 +88,20 @@ mod tests {
         ("/v1/audio/transcriptions", "get"),
         ("/v1/audio/transcriptions", "post"),
         ("/v1/audio/transcriptions/{id}", "get"),
+        ("/v1/audio/transcriptions/batch", "post"),
         ("/v1/backends", "get"),
         ("/v1/backends/status", "get"),
         ("/v1/chat/completions", "post"),
         ("/v1/chat/models", "get"),
+        ("/v1/chat/ws", "get"),
         ("/v1/completions", "post"),
         ("/v1/ffmpeg/convert", "post"),
+        ("/v1/images/edits", "post"),
         ("/v1/images/generations", "get"),
         ("/v1/images/generations", "post"),
         ("/v1/images/generations/{id}", "get"),
         ("/v1/images/generations/{id}/artifacts/{index}", "get"),
+        ("/v1/images/generations/{id}/preview", "get"),
         ("/v1/images/generations/{id}/reference", "get"),
         ("/v1/models", "get"),
         ("/v1/models", "post"),
@@ -140,6 +144,7 @@ mod tests {
         ("/v1/system/diagnostics/agent-stats", "get"),
         ("/v1/system/gpu", "get"),
         ("/v1/tasks", "get"),
+        ("/v1/tasks/{id}", "delete"),
         ("/v1/tasks/{id}", "get"),
         ("/v1/tasks/{id}/cancel", "post"),
         ("/v1/tasks/{id}/restart", "post"),
@@ -17,7 +17,7 @@ use slab_app_core::domain::services::TaskApplicationService;
 
 #[derive(OpenApi)]
 #[openapi(
-    paths(list_tasks, get_task, get_task_result, cancel_task, restart_task),
+    paths(list_tasks, get_task, delete_task, get_task_result, cancel_task, restart_task),
     components(schemas(
         TaskProgressResponse,
         TaskResponse,
@@ -32,7 +32,7 @@ pub struct TasksApi;
 pub fn router() -> Router<Arc<AppState>> {
     Router::new()
         .route("/tasks", get(list_tasks))
-        .route("/tasks/{id}", get(get_task))
+        .route("/tasks/{id}", get(get_task).delete(delete_task))
         .route("/tasks/{id}/result", get(get_task_result))
         .route("/tasks/{id}/cancel", post(cancel_task))
         .route("/tasks/{id}/restart", post(restart_task))
@@ -124,6 +124,28 @@ async fn cancel_task(
     Ok(Json(service.cancel_task(&params.id).await?.into()))
 }
 
+#[utoipa::path(
+    delete,
+    path = "/v1/tasks/{id}",
+    tag = "tasks",
+    params(
+        ("id" = String, Path, description = "ID of the task to cancel")
+    ),
+    responses(
+        (status = 200, description = "Task cancelled", body = TaskResponse),
+        (status = 400, description = "Bad request"),
+        (status = 404, description = "Task not found"),
+        (status = 500, description = "Backend error"),
+    )
+)]
+async fn delete_task(
+    State(service): State<TaskApplicationService>,
+    Path(params): Path<IdPath>,
+) -> Result<Json<TaskResponse>, ServerError> {
+    let params = validate(params)?;
+    Ok(Json(service.cancel_task(&params.id).await?.into()))
+}
+
 #[utoipa::path(
     post,
     path = "/v1/tasks/{id}/restart",
@@ -210,6 +232,26 @@ mod tests {
         assert_eq!(response.body["status"], "cancelled");
     }
 
+    #[tokio::test]
+    async fn delete_task_cancels_active_task_and_reports_missing() {
+        let server = TestServer::new().await;
+        server
+            .store
+            .insert_task(task_record("task-delete", "image_generation", DomainTaskStatus::Running))
+            .await
+            .expect("seed task");
+
+        let response = server.delete("/v1/tasks/task-delete").await;
+        assert_eq!(response.status, StatusCode::OK);
+        assert_eq!(response.body["status"], "cancelled");
+
+        let response = server.get("/v1/tasks/task-delete").await;
+        assert_eq!(response.body["status"], "cancelled");
+
+        let response = server.delete("/v1/tasks/missing-task").await;
+        assert_eq!(response.status, StatusCode::NOT_FOUND);
+    }
+
     #[tokio::test]
     async fn restart_rejects_non_model_download_tasks() {
         let server = TestServer::new().await;
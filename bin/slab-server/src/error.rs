@@ -222,7 +222,7 @@ impl IntoResponse for ServerError {
                 message_i18n_with_detail(ServerI18nKey::ErrorBackendNotReady, m),
             ),
             ServerError::RuntimeFailure { message, data } => (
-                StatusCode::INTERNAL_SERVER_ERROR,
+                runtime_failure_status(data.runtime_code()),
                 error_codes::RUNTIME_ERROR,
                 Some(data.as_ref().clone()),
                 message.clone(),
@@ -367,6 +367,19 @@ fn runtime_client_message(error: &slab_runtime_core::CoreError) -> String {
     }
 }
 
+/// HTTP status for a structured runtime failure, keyed on its stable runtime code.
+fn runtime_failure_status(runtime_code: Option<&str>) -> StatusCode {
+    match runtime_code {
+        Some("runtime_model_not_loaded" | "runtime_library_not_loaded") => {
+            StatusCode::SERVICE_UNAVAILABLE
+        }
+        Some("runtime_preprocess_failed" | "runtime_invalid_request_payload") => {
+            StatusCode::BAD_REQUEST
+        }
+        _ => StatusCode::INTERNAL_SERVER_ERROR,
+    }
+}
+
 fn runtime_error_data(error: &slab_runtime_core::CoreError) -> AppCoreErrorData {
     AppCoreErrorData::runtime_failure(error.runtime_code(), error.runtime_detail())
 }
@@ -508,6 +521,29 @@ mod tests {
         assert_eq!(payload["data"]["detail"]["backend_id"], "ggml.llama");
     }
 
+    #[tokio::test]
+    async fn runtime_failure_status_follows_typed_runtime_code() {
+        for (runtime_code, status) in [
+            ("runtime_model_not_loaded", StatusCode::SERVICE_UNAVAILABLE),
+            ("runtime_library_not_loaded", StatusCode::SERVICE_UNAVAILABLE),
+            ("runtime_preprocess_failed", StatusCode::BAD_REQUEST),
+            ("runtime_invalid_request_payload", StatusCode::BAD_REQUEST),
+            ("runtime_backend_disabled", StatusCode::INTERNAL_SERVER_ERROR),
+            ("runtime_gpu_stage_failed", StatusCode::INTERNAL_SERVER_ERROR),
+        ] {
+            let response = ServerError::RuntimeFailure {
+                message: runtime_code.to_owned(),
+                data: Box::new(AppCoreErrorData::runtime_failure(
+                    runtime_code,
+                    serde_json::json!({}),
+                )),
+            }
+            .into_response();
+
+            assert_eq!(response.status(), status, "{runtime_code}");
+        }
+    }
+
     #[tokio::test]
     async fn runtime_engine_exhausted_response_uses_runtime_failure_envelope() {
         let response = ServerError::RuntimeFailure {
@@ -36,6 +36,11 @@ use slab_app_core::infra::runtime::{ManagedRuntimeHost, ManagedRuntimeHostStartO
 use slab_app_core::runtime_supervisor::RuntimeSupervisorStatus;
 use slab_otel::config::OtelExporter;
 
+/// How long the gateway waits for in-flight tasks before unloading models.
+const SHUTDOWN_DRAIN_TIMEOUT: Duration = Duration::from_secs(10);
+/// Extra time the supervisor grants the gateway beyond the drain window.
+const GATEWAY_SHUTDOWN_GRACE: Duration = Duration::from_secs(5);
+
 #[derive(Parser, Debug, Clone, Default)]
 #[command(name = "slab-server", version, about = "Slab supervisor and HTTP gateway")]
 struct SupervisorArgs {
@@ -456,6 +461,8 @@ where
     info!(%addr, "HTTP gateway listening");
     axum::serve(listener, app).with_graceful_shutdown(shutdown).await?;
 
+    state.services.model.drain_and_unload(SHUTDOWN_DRAIN_TIMEOUT).await;
+
     if let Err(e) = store.interrupt_running_tasks().await {
         warn!(
             error = %e,
@@ -547,7 +554,12 @@ async fn run_supervisor(args: SupervisorArgs, mut gateway_cfg: Config) -> anyhow
     }
 
     if !gateway_result_observed {
-        match tokio::time::timeout(Duration::from_secs(5), &mut gateway_join).await {
+        match tokio::time::timeout(
+            SHUTDOWN_DRAIN_TIMEOUT + GATEWAY_SHUTDOWN_GRACE,
+            &mut gateway_join,
+        )
+        .await
+        {
             Ok(gateway_res) => {
                 let gateway_outcome = map_gateway_join_result(gateway_res);
                 if result.is_ok() {
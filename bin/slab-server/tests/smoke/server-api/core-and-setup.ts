@@ -33,6 +33,12 @@ export function registerCoreAndSetupSmoke(
       expect(typeof health.body.version).toBe("string");
       expect(health.body.version?.length ?? 0).toBeGreaterThan(0);
 
+      const readiness = await expectJson<Schema["ReadinessResponse"]>(server, "/health/ready");
+      expect([200, 503]).toContain(readiness.response.status);
+      expect(readiness.body.status).toBe(readiness.response.ok ? "ready" : "not_ready");
+      expect(Array.isArray(readiness.body.backends)).toBe(true);
+      expect(Array.isArray(readiness.body.loaded_backends)).toBe(true);
+
       const openapi = await expectJson<OpenApiDocument>(server, "/api-docs/openapi.json");
       expect(openapi.response.ok).toBe(true);
       expect(openapi.body.openapi).toBeTypeOf("string");
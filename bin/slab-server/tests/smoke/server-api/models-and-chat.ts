@@ -112,6 +112,8 @@ export function registerModelsAndChatSmoke(
           { method: "POST" }
         )
       );
+      const chatSocketWithoutUpgrade = await expectError(server, "/v1/chat/ws", 400);
+      expect(chatSocketWithoutUpgrade.message).toContain("websocket upgrade");
       await expectOpenAiError(
         server,
         "/v1/completions",
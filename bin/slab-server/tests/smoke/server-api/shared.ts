@@ -59,21 +59,26 @@ const locallySeededOperationKeys = new Set(["POST /v1/tasks/{id}/restart"]);
 
 export const executableSmokeOperations = [
   { method: "get", path: "/health" },
+  { method: "get", path: "/health/ready" },
   { method: "get", path: "/v1/agents/responses" },
   { method: "post", path: "/v1/agents/responses" },
   { method: "get", path: "/v1/audio/transcriptions" },
   { method: "post", path: "/v1/audio/transcriptions" },
   { method: "get", path: "/v1/audio/transcriptions/{id}" },
+  { method: "post", path: "/v1/audio/transcriptions/batch" },
   { method: "get", path: "/v1/backends" },
   { method: "get", path: "/v1/backends/status" },
   { method: "get", path: "/v1/chat/models" },
   { method: "post", path: "/v1/chat/completions" },
+  { method: "get", path: "/v1/chat/ws" },
   { method: "post", path: "/v1/completions" },
   { method: "post", path: "/v1/ffmpeg/convert" },
+  { method: "post", path: "/v1/images/edits" },
   { method: "get", path: "/v1/images/generations" },
   { method: "post", path: "/v1/images/generations" },
   { method: "get", path: "/v1/images/generations/{id}" },
   { method: "get", path: "/v1/images/generations/{id}/artifacts/{index}" },
+  { method: "get", path: "/v1/images/generations/{id}/preview" },
   { method: "get", path: "/v1/images/generations/{id}/reference" },
   { method: "get", path: "/v1/models" },
   { method: "post", path: "/v1/models" },
@@ -115,6 +120,7 @@ export const executableSmokeOperations = [
   { method: "get", path: "/v1/system/diagnostics" },
   { method: "get", path: "/v1/system/gpu" },
   { method: "get", path: "/v1/tasks" },
+  { method: "delete", path: "/v1/tasks/{id}" },
   { method: "get", path: "/v1/tasks/{id}" },
   { method: "post", path: "/v1/tasks/{id}/cancel" },
   { method: "post", path: "/v1/tasks/{id}/restart" },
@@ -30,6 +30,7 @@ export function registerTasksAndMediaSmoke(getServer: () => SlabServerTestHarnes
       await expectError(server, "/v1/tasks/missing-task", 404);
       await expectError(server, "/v1/tasks/missing-task/result", 404);
       await expectError(server, "/v1/tasks/missing-task/cancel", 404, { method: "POST" });
+      await expectError(server, "/v1/tasks/missing-task", 404, { method: "DELETE" });
 
       const audioTasks = await expectJson<Schema["AudioTranscriptionTaskResponse"][]>(
         server,
@@ -38,6 +39,12 @@ export function registerTasksAndMediaSmoke(getServer: () => SlabServerTestHarnes
       expect(audioTasks.response.ok).toBe(true);
       expect(Array.isArray(audioTasks.body)).toBe(true);
       await expectError(server, "/v1/audio/transcriptions/missing-task", 404);
+      const emptyBatch = await server.requestFormData(
+        "/v1/audio/transcriptions/batch",
+        new FormData(),
+        { method: "POST" }
+      );
+      expect(emptyBatch.status).toBe(400);
       await expectError(
         server,
         "/v1/audio/transcriptions",
@@ -55,6 +62,7 @@ export function registerTasksAndMediaSmoke(getServer: () => SlabServerTestHarnes
       expect(Array.isArray(imageTasks.body)).toBe(true);
       await expectError(server, "/v1/images/generations/missing-task", 404);
       await expectError(server, "/v1/images/generations/missing-task/artifacts/0", 404);
+      await expectError(server, "/v1/images/generations/missing-task/preview", 404);
       await expectError(server, "/v1/images/generations/missing-task/reference", 404);
       await expectError(
         server,
@@ -70,6 +78,14 @@ export function registerTasksAndMediaSmoke(getServer: () => SlabServerTestHarnes
         )
       );
 
+      const editForm = new FormData();
+      editForm.set("model", "missing-model");
+      editForm.set("prompt", "smoke");
+      const imageEdit = await server.requestFormData("/v1/images/edits", editForm, {
+        method: "POST"
+      });
+      expect(imageEdit.status).toBe(400);
+
       const videoTasks = await expectJson<Schema["VideoGenerationTaskResponse"][]>(
         server,
         "/v1/video/generations"
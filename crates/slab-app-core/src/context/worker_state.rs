@@ -3,6 +3,7 @@
 use std::collections::HashMap;
 use std::future::Future;
 use std::sync::{Arc, Mutex};
+use std::time::Duration;
 
 use chrono::Utc;
 use uuid::Uuid;
@@ -15,6 +16,7 @@ use crate::infra::db::{TaskRecord, TaskStore};
 #[derive(Default)]
 pub struct OperationManager {
     handles: Mutex<HashMap<String, tokio::task::AbortHandle>>,
+    idle: tokio::sync::Notify,
 }
 
 impl std::fmt::Debug for OperationManager {
@@ -26,7 +28,7 @@ impl std::fmt::Debug for OperationManager {
 
 impl OperationManager {
     pub fn new() -> Self {
-        Self { handles: Mutex::new(HashMap::new()) }
+        Self { handles: Mutex::new(HashMap::new()), idle: tokio::sync::Notify::new() }
     }
 
     pub fn insert(&self, id: impl Into<String>, handle: tokio::task::AbortHandle) {
@@ -48,6 +50,9 @@ impl OperationManager {
             Ok(mut map) => {
                 if let Some(h) = map.remove(id) {
                     h.abort();
+                    if map.is_empty() {
+                        self.idle.notify_waiters();
+                    }
                     return true;
                 }
             }
@@ -60,10 +65,40 @@ impl OperationManager {
         match self.handles.lock() {
             Ok(mut map) => {
                 map.remove(id);
+                if map.is_empty() {
+                    self.idle.notify_waiters();
+                }
             }
             Err(e) => tracing::warn!(error = %e, "OperationManager mutex poisoned on remove"),
         }
     }
+
+    pub fn len(&self) -> usize {
+        self.handles.lock().map(|map| map.len()).unwrap_or(0)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Wait until every tracked operation has finished or `timeout` elapses.
+    ///
+    /// Returns `true` when the manager drained in time.
+    pub async fn wait_idle(&self, timeout: Duration) -> bool {
+        tokio::time::timeout(timeout, async {
+            loop {
+                let notified = self.idle.notified();
+                tokio::pin!(notified);
+                notified.as_mut().enable();
+                if self.is_empty() {
+                    return;
+                }
+                notified.await;
+            }
+        })
+        .await
+        .is_ok()
+    }
 }
 
 #[derive(Debug, Clone)]
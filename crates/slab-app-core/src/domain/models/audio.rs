@@ -1,3 +1,5 @@
+use std::path::PathBuf;
+
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -11,6 +13,29 @@ pub struct AudioTranscriptionCommand {
     pub decode: Option<TranscribeDecodeOptions>,
 }
 
+/// One uploaded audio file in a batch transcription request, already staged
+/// on disk.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AudioUpload {
+    pub filename: String,
+    pub path: PathBuf,
+    pub size: usize,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct AudioTranscriptionBatchCommand {
+    pub model_id: Option<String>,
+    pub language: Option<String>,
+    pub prompt: Option<String>,
+    pub files: Vec<AudioUpload>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AcceptedAudioUpload {
+    pub filename: String,
+    pub operation_id: String,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TranscribeVadOptions {
     pub enabled: bool,
@@ -41,4 +66,5 @@ pub struct TranscribeDecodeOptions {
     pub logprob_thold: Option<f32>,
     pub no_speech_thold: Option<f32>,
     pub tdrz_enable: Option<bool>,
+    pub translate: Option<bool>,
 }
@@ -10,3 +10,13 @@ pub struct BackendStatusView {
     pub backend: String,
     pub status: String,
 }
+
+/// Readiness of every enabled runtime backend.
+#[derive(Debug, Clone)]
+pub struct BackendReadinessView {
+    /// Whether all enabled backends are ready and at least one model is loaded.
+    pub ready: bool,
+    pub backends: Vec<BackendStatusView>,
+    /// Backends that currently hold a loaded model.
+    pub loaded_backends: Vec<String>,
+}
@@ -53,7 +53,33 @@ pub struct TextGenerationChunk {
     pub metadata: JsonOptions,
 }
 
-pub type ChatStreamChunk = String;
+/// Typed content of one chat stream chunk.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ChatStreamEvent {
+    Role,
+    Content(String),
+    Reasoning(String),
+    ToolCalls(Vec<ConversationToolCall>),
+    Finish(String),
+    Usage(TextGenerationUsage),
+    Error(String),
+    Done,
+}
+
+/// One chat stream chunk: its typed event plus the OpenAI-compatible SSE
+/// `data` payload it is sent as over HTTP.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChatStreamChunk {
+    pub event: ChatStreamEvent,
+    pub data: String,
+}
+
+impl ChatStreamChunk {
+    /// The `[DONE]` sentinel that terminates every stream.
+    pub fn done() -> Self {
+        Self { event: ChatStreamEvent::Done, data: "[DONE]".to_owned() }
+    }
+}
 
 pub enum ChatCompletionOutput {
     Json(ChatCompletionResult),
@@ -86,17 +112,36 @@ pub struct CommonChatParams {
     pub top_k: Option<i32>,
     pub min_p: Option<f32>,
     pub presence_penalty: Option<f32>,
+    pub frequency_penalty: Option<f32>,
     pub repetition_penalty: Option<f32>,
+    pub repeat_last_n: Option<i32>,
+    pub n_keep: Option<u32>,
+    pub seed: Option<u32>,
     pub n: u32,
     pub stream: bool,
     pub stop: Vec<String>,
     pub stream_options: ChatStreamOptions,
 }
 
+/// How a local model may call the request's tools under the tool-call grammar.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LocalToolChoice {
+    /// Answer in plain text or call one tool.
+    Auto,
+    /// Call one of the tools.
+    Required,
+    /// Call the named tool.
+    Function(String),
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct LocalChatParams {
     pub gbnf: Option<String>,
     pub structured_output: Option<StructuredOutput>,
+    /// Tools declared by the request. They are only offered to local models,
+    /// which are grammar-constrained to call them and return `tool_calls`.
+    pub tools: Vec<FunctionTool>,
+    pub tool_choice: Option<LocalToolChoice>,
 }
 
 #[derive(Debug, Clone, Default)]
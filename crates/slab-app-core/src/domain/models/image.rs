@@ -34,4 +34,5 @@ pub struct ImageGenerationCommand {
     pub strength: Option<f32>,
     pub init_image: Option<DecodedImageInput>,
     pub mode: ImageGenerationMode,
+    pub preview_interval: Option<u32>,
 }
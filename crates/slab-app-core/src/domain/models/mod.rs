@@ -17,16 +17,19 @@ mod ui_state;
 mod video;
 mod workspace;
 
-pub use audio::{AudioTranscriptionCommand, TranscribeDecodeOptions, TranscribeVadOptions};
-pub use backend::{BackendStatusQuery, BackendStatusView};
+pub use audio::{
+    AcceptedAudioUpload, AudioTranscriptionBatchCommand, AudioTranscriptionCommand, AudioUpload,
+    TranscribeDecodeOptions, TranscribeVadOptions,
+};
+pub use backend::{BackendReadinessView, BackendStatusQuery, BackendStatusView};
 #[allow(unused_imports)]
 pub use chat::StructuredOutputJsonSchema;
 pub use chat::{
     ChatCompletionCommand, ChatCompletionOutput, ChatCompletionResult, ChatModelCapabilities,
     ChatModelOption, ChatModelSource, ChatReasoningEffort, ChatResultChoice, ChatStreamChunk,
-    ChatStreamOptions, ChatVerbosity, CloudChatParams, CommonChatParams, ConversationContentPart,
-    ConversationMessage, ConversationMessageContent, ConversationToolCall,
-    ConversationToolFunction, JsonOptions, LocalChatParams, StructuredOutput,
+    ChatStreamEvent, ChatStreamOptions, ChatVerbosity, CloudChatParams, CommonChatParams,
+    ConversationContentPart, ConversationMessage, ConversationMessageContent, ConversationToolCall,
+    ConversationToolFunction, JsonOptions, LocalChatParams, LocalToolChoice, StructuredOutput,
     TextCompletionCommand, TextCompletionOutput, TextCompletionResult, TextGenerationChunk,
     TextGenerationResponse, TextGenerationUsage, TextPromptTokensDetails, TextResultChoice,
     assistant_message_from_parts, assistant_message_from_text_response,
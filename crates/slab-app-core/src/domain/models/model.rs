@@ -597,6 +597,7 @@ pub struct ModelStatus {
     pub status: String,
     pub context_length: Option<u32>,
     pub training_context_length: Option<u32>,
+    pub version: Option<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -127,6 +127,7 @@ pub struct TimedTextSegment {
 pub struct TaskResult {
     pub image: Option<String>,
     pub images: Option<Vec<String>>,
+    pub seeds: Option<Vec<i64>>,
     pub video_path: Option<String>,
     pub output_path: Option<String>,
     pub text: Option<String>,
@@ -1,11 +1,11 @@
 mod runtime;
 
 pub use runtime::{
-    RuntimeBackendStatus, RuntimeDiffusionImageRequest, RuntimeDiffusionImageResult,
-    RuntimeDiffusionVideoRequest, RuntimeDiffusionVideoResult, RuntimeGeneratedFrame,
-    RuntimeGeneratedImage, RuntimeInferenceGateway, RuntimeJsonOptions, RuntimeRawImageInput,
-    RuntimeTextGenerationChunk, RuntimeTextGenerationRequest, RuntimeTextGenerationResponse,
-    RuntimeTextGenerationUsage, RuntimeTextPromptTokensDetails, RuntimeTranscriptionDecodeOptions,
-    RuntimeTranscriptionRequest, RuntimeTranscriptionResult, RuntimeTranscriptionVadOptions,
-    RuntimeTranscriptionVadParams,
+    RuntimeBackendStatus, RuntimeDiffusionImageChunk, RuntimeDiffusionImageRequest,
+    RuntimeDiffusionImageResult, RuntimeDiffusionVideoRequest, RuntimeDiffusionVideoResult,
+    RuntimeGeneratedFrame, RuntimeGeneratedImage, RuntimeInferenceGateway, RuntimeJsonOptions,
+    RuntimeRawImageInput, RuntimeTextGenerationChunk, RuntimeTextGenerationRequest,
+    RuntimeTextGenerationResponse, RuntimeTextGenerationUsage, RuntimeTextPromptTokensDetails,
+    RuntimeTranscriptionDecodeOptions, RuntimeTranscriptionRequest, RuntimeTranscriptionResult,
+    RuntimeTranscriptionVadOptions, RuntimeTranscriptionVadParams,
 };
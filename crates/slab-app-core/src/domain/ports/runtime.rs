@@ -37,7 +37,11 @@ pub struct RuntimeTextGenerationRequest {
     pub top_k: Option<i32>,
     pub min_p: Option<f32>,
     pub presence_penalty: Option<f32>,
+    pub frequency_penalty: Option<f32>,
     pub repetition_penalty: Option<f32>,
+    pub repeat_last_n: Option<i32>,
+    pub n_keep: Option<u32>,
+    pub seed: Option<u32>,
     pub session_key: Option<String>,
     pub stream: bool,
     pub gbnf: Option<String>,
@@ -77,6 +81,8 @@ pub struct RuntimeGeneratedImage {
     pub width: u32,
     pub height: u32,
     pub channels: u8,
+    /// Seed the backend actually sampled this image with, when reported.
+    pub seed: Option<i64>,
 }
 
 #[derive(Debug, Clone, Default, PartialEq, Eq)]
@@ -106,6 +112,7 @@ pub struct RuntimeDiffusionImageRequest {
     pub clip_skip: Option<i32>,
     pub strength: Option<f32>,
     pub eta: Option<f32>,
+    pub preview_interval: Option<u32>,
 }
 
 #[derive(Debug, Clone, Default, PartialEq)]
@@ -114,6 +121,16 @@ pub struct RuntimeDiffusionImageResult {
     pub metadata: RuntimeJsonOptions,
 }
 
+/// One event of a streamed image generation: step progress, a PNG-encoded
+/// low-resolution preview, or the final `result`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RuntimeDiffusionImageChunk {
+    pub step: Option<i32>,
+    pub total_steps: Option<i32>,
+    pub preview_png: Option<Vec<u8>>,
+    pub result: Option<RuntimeDiffusionImageResult>,
+}
+
 #[derive(Debug, Clone, Default, PartialEq)]
 pub struct RuntimeDiffusionVideoRequest {
     pub model: String,
@@ -174,6 +191,7 @@ pub struct RuntimeTranscriptionDecodeOptions {
     pub logprob_thold: Option<f32>,
     pub no_speech_thold: Option<f32>,
     pub tdrz_enable: Option<bool>,
+    pub translate: Option<bool>,
 }
 
 #[derive(Debug, Clone, Default, PartialEq)]
@@ -199,6 +217,7 @@ pub struct RuntimeBackendStatus {
     pub status: String,
     pub context_length: Option<u32>,
     pub training_context_length: Option<u32>,
+    pub version: Option<String>,
 }
 
 /// Domain port for model runtime inference.
@@ -229,6 +248,11 @@ pub trait RuntimeInferenceGateway: Send + Sync + std::fmt::Debug {
         request: RuntimeDiffusionImageRequest,
     ) -> Result<RuntimeDiffusionImageResult, AppCoreError>;
 
+    async fn generate_image_stream(
+        &self,
+        request: RuntimeDiffusionImageRequest,
+    ) -> Result<BoxStream<'static, Result<RuntimeDiffusionImageChunk, AppCoreError>>, AppCoreError>;
+
     async fn generate_video(
         &self,
         request: RuntimeDiffusionVideoRequest,
@@ -1,14 +1,17 @@
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 use slab_types::RuntimeBackendId;
+use tokio::io::AsyncWriteExt;
 use tracing::{debug, warn};
 use uuid::Uuid;
 
 use crate::context::WorkerState;
 use crate::domain::models::{
-    AUDIO_TRANSCRIPTION_TASK_TYPE, AcceptedOperation, AudioTranscriptionCommand,
-    AudioTranscriptionRequestData, AudioTranscriptionResultData, AudioTranscriptionTaskView,
-    TaskResult, TaskStatus, TranscribeDecodeOptions, TranscribeVadOptions,
+    AUDIO_TRANSCRIPTION_TASK_TYPE, AcceptedAudioUpload, AcceptedOperation,
+    AudioTranscriptionBatchCommand, AudioTranscriptionCommand, AudioTranscriptionRequestData,
+    AudioTranscriptionResultData, AudioTranscriptionTaskView, AudioUpload, TaskResult, TaskStatus,
+    TranscribeDecodeOptions, TranscribeVadOptions,
 };
 use crate::domain::ports::{
     RuntimeTranscriptionDecodeOptions, RuntimeTranscriptionRequest, RuntimeTranscriptionVadOptions,
@@ -24,6 +27,82 @@ use crate::infra::db::{
 };
 
 const DEFAULT_AUDIO_BACKEND_ID: RuntimeBackendId = RuntimeBackendId::GgmlWhisper;
+/// Largest single file accepted by batch transcription uploads.
+pub const MAX_AUDIO_UPLOAD_FILE_BYTES: usize = 256 * 1024 * 1024;
+/// Largest combined size of all files in one batch transcription upload.
+pub const MAX_AUDIO_UPLOAD_BATCH_BYTES: usize = 1024 * 1024 * 1024;
+
+/// Streams one uploaded file into the staging directory.
+///
+/// The per-file limit is enforced as chunks arrive. Dropping the writer before
+/// [`AudioUploadWriter::finish`] deletes the partial file.
+pub struct AudioUploadWriter {
+    filename: String,
+    path: PathBuf,
+    file: Option<tokio::fs::File>,
+    size: usize,
+    finished: bool,
+}
+
+impl AudioUploadWriter {
+    pub async fn write(&mut self, chunk: &[u8]) -> Result<(), AppCoreError> {
+        self.size = self.size.saturating_add(chunk.len());
+        if self.size > MAX_AUDIO_UPLOAD_FILE_BYTES {
+            return Err(file_too_large(&self.filename, self.size));
+        }
+        let Some(file) = self.file.as_mut() else {
+            return Err(AppCoreError::Internal("audio upload writer is closed".into()));
+        };
+        file.write_all(chunk).await.map_err(|error| self.store_error(error))
+    }
+
+    pub async fn finish(mut self) -> Result<AudioUpload, AppCoreError> {
+        if let Some(mut file) = self.file.take() {
+            file.flush().await.map_err(|error| self.store_error(error))?;
+        }
+        self.finished = true;
+        Ok(AudioUpload {
+            filename: std::mem::take(&mut self.filename),
+            path: std::mem::take(&mut self.path),
+            size: self.size,
+        })
+    }
+
+    fn store_error(&self, error: std::io::Error) -> AppCoreError {
+        AppCoreError::Internal(format!(
+            "failed to store uploaded audio '{}': {error}",
+            self.filename
+        ))
+    }
+}
+
+impl Drop for AudioUploadWriter {
+    fn drop(&mut self) {
+        if !self.finished {
+            drop(self.file.take());
+            let _ = std::fs::remove_file(&self.path);
+        }
+    }
+}
+
+/// A staged upload owned by its transcription task; the file is removed when
+/// the task is done with it.
+struct StagedUpload(PathBuf);
+
+impl Drop for StagedUpload {
+    fn drop(&mut self) {
+        if let Err(error) = std::fs::remove_file(&self.0) {
+            warn!(path = %self.0.display(), error = %error, "failed to remove staged audio upload");
+        }
+    }
+}
+
+/// A recorded transcription task that is ready to be spawned.
+struct PreparedTranscription {
+    operation_id: String,
+    backend_id: RuntimeBackendId,
+    runtime_request: RuntimeTranscriptionRequest,
+}
 
 #[derive(Clone)]
 pub struct AudioService {
@@ -41,90 +120,116 @@ impl AudioService {
     ) -> Result<AcceptedOperation, AppCoreError> {
         let vad = build_vad_request(req.vad.as_ref())?;
         let decode = build_decode_request(req.decode.as_ref())?;
-        let backend_id = model::resolve_worker_model_backend_or_default(
-            &self.state,
-            req.model_id.as_deref(),
-            DEFAULT_AUDIO_BACKEND_ID,
-        )
-        .await?;
-        ensure_audio_backend(backend_id)?;
-        let vad_enabled = vad.is_some();
-        let decode_configured = decode.is_some();
+        let backend_id = self.resolve_audio_backend(req.model_id.as_deref()).await?;
         debug!(
             file_path = %req.path,
-            vad_enabled,
-            decode_configured,
+            vad_enabled = vad.is_some(),
+            decode_configured = decode.is_some(),
             "transcription request"
         );
 
-        if !self.state.runtime().backend_available(backend_id) {
-            return Err(AppCoreError::BackendNotReady(format!(
-                "{} gRPC endpoint is not configured",
-                backend_id.canonical_id()
-            )));
+        let (prepared, task, audio_task) =
+            prepare_transcription(&req, &req.path, backend_id, vad, decode)?;
+        self.state.store().insert_audio_transcription_operation(task, audio_task).await?;
+
+        let operation_id = prepared.operation_id.clone();
+        self.spawn_transcription(prepared, None);
+        Ok(AcceptedOperation { operation_id })
+    }
+
+    /// Create a staging file for one uploaded audio file.
+    pub async fn stage_upload(&self, filename: String) -> Result<AudioUploadWriter, AppCoreError> {
+        let upload_dir = std::env::temp_dir().join("slab-audio-uploads");
+        tokio::fs::create_dir_all(&upload_dir).await.map_err(|error| {
+            AppCoreError::Internal(format!("failed to create audio upload directory: {error}"))
+        })?;
+        let path = upload_path(&upload_dir, &filename);
+        let file = tokio::fs::File::create(&path).await.map_err(|error| {
+            AppCoreError::Internal(format!("failed to store uploaded audio '{filename}': {error}"))
+        })?;
+        Ok(AudioUploadWriter { filename, path, file: Some(file), size: 0, finished: false })
+    }
+
+    /// Delete staged uploads that will not be transcribed.
+    pub async fn discard_uploads(&self, files: &[AudioUpload]) {
+        for file in files {
+            if let Err(error) = tokio::fs::remove_file(&file.path).await {
+                warn!(path = %file.path.display(), error = %error, "failed to remove staged audio upload");
+            }
         }
+    }
 
-        let runtime_request = RuntimeTranscriptionRequest {
-            backend_id: Some(backend_id),
-            path: req.path.clone(),
-            language: req.language.clone(),
-            prompt: req.prompt.clone(),
-            detect_language: req.detect_language,
-            vad,
-            decode,
+    /// Start one transcription task per staged upload.
+    ///
+    /// Limits and backend readiness are checked and every task is recorded in
+    /// one transaction before any of them starts, so a rejected batch never
+    /// leaves partial tasks behind. Staged files are deleted when the batch is
+    /// rejected or, otherwise, once their task ends.
+    pub async fn transcribe_uploads(
+        &self,
+        req: AudioTranscriptionBatchCommand,
+    ) -> Result<Vec<AcceptedAudioUpload>, AppCoreError> {
+        let prepared = match self.record_uploads(&req).await {
+            Ok(prepared) => prepared,
+            Err(error) => {
+                self.discard_uploads(&req.files).await;
+                return Err(error);
+            }
         };
 
-        let request_payload = AudioTranscriptionRequestData {
-            model_id: req.model_id.clone(),
-            source_path: req.path.clone(),
-            language: req.language.clone(),
-            prompt: req.prompt.clone(),
-            detect_language: req.detect_language,
-            vad: req.vad.clone(),
-            decode: req.decode.clone(),
-        };
-        let request_data = serialize_json_payload(&request_payload)?;
+        let accepted = req
+            .files
+            .into_iter()
+            .zip(prepared)
+            .map(|(file, prepared)| {
+                let operation_id = prepared.operation_id.clone();
+                self.spawn_transcription(prepared, Some(StagedUpload(file.path)));
+                AcceptedAudioUpload { filename: file.filename, operation_id }
+            })
+            .collect();
+        Ok(accepted)
+    }
 
-        let operation_id = Uuid::new_v4().to_string();
-        let now = chrono::Utc::now();
-        self.state
-            .store()
-            .insert_audio_transcription_operation(
-                TaskRecord {
-                    id: operation_id.clone(),
-                    task_type: AUDIO_TRANSCRIPTION_TASK_TYPE.to_owned(),
-                    status: TaskStatus::Running,
-                    model_id: req.model_id.clone(),
-                    input_data: Some(request_data.clone()),
-                    result_data: None,
-                    error_msg: None,
-                    core_task_id: None,
-                    created_at: now,
-                    updated_at: now,
-                },
-                NewAudioTranscriptionTaskRecord {
-                    task_id: operation_id.clone(),
-                    backend_id: backend_id.canonical_id().to_owned(),
-                    model_id: req.model_id.clone(),
-                    source_path: req.path.clone(),
-                    language: req.language.clone(),
-                    prompt: req.prompt.clone(),
-                    detect_language: req.detect_language,
-                    vad_json: req.vad.as_ref().map(to_json_string),
-                    decode_json: req.decode.as_ref().map(to_json_string),
-                    request_data,
-                    created_at: now,
-                    updated_at: now,
-                },
-            )
-            .await?;
+    async fn record_uploads(
+        &self,
+        req: &AudioTranscriptionBatchCommand,
+    ) -> Result<Vec<PreparedTranscription>, AppCoreError> {
+        validate_audio_uploads(&req.files)?;
+        let backend_id = self.resolve_audio_backend(req.model_id.as_deref()).await?;
+
+        let mut prepared = Vec::with_capacity(req.files.len());
+        let mut records = Vec::with_capacity(req.files.len());
+        for file in &req.files {
+            let command = AudioTranscriptionCommand {
+                model_id: req.model_id.clone(),
+                path: file.path.to_string_lossy().into_owned(),
+                language: req.language.clone(),
+                prompt: req.prompt.clone(),
+                detect_language: None,
+                vad: None,
+                decode: None,
+            };
+            // The staged copy is deleted once the task ends, so the record keeps
+            // the client filename instead of a path that no longer exists.
+            let (transcription, task, audio_task) =
+                prepare_transcription(&command, &file.filename, backend_id, None, None)?;
+            prepared.push(transcription);
+            records.push((task, audio_task));
+        }
+        self.state.store().insert_audio_transcription_operations(records).await?;
+        Ok(prepared)
+    }
 
+    fn spawn_transcription(&self, prepared: PreparedTranscription, staged: Option<StagedUpload>) {
+        let PreparedTranscription { operation_id, backend_id, runtime_request } = prepared;
         let model_auto_unload = Arc::clone(self.state.auto_unload());
         let store = Arc::clone(self.state.store());
         let worker_state = self.state.clone();
         self.state
             .clone()
-            .spawn_existing_operation(operation_id.clone(), move |operation| async move {
+            .spawn_existing_operation(operation_id, move |operation| async move {
+                // Dropped when the task ends or is aborted by cancellation.
+                let _staged = staged;
                 let operation_id = operation.id().to_owned();
                 let _usage_guard = match model_auto_unload.acquire_for_inference(backend_id).await {
                     Ok(guard) => guard,
@@ -149,6 +254,7 @@ impl AudioService {
                         let task_payload = serde_json::to_string(&TaskResult {
                             image: None,
                             images: None,
+                            seeds: None,
                             video_path: None,
                             output_path: None,
                             text: Some(text.clone()),
@@ -178,8 +284,26 @@ impl AudioService {
                     }
                 }
             });
+    }
 
-        Ok(AcceptedOperation { operation_id })
+    async fn resolve_audio_backend(
+        &self,
+        model_id: Option<&str>,
+    ) -> Result<RuntimeBackendId, AppCoreError> {
+        let backend_id = model::resolve_worker_model_backend_or_default(
+            &self.state,
+            model_id,
+            DEFAULT_AUDIO_BACKEND_ID,
+        )
+        .await?;
+        ensure_audio_backend(backend_id)?;
+        if !self.state.runtime().backend_available(backend_id) {
+            return Err(AppCoreError::BackendNotReady(format!(
+                "{} gRPC endpoint is not configured",
+                backend_id.canonical_id()
+            )));
+        }
+        Ok(backend_id)
     }
 
     pub async fn list_transcription_tasks(
@@ -201,6 +325,114 @@ impl AudioService {
     }
 }
 
+/// Build the task records and runtime request for one transcription.
+///
+/// `source_path` is what gets persisted for the task; the runtime always reads
+/// `req.path`.
+fn prepare_transcription(
+    req: &AudioTranscriptionCommand,
+    source_path: &str,
+    backend_id: RuntimeBackendId,
+    vad: Option<RuntimeTranscriptionVadOptions>,
+    decode: Option<RuntimeTranscriptionDecodeOptions>,
+) -> Result<(PreparedTranscription, TaskRecord, NewAudioTranscriptionTaskRecord), AppCoreError> {
+    let request_payload = AudioTranscriptionRequestData {
+        model_id: req.model_id.clone(),
+        source_path: source_path.to_owned(),
+        language: req.language.clone(),
+        prompt: req.prompt.clone(),
+        detect_language: req.detect_language,
+        vad: req.vad.clone(),
+        decode: req.decode.clone(),
+    };
+    let request_data = serialize_json_payload(&request_payload)?;
+
+    let operation_id = Uuid::new_v4().to_string();
+    let now = chrono::Utc::now();
+    let task = TaskRecord {
+        id: operation_id.clone(),
+        task_type: AUDIO_TRANSCRIPTION_TASK_TYPE.to_owned(),
+        status: TaskStatus::Running,
+        model_id: req.model_id.clone(),
+        input_data: Some(request_data.clone()),
+        result_data: None,
+        error_msg: None,
+        core_task_id: None,
+        created_at: now,
+        updated_at: now,
+    };
+    let audio_task = NewAudioTranscriptionTaskRecord {
+        task_id: operation_id.clone(),
+        backend_id: backend_id.canonical_id().to_owned(),
+        model_id: req.model_id.clone(),
+        source_path: source_path.to_owned(),
+        language: req.language.clone(),
+        prompt: req.prompt.clone(),
+        detect_language: req.detect_language,
+        vad_json: req.vad.as_ref().map(to_json_string),
+        decode_json: req.decode.as_ref().map(to_json_string),
+        request_data,
+        created_at: now,
+        updated_at: now,
+    };
+    let runtime_request = RuntimeTranscriptionRequest {
+        backend_id: Some(backend_id),
+        path: req.path.clone(),
+        language: req.language.clone(),
+        prompt: req.prompt.clone(),
+        detect_language: req.detect_language,
+        vad,
+        decode,
+    };
+
+    Ok((PreparedTranscription { operation_id, backend_id, runtime_request }, task, audio_task))
+}
+
+fn validate_audio_uploads(files: &[AudioUpload]) -> Result<(), AppCoreError> {
+    if files.is_empty() {
+        return Err(AppCoreError::BadRequest("at least one audio file is required".into()));
+    }
+
+    let mut total = 0usize;
+    for file in files {
+        if file.size == 0 {
+            return Err(AppCoreError::BadRequest(format!(
+                "uploaded audio '{}' is empty",
+                file.filename
+            )));
+        }
+        if file.size > MAX_AUDIO_UPLOAD_FILE_BYTES {
+            return Err(file_too_large(&file.filename, file.size));
+        }
+        total = total.saturating_add(file.size);
+    }
+    if total > MAX_AUDIO_UPLOAD_BATCH_BYTES {
+        return Err(AppCoreError::BadRequest(format!(
+            "uploaded audio batch is too large ({total} bytes); maximum size is {MAX_AUDIO_UPLOAD_BATCH_BYTES} bytes"
+        )));
+    }
+    Ok(())
+}
+
+fn file_too_large(filename: &str, size: usize) -> AppCoreError {
+    AppCoreError::BadRequest(format!(
+        "uploaded audio '{filename}' is too large ({size} bytes); maximum size is {MAX_AUDIO_UPLOAD_FILE_BYTES} bytes"
+    ))
+}
+
+/// Unique path for an uploaded file that keeps its extension for ffmpeg probing
+/// and never escapes `dir`.
+fn upload_path(dir: &Path, filename: &str) -> PathBuf {
+    let base = Path::new(filename).file_name().and_then(|name| name.to_str()).unwrap_or("audio");
+    let sanitized: String = base
+        .chars()
+        .map(
+            |ch| if ch.is_ascii_alphanumeric() || matches!(ch, '.' | '-' | '_') { ch } else { '_' },
+        )
+        .collect();
+    dir.join(format!("{}-{sanitized}", Uuid::new_v4()))
+}
+
 fn ensure_audio_backend(backend_id: RuntimeBackendId) -> Result<(), AppCoreError> {
     match backend_id {
         RuntimeBackendId::GgmlWhisper | RuntimeBackendId::CandleWhisper => Ok(()),
@@ -273,7 +505,8 @@ fn build_decode_request(
         || decode.entropy_thold.is_some()
         || decode.logprob_thold.is_some()
         || decode.no_speech_thold.is_some()
-        || decode.tdrz_enable.is_some();
+        || decode.tdrz_enable.is_some()
+        || decode.translate.is_some();
 
     if !has_values {
         return Ok(None);
@@ -296,6 +529,7 @@ fn build_decode_request(
         logprob_thold: decode.logprob_thold,
         no_speech_thold: decode.no_speech_thold,
         tdrz_enable: decode.tdrz_enable,
+        translate: decode.translate,
     }))
 }
 
@@ -333,7 +567,15 @@ fn to_json_string<T: serde::Serialize>(value: &T) -> String {
 
 #[cfg(test)]
 mod tests {
-    use super::parse_result_segments;
+    use super::{
+        AudioService, AudioUpload, MAX_AUDIO_UPLOAD_FILE_BYTES, parse_result_segments, upload_path,
+        validate_audio_uploads,
+    };
+    use crate::domain::models::AudioTranscriptionBatchCommand;
+    use crate::infra::db::MediaTaskStore;
+    use crate::test_support::TestAppCore;
+    use slab_types::RuntimeBackendId;
+    use std::path::Path;
 
     #[test]
     fn parses_segments_from_task_result_payload() {
@@ -345,4 +587,70 @@ mod tests {
         assert_eq!(segments.len(), 1);
         assert_eq!(segments[0].text.as_deref(), Some("hello"));
     }
+
+    #[test]
+    fn validate_audio_uploads_enforces_presence_and_size() {
+        let clip = |name: &str, size: usize| AudioUpload {
+            filename: name.into(),
+            path: Path::new("/tmp").join(name),
+            size,
+        };
+
+        assert!(validate_audio_uploads(&[]).is_err());
+        assert!(validate_audio_uploads(&[clip("a.wav", 0)]).is_err());
+        assert!(validate_audio_uploads(&[clip("a.wav", 16), clip("b.mp3", 16)]).is_ok());
+
+        let oversized = clip("big.wav", MAX_AUDIO_UPLOAD_FILE_BYTES + 1);
+        let error = validate_audio_uploads(&[oversized]).expect_err("oversized file rejected");
+        assert!(error.to_string().contains("too large"));
+    }
+
+    #[test]
+    fn upload_path_stays_in_dir_and_keeps_extension() {
+        let dir = Path::new("/tmp/uploads");
+        let path = upload_path(dir, "../../etc/My Clip #1.mp3");
+
+        assert_eq!(path.parent(), Some(dir));
+        let name = path.file_name().and_then(|name| name.to_str()).expect("file name");
+        assert!(name.ends_with("-My_Clip__1.mp3"), "unexpected name: {name}");
+    }
+
+    #[tokio::test]
+    async fn transcribe_uploads_records_every_clip_under_its_filename() {
+        let core = TestAppCore::new().await;
+        core.runtime.allow_backend(RuntimeBackendId::GgmlWhisper);
+        let service = AudioService::new(core.worker_state.clone());
+
+        let mut files = Vec::new();
+        for name in ["a.wav", "b.mp3"] {
+            let mut writer = service.stage_upload(name.to_owned()).await.expect("stage upload");
+            writer.write(b"RIFF").await.expect("write upload");
+            files.push(writer.finish().await.expect("finish upload"));
+        }
+
+        let accepted = service
+            .transcribe_uploads(AudioTranscriptionBatchCommand {
+                model_id: None,
+                language: None,
+                prompt: None,
+                files,
+            })
+            .await
+            .expect("batch accepted");
+
+        assert_eq!(accepted.len(), 2);
+        assert_eq!(accepted[0].filename, "a.wav");
+        assert_eq!(accepted[1].filename, "b.mp3");
+        assert_ne!(accepted[0].operation_id, accepted[1].operation_id);
+
+        for (upload, filename) in accepted.iter().zip(["a.wav", "b.mp3"]) {
+            let row = core
+                .store
+                .get_audio_transcription_task(&upload.operation_id)
+                .await
+                .expect("load task")
+                .expect("task recorded");
+            assert_eq!(row.task.source_path, filename);
+        }
+    }
 }
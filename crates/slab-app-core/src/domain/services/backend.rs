@@ -1,7 +1,7 @@
 use slab_types::RuntimeBackendId;
 
 use crate::context::ModelState;
-use crate::domain::models::{BackendStatusQuery, BackendStatusView};
+use crate::domain::models::{BackendReadinessView, BackendStatusQuery, BackendStatusView};
 use crate::error::AppCoreError;
 use crate::runtime_supervisor::RuntimeBackendRuntimeStatus;
 
@@ -42,6 +42,37 @@ impl BackendService {
             .collect();
         Ok(backends)
     }
+
+    /// Report whether every enabled backend is ready and a model is loaded.
+    ///
+    /// Disabled backends are skipped. A server with healthy runtimes but no
+    /// loaded model cannot serve inference yet, so it is not ready.
+    pub async fn readiness(&self) -> Result<BackendReadinessView, AppCoreError> {
+        self.model_state.auto_unload().sync_runtime_restart_states().await;
+        let statuses: Vec<_> = RuntimeBackendId::ALL
+            .into_iter()
+            .map(|name| (name, self.model_state.runtime_status().status(name)))
+            .filter(|(_, status)| *status != RuntimeBackendRuntimeStatus::Disabled)
+            .collect();
+        let loaded_backends: Vec<String> = self
+            .model_state
+            .auto_unload()
+            .resident_backends()
+            .await
+            .into_iter()
+            .map(|backend_id| backend_id.to_string())
+            .collect();
+        let ready = !loaded_backends.is_empty()
+            && statuses.iter().all(|(_, status)| *status == RuntimeBackendRuntimeStatus::Ready);
+        let backends = statuses
+            .into_iter()
+            .map(|(name, status)| BackendStatusView {
+                backend: name.to_string(),
+                status: runtime_status_label(status).to_owned(),
+            })
+            .collect();
+        Ok(BackendReadinessView { ready, backends, loaded_backends })
+    }
 }
 
 fn runtime_status_label(status: RuntimeBackendRuntimeStatus) -> &'static str {
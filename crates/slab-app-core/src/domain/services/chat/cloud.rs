@@ -208,7 +208,7 @@ pub(super) async fn create_chat_completion(
             .chain(token_stream)
             .chain(finish_chunk.filter_map(futures::future::ready))
             .chain(usage_chunk.filter_map(futures::future::ready))
-            .chain(stream::once(async { "[DONE]".to_owned() }));
+            .chain(stream::once(async { ChatStreamChunk::done() }));
 
         return Ok(GeneratedChatOutput::Stream(Box::pin(sse_stream)));
     }
@@ -1,6 +1,6 @@
 use serde_json::{Map, Value};
 
-use crate::domain::models::{StructuredOutput, StructuredOutputJsonSchema};
+use crate::domain::models::{LocalToolChoice, StructuredOutput, StructuredOutputJsonSchema};
 use crate::error::AppCoreError;
 
 const GENERIC_JSON_VALUE_RULE: &str = "json_value";
@@ -29,6 +29,13 @@ json_exp ::= [eE] [+-]? json_digits1
 ws ::= [ \t\n\r]*
 "#;
 
+/// Plain-text answers allowed next to a tool call under `tool_choice: auto`.
+/// They may not start with `{` or whitespace, so the first visible character
+/// tells a streamed answer apart from a call.
+const TOOL_TEXT_GBNF_RULES: &str = r#"tool_text ::= [^{ \t\n\r] tool_text_chars
+tool_text_chars ::= [^\x00]*
+"#;
+
 pub(super) fn resolve_effective_gbnf(
     request_gbnf: Option<&str>,
     structured_output: Option<&StructuredOutput>,
@@ -52,6 +59,61 @@ pub(super) fn compile_structured_output_to_gbnf(
     }
 }
 
+/// Compile a grammar that admits a single `{"name": ..., "arguments": {...}}`
+/// call of one of `tools`, with arguments matching that tool's parameter schema.
+/// With [`LocalToolChoice::Auto`] a plain-text answer is allowed instead.
+pub(super) fn compile_tool_call_gbnf(
+    tools: &[slab_proto::openai::FunctionTool],
+    choice: &LocalToolChoice,
+) -> Result<String, AppCoreError> {
+    let variants = tools
+        .iter()
+        .filter(|tool| match choice {
+            LocalToolChoice::Function(name) => &tool.name == name,
+            LocalToolChoice::Auto | LocalToolChoice::Required => true,
+        })
+        .map(|tool| {
+            let arguments = tool
+                .parameters
+                .as_ref()
+                .map(|parameters| {
+                    Value::Object(
+                        parameters
+                            .iter()
+                            .map(|(key, value)| (key.clone(), value.clone()))
+                            .collect(),
+                    )
+                })
+                .unwrap_or_else(|| serde_json::json!({ "type": "object" }));
+            serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "name": { "const": tool.name },
+                    "arguments": arguments,
+                },
+                "required": ["name", "arguments"],
+            })
+        })
+        .collect::<Vec<_>>();
+    if variants.is_empty() {
+        return Err(AppCoreError::BadRequest(
+            "tool_choice does not match any of the request tools".into(),
+        ));
+    }
+    let schema = serde_json::json!({ "anyOf": variants });
+
+    let mut compiler = JsonSchemaGbnfCompiler::new(&schema);
+    match choice {
+        LocalToolChoice::Auto => {
+            let mut rendered =
+                compiler.compile_with_root(|call_rule| format!("ws {call_rule} ws | tool_text"))?;
+            rendered.push_str(TOOL_TEXT_GBNF_RULES);
+            Ok(rendered)
+        }
+        LocalToolChoice::Required | LocalToolChoice::Function(_) => compiler.compile(),
+    }
+}
+
 fn compile_json_schema_to_gbnf(
     schema: &StructuredOutputJsonSchema,
 ) -> Result<String, AppCoreError> {
@@ -86,8 +148,15 @@ impl<'a> JsonSchemaGbnfCompiler<'a> {
     }
 
     fn compile(&mut self) -> Result<String, AppCoreError> {
+        self.compile_with_root(|root_rule| format!("ws {root_rule} ws"))
+    }
+
+    fn compile_with_root(
+        &mut self,
+        root_body: impl FnOnce(&str) -> String,
+    ) -> Result<String, AppCoreError> {
         let root_rule = self.compile_schema(self.root_schema)?;
-        let mut rendered = format!("root ::= ws {root_rule} ws\n");
+        let mut rendered = format!("root ::= {}\n", root_body(&root_rule));
         for (name, body) in &self.rules {
             rendered.push_str(name);
             rendered.push_str(" ::= ");
@@ -699,8 +768,10 @@ fn unsupported_schema_keyword(keyword: &str) -> AppCoreError {
 
 #[cfg(test)]
 mod tests {
-    use super::{compile_structured_output_to_gbnf, resolve_effective_gbnf};
-    use crate::domain::models::{StructuredOutput, StructuredOutputJsonSchema};
+    use super::{
+        compile_structured_output_to_gbnf, compile_tool_call_gbnf, resolve_effective_gbnf,
+    };
+    use crate::domain::models::{LocalToolChoice, StructuredOutput, StructuredOutputJsonSchema};
     use serde_json::json;
 
     #[test]
@@ -738,4 +809,68 @@ mod tests {
 
         assert!(rendered.contains("\\\"ok\\\""));
     }
+
+    #[test]
+    fn tool_call_grammar_pins_tool_name_and_argument_fields() {
+        let rendered =
+            compile_tool_call_gbnf(&[weather_tool()], &LocalToolChoice::Required).expect("gbnf");
+
+        assert!(rendered.starts_with("root ::= ws any_of"));
+        assert!(rendered.contains("\\\"get_weather\\\""));
+        assert!(rendered.contains("\\\"arguments\\\""));
+        assert!(rendered.contains("\\\"city\\\""));
+        assert!(!rendered.contains("tool_text"));
+    }
+
+    #[test]
+    fn auto_tool_call_grammar_alternates_with_plain_text() {
+        let rendered =
+            compile_tool_call_gbnf(&[weather_tool()], &LocalToolChoice::Auto).expect("gbnf");
+
+        let root = rendered.lines().next().expect("root rule");
+        assert!(root.starts_with("root ::= ws any_of"));
+        assert!(root.ends_with(" ws | tool_text"));
+        assert!(rendered.contains("tool_text ::= [^{ \\t\\n\\r] tool_text_chars"));
+    }
+
+    #[test]
+    fn named_tool_choice_only_admits_that_tool() {
+        let other = slab_proto::openai::FunctionTool::new(
+            slab_proto::openai::FunctionToolType::Function,
+            "get_time".to_owned(),
+            None,
+            Some(true),
+        );
+        let tools = [weather_tool(), other];
+
+        let rendered =
+            compile_tool_call_gbnf(&tools, &LocalToolChoice::Function("get_time".into()))
+                .expect("gbnf");
+        assert!(rendered.contains("\\\"get_time\\\""));
+        assert!(!rendered.contains("\\\"get_weather\\\""));
+
+        assert!(
+            compile_tool_call_gbnf(&tools, &LocalToolChoice::Function("missing".into())).is_err()
+        );
+    }
+
+    fn weather_tool() -> slab_proto::openai::FunctionTool {
+        slab_proto::openai::FunctionTool::new(
+            slab_proto::openai::FunctionToolType::Function,
+            "get_weather".to_owned(),
+            Some(
+                json!({
+                    "type": "object",
+                    "properties": { "city": { "type": "string" } },
+                    "required": ["city"],
+                })
+                .as_object()
+                .expect("object schema")
+                .clone()
+                .into_iter()
+                .collect(),
+            ),
+            Some(true),
+        )
+    }
 }
@@ -8,9 +8,10 @@ use uuid::Uuid;
 
 use crate::context::ModelState;
 use crate::domain::models::{
-    ChatReasoningEffort, ChatVerbosity, ConversationMessage as DomainConversationMessage,
-    StructuredOutput, TextGenerationChunk, TextGenerationResponse, TextGenerationUsage,
-    TextPromptTokensDetails,
+    ChatReasoningEffort, ChatStreamChunk, ChatVerbosity,
+    ConversationMessage as DomainConversationMessage, ConversationToolCall,
+    ConversationToolFunction, LocalToolChoice, StructuredOutput, TextGenerationChunk,
+    TextGenerationResponse, TextGenerationUsage, TextPromptTokensDetails,
 };
 use crate::domain::ports::{
     RuntimeTextGenerationChunk, RuntimeTextGenerationRequest, RuntimeTextGenerationResponse,
@@ -45,12 +46,17 @@ pub(super) struct LocalChatRequestConfig {
     pub(super) top_k: Option<i32>,
     pub(super) min_p: Option<f32>,
     pub(super) presence_penalty: Option<f32>,
+    pub(super) frequency_penalty: Option<f32>,
     pub(super) repetition_penalty: Option<f32>,
+    pub(super) repeat_last_n: Option<i32>,
+    pub(super) n_keep: Option<u32>,
+    pub(super) seed: Option<u32>,
     pub(super) reasoning_effort: Option<ChatReasoningEffort>,
     pub(super) verbosity: Option<ChatVerbosity>,
     pub(super) gbnf: Option<String>,
     pub(super) structured_output: Option<StructuredOutput>,
     pub(super) tools: Vec<slab_proto::openai::FunctionTool>,
+    pub(super) tool_choice: Option<LocalToolChoice>,
     pub(super) stop: Vec<String>,
     pub(super) agent_trace: Option<slab_agent_tracing::AgentTraceContext>,
     pub(super) stream: bool,
@@ -65,7 +71,11 @@ pub(super) struct LocalTextRequestConfig {
     pub(super) top_k: Option<i32>,
     pub(super) min_p: Option<f32>,
     pub(super) presence_penalty: Option<f32>,
+    pub(super) frequency_penalty: Option<f32>,
     pub(super) repetition_penalty: Option<f32>,
+    pub(super) repeat_last_n: Option<i32>,
+    pub(super) n_keep: Option<u32>,
+    pub(super) seed: Option<u32>,
     pub(super) reasoning_effort: Option<ChatReasoningEffort>,
     pub(super) verbosity: Option<ChatVerbosity>,
     pub(super) gbnf: Option<String>,
@@ -125,11 +135,19 @@ pub(super) async fn create_chat_completion(
     );
     let trailing_stop_markers =
         super::template::trailing_stop_markers(prompt_profile.chat_template_source.as_deref());
-    let gbnf = super::gbnf::resolve_effective_gbnf(
-        config.gbnf.as_deref(),
-        config.structured_output.as_ref(),
-        prompt_profile.default_gbnf.as_deref(),
-    )?;
+    // An explicit grammar or structured output request keeps precedence over
+    // the tool-call grammar.
+    let tool_choice = config.tool_choice.clone().filter(|_| {
+        !config.tools.is_empty() && config.gbnf.is_none() && config.structured_output.is_none()
+    });
+    let gbnf = match tool_choice.as_ref() {
+        Some(choice) => Some(super::gbnf::compile_tool_call_gbnf(&config.tools, choice)?),
+        None => super::gbnf::resolve_effective_gbnf(
+            config.gbnf.as_deref(),
+            config.structured_output.as_ref(),
+            prompt_profile.default_gbnf.as_deref(),
+        )?,
+    };
     tracing::debug!(
         prompt_tail = &prompt[prompt.len().saturating_sub(120)..],
         native_thinking,
@@ -165,7 +183,11 @@ pub(super) async fn create_chat_completion(
         top_k: config.top_k,
         min_p: config.min_p,
         presence_penalty: config.presence_penalty,
+        frequency_penalty: config.frequency_penalty,
         repetition_penalty: config.repetition_penalty,
+        repeat_last_n: config.repeat_last_n,
+        n_keep: config.n_keep,
+        seed: config.seed,
         session_key: config.session_id.clone(),
         stream: config.stream,
         gbnf,
@@ -219,6 +241,8 @@ pub(super) async fn create_chat_completion(
         let token_stream_terminal_metadata = Arc::clone(&terminal_metadata);
         let content_stop_state = Arc::new(Mutex::new(ContentStopState::default()));
         let token_stream_content_stop_state = Arc::clone(&content_stop_state);
+        let tool_call_state = Arc::new(Mutex::new(ToolCallStreamState::new(tool_choice.as_ref())));
+        let tools_for_tokens = config.tools.clone();
         let effective_stop_for_tokens = effective_stop.clone();
         let trailing_stop_markers_for_tokens = trailing_stop_markers.clone();
         let trace_context_for_tokens = config.agent_trace.clone();
@@ -233,6 +257,8 @@ pub(super) async fn create_chat_completion(
                 let effective_stop = effective_stop_for_tokens.clone();
                 let trailing_stop_markers = trailing_stop_markers_for_tokens.clone();
                 let trace_context = trace_context_for_tokens.clone();
+                let tool_call_state = Arc::clone(&tool_call_state);
+                let tools = tools_for_tokens.clone();
                 async move {
                     match chunk {
                         Ok(message) => {
@@ -276,13 +302,28 @@ pub(super) async fn create_chat_completion(
                                         );
                                     }
                                 }
+                                let mut tool_call_state = tool_call_state
+                                    .lock()
+                                    .expect("local tool call stream state lock poisoned");
+                                let mut text = tool_call_state.ingest(&emission.text);
+                                let (tool_call, held_back) = tool_call_state.finish(&tools);
+                                text.push_str(&held_back);
                                 let mut chunks = Vec::new();
-                                if !emission.text.is_empty() {
+                                if !text.is_empty() {
                                     chunks.push(super::build_chunk(
                                         &completion_id,
                                         created_ts,
                                         &model_name,
-                                        &emission.text,
+                                        &text,
+                                    ));
+                                }
+                                if let Some(tool_call) = tool_call {
+                                    terminal.finish_reason = Some("tool_calls".to_owned());
+                                    chunks.push(super::build_tool_calls_chunk(
+                                        &completion_id,
+                                        created_ts,
+                                        &model_name,
+                                        &[tool_call],
                                     ));
                                 }
                                 chunks
@@ -337,12 +378,16 @@ pub(super) async fn create_chat_completion(
                                             );
                                         }
                                     }
-                                    if !emission.text.is_empty() {
+                                    let text = tool_call_state
+                                        .lock()
+                                        .expect("local tool call stream state lock poisoned")
+                                        .ingest(&emission.text);
+                                    if !text.is_empty() {
                                         chunks.push(super::build_chunk(
                                             &completion_id,
                                             created_ts,
                                             &model_name,
-                                            &emission.text,
+                                            &text,
                                         ));
                                     }
                                 }
@@ -380,12 +425,16 @@ pub(super) async fn create_chat_completion(
                                         );
                                     }
                                 }
-                                if !emission.text.is_empty() {
+                                let text = tool_call_state
+                                    .lock()
+                                    .expect("local tool call stream state lock poisoned")
+                                    .ingest(&emission.text);
+                                if !text.is_empty() {
                                     vec![super::build_chunk(
                                         &completion_id,
                                         created_ts,
                                         &model_name,
-                                        &emission.text,
+                                        &text,
                                     )]
                                 } else {
                                     Vec::new()
@@ -462,7 +511,7 @@ pub(super) async fn create_chat_completion(
             .chain(token_stream)
             .chain(finish_chunk)
             .chain(usage_chunk)
-            .chain(stream::once(async { "[DONE]".to_owned() }))
+            .chain(stream::once(async { ChatStreamChunk::done() }))
             .map(move |item| {
                 let _keep_alive = &usage_guard;
                 item
@@ -525,6 +574,13 @@ pub(super) async fn create_chat_completion(
             response.finish_reason.get_or_insert_with(|| "stop".to_owned());
         }
     }
+    if tool_choice.is_some()
+        && let Some(tool_call) = tool_call_from_output(&response.text, &config.tools)
+    {
+        response.tool_calls = vec![tool_call];
+        response.text.clear();
+        response.finish_reason = Some("tool_calls".to_owned());
+    }
 
     Ok(GeneratedChatOutput::Text(response))
 }
@@ -555,7 +611,11 @@ pub(super) async fn create_text_completion(
         top_k: config.top_k,
         min_p: config.min_p,
         presence_penalty: config.presence_penalty,
+        frequency_penalty: config.frequency_penalty,
         repetition_penalty: config.repetition_penalty,
+        repeat_last_n: config.repeat_last_n,
+        n_keep: config.n_keep,
+        seed: config.seed,
         session_key: None,
         stream: false,
         gbnf,
@@ -609,7 +669,11 @@ fn runtime_request_payload(request: &RuntimeTextGenerationRequest) -> serde_json
         "top_k": request.top_k,
         "min_p": request.min_p,
         "presence_penalty": request.presence_penalty,
+        "frequency_penalty": request.frequency_penalty,
         "repetition_penalty": request.repetition_penalty,
+        "repeat_last_n": request.repeat_last_n,
+        "n_keep": request.n_keep,
+        "seed": request.seed,
         "session_key": request.session_key,
         "stream": request.stream,
         "gbnf": request.gbnf,
@@ -660,6 +724,97 @@ fn text_response_from_runtime(response: RuntimeTextGenerationResponse) -> TextGe
     }
 }
 
+/// Holds back streamed content that may be a grammar-constrained tool call.
+///
+/// Under `auto` the grammar keeps plain answers from starting with `{` or
+/// whitespace, so the first visible character decides between streaming the
+/// text through and buffering a call until generation ends.
+struct ToolCallStreamState {
+    mode: ToolCallStreamMode,
+    buffer: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ToolCallStreamMode {
+    Undecided,
+    Text,
+    Call,
+}
+
+impl ToolCallStreamState {
+    fn new(tool_choice: Option<&LocalToolChoice>) -> Self {
+        let mode = match tool_choice {
+            None => ToolCallStreamMode::Text,
+            Some(LocalToolChoice::Auto) => ToolCallStreamMode::Undecided,
+            Some(LocalToolChoice::Required | LocalToolChoice::Function(_)) => {
+                ToolCallStreamMode::Call
+            }
+        };
+        Self { mode, buffer: String::new() }
+    }
+
+    /// Returns the part of `text` that can be streamed as content right away.
+    fn ingest(&mut self, text: &str) -> String {
+        match self.mode {
+            ToolCallStreamMode::Text => text.to_owned(),
+            ToolCallStreamMode::Call => {
+                self.buffer.push_str(text);
+                String::new()
+            }
+            ToolCallStreamMode::Undecided => {
+                self.buffer.push_str(text);
+                match self.buffer.trim_start().chars().next() {
+                    None => String::new(),
+                    Some('{') => {
+                        self.mode = ToolCallStreamMode::Call;
+                        String::new()
+                    }
+                    Some(_) => {
+                        self.mode = ToolCallStreamMode::Text;
+                        std::mem::take(&mut self.buffer)
+                    }
+                }
+            }
+        }
+    }
+
+    /// Parses the held-back call once generation ends. Held-back content that
+    /// is not a call is returned as text instead.
+    fn finish(
+        &mut self,
+        tools: &[slab_proto::openai::FunctionTool],
+    ) -> (Option<ConversationToolCall>, String) {
+        let buffered = std::mem::take(&mut self.buffer);
+        if self.mode == ToolCallStreamMode::Call
+            && let Some(tool_call) = tool_call_from_output(&buffered, tools)
+        {
+            return (Some(tool_call), String::new());
+        }
+        (None, buffered)
+    }
+}
+
+/// Parse grammar-constrained `{"name": ..., "arguments": {...}}` output into an
+/// OpenAI-style function call. Unknown tool names are left as plain text.
+fn tool_call_from_output(
+    text: &str,
+    tools: &[slab_proto::openai::FunctionTool],
+) -> Option<ConversationToolCall> {
+    let payload = serde_json::from_str::<serde_json::Value>(text.trim()).ok()?;
+    let name = payload.get("name")?.as_str()?;
+    if !tools.iter().any(|tool| tool.name == name) {
+        return None;
+    }
+    let arguments =
+        payload.get("arguments").cloned().unwrap_or_else(|| serde_json::json!({})).to_string();
+
+    Some(ConversationToolCall {
+        id: Some(format!("call_{}", Uuid::new_v4().simple())),
+        r#type: "function".to_owned(),
+        function: ConversationToolFunction { name: name.to_owned(), arguments },
+    })
+}
+
 fn text_chunk_from_runtime(chunk: RuntimeTextGenerationChunk) -> TextGenerationChunk {
     TextGenerationChunk {
         delta: chunk.delta,
@@ -669,3 +824,76 @@ fn text_chunk_from_runtime(chunk: RuntimeTextGenerationChunk) -> TextGenerationC
         metadata: chunk.metadata,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{ToolCallStreamState, tool_call_from_output};
+    use crate::domain::models::LocalToolChoice;
+
+    fn weather_tool() -> slab_proto::openai::FunctionTool {
+        slab_proto::openai::FunctionTool::new(
+            slab_proto::openai::FunctionToolType::Function,
+            "get_weather".to_owned(),
+            None,
+            Some(true),
+        )
+    }
+
+    #[test]
+    fn grammar_output_parses_into_well_formed_tool_call() {
+        let tool_call = tool_call_from_output(
+            " {\"name\": \"get_weather\", \"arguments\": {\"city\": \"Paris\"}} ",
+            &[weather_tool()],
+        )
+        .expect("tool call");
+
+        assert_eq!(tool_call.r#type, "function");
+        assert!(tool_call.id.as_deref().is_some_and(|id| id.starts_with("call_")));
+        assert_eq!(tool_call.function.name, "get_weather");
+        let arguments = serde_json::from_str::<serde_json::Value>(&tool_call.function.arguments)
+            .expect("arguments are JSON");
+        assert_eq!(arguments, serde_json::json!({ "city": "Paris" }));
+    }
+
+    #[test]
+    fn unknown_tool_or_plain_text_output_is_not_a_tool_call() {
+        assert!(
+            tool_call_from_output(r#"{"name":"other","arguments":{}}"#, &[weather_tool()])
+                .is_none()
+        );
+        assert!(tool_call_from_output("It is sunny.", &[weather_tool()]).is_none());
+    }
+
+    #[test]
+    fn auto_stream_passes_plain_text_through() {
+        let mut state = ToolCallStreamState::new(Some(&LocalToolChoice::Auto));
+
+        assert_eq!(state.ingest(""), "");
+        assert_eq!(state.ingest("It is"), "It is");
+        assert_eq!(state.ingest(" sunny."), " sunny.");
+        let (tool_call, held_back) = state.finish(&[weather_tool()]);
+        assert!(tool_call.is_none());
+        assert!(held_back.is_empty());
+    }
+
+    #[test]
+    fn auto_stream_holds_back_a_tool_call_until_finish() {
+        let mut state = ToolCallStreamState::new(Some(&LocalToolChoice::Auto));
+
+        assert_eq!(state.ingest(" {\"name\": \"get_weather\","), "");
+        assert_eq!(state.ingest(" \"arguments\": {\"city\": \"Paris\"}}"), "");
+        let (tool_call, held_back) = state.finish(&[weather_tool()]);
+        assert_eq!(tool_call.expect("tool call").function.arguments, r#"{"city":"Paris"}"#);
+        assert!(held_back.is_empty());
+    }
+
+    #[test]
+    fn stream_without_tool_choice_is_untouched() {
+        let mut state = ToolCallStreamState::new(None);
+
+        assert_eq!(state.ingest("{\"a\":1}"), "{\"a\":1}");
+        let (tool_call, held_back) = state.finish(&[weather_tool()]);
+        assert!(tool_call.is_none());
+        assert!(held_back.is_empty());
+    }
+}
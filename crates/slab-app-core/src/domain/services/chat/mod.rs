@@ -32,7 +32,8 @@ use params::{
 use session::{build_messages, persist_session_message};
 use streaming::{
     build_chunk, build_error_chunk, build_finish_chunk, build_reasoning_chunk, build_role_chunk,
-    build_usage_chunk, into_text_completion_stream, with_stream_session_persistence,
+    build_tool_calls_chunk, build_usage_chunk, into_text_completion_stream,
+    with_stream_session_persistence,
 };
 
 const CLOUD_MODEL_ID_PREFIX: &str = "cloud";
@@ -67,6 +68,29 @@ impl ChatService {
     ) -> Result<TextCompletionOutput, AppCoreError> {
         create_text_completion_with_state(self.state.clone(), command).await
     }
+
+    /// The budget a request's `max_tokens` is clamped to, when the server cap
+    /// lowers it, so the transport can tell the client.
+    pub fn max_tokens_clamp(&self, requested: Option<u32>) -> Option<u32> {
+        let max_tokens = clamp_max_tokens(requested, self.state.config().max_completion_tokens);
+        requested.filter(|requested| *requested > max_tokens).map(|_| max_tokens)
+    }
+}
+
+fn effective_max_tokens(state: &ModelState, requested: Option<u32>) -> u32 {
+    let cap = state.config().max_completion_tokens;
+    let max_tokens = clamp_max_tokens(requested, cap);
+    if let Some(requested) = requested
+        && requested > max_tokens
+    {
+        warn!(requested, cap, "max_tokens exceeds the server cap; clamping");
+    }
+    max_tokens
+}
+
+fn clamp_max_tokens(requested: Option<u32>, cap: Option<u32>) -> u32 {
+    let max_tokens = requested.unwrap_or(DEFAULT_COMPLETION_MAX_TOKENS);
+    cap.map_or(max_tokens, |cap| max_tokens.min(cap.max(1)))
 }
 
 async fn resolve_requested_model(
@@ -107,7 +131,7 @@ async fn create_chat_completion_with_state(
         .map(DomainConversationMessage::rendered_text)
         .unwrap_or_default();
 
-    let max_tokens = command.common.max_tokens.unwrap_or(DEFAULT_COMPLETION_MAX_TOKENS);
+    let max_tokens = effective_max_tokens(&state, command.common.max_tokens);
     let temperature = command.common.temperature.unwrap_or(0.7);
     let route_to_cloud = cloud::should_route_to_cloud(&state, &resolved_model).await?;
     let telemetry_config = state.pmid().config().telemetry;
@@ -190,12 +214,17 @@ async fn create_chat_completion_with_state(
                     top_k: command.common.top_k,
                     min_p: command.common.min_p,
                     presence_penalty: command.common.presence_penalty,
+                    frequency_penalty: command.common.frequency_penalty,
                     repetition_penalty: command.common.repetition_penalty,
+                    repeat_last_n: command.common.repeat_last_n,
+                    n_keep: command.common.n_keep,
+                    seed: command.common.seed,
                     reasoning_effort: command.cloud.reasoning_effort,
                     verbosity: command.cloud.verbosity,
                     gbnf: command.local.gbnf.clone(),
                     structured_output: command.local.structured_output.clone(),
-                    tools: command.tools.clone(),
+                    tools: command.tools.iter().chain(&command.local.tools).cloned().collect(),
+                    tool_choice: command.local.tool_choice.clone(),
                     stop: command.common.stop.clone(),
                     agent_trace: command.agent_trace.clone(),
                     stream: true,
@@ -290,12 +319,17 @@ async fn create_chat_completion_with_state(
                     top_k: command.common.top_k,
                     min_p: command.common.min_p,
                     presence_penalty: command.common.presence_penalty,
+                    frequency_penalty: command.common.frequency_penalty,
                     repetition_penalty: command.common.repetition_penalty,
+                    repeat_last_n: command.common.repeat_last_n,
+                    n_keep: command.common.n_keep,
+                    seed: command.common.seed,
                     reasoning_effort: command.cloud.reasoning_effort,
                     verbosity: command.cloud.verbosity,
                     gbnf: command.local.gbnf.clone(),
                     structured_output: command.local.structured_output.clone(),
-                    tools: command.tools.clone(),
+                    tools: command.tools.iter().chain(&command.local.tools).cloned().collect(),
+                    tool_choice: command.local.tool_choice.clone(),
                     stop: command.common.stop.clone(),
                     agent_trace: command.agent_trace.clone(),
                     stream: false,
@@ -509,7 +543,7 @@ async fn create_text_completion_with_state(
     }
 
     let resolved_model = resolve_requested_model(&state, &command.model).await?;
-    let max_tokens = command.common.max_tokens.unwrap_or(DEFAULT_COMPLETION_MAX_TOKENS);
+    let max_tokens = effective_max_tokens(&state, command.common.max_tokens);
     let temperature = command.common.temperature.unwrap_or(0.7);
     let route_to_cloud = cloud::should_route_to_cloud(&state, &resolved_model).await?;
     validate_text_route_params(route_to_cloud, &command)?;
@@ -554,7 +588,11 @@ async fn create_text_completion_with_state(
                     top_k: command.common.top_k,
                     min_p: command.common.min_p,
                     presence_penalty: command.common.presence_penalty,
+                    frequency_penalty: command.common.frequency_penalty,
                     repetition_penalty: command.common.repetition_penalty,
+                    repeat_last_n: command.common.repeat_last_n,
+                    n_keep: command.common.n_keep,
+                    seed: command.common.seed,
                     reasoning_effort: command.cloud.reasoning_effort,
                     verbosity: command.cloud.verbosity,
                     gbnf: command.local.gbnf.clone(),
@@ -661,13 +699,17 @@ mod test {
                 top_k: None,
                 min_p: None,
                 presence_penalty: None,
+                frequency_penalty: None,
                 repetition_penalty: None,
+                repeat_last_n: None,
+                n_keep: None,
+                seed: None,
                 n: 1,
                 stream: false,
                 stop: Vec::new(),
                 stream_options: Default::default(),
             },
-            local: crate::domain::models::LocalChatParams { gbnf: None, structured_output: None },
+            local: crate::domain::models::LocalChatParams::default(),
             cloud: crate::domain::models::CloudChatParams {
                 reasoning_effort: None,
                 verbosity: None,
@@ -688,13 +730,17 @@ mod test {
                 top_k: None,
                 min_p: None,
                 presence_penalty: None,
+                frequency_penalty: None,
                 repetition_penalty: None,
+                repeat_last_n: None,
+                n_keep: None,
+                seed: None,
                 n: 1,
                 stream: false,
                 stop: Vec::new(),
                 stream_options: Default::default(),
             },
-            local: crate::domain::models::LocalChatParams { gbnf: None, structured_output: None },
+            local: crate::domain::models::LocalChatParams::default(),
             cloud: crate::domain::models::CloudChatParams {
                 reasoning_effort: None,
                 verbosity: None,
@@ -720,6 +766,15 @@ mod test {
         assert_eq!(max_tokens, 81_920);
     }
 
+    #[test]
+    fn max_tokens_is_clamped_to_the_server_cap() {
+        assert_eq!(clamp_max_tokens(Some(5), Some(32_768)), 5);
+        assert_eq!(clamp_max_tokens(Some(81_920), Some(32_768)), 32_768);
+        assert_eq!(clamp_max_tokens(None, Some(256)), 256);
+        assert_eq!(clamp_max_tokens(None, Some(32_768)), DEFAULT_COMPLETION_MAX_TOKENS);
+        assert_eq!(clamp_max_tokens(Some(81_920), None), 81_920);
+    }
+
     #[test]
     fn validate_temperature_out_of_range() {
         let temperature = 3.0_f32;
@@ -784,6 +839,23 @@ mod test {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn cloud_route_rejects_request_tools() {
+        let mut command = make_command("user", "hello");
+        command.local.tools = vec![slab_proto::openai::FunctionTool::new(
+            slab_proto::openai::FunctionToolType::Function,
+            "get_weather".to_owned(),
+            None,
+            Some(true),
+        )];
+        command.local.tool_choice = Some(crate::domain::models::LocalToolChoice::Auto);
+
+        let result = validate_chat_route_params(true, &command);
+
+        assert!(matches!(result, Err(AppCoreError::BadRequestData { .. })));
+        assert!(validate_chat_route_params(false, &command).is_ok());
+    }
+
     #[test]
     fn cloud_route_rejects_raw_gbnf() {
         let mut command = make_command("user", "hello");
@@ -129,6 +129,12 @@ pub(super) fn validate_chat_route_params(
                 "cloud chat completions do not support raw gbnf constraints",
             ));
         }
+        if !command.local.tools.is_empty() {
+            return Err(unsupported_chat_parameter(
+                "tools",
+                "cloud chat completions do not support request tools; only local models can call them",
+            ));
+        }
         if command.common.top_k.is_some() {
             return Err(unsupported_chat_parameter(
                 "top_k",
@@ -147,12 +153,36 @@ pub(super) fn validate_chat_route_params(
                 "cloud chat completions do not support local presence penalty controls",
             ));
         }
+        if command.common.frequency_penalty.is_some() {
+            return Err(unsupported_chat_parameter(
+                "frequency_penalty",
+                "cloud chat completions do not support local frequency penalty controls",
+            ));
+        }
         if command.common.repetition_penalty.is_some() {
             return Err(unsupported_chat_parameter(
                 "repetition_penalty",
                 "cloud chat completions do not support local repetition penalty controls",
             ));
         }
+        if command.common.repeat_last_n.is_some() {
+            return Err(unsupported_chat_parameter(
+                "repeat_last_n",
+                "cloud chat completions do not support local penalty window controls",
+            ));
+        }
+        if command.common.n_keep.is_some() {
+            return Err(unsupported_chat_parameter(
+                "n_keep",
+                "cloud chat completions do not support local context window controls",
+            ));
+        }
+        if command.common.seed.is_some() {
+            return Err(unsupported_chat_parameter(
+                "seed",
+                "cloud chat completions do not support local sampler seeds",
+            ));
+        }
         validate_cloud_structured_output(command.cloud.structured_output.as_ref())?;
         return Ok(());
     }
@@ -189,12 +219,36 @@ pub(super) fn validate_text_route_params(
                 "cloud text completions do not support local presence penalty controls",
             ));
         }
+        if command.common.frequency_penalty.is_some() {
+            return Err(unsupported_chat_parameter(
+                "frequency_penalty",
+                "cloud text completions do not support local frequency penalty controls",
+            ));
+        }
         if command.common.repetition_penalty.is_some() {
             return Err(unsupported_chat_parameter(
                 "repetition_penalty",
                 "cloud text completions do not support local repetition penalty controls",
             ));
         }
+        if command.common.repeat_last_n.is_some() {
+            return Err(unsupported_chat_parameter(
+                "repeat_last_n",
+                "cloud text completions do not support local penalty window controls",
+            ));
+        }
+        if command.common.n_keep.is_some() {
+            return Err(unsupported_chat_parameter(
+                "n_keep",
+                "cloud text completions do not support local context window controls",
+            ));
+        }
+        if command.common.seed.is_some() {
+            return Err(unsupported_chat_parameter(
+                "seed",
+                "cloud text completions do not support local sampler seeds",
+            ));
+        }
         validate_cloud_structured_output(command.cloud.structured_output.as_ref())?;
     }
 
@@ -8,8 +8,8 @@ use tracing::warn;
 
 use crate::context::ModelState;
 use crate::domain::models::{
-    ChatStreamChunk, ConversationMessage as DomainConversationMessage, TextCompletionOutput,
-    TextGenerationUsage, assistant_message_from_parts,
+    ChatStreamChunk, ChatStreamEvent, ConversationMessage as DomainConversationMessage,
+    ConversationToolCall, TextCompletionOutput, TextGenerationUsage, assistant_message_from_parts,
 };
 
 use super::SYSTEM_FINGERPRINT;
@@ -51,6 +51,24 @@ struct ChatCompletionChunkDelta<'a> {
     content: Option<&'a str>,
     #[serde(skip_serializing_if = "Option::is_none")]
     reasoning_content: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tool_calls: Option<Vec<ChatCompletionChunkToolCall<'a>>>,
+}
+
+#[derive(Serialize)]
+struct ChatCompletionChunkToolCall<'a> {
+    index: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    id: Option<&'a str>,
+    #[serde(rename = "type")]
+    call_type: &'a str,
+    function: ChatCompletionChunkToolFunction<'a>,
+}
+
+#[derive(Serialize)]
+struct ChatCompletionChunkToolFunction<'a> {
+    name: &'a str,
+    arguments: &'a str,
 }
 
 #[derive(Serialize)]
@@ -85,8 +103,8 @@ struct ChatStreamErrorBody<'a> {
 }
 
 /// Build an OpenAI-compatible `chat.completion.chunk` SSE data payload.
-pub(super) fn build_chunk(id: &str, created: i64, model: &str, token: &str) -> String {
-    serialize_chunk(&ChatCompletionChunkPayload {
+pub(super) fn build_chunk(id: &str, created: i64, model: &str, token: &str) -> ChatStreamChunk {
+    let data = serialize_chunk(&ChatCompletionChunkPayload {
         id,
         object_type: "chat.completion.chunk",
         created,
@@ -98,12 +116,13 @@ pub(super) fn build_chunk(id: &str, created: i64, model: &str, token: &str) -> S
             finish_reason: None,
         }],
         usage: None,
-    })
+    });
+    ChatStreamChunk { event: ChatStreamEvent::Content(token.to_owned()), data }
 }
 
 /// Build an OpenAI-compatible initial SSE chunk that announces the assistant role.
-pub(super) fn build_role_chunk(id: &str, created: i64, model: &str) -> String {
-    serialize_chunk(&ChatCompletionChunkPayload {
+pub(super) fn build_role_chunk(id: &str, created: i64, model: &str) -> ChatStreamChunk {
+    let data = serialize_chunk(&ChatCompletionChunkPayload {
         id,
         object_type: "chat.completion.chunk",
         created,
@@ -115,12 +134,18 @@ pub(super) fn build_role_chunk(id: &str, created: i64, model: &str) -> String {
             finish_reason: None,
         }],
         usage: None,
-    })
+    });
+    ChatStreamChunk { event: ChatStreamEvent::Role, data }
 }
 
 /// Build an OpenAI-compatible reasoning SSE chunk payload.
-pub(super) fn build_reasoning_chunk(id: &str, created: i64, model: &str, token: &str) -> String {
-    serialize_chunk(&ChatCompletionChunkPayload {
+pub(super) fn build_reasoning_chunk(
+    id: &str,
+    created: i64,
+    model: &str,
+    token: &str,
+) -> ChatStreamChunk {
+    let data = serialize_chunk(&ChatCompletionChunkPayload {
         id,
         object_type: "chat.completion.chunk",
         created,
@@ -135,7 +160,47 @@ pub(super) fn build_reasoning_chunk(id: &str, created: i64, model: &str, token:
             finish_reason: None,
         }],
         usage: None,
-    })
+    });
+    ChatStreamChunk { event: ChatStreamEvent::Reasoning(token.to_owned()), data }
+}
+
+/// Build an OpenAI-compatible SSE chunk that carries complete tool calls.
+pub(super) fn build_tool_calls_chunk(
+    id: &str,
+    created: i64,
+    model: &str,
+    tool_calls: &[ConversationToolCall],
+) -> ChatStreamChunk {
+    let payload_tool_calls = tool_calls
+        .iter()
+        .zip(0..)
+        .map(|(tool_call, index)| ChatCompletionChunkToolCall {
+            index,
+            id: tool_call.id.as_deref(),
+            call_type: &tool_call.r#type,
+            function: ChatCompletionChunkToolFunction {
+                name: &tool_call.function.name,
+                arguments: &tool_call.function.arguments,
+            },
+        })
+        .collect();
+    let data = serialize_chunk(&ChatCompletionChunkPayload {
+        id,
+        object_type: "chat.completion.chunk",
+        created,
+        model,
+        system_fingerprint: SYSTEM_FINGERPRINT,
+        choices: vec![ChatCompletionChunkChoice {
+            index: 0,
+            delta: ChatCompletionChunkDelta {
+                tool_calls: Some(payload_tool_calls),
+                ..Default::default()
+            },
+            finish_reason: None,
+        }],
+        usage: None,
+    });
+    ChatStreamChunk { event: ChatStreamEvent::ToolCalls(tool_calls.to_vec()), data }
 }
 
 /// Build an OpenAI-compatible final SSE chunk with a finish reason.
@@ -144,8 +209,8 @@ pub(super) fn build_finish_chunk(
     created: i64,
     model: &str,
     finish_reason: &str,
-) -> String {
-    serialize_chunk(&ChatCompletionChunkPayload {
+) -> ChatStreamChunk {
+    let data = serialize_chunk(&ChatCompletionChunkPayload {
         id,
         object_type: "chat.completion.chunk",
         created,
@@ -157,7 +222,8 @@ pub(super) fn build_finish_chunk(
             finish_reason: Some(finish_reason),
         }],
         usage: None,
-    })
+    });
+    ChatStreamChunk { event: ChatStreamEvent::Finish(finish_reason.to_owned()), data }
 }
 
 pub(super) fn build_usage_chunk(
@@ -165,8 +231,8 @@ pub(super) fn build_usage_chunk(
     created: i64,
     model: &str,
     usage: &TextGenerationUsage,
-) -> String {
-    serialize_chunk(&ChatCompletionChunkPayload {
+) -> ChatStreamChunk {
+    let data = serialize_chunk(&ChatCompletionChunkPayload {
         id,
         object_type: "chat.completion.chunk",
         created,
@@ -174,7 +240,8 @@ pub(super) fn build_usage_chunk(
         system_fingerprint: SYSTEM_FINGERPRINT,
         choices: Vec::new(),
         usage: Some(usage),
-    })
+    });
+    ChatStreamChunk { event: ChatStreamEvent::Usage(usage.clone()), data }
 }
 
 fn build_text_completion_chunk(
@@ -183,15 +250,16 @@ fn build_text_completion_chunk(
     model: &str,
     index: u32,
     text: &str,
-) -> String {
-    serialize_chunk(&TextCompletionChunkPayload {
+) -> ChatStreamChunk {
+    let data = serialize_chunk(&TextCompletionChunkPayload {
         id,
         object_type: "text_completion",
         created,
         model,
         system_fingerprint: SYSTEM_FINGERPRINT,
         choices: vec![TextCompletionChunkChoice { index, text, finish_reason: None }],
-    })
+    });
+    ChatStreamChunk { event: ChatStreamEvent::Content(text.to_owned()), data }
 }
 
 fn build_text_completion_finish_chunk(
@@ -200,8 +268,8 @@ fn build_text_completion_finish_chunk(
     model: &str,
     index: u32,
     finish_reason: &str,
-) -> String {
-    serialize_chunk(&TextCompletionChunkPayload {
+) -> ChatStreamChunk {
+    let data = serialize_chunk(&TextCompletionChunkPayload {
         id,
         object_type: "text_completion",
         created,
@@ -212,13 +280,15 @@ fn build_text_completion_finish_chunk(
             text: "",
             finish_reason: Some(finish_reason),
         }],
-    })
+    });
+    ChatStreamChunk { event: ChatStreamEvent::Finish(finish_reason.to_owned()), data }
 }
 
-pub(super) fn build_error_chunk(message: &str) -> String {
-    serialize_chunk(&ChatStreamErrorPayload {
+pub(super) fn build_error_chunk(message: &str) -> ChatStreamChunk {
+    let data = serialize_chunk(&ChatStreamErrorPayload {
         error: ChatStreamErrorBody { message, error_type: "server_error", code: None },
-    })
+    });
+    ChatStreamChunk { event: ChatStreamEvent::Error(message.to_owned()), data }
 }
 
 fn serialize_chunk<T: Serialize>(payload: &T) -> String {
@@ -294,7 +364,7 @@ pub(super) fn with_stream_session_persistence(
     let assistant = Arc::new(Mutex::new(StreamedAssistantContent::default()));
     let capture_target = Arc::clone(&assistant);
     let streamed = stream.map(move |chunk| {
-        capture_streamed_assistant_chunk(&chunk, &capture_target);
+        capture_streamed_assistant_chunk(&chunk.data, &capture_target);
         chunk
     });
 
@@ -347,7 +417,7 @@ pub(super) fn into_text_completion_stream(
         chunks.push(build_text_completion_chunk(&id, created, &model, 0, &text));
     }
     chunks.push(build_text_completion_finish_chunk(&id, created, &model, 0, &finish_reason));
-    chunks.push("[DONE]".into());
+    chunks.push(ChatStreamChunk::done());
 
     TextCompletionOutput::Stream(Box::pin(stream::iter(chunks)))
 }
@@ -356,16 +426,19 @@ pub(super) fn into_text_completion_stream(
 mod tests {
     use std::sync::{Arc, Mutex};
 
-    use crate::domain::models::ConversationMessageContent;
+    use crate::domain::models::{
+        ChatStreamEvent, ConversationMessageContent, ConversationToolCall, ConversationToolFunction,
+    };
 
-    use super::{StreamedAssistantContent, build_chunk};
+    use super::{StreamedAssistantContent, build_chunk, build_tool_calls_chunk};
     use super::{build_streamed_assistant_message, capture_streamed_assistant_chunk};
     use crate::domain::services::chat::SYSTEM_FINGERPRINT;
 
     #[test]
     fn build_chunk_produces_openai_format() {
-        let json_str = build_chunk("chatcmpl-test", 1_700_000_000, "slab-llama", "Hello");
-        let value: serde_json::Value = serde_json::from_str(&json_str).expect("valid JSON");
+        let chunk = build_chunk("chatcmpl-test", 1_700_000_000, "slab-llama", "Hello");
+        assert_eq!(chunk.event, ChatStreamEvent::Content("Hello".to_owned()));
+        let value: serde_json::Value = serde_json::from_str(&chunk.data).expect("valid JSON");
         assert_eq!(value["id"], "chatcmpl-test");
         assert_eq!(value["object"], "chat.completion.chunk");
         assert_eq!(value["created"], 1_700_000_000_i64);
@@ -377,6 +450,34 @@ mod tests {
         assert!(choice["finish_reason"].is_null());
     }
 
+    #[test]
+    fn build_tool_calls_chunk_produces_openai_tool_call_delta() {
+        let tool_call = ConversationToolCall {
+            id: Some("call_1".into()),
+            r#type: "function".into(),
+            function: ConversationToolFunction {
+                name: "get_weather".into(),
+                arguments: r#"{"city":"Paris"}"#.into(),
+            },
+        };
+
+        let chunk = build_tool_calls_chunk(
+            "chatcmpl-test",
+            1,
+            "slab-llama",
+            std::slice::from_ref(&tool_call),
+        );
+        assert_eq!(chunk.event, ChatStreamEvent::ToolCalls(vec![tool_call]));
+        let value: serde_json::Value = serde_json::from_str(&chunk.data).expect("valid JSON");
+        let delta = &value["choices"][0]["delta"];
+        assert!(delta.get("content").is_none());
+        assert_eq!(delta["tool_calls"][0]["index"], 0);
+        assert_eq!(delta["tool_calls"][0]["id"], "call_1");
+        assert_eq!(delta["tool_calls"][0]["type"], "function");
+        assert_eq!(delta["tool_calls"][0]["function"]["name"], "get_weather");
+        assert_eq!(delta["tool_calls"][0]["function"]["arguments"], r#"{"city":"Paris"}"#);
+    }
+
     #[test]
     fn streamed_assistant_message_restores_reasoning_chunks_for_session_storage() {
         let assistant = Arc::new(Mutex::new(StreamedAssistantContent::default()));
@@ -1,6 +1,7 @@
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
+use futures::StreamExt;
 use slab_types::RuntimeBackendId;
 use tracing::{debug, warn};
 use uuid::Uuid;
@@ -11,7 +12,10 @@ use crate::domain::models::{
     AcceptedOperation, IMAGE_GENERATION_TASK_TYPE, ImageGenerationCommand, ImageGenerationMode,
     ImageGenerationRequestData, ImageGenerationTaskView, TaskResult, TaskStatus,
 };
-use crate::domain::ports::{RuntimeDiffusionImageRequest, RuntimeRawImageInput};
+use crate::domain::ports::{
+    RuntimeDiffusionImageRequest, RuntimeDiffusionImageResult, RuntimeInferenceGateway,
+    RuntimeRawImageInput,
+};
 use crate::domain::services::model;
 use crate::domain::services::task::{
     cleanup_dir, parse_json_payload, read_managed_file, save_rgb_png, serialize_json_payload,
@@ -22,6 +26,7 @@ use crate::infra::db::{
 };
 
 const DEFAULT_IMAGE_BACKEND_ID: RuntimeBackendId = RuntimeBackendId::GgmlDiffusion;
+const PREVIEW_FILE_NAME: &str = "preview.png";
 
 #[derive(Clone)]
 pub struct ImageService {
@@ -131,6 +136,7 @@ impl ImageService {
             clip_skip: req.clip_skip,
             strength: effective_strength,
             eta: req.eta,
+            preview_interval: req.preview_interval,
         };
 
         let now = chrono::Utc::now();
@@ -196,7 +202,16 @@ impl ImageService {
                 }
             };
 
-            let rpc_result = worker_state.runtime().generate_image(runtime_request).await;
+            let rpc_result = if runtime_request.preview_interval.is_some() {
+                generate_image_with_previews(
+                    worker_state.runtime().as_ref(),
+                    runtime_request,
+                    &task_output_dir,
+                )
+                .await
+            } else {
+                worker_state.runtime().generate_image(runtime_request).await
+            };
             if operation.is_cancelled().await {
                 cleanup_dir(&task_output_dir).await;
                 return;
@@ -235,6 +250,11 @@ impl ImageService {
                                 .map(|index| format!("/v1/images/generations/{operation_id}/artifacts/{index}"))
                                 .collect(),
                         ),
+                        seeds: payload
+                            .images
+                            .iter()
+                            .map(|image| image.seed)
+                            .collect::<Option<Vec<_>>>(),
                         video_path: None,
                         output_path: None,
                         text: None,
@@ -318,6 +338,19 @@ impl ImageService {
         read_managed_file(path, &self.output_root()).await
     }
 
+    /// Latest low-resolution preview of a generation started with
+    /// `preview_interval`.
+    pub async fn read_preview_image(&self, task_id: &str) -> Result<Vec<u8>, AppCoreError> {
+        if self.state.store().get_image_generation_task(task_id).await?.is_none() {
+            return Err(AppCoreError::NotFound(format!(
+                "image generation task {task_id} not found"
+            )));
+        }
+        let output_root = self.output_root();
+        let path = image_task_dir(&output_root, task_id).join(PREVIEW_FILE_NAME);
+        read_managed_file(&path.to_string_lossy(), &output_root).await
+    }
+
     pub async fn read_reference_image(&self, task_id: &str) -> Result<Vec<u8>, AppCoreError> {
         let row =
             self.state.store().get_image_generation_task(task_id).await?.ok_or_else(|| {
@@ -396,6 +429,35 @@ fn map_image_view(row: ImageGenerationTaskViewRecord) -> ImageGenerationTaskView
     }
 }
 
+/// Runs a streamed generation, keeping the latest preview in the task
+/// directory so `read_preview_image` can serve it while sampling continues.
+async fn generate_image_with_previews(
+    runtime: &dyn RuntimeInferenceGateway,
+    request: RuntimeDiffusionImageRequest,
+    task_output_dir: &Path,
+) -> Result<RuntimeDiffusionImageResult, AppCoreError> {
+    let mut stream = runtime.generate_image_stream(request).await?;
+    while let Some(chunk) = stream.next().await {
+        let chunk = chunk?;
+        if let Some(preview) = chunk.preview_png
+            && let Err(error) = write_preview(task_output_dir, &preview).await
+        {
+            warn!(error = %error, "failed to write image generation preview");
+        }
+        if let Some(result) = chunk.result {
+            return Ok(result);
+        }
+    }
+    Err(AppCoreError::Internal("diffusion image stream ended without a result".to_owned()))
+}
+
+/// Replaces the preview through a rename so readers never see a partial PNG.
+async fn write_preview(task_output_dir: &Path, bytes: &[u8]) -> std::io::Result<()> {
+    let staging = task_output_dir.join(format!("{PREVIEW_FILE_NAME}.tmp"));
+    tokio::fs::write(&staging, bytes).await?;
+    tokio::fs::rename(&staging, task_output_dir.join(PREVIEW_FILE_NAME)).await
+}
+
 fn image_task_dir(output_root: &Path, task_id: &str) -> PathBuf {
     output_root.join("images").join(task_id)
 }
@@ -19,7 +19,9 @@ mod video;
 mod workspace;
 
 pub use agent::AgentService;
-pub use audio::AudioService;
+pub use audio::{
+    AudioService, AudioUploadWriter, MAX_AUDIO_UPLOAD_BATCH_BYTES, MAX_AUDIO_UPLOAD_FILE_BYTES,
+};
 pub use backend::BackendService;
 pub use chat::ChatService;
 pub use ffmpeg::FfmpegService;
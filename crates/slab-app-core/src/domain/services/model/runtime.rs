@@ -1,4 +1,5 @@
 use std::path::{Path, PathBuf};
+use std::time::Duration;
 
 use slab_types::load_config::{
     CandleDiffusionLoadConfig, CandleLlamaLoadConfig, CandleWhisperLoadConfig,
@@ -6,7 +7,7 @@ use slab_types::load_config::{
 };
 use slab_types::runtime::DiffusionLoadOptions;
 use slab_types::{RuntimeBackendId, RuntimeBackendLoadSpec};
-use tracing::info;
+use tracing::{info, warn};
 
 use crate::context::{ModelState, WorkerState};
 use crate::domain::models::{
@@ -62,6 +63,40 @@ impl ModelService {
         decode_model_status(response)
     }
 
+    /// Prepare backends for process exit.
+    ///
+    /// Waits up to `drain_timeout` for in-flight operations to reach a terminal
+    /// state, then unloads every resident model so backends release their
+    /// memory. Failures are logged instead of returned so shutdown always
+    /// proceeds.
+    pub async fn drain_and_unload(&self, drain_timeout: Duration) {
+        let operations = self.worker_state.operations();
+        if !operations.wait_idle(drain_timeout).await {
+            warn!(
+                remaining = operations.len(),
+                timeout_ms = drain_timeout.as_millis() as u64,
+                "in-flight operations did not drain before shutdown"
+            );
+        }
+
+        let auto_unload = self.model_state.auto_unload();
+        let runtime = self.model_state.runtime();
+        for backend_id in auto_unload.resident_backends().await {
+            if !runtime.backend_available(backend_id) {
+                continue;
+            }
+            match runtime.unload_model(backend_id).await {
+                Ok(_) => {
+                    auto_unload.notify_model_unloaded(backend_id).await;
+                    info!(backend = %backend_id, "unloaded model for shutdown");
+                }
+                Err(error) => {
+                    warn!(backend = %backend_id, error = %error, "failed to unload model for shutdown");
+                }
+            }
+        }
+    }
+
     pub async fn switch_model(
         &self,
         command: ModelLoadCommand,
@@ -279,6 +314,7 @@ pub(super) fn decode_model_status(
         status: response.status,
         context_length: response.context_length,
         training_context_length: response.training_context_length,
+        version: response.version,
     })
 }
 
@@ -349,6 +385,8 @@ async fn load_model_candidate(
         resolve_llama_context_length(state, candidate.backend_id).await?
     };
     let flash_attn = resolve_backend_flash_attn(state, candidate.backend_id);
+    let least_loaded_sessions = state.pmid().config().runtime.llama.least_loaded_sessions;
+    let stop_on_context_full = state.pmid().config().runtime.llama.stop_on_context_full;
     let diffusion = if let Some(defaults) = candidate.pack_load_defaults.as_ref() {
         model_packs::merge_diffusion_load_defaults(
             defaults.diffusion.clone(),
@@ -385,6 +423,8 @@ async fn load_model_candidate(
                 .as_ref()
                 .and_then(|defaults| defaults.gbnf_source.clone()),
             flash_attn,
+            least_loaded_sessions,
+            stop_on_context_full,
             diffusion,
         },
     )?;
@@ -455,6 +495,8 @@ struct BackendLoadSpecOptions {
     chat_template: Option<String>,
     gbnf: Option<String>,
     flash_attn: bool,
+    least_loaded_sessions: bool,
+    stop_on_context_full: bool,
     diffusion: Option<DiffusionLoadOptions>,
 }
 
@@ -470,6 +512,8 @@ fn build_backend_load_spec(
         chat_template,
         gbnf,
         flash_attn,
+        least_loaded_sessions,
+        stop_on_context_full,
         diffusion,
     } = options;
 
@@ -485,6 +529,8 @@ fn build_backend_load_spec(
             flash_attn,
             chat_template,
             gbnf,
+            least_loaded_sessions,
+            stop_on_context_full,
         })),
         RuntimeBackendId::GgmlWhisper => {
             Ok(RuntimeBackendLoadSpec::GgmlWhisper(GgmlWhisperLoadConfig {
@@ -1,12 +1,15 @@
 use chrono::Utc;
 use slab_types::{RuntimeBackendId, RuntimeBackendLoadSpec};
 use std::path::{Path, PathBuf};
+use std::time::Duration;
 
+use crate::context::SubmitOperation;
 use crate::domain::models::{
-    ManagedModelBackendId, ModelLoadCommand, ModelSpec, UnifiedModel, UnifiedModelKind,
+    ManagedModelBackendId, ModelLoadCommand, ModelSpec, TaskStatus, UnifiedModel, UnifiedModelKind,
     UnifiedModelStatus,
 };
 use crate::error::{AppCoreError, AppCoreErrorData};
+use crate::infra::db::TaskStore;
 use crate::infra::model_packs;
 use crate::test_support::{TestAppCore, ready_local_llama_command};
 
@@ -263,3 +266,49 @@ async fn model_runtime_rejects_manual_unload_while_inference_active() {
     );
     assert!(app.runtime.unloads().is_empty());
 }
+
+#[tokio::test]
+async fn model_runtime_shutdown_drains_operations_before_unloading() {
+    let app = TestAppCore::new().await;
+    let model_path = app.write_model_file("runtime-shutdown.gguf");
+    let model = app
+        .model
+        .create_model(ready_local_llama_command("runtime-shutdown", &model_path))
+        .await
+        .expect("create runtime model");
+    app.runtime.allow_backend(RuntimeBackendId::GgmlLlama);
+    app.model
+        .load_model(ModelLoadCommand {
+            model_id: Some(model.id),
+            backend_id: None,
+            model_path: None,
+            num_workers: None,
+        })
+        .await
+        .expect("load catalog model");
+
+    let (release_tx, release_rx) = tokio::sync::oneshot::channel::<()>();
+    let operation_id = app
+        .worker_state
+        .submit_operation(SubmitOperation::running("test", None, None), |operation| async move {
+            let _ = release_rx.await;
+            operation.mark_succeeded("{}").await.expect("mark operation succeeded");
+        })
+        .await
+        .expect("submit operation");
+
+    let shutdown = tokio::spawn({
+        let model = app.model.clone();
+        async move { model.drain_and_unload(Duration::from_secs(5)).await }
+    });
+    tokio::task::yield_now().await;
+    assert!(app.runtime.unloads().is_empty(), "models must stay loaded while work is in flight");
+
+    release_tx.send(()).expect("release operation");
+    shutdown.await.expect("shutdown task");
+
+    let record = app.store.get_task(&operation_id).await.expect("get task").expect("task exists");
+    assert_eq!(record.status, TaskStatus::Succeeded);
+    assert!(app.worker_state.operations().is_empty());
+    assert_eq!(app.runtime.unloads(), vec![RuntimeBackendId::GgmlLlama]);
+}
@@ -285,6 +285,7 @@ impl VideoService {
                         let task_result = TaskResult {
                             image: None,
                             images: None,
+                            seeds: None,
                             video_path: Some(format!("/v1/video/generations/{operation_id}/artifact")),
                             output_path: None,
                             text: None,
@@ -459,6 +459,7 @@ mod tests {
             cloud_http_trace: false,
             queue_capacity: 1,
             backend_capacity: 1,
+            max_completion_tokens: None,
             enable_swagger: false,
             cors_allowed_origins: None,
             admin_api_token: None,
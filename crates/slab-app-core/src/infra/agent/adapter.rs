@@ -263,13 +263,21 @@ fn chat_command_from_agent_config(
             top_k: config.top_k,
             min_p: config.min_p,
             presence_penalty: config.presence_penalty,
+            frequency_penalty: None,
             repetition_penalty: config.repetition_penalty,
+            repeat_last_n: None,
+            n_keep: None,
+            seed: None,
             n: 1,
             stream,
             stop: vec![],
             stream_options: ChatStreamOptions::default(),
         },
-        local: LocalChatParams { gbnf: None, structured_output: config.structured_output.clone() },
+        local: LocalChatParams {
+            gbnf: None,
+            structured_output: config.structured_output.clone(),
+            ..Default::default()
+        },
         cloud: CloudChatParams {
             reasoning_effort: config.reasoning_effort,
             verbosity: config.verbosity,
@@ -364,9 +372,9 @@ async fn llm_response_from_chat_stream(
             trace_context,
             "slab-app-core",
             "chat_stream_chunk",
-            serde_json::json!({ "data": &chunk }),
+            serde_json::json!({ "data": &chunk.data }),
         );
-        for delta in assembler.ingest_data(&chunk)? {
+        for delta in assembler.ingest_data(&chunk.data)? {
             match delta {
                 AgentStreamDelta::Text(text) => observer.on_text_delta(&text).await?,
                 AgentStreamDelta::Reasoning(reasoning) => {
@@ -449,7 +457,10 @@ fn record_chat_command(
                 "top_k": command.common.top_k,
                 "min_p": command.common.min_p,
                 "presence_penalty": command.common.presence_penalty,
+                "frequency_penalty": command.common.frequency_penalty,
                 "repetition_penalty": command.common.repetition_penalty,
+                "repeat_last_n": command.common.repeat_last_n,
+                "n_keep": command.common.n_keep,
                 "n": command.common.n,
                 "stream": command.common.stream,
                 "stop": command.common.stop,
@@ -505,7 +516,7 @@ fn parsed_tool_calls_payload(tool_calls: &[ParsedToolCall]) -> Vec<Value> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::domain::models::ChatResultChoice;
+    use crate::domain::models::{ChatResultChoice, ChatStreamEvent, TextGenerationUsage};
 
     fn text_message(role: &str, content: &str) -> ConversationMessage {
         ConversationMessage {
@@ -660,11 +671,30 @@ mod tests {
             }
         }
 
+        let chunk = |event, data: &str| ChatStreamChunk { event, data: data.to_owned() };
         let stream = futures::stream::iter([
-            r#"{"choices":[{"delta":{"reasoning_content":"plan "}}]}"#.to_owned(),
-            r#"{"choices":[{"delta":{"reasoning_content":"done","content":"answer"}}]}"#.to_owned(),
-            r#"{"choices":[{"delta":{},"finish_reason":"stop"}]}"#.to_owned(),
-            r#"{"choices":[],"usage":{"prompt_tokens":2,"completion_tokens":3,"total_tokens":5,"estimated":true}}"#.to_owned(),
+            chunk(
+                ChatStreamEvent::Reasoning("plan ".to_owned()),
+                r#"{"choices":[{"delta":{"reasoning_content":"plan "}}]}"#,
+            ),
+            chunk(
+                ChatStreamEvent::Content("answer".to_owned()),
+                r#"{"choices":[{"delta":{"reasoning_content":"done","content":"answer"}}]}"#,
+            ),
+            chunk(
+                ChatStreamEvent::Finish("stop".to_owned()),
+                r#"{"choices":[{"delta":{},"finish_reason":"stop"}]}"#,
+            ),
+            chunk(
+                ChatStreamEvent::Usage(TextGenerationUsage {
+                    prompt_tokens: 2,
+                    completion_tokens: 3,
+                    total_tokens: 5,
+                    estimated: true,
+                    ..Default::default()
+                }),
+                r#"{"choices":[],"usage":{"prompt_tokens":2,"completion_tokens":3,"total_tokens":5,"estimated":true}}"#,
+            ),
         ])
         .boxed();
         let mut observer = RecordingObserver {
@@ -295,7 +295,11 @@ impl AgentMemoryPipeline {
                 top_k: None,
                 min_p: None,
                 presence_penalty: None,
+                frequency_penalty: None,
                 repetition_penalty: None,
+                repeat_last_n: None,
+                n_keep: None,
+                seed: None,
                 n: 1,
                 stream: false,
                 stop: Vec::new(),
@@ -304,6 +308,7 @@ impl AgentMemoryPipeline {
             local: LocalChatParams {
                 gbnf: None,
                 structured_output: Some(StructuredOutput::JsonObject),
+                ..Default::default()
             },
             cloud: CloudChatParams {
                 reasoning_effort: None,
@@ -103,6 +103,13 @@ pub trait MediaTaskStore: Send + Sync + 'static {
         audio_task: NewAudioTranscriptionTaskRecord,
     ) -> impl Future<Output = Result<(), sqlx::Error>> + Send;
 
+    /// Inserts several audio transcription operations in one transaction, so
+    /// either every task is recorded or none is.
+    fn insert_audio_transcription_operations(
+        &self,
+        operations: Vec<(TaskRecord, NewAudioTranscriptionTaskRecord)>,
+    ) -> impl Future<Output = Result<(), sqlx::Error>> + Send;
+
     fn update_image_generation_result(
         &self,
         task_id: &str,
@@ -219,28 +226,37 @@ impl MediaTaskStore for AnyStore {
         &self,
         task: TaskRecord,
         audio_task: NewAudioTranscriptionTaskRecord,
+    ) -> Result<(), sqlx::Error> {
+        self.insert_audio_transcription_operations(vec![(task, audio_task)]).await
+    }
+
+    async fn insert_audio_transcription_operations(
+        &self,
+        operations: Vec<(TaskRecord, NewAudioTranscriptionTaskRecord)>,
     ) -> Result<(), sqlx::Error> {
         let mut tx = self.pool.begin().await?;
-        super::insert_task_row(&mut tx, &task, task.result_data.as_deref()).await?;
-        sqlx::query(
-            "INSERT INTO audio_transcription_tasks \
-             (task_id, backend_id, model_id, source_path, language, prompt, detect_language, vad_json, decode_json, transcript_text, request_data, created_at, updated_at) \
-             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, NULL, ?10, ?11, ?12)",
-        )
-        .bind(&audio_task.task_id)
-        .bind(&audio_task.backend_id)
-        .bind(&audio_task.model_id)
-        .bind(&audio_task.source_path)
-        .bind(&audio_task.language)
-        .bind(&audio_task.prompt)
-        .bind(audio_task.detect_language.map(|value| if value { 1_i64 } else { 0_i64 }))
-        .bind(&audio_task.vad_json)
-        .bind(&audio_task.decode_json)
-        .bind(&audio_task.request_data)
-        .bind(audio_task.created_at.to_rfc3339())
-        .bind(audio_task.updated_at.to_rfc3339())
-        .execute(&mut *tx)
-        .await?;
+        for (task, audio_task) in &operations {
+            super::insert_task_row(&mut tx, task, task.result_data.as_deref()).await?;
+            sqlx::query(
+                "INSERT INTO audio_transcription_tasks \
+                 (task_id, backend_id, model_id, source_path, language, prompt, detect_language, vad_json, decode_json, transcript_text, request_data, created_at, updated_at) \
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, NULL, ?10, ?11, ?12)",
+            )
+            .bind(&audio_task.task_id)
+            .bind(&audio_task.backend_id)
+            .bind(&audio_task.model_id)
+            .bind(&audio_task.source_path)
+            .bind(&audio_task.language)
+            .bind(&audio_task.prompt)
+            .bind(audio_task.detect_language.map(|value| if value { 1_i64 } else { 0_i64 }))
+            .bind(&audio_task.vad_json)
+            .bind(&audio_task.decode_json)
+            .bind(&audio_task.request_data)
+            .bind(audio_task.created_at.to_rfc3339())
+            .bind(audio_task.updated_at.to_rfc3339())
+            .execute(&mut *tx)
+            .await?;
+        }
         tx.commit().await?;
         Ok(())
     }
@@ -821,6 +837,47 @@ mod tests {
         assert_eq!(list[0].task.task_id, "audio-task");
     }
 
+    #[tokio::test]
+    async fn audio_transcription_batch_insert_is_all_or_nothing() {
+        let store = migrated_test_store().await;
+        let created_at = timestamp();
+        let audio_task = |id: &str| NewAudioTranscriptionTaskRecord {
+            task_id: id.to_owned(),
+            backend_id: "ggml.whisper".to_owned(),
+            model_id: None,
+            source_path: format!("{id}.wav"),
+            language: None,
+            prompt: None,
+            detect_language: None,
+            vad_json: None,
+            decode_json: None,
+            request_data: "{}".to_owned(),
+            created_at,
+            updated_at: created_at,
+        };
+
+        let error = store
+            .insert_audio_transcription_operations(vec![
+                (task_record("audio-a", AUDIO_TRANSCRIPTION_TASK_TYPE), audio_task("audio-a")),
+                (task_record("audio-a", AUDIO_TRANSCRIPTION_TASK_TYPE), audio_task("audio-a")),
+            ])
+            .await;
+        assert!(error.is_err());
+        assert!(store.list_audio_transcription_tasks().await.expect("list audio tasks").is_empty());
+
+        store
+            .insert_audio_transcription_operations(vec![
+                (task_record("audio-a", AUDIO_TRANSCRIPTION_TASK_TYPE), audio_task("audio-a")),
+                (task_record("audio-b", AUDIO_TRANSCRIPTION_TASK_TYPE), audio_task("audio-b")),
+            ])
+            .await
+            .expect("insert audio batch");
+        assert_eq!(
+            store.list_audio_transcription_tasks().await.expect("list audio tasks").len(),
+            2
+        );
+    }
+
     fn task_record(id: &str, task_type: &str) -> TaskRecord {
         let now = timestamp();
         TaskRecord {
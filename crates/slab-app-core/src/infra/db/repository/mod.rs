@@ -32,6 +32,7 @@ pub struct SqlxStore {
 
 impl SqlxStore {
     pub async fn connect(url: &str) -> Result<Self, sqlx::Error> {
+        reject_unsupported_database_url(url)?;
         let options = sqlx::sqlite::SqliteConnectOptions::from_str(url)?
             .foreign_keys(true)
             .journal_mode(sqlx::sqlite::SqliteJournalMode::Wal)
@@ -44,6 +45,20 @@ impl SqlxStore {
 
 pub type AnyStore = SqlxStore;
 
+/// The repositories and migrations are written against SQLite (`?N` placeholders,
+/// `INTEGER` affinity, SQLite table-rebuild migrations). Fail fast with a clear
+/// message instead of letting the SQLite URL parser reject other schemes.
+fn reject_unsupported_database_url(url: &str) -> Result<(), sqlx::Error> {
+    let scheme = url.split_once(':').map(|(scheme, _)| scheme.to_ascii_lowercase());
+    match scheme.as_deref() {
+        Some("postgres" | "postgresql") => Err(sqlx::Error::Configuration(
+            "PostgreSQL database URLs are not supported yet; the store only ships SQLite migrations"
+                .into(),
+        )),
+        _ => Ok(()),
+    }
+}
+
 async fn insert_task_row(
     tx: &mut sqlx::Transaction<'_, sqlx::Sqlite>,
     record: &TaskRecord,
@@ -101,6 +116,15 @@ mod tests {
         assert_eq!(foreign_keys, 1);
     }
 
+    #[tokio::test]
+    async fn connect_rejects_postgres_urls_with_clear_error() {
+        for url in ["postgres://localhost/slab", "PostgreSQL://user@db/slab"] {
+            let error = SqlxStore::connect(url).await.expect_err("postgres url must be rejected");
+            assert!(matches!(error, sqlx::Error::Configuration(_)), "unexpected error: {error}");
+            assert!(error.to_string().contains("PostgreSQL"), "unexpected error: {error}");
+        }
+    }
+
     #[tokio::test]
     async fn migrations_apply_expected_constraints_and_indexes() {
         let pool = migrated_test_pool().await;
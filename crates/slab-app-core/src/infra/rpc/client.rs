@@ -352,6 +352,21 @@ pub async fn generate_image(
     Ok(response.into_inner())
 }
 
+pub async fn generate_image_stream(
+    channel: Channel,
+    req: pb::GgmlDiffusionGenerateImageRequest,
+) -> anyhow::Result<tonic::Streaming<pb::GgmlDiffusionGenerateImageStreamChunk>> {
+    debug!("sending gRPC ggml diffusion generate_image_stream request");
+    let response = call_initial_response_with_retry("generate_image_stream", || {
+        let (mut client, request_id) = ggml_diffusion_client(channel.clone());
+        let request = with_request_timeout(req.clone());
+        (async move { client.generate_image_stream(request).await }, request_id)
+    })
+    .await
+    .map_err(|status| grpc_status_to_anyhow("generate_image_stream", "retry-exhausted", status))?;
+    Ok(response.into_inner())
+}
+
 pub async fn candle_generate_image(
     channel: Channel,
     req: pb::CandleDiffusionGenerateImageRequest,
@@ -62,6 +62,8 @@ pub fn encode_model_load_request(spec: &RuntimeBackendLoadSpec) -> ModelLoadRpcR
                 chat_template: non_empty_string(config.chat_template.as_deref()),
                 gbnf: non_empty_string(config.gbnf.as_deref()),
                 flash_attn: Some(config.flash_attn),
+                least_loaded_sessions: Some(config.least_loaded_sessions),
+                stop_on_context_full: Some(config.stop_on_context_full),
             })
         }
         RuntimeBackendLoadSpec::GgmlWhisper(config) => {
@@ -8,11 +8,11 @@ use slab_types::{RuntimeBackendId, RuntimeBackendLoadSpec};
 use tonic::transport::Channel;
 
 use crate::domain::ports::{
-    RuntimeBackendStatus, RuntimeDiffusionImageRequest, RuntimeDiffusionImageResult,
-    RuntimeDiffusionVideoRequest, RuntimeDiffusionVideoResult, RuntimeInferenceGateway,
-    RuntimeTextGenerationChunk, RuntimeTextGenerationRequest, RuntimeTextGenerationResponse,
-    RuntimeTranscriptionDecodeOptions, RuntimeTranscriptionRequest, RuntimeTranscriptionResult,
-    RuntimeTranscriptionVadOptions, RuntimeTranscriptionVadParams,
+    RuntimeBackendStatus, RuntimeDiffusionImageChunk, RuntimeDiffusionImageRequest,
+    RuntimeDiffusionImageResult, RuntimeDiffusionVideoRequest, RuntimeDiffusionVideoResult,
+    RuntimeInferenceGateway, RuntimeTextGenerationChunk, RuntimeTextGenerationRequest,
+    RuntimeTextGenerationResponse, RuntimeTranscriptionDecodeOptions, RuntimeTranscriptionRequest,
+    RuntimeTranscriptionResult, RuntimeTranscriptionVadOptions, RuntimeTranscriptionVadParams,
 };
 use crate::error::AppCoreError;
 use crate::error::AppCoreErrorData;
@@ -180,6 +180,36 @@ impl RuntimeInferenceGateway for GrpcRuntimeInferenceGateway {
         }
     }
 
+    async fn generate_image_stream(
+        &self,
+        request: RuntimeDiffusionImageRequest,
+    ) -> Result<BoxStream<'static, Result<RuntimeDiffusionImageChunk, AppCoreError>>, AppCoreError>
+    {
+        let backend_id = request.backend_id.unwrap_or(RuntimeBackendId::GgmlDiffusion);
+        match backend_id {
+            RuntimeBackendId::GgmlDiffusion => {
+                let channel = self.channel(backend_id)?;
+                let grpc_request = runtime_protocol::encode_diffusion_image_request(&request);
+                let stream = client::generate_image_stream(channel, grpc_request)
+                    .await
+                    .map_err(map_runtime_error("generate image stream"))?;
+                Ok(stream
+                    .map(|chunk| {
+                        let chunk = chunk.map_err(map_runtime_status("generate image stream"))?;
+                        runtime_protocol::decode_diffusion_image_stream_chunk(&chunk).map_err(
+                            |error| {
+                                AppCoreError::Internal(format!(
+                                    "invalid diffusion image stream payload: {error}"
+                                ))
+                            },
+                        )
+                    })
+                    .boxed())
+            }
+            other => Err(unsupported_inference_backend("generate image stream", other)),
+        }
+    }
+
     async fn generate_video(
         &self,
         request: RuntimeDiffusionVideoRequest,
@@ -428,6 +458,7 @@ fn pb_whisper_decode_options_from_runtime(
         logprob_thold: value.logprob_thold,
         no_speech_thold: value.no_speech_thold,
         tdrz_enable: value.tdrz_enable,
+        translate: value.translate,
     }
 }
 
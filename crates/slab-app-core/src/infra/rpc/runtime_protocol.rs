@@ -4,11 +4,11 @@ use image::{DynamicImage, ImageFormat};
 
 use crate::domain::models::TimedTextSegment;
 use crate::domain::ports::{
-    RuntimeBackendStatus, RuntimeDiffusionImageRequest, RuntimeDiffusionImageResult,
-    RuntimeDiffusionVideoRequest, RuntimeDiffusionVideoResult, RuntimeGeneratedFrame,
-    RuntimeGeneratedImage, RuntimeJsonOptions, RuntimeRawImageInput, RuntimeTextGenerationChunk,
-    RuntimeTextGenerationRequest, RuntimeTextGenerationResponse, RuntimeTextGenerationUsage,
-    RuntimeTextPromptTokensDetails, RuntimeTranscriptionResult,
+    RuntimeBackendStatus, RuntimeDiffusionImageChunk, RuntimeDiffusionImageRequest,
+    RuntimeDiffusionImageResult, RuntimeDiffusionVideoRequest, RuntimeDiffusionVideoResult,
+    RuntimeGeneratedFrame, RuntimeGeneratedImage, RuntimeJsonOptions, RuntimeRawImageInput,
+    RuntimeTextGenerationChunk, RuntimeTextGenerationRequest, RuntimeTextGenerationResponse,
+    RuntimeTextGenerationUsage, RuntimeTextPromptTokensDetails, RuntimeTranscriptionResult,
 };
 use slab_types::RuntimeBackendId;
 
@@ -32,11 +32,15 @@ pub fn encode_chat_request(request: &RuntimeTextGenerationRequest) -> pb::GgmlLl
         top_k: request.top_k,
         min_p: request.min_p,
         presence_penalty: request.presence_penalty,
+        frequency_penalty: request.frequency_penalty,
         repetition_penalty: request.repetition_penalty,
+        repeat_last_n: request.repeat_last_n,
+        n_keep: request.n_keep,
         session_key: request.session_key.clone(),
         gbnf: request.gbnf.clone(),
         stop_sequences: Some(pb::StringList { values: request.stop_sequences.clone() }),
         ignore_eos: None,
+        seed: request.seed,
         logit_bias_json: None,
         agent_trace_json: request
             .agent_trace
@@ -130,6 +134,7 @@ pub fn encode_diffusion_image_request(
         clip_skip: request.clip_skip,
         strength: request.strength,
         eta: request.eta,
+        preview_interval: request.preview_interval,
     }
 }
 
@@ -175,12 +180,14 @@ pub fn decode_diffusion_image_response(
     let images = response
         .images
         .iter()
-        .map(|image| {
+        .enumerate()
+        .map(|(index, image)| {
             Ok(RuntimeGeneratedImage {
                 bytes: raw_image_to_png_bytes(image)?,
                 width: required_u32(image.width, "images[].width")?,
                 height: required_u32(image.height, "images[].height")?,
                 channels: required_u8(image.channels, "images[].channels")?,
+                seed: response.seeds.get(index).copied(),
             })
         })
         .collect::<Result<Vec<_>, RpcCodecError>>()?;
@@ -188,6 +195,26 @@ pub fn decode_diffusion_image_response(
     Ok(RuntimeDiffusionImageResult { images, metadata: RuntimeJsonOptions::default() })
 }
 
+pub fn decode_diffusion_image_stream_chunk(
+    chunk: &pb::GgmlDiffusionGenerateImageStreamChunk,
+) -> Result<RuntimeDiffusionImageChunk, RpcCodecError> {
+    let result = if chunk.done.unwrap_or_default() {
+        Some(decode_diffusion_image_response(&pb::GgmlDiffusionGenerateImageResponse {
+            images: chunk.images.clone(),
+            seeds: chunk.seeds.clone(),
+        })?)
+    } else {
+        None
+    };
+
+    Ok(RuntimeDiffusionImageChunk {
+        step: chunk.step,
+        total_steps: chunk.total_steps,
+        preview_png: chunk.preview_png.clone(),
+        result,
+    })
+}
+
 pub fn decode_candle_diffusion_image_response(
     response: &pb::CandleDiffusionGenerateImageResponse,
 ) -> Result<RuntimeDiffusionImageResult, RpcCodecError> {
@@ -200,6 +227,7 @@ pub fn decode_candle_diffusion_image_response(
                 width: required_u32(image.width, "images[].width")?,
                 height: required_u32(image.height, "images[].height")?,
                 channels: required_u8(image.channels, "images[].channels")?,
+                seed: None,
             })
         })
         .collect::<Result<Vec<_>, RpcCodecError>>()?;
@@ -292,6 +320,7 @@ pub fn decode_model_status_response(
         status: response.status.clone(),
         context_length: response.context_length,
         training_context_length: response.training_context_length,
+        version: response.version.clone(),
     })
 }
 
@@ -490,6 +519,18 @@ mod tests {
         );
     }
 
+    #[test]
+    fn encode_chat_request_forwards_sampler_seed() {
+        let request = RuntimeTextGenerationRequest {
+            prompt: "hello".to_owned(),
+            seed: Some(42),
+            ..Default::default()
+        };
+
+        assert_eq!(encode_chat_request(&request).seed, Some(42));
+        assert_eq!(encode_chat_request(&RuntimeTextGenerationRequest::default()).seed, None);
+    }
+
     #[test]
     fn decode_chat_stream_chunk_falls_back_to_legacy_reasoning() {
         let chunk = pb::GgmlLlamaChatStreamChunk {
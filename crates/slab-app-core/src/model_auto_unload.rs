@@ -191,6 +191,18 @@ impl ModelAutoUnloadManager {
         debug!(backend = %backend, "model unload state updated (manual)");
     }
 
+    /// Backends that currently hold a loaded model.
+    pub async fn resident_backends(&self) -> Vec<RuntimeBackendId> {
+        let states = self.states.lock().await;
+        let mut backends = states
+            .iter()
+            .filter(|(_, state)| state.resident)
+            .map(|(backend_id, _)| *backend_id)
+            .collect::<Vec<_>>();
+        backends.sort_by_key(|backend_id| backend_id.canonical_id());
+        backends
+    }
+
     pub async fn ensure_idle_for_manual_unload(
         &self,
         backend_id: RuntimeBackendId,
@@ -3,7 +3,7 @@ use utoipa::ToSchema;
 use validator::{Validate, ValidationError};
 
 use crate::domain::models::{
-    AudioTranscriptionCommand,
+    AcceptedAudioUpload, AudioTranscriptionCommand,
     AudioTranscriptionRequestData as DomainAudioTranscriptionRequestData,
     AudioTranscriptionResultData as DomainAudioTranscriptionResultData, AudioTranscriptionTaskView,
     TranscribeDecodeOptions, TranscribeVadOptions,
@@ -142,6 +142,9 @@ pub struct TranscribeDecodeRequest {
     /// Enable tinydiarize speaker turn detection.
     #[serde(skip_serializing_if = "Option::is_none", default)]
     pub tdrz_enable: Option<bool>,
+    /// Translate the transcript into English.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub translate: Option<bool>,
 }
 
 fn validate_vad_request(request: &TranscribeVadRequest) -> Result<(), ValidationError> {
@@ -196,6 +199,7 @@ impl From<TranscribeDecodeRequest> for TranscribeDecodeOptions {
             logprob_thold: request.logprob_thold,
             no_speech_thold: request.no_speech_thold,
             tdrz_enable: request.tdrz_enable,
+            translate: request.translate,
         }
     }
 }
@@ -214,6 +218,20 @@ impl From<AudioTranscriptionRequest> for AudioTranscriptionCommand {
     }
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct AudioTranscriptionBatchItemResponse {
+    /// Original name of the uploaded file.
+    pub filename: String,
+    /// Transcription task created for the file.
+    pub task_id: String,
+}
+
+impl From<AcceptedAudioUpload> for AudioTranscriptionBatchItemResponse {
+    fn from(upload: AcceptedAudioUpload) -> Self {
+        Self { filename: upload.filename, task_id: upload.operation_id }
+    }
+}
+
 fn normalize_optional_text(value: Option<String>) -> Option<String> {
     value.and_then(|value| {
         let trimmed = value.trim();
@@ -274,6 +292,8 @@ pub struct TranscribeDecodeOptionsResponse {
     pub no_speech_thold: Option<f32>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tdrz_enable: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub translate: Option<bool>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
@@ -393,6 +413,7 @@ impl From<TranscribeDecodeOptions> for TranscribeDecodeOptionsResponse {
             logprob_thold: value.logprob_thold,
             no_speech_thold: value.no_speech_thold,
             tdrz_enable: value.tdrz_enable,
+            translate: value.translate,
         }
     }
 }
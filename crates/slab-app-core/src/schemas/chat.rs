@@ -23,7 +23,8 @@ use crate::domain::models::{
     ConversationMessageContent as DomainConversationMessageContent,
     ConversationToolCall as DomainConversationToolCall,
     ConversationToolFunction as DomainConversationToolFunction,
-    LocalChatParams as DomainLocalChatParams, StructuredOutput as DomainStructuredOutput,
+    LocalChatParams as DomainLocalChatParams, LocalToolChoice as DomainLocalToolChoice,
+    StructuredOutput as DomainStructuredOutput,
     StructuredOutputJsonSchema as DomainStructuredOutputJsonSchema,
     TextCompletionCommand as DomainTextCompletionCommand,
     TextCompletionResult as DomainTextCompletionResult,
@@ -100,6 +101,57 @@ fn default_tool_call_type() -> String {
     "function".to_owned()
 }
 
+/// Function definition offered to the model through `tools`.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ChatFunctionDefinition {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub description: Option<String>,
+    /// JSON Schema for the call arguments.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub parameters: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub strict: Option<bool>,
+}
+
+/// OpenAI-style tool declaration accepted by `POST /v1/chat/completions`.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ChatTool {
+    #[serde(default = "default_tool_call_type")]
+    pub r#type: String,
+    pub function: ChatFunctionDefinition,
+}
+
+/// Controls whether and which tool the model calls: `"none"`, `"auto"`
+/// (the default when tools are given), `"required"`, or a named function.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(untagged)]
+pub enum ChatToolChoice {
+    Mode(ChatToolChoiceMode),
+    Named(ChatNamedToolChoice),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ChatToolChoiceMode {
+    None,
+    Auto,
+    Required,
+}
+
+/// Forces a call of one specific function.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct ChatNamedToolChoice {
+    #[serde(default = "default_tool_call_type")]
+    pub r#type: String,
+    pub function: ChatNamedToolChoiceFunction,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct ChatNamedToolChoiceFunction {
+    pub name: String,
+}
+
 /// A single message in the conversation history.
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema, Validate)]
 #[validate(schema(function = "validate_chat_message"))]
@@ -325,10 +377,30 @@ pub struct ChatCompletionRequest {
         message = "presence_penalty must be between -2.0 and 2.0"
     ))]
     pub presence_penalty: Option<f32>,
+    /// Frequency penalty for local llama backends.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(range(
+        min = -2.0,
+        max = 2.0,
+        message = "frequency_penalty must be between -2.0 and 2.0"
+    ))]
+    pub frequency_penalty: Option<f32>,
     /// Repetition penalty for local llama backends.
     #[serde(skip_serializing_if = "Option::is_none")]
     #[validate(range(min = 0.0, message = "repetition_penalty must be >= 0.0"))]
     pub repetition_penalty: Option<f32>,
+    /// Number of recent tokens the penalties look back over for local llama
+    /// backends; `-1` uses the whole context.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(range(min = -1, message = "repeat_last_n must be >= -1"))]
+    pub repeat_last_n: Option<i32>,
+    /// Leading prompt tokens (e.g. the system prompt) preserved when a local
+    /// llama session shifts its context window.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub n_keep: Option<u32>,
+    /// Sampler seed for reproducible output from local llama backends.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub seed: Option<u32>,
     /// Number of completions to generate.
     #[serde(skip_serializing_if = "Option::is_none")]
     #[validate(range(min = 1, message = "n must be at least 1"))]
@@ -354,6 +426,125 @@ pub struct ChatCompletionRequest {
     /// Optional provider verbosity override.
     #[serde(skip_serializing_if = "Option::is_none", default)]
     pub verbosity: Option<ChatVerbosity>,
+    /// Functions a local model may call. Its output is constrained to plain
+    /// text or a single call, which is returned as `tool_calls`. Cloud models
+    /// reject requests that carry tools.
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub tools: Vec<ChatTool>,
+    /// How the model may use `tools`. Omitting it while `tools` is set behaves
+    /// like `"auto"`, as in OpenAI: the model may answer in text or call a tool.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub tool_choice: Option<ChatToolChoice>,
+}
+
+/// Client frame accepted by the `GET /v1/chat/ws` WebSocket.
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+#[serde(tag = "type")]
+pub enum ChatSocketClientMessage {
+    /// Append a user turn to the socket session and stream the reply.
+    #[serde(rename = "chat.input")]
+    Input {
+        #[serde(default)]
+        request_id: Option<String>,
+        /// Unified model identifier from `/v1/models`.
+        #[serde(default)]
+        model: String,
+        content: String,
+        #[serde(default)]
+        max_tokens: Option<u32>,
+        #[serde(default)]
+        temperature: Option<f32>,
+        #[serde(default)]
+        top_p: Option<f32>,
+    },
+    /// Stop the reply currently streaming on the socket.
+    #[serde(rename = "chat.cancel")]
+    Cancel {
+        #[serde(default)]
+        request_id: Option<String>,
+    },
+}
+
+impl ChatSocketClientMessage {
+    pub fn request_id(&self) -> Option<&str> {
+        match self {
+            Self::Input { request_id, .. } | Self::Cancel { request_id } => request_id.as_deref(),
+        }
+    }
+
+    /// Build the streaming chat request for an input frame on the given socket
+    /// session; other frames yield `None`.
+    pub fn into_completion_request(self, session_id: &str) -> Option<ChatCompletionRequest> {
+        let Self::Input { model, content, max_tokens, temperature, top_p, .. } = self else {
+            return None;
+        };
+        Some(ChatCompletionRequest {
+            id: Some(session_id.to_owned()),
+            model,
+            messages: vec![ChatMessage {
+                role: "user".to_owned(),
+                content: Some(ChatMessageContent::Text(content)),
+                name: None,
+                tool_call_id: None,
+                tool_calls: Vec::new(),
+            }],
+            continue_generation: false,
+            stream: true,
+            stream_options: None,
+            max_tokens,
+            temperature,
+            top_p,
+            top_k: None,
+            min_p: None,
+            presence_penalty: None,
+            frequency_penalty: None,
+            repetition_penalty: None,
+            repeat_last_n: None,
+            n_keep: None,
+            seed: None,
+            n: None,
+            stop: None,
+            gbnf: None,
+            response_format: None,
+            json_schema: None,
+            thinking: None,
+            reasoning_effort: None,
+            verbosity: None,
+            tools: Vec::new(),
+            tool_choice: None,
+        })
+    }
+}
+
+/// Server frame emitted on the `GET /v1/chat/ws` WebSocket.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[serde(tag = "type")]
+pub enum ChatSocketServerMessage {
+    /// Sent once after the upgrade with the session backing this socket.
+    #[serde(rename = "chat.session")]
+    Session { session_id: String },
+    #[serde(rename = "chat.token")]
+    Token {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        request_id: Option<String>,
+        content: String,
+    },
+    #[serde(rename = "chat.done")]
+    Done {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        request_id: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        finish_reason: Option<String>,
+    },
+    #[serde(rename = "chat.error")]
+    Error {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        request_id: Option<String>,
+        code: String,
+        message: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        i18n: Option<I18nPayload>,
+    },
 }
 
 /// Request body for `POST /v1/completions`.
@@ -395,10 +586,30 @@ pub struct CompletionRequest {
         message = "presence_penalty must be between -2.0 and 2.0"
     ))]
     pub presence_penalty: Option<f32>,
+    /// Frequency penalty for local llama backends.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(range(
+        min = -2.0,
+        max = 2.0,
+        message = "frequency_penalty must be between -2.0 and 2.0"
+    ))]
+    pub frequency_penalty: Option<f32>,
     /// Repetition penalty for local llama backends.
     #[serde(skip_serializing_if = "Option::is_none")]
     #[validate(range(min = 0.0, message = "repetition_penalty must be >= 0.0"))]
     pub repetition_penalty: Option<f32>,
+    /// Number of recent tokens the penalties look back over for local llama
+    /// backends; `-1` uses the whole context.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(range(min = -1, message = "repeat_last_n must be >= -1"))]
+    pub repeat_last_n: Option<i32>,
+    /// Leading prompt tokens (e.g. the system prompt) preserved when a local
+    /// llama session shifts its context window.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub n_keep: Option<u32>,
+    /// Sampler seed for reproducible output from local llama backends.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub seed: Option<u32>,
     /// Number of completions to generate.
     #[serde(skip_serializing_if = "Option::is_none")]
     #[validate(range(min = 1, message = "n must be at least 1"))]
@@ -631,6 +842,24 @@ impl From<DomainConversationToolCall> for ChatToolCall {
     }
 }
 
+impl From<ChatTool> for slab_proto::openai::FunctionTool {
+    fn from(tool: ChatTool) -> Self {
+        let ChatFunctionDefinition { name, description, parameters, strict } = tool.function;
+        let parameters = parameters.and_then(|parameters| match parameters {
+            Value::Object(object) => Some(object.into_iter().collect()),
+            _ => None,
+        });
+        let mut function_tool = slab_proto::openai::FunctionTool::new(
+            slab_proto::openai::FunctionToolType::Function,
+            name,
+            parameters,
+            strict,
+        );
+        function_tool.description = description.map(Some);
+        function_tool
+    }
+}
+
 impl From<ChatToolCall> for DomainConversationToolCall {
     fn from(value: ChatToolCall) -> Self {
         Self { id: value.id, r#type: value.r#type, function: value.function.into() }
@@ -680,7 +909,11 @@ impl From<ChatCompletionRequest> for DomainChatCompletionCommand {
             top_k,
             min_p,
             presence_penalty,
+            frequency_penalty,
             repetition_penalty,
+            repeat_last_n,
+            n_keep,
+            seed,
             n,
             stop,
             gbnf,
@@ -689,6 +922,8 @@ impl From<ChatCompletionRequest> for DomainChatCompletionCommand {
             thinking,
             reasoning_effort,
             verbosity,
+            tools,
+            tool_choice,
         } = request;
 
         let reasoning_effort = reasoning_effort
@@ -699,6 +934,24 @@ impl From<ChatCompletionRequest> for DomainChatCompletionCommand {
             .or_else(|| thinking.as_ref().and_then(verbosity_from_thinking));
         let structured_output = structured_output_from_api(response_format, json_schema);
         let stop = stop.as_ref().map(StopSequences::normalized).unwrap_or_default();
+        let tool_choice = match tool_choice {
+            _ if tools.is_empty() => None,
+            None | Some(ChatToolChoice::Mode(ChatToolChoiceMode::Auto)) => {
+                Some(DomainLocalToolChoice::Auto)
+            }
+            Some(ChatToolChoice::Mode(ChatToolChoiceMode::None)) => None,
+            Some(ChatToolChoice::Mode(ChatToolChoiceMode::Required)) => {
+                Some(DomainLocalToolChoice::Required)
+            }
+            Some(ChatToolChoice::Named(named)) => {
+                Some(DomainLocalToolChoice::Function(named.function.name))
+            }
+        };
+        let tools = if tool_choice.is_some() {
+            tools.into_iter().map(Into::into).collect()
+        } else {
+            Vec::new()
+        };
 
         Self {
             id,
@@ -714,13 +967,22 @@ impl From<ChatCompletionRequest> for DomainChatCompletionCommand {
                 top_k,
                 min_p,
                 presence_penalty,
+                frequency_penalty,
                 repetition_penalty,
+                repeat_last_n,
+                n_keep,
+                seed,
                 n: n.unwrap_or(1),
                 stream,
                 stop,
                 stream_options: stream_options.map(Into::into).unwrap_or_default(),
             },
-            local: DomainLocalChatParams { gbnf, structured_output: structured_output.clone() },
+            local: DomainLocalChatParams {
+                gbnf,
+                structured_output: structured_output.clone(),
+                tools,
+                tool_choice,
+            },
             cloud: DomainCloudChatParams { reasoning_effort, verbosity, structured_output },
         }
     }
@@ -737,7 +999,11 @@ impl From<CompletionRequest> for DomainTextCompletionCommand {
             top_k,
             min_p,
             presence_penalty,
+            frequency_penalty,
             repetition_penalty,
+            repeat_last_n,
+            n_keep,
+            seed,
             n,
             stop,
             stream,
@@ -758,13 +1024,21 @@ impl From<CompletionRequest> for DomainTextCompletionCommand {
                 top_k,
                 min_p,
                 presence_penalty,
+                frequency_penalty,
                 repetition_penalty,
+                repeat_last_n,
+                n_keep,
+                seed,
                 n: n.unwrap_or(1),
                 stream,
                 stop,
                 stream_options: DomainChatStreamOptions::default(),
             },
-            local: DomainLocalChatParams { gbnf, structured_output: structured_output.clone() },
+            local: DomainLocalChatParams {
+                gbnf,
+                structured_output: structured_output.clone(),
+                ..Default::default()
+            },
             cloud: DomainCloudChatParams {
                 reasoning_effort: None,
                 verbosity: None,
@@ -911,6 +1185,43 @@ fn validate_chat_completion_request(
         ));
     }
     validate_structured_output(request.response_format.as_ref(), request.json_schema.as_ref())?;
+    for tool in &request.tools {
+        if tool.r#type != "function" {
+            return Err(validation_error(
+                "unsupported_tool_type",
+                &format!("tool type '{}' is not supported; expected 'function'", tool.r#type),
+            ));
+        }
+        if tool.function.name.trim().is_empty() {
+            return Err(validation_error("invalid_tool", "tool function name must not be empty"));
+        }
+    }
+    match &request.tool_choice {
+        Some(ChatToolChoice::Mode(ChatToolChoiceMode::Required)) if request.tools.is_empty() => {
+            return Err(validation_error(
+                "invalid_tool_choice",
+                "tool_choice 'required' needs at least one tool",
+            ));
+        }
+        Some(ChatToolChoice::Named(named)) => {
+            if named.r#type != "function" {
+                return Err(validation_error(
+                    "invalid_tool_choice",
+                    &format!(
+                        "tool_choice type '{}' is not supported; expected 'function'",
+                        named.r#type
+                    ),
+                ));
+            }
+            if !request.tools.iter().any(|tool| tool.function.name == named.function.name) {
+                return Err(validation_error(
+                    "invalid_tool_choice",
+                    &format!("tool_choice names unknown tool '{}'", named.function.name),
+                ));
+            }
+        }
+        _ => {}
+    }
 
     if request.messages.len() > MAX_MESSAGES {
         return Err(validation_error(
@@ -1128,17 +1439,18 @@ fn normalize_stop_values<'a>(values: impl IntoIterator<Item = &'a str>) -> Vec<S
 #[cfg(test)]
 mod tests {
     use super::{
-        ChatCompletionRequest, ChatMessage, ChatMessageContent, ChatReasoningEffort,
-        ChatResponseFormat, ChatResponseFormatType, ChatResponseJsonSchema, ChatStreamOptions,
-        ChatThinkingConfig, ChatThinkingType, ChatToolCall, ChatToolFunction, ChatVerbosity,
-        CompletionRequest, StopSequences,
+        ChatCompletionRequest, ChatMessage, ChatMessageContent, ChatNamedToolChoice,
+        ChatNamedToolChoiceFunction, ChatReasoningEffort, ChatResponseFormat,
+        ChatResponseFormatType, ChatResponseJsonSchema, ChatStreamOptions, ChatThinkingConfig,
+        ChatThinkingType, ChatToolCall, ChatToolChoice, ChatToolChoiceMode, ChatToolFunction,
+        ChatVerbosity, CompletionRequest, StopSequences, validate_chat_completion_request,
     };
     use crate::domain::models::{
         ChatCompletionCommand as DomainChatCompletionCommand,
         ChatReasoningEffort as DomainChatReasoningEffort, ChatVerbosity as DomainChatVerbosity,
         ConversationToolCall as DomainConversationToolCall,
         ConversationToolFunction as DomainConversationToolFunction,
-        StructuredOutput as DomainStructuredOutput,
+        LocalToolChoice as DomainLocalToolChoice, StructuredOutput as DomainStructuredOutput,
         TextCompletionCommand as DomainTextCompletionCommand,
     };
     use serde_json::json;
@@ -1163,7 +1475,11 @@ mod tests {
             top_k: None,
             min_p: None,
             presence_penalty: None,
+            frequency_penalty: None,
             repetition_penalty: None,
+            repeat_last_n: None,
+            n_keep: None,
+            seed: None,
             n: None,
             stop: None,
             gbnf: None,
@@ -1172,6 +1488,8 @@ mod tests {
             thinking: None,
             reasoning_effort: None,
             verbosity: None,
+            tools: Vec::new(),
+            tool_choice: None,
         }
     }
 
@@ -1185,7 +1503,11 @@ mod tests {
             top_k: None,
             min_p: None,
             presence_penalty: None,
+            frequency_penalty: None,
             repetition_penalty: None,
+            repeat_last_n: None,
+            n_keep: None,
+            seed: None,
             n: None,
             stop: None,
             stream: false,
@@ -1250,6 +1572,21 @@ mod tests {
         assert!(command.continue_generation);
     }
 
+    #[test]
+    fn seed_is_carried_into_common_params() {
+        let request: ChatCompletionRequest = serde_json::from_value(json!({
+            "model": "local-qwen",
+            "messages": [{ "role": "user", "content": "hello" }],
+            "seed": 42,
+        }))
+        .expect("seed should be accepted");
+
+        let command = DomainChatCompletionCommand::from(request);
+
+        assert_eq!(command.common.seed, Some(42));
+        assert_eq!(DomainChatCompletionCommand::from(make_request()).common.seed, None);
+    }
+
     #[test]
     fn response_format_json_object_maps_to_structured_output() {
         let mut request = make_request();
@@ -1381,6 +1718,85 @@ mod tests {
         assert_eq!(restored_schema_tool_call.r#type, schema_tool_call.r#type);
     }
 
+    #[test]
+    fn chat_tools_map_to_function_tools_and_enable_tool_call_grammar() {
+        let request = serde_json::from_value::<ChatCompletionRequest>(json!({
+            "model": "local-model",
+            "messages": [{"role": "user", "content": "weather in Paris?"}],
+            "tools": [{
+                "type": "function",
+                "function": {
+                    "name": "get_weather",
+                    "description": "Look up the weather",
+                    "parameters": {
+                        "type": "object",
+                        "properties": {"city": {"type": "string"}},
+                        "required": ["city"]
+                    }
+                }
+            }]
+        }))
+        .expect("request with tools");
+
+        let command = DomainChatCompletionCommand::from(request.clone());
+
+        assert_eq!(command.local.tool_choice, Some(DomainLocalToolChoice::Auto));
+        assert!(command.tools.is_empty(), "request tools must not reach cloud routes");
+        assert_eq!(command.local.tools.len(), 1);
+        assert_eq!(command.local.tools[0].name, "get_weather");
+        assert_eq!(
+            command.local.tools[0]
+                .parameters
+                .as_ref()
+                .and_then(|parameters| parameters.get("type")),
+            Some(&json!("object"))
+        );
+        assert_eq!(DomainChatCompletionCommand::from(make_request()).local.tool_choice, None);
+
+        let mut required = request.clone();
+        required.tool_choice = Some(ChatToolChoice::Mode(ChatToolChoiceMode::Required));
+        assert_eq!(
+            DomainChatCompletionCommand::from(required).local.tool_choice,
+            Some(DomainLocalToolChoice::Required)
+        );
+
+        let mut none = request;
+        none.tool_choice = Some(ChatToolChoice::Mode(ChatToolChoiceMode::None));
+        let command = DomainChatCompletionCommand::from(none);
+        assert_eq!(command.local.tool_choice, None);
+        assert!(command.local.tools.is_empty());
+    }
+
+    #[test]
+    fn tool_choice_accepts_openai_forms_and_rejects_unknown_tools() {
+        let mut request = serde_json::from_value::<ChatCompletionRequest>(json!({
+            "model": "local-model",
+            "messages": [{"role": "user", "content": "weather in Paris?"}],
+            "tools": [{"type": "function", "function": {"name": "get_weather"}}],
+            "tool_choice": {"type": "function", "function": {"name": "get_weather"}}
+        }))
+        .expect("request with named tool_choice");
+        assert!(validate_chat_completion_request(&request).is_ok());
+        assert_eq!(
+            DomainChatCompletionCommand::from(request.clone()).local.tool_choice,
+            Some(DomainLocalToolChoice::Function("get_weather".into()))
+        );
+
+        request.tool_choice = Some(ChatToolChoice::Named(ChatNamedToolChoice {
+            r#type: "function".into(),
+            function: ChatNamedToolChoiceFunction { name: "missing".into() },
+        }));
+        assert!(validate_chat_completion_request(&request).is_err());
+
+        let request = serde_json::from_value::<ChatCompletionRequest>(json!({
+            "model": "local-model",
+            "messages": [{"role": "user", "content": "hi"}],
+            "tool_choice": "required"
+        }))
+        .expect("request with required tool_choice");
+        assert!(validate_chat_completion_request(&request).is_err());
+    }
+
     #[test]
     fn chat_completion_request_rejects_legacy_grammar_field() {
         let error = serde_json::from_value::<ChatCompletionRequest>(json!({
@@ -137,6 +137,13 @@ pub struct ImageGenerationRequest {
     /// Generation mode (default `txt2img`).
     #[serde(default)]
     pub mode: ImageMode,
+
+    /// Denoising steps between low-resolution previews, served from
+    /// `GET /v1/images/generations/{id}/preview` while the task runs. Requires a
+    /// model loaded with a TAESD; omit to disable previews.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate(range(min = 1, message = "preview_interval must be at least 1"))]
+    pub preview_interval: Option<u32>,
 }
 
 fn default_n() -> u32 {
@@ -234,6 +241,7 @@ impl TryFrom<ImageGenerationRequest> for ImageGenerationCommand {
             strength: request.strength,
             init_image,
             mode,
+            preview_interval: request.preview_interval,
         })
     }
 }
@@ -243,6 +251,81 @@ fn decode_init_image(data_uri: &str) -> Result<DecodedImageInput, AppCoreError>
     Ok(DecodedImageInput { data, width, height, channels: 3 })
 }
 
+/// Fields of a `POST /v1/images/edits` multipart upload.
+///
+/// The uploaded `image` is used as the img2img init image; output dimensions
+/// follow the uploaded image.
+#[derive(Debug, Clone, Default)]
+pub struct ImageEditUpload {
+    pub model_id: Option<String>,
+    pub model: String,
+    pub prompt: String,
+    pub negative_prompt: Option<String>,
+    pub n: Option<u32>,
+    pub strength: Option<f32>,
+    pub steps: Option<i32>,
+    pub seed: Option<i64>,
+    pub image: Vec<u8>,
+}
+
+impl TryFrom<ImageEditUpload> for ImageGenerationCommand {
+    type Error = AppCoreError;
+
+    fn try_from(upload: ImageEditUpload) -> Result<Self, Self::Error> {
+        if upload.model.trim().is_empty() {
+            return Err(AppCoreError::BadRequest("model must not be empty".into()));
+        }
+        if upload.prompt.trim().is_empty() {
+            return Err(AppCoreError::BadRequest("prompt must not be empty".into()));
+        }
+        if upload.prompt.len() > MAX_PROMPT_BYTES {
+            return Err(AppCoreError::BadRequest(format!(
+                "prompt is too large ({} bytes); maximum is {MAX_PROMPT_BYTES} bytes",
+                upload.prompt.len()
+            )));
+        }
+        let n = upload.n.unwrap_or_else(default_n);
+        if !(1..=MAX_IMAGES_PER_REQUEST).contains(&n) {
+            return Err(AppCoreError::BadRequest(format!(
+                "n must be between 1 and {MAX_IMAGES_PER_REQUEST}"
+            )));
+        }
+        if upload.strength.is_some_and(|strength| !(0.0..=1.0).contains(&strength)) {
+            return Err(AppCoreError::BadRequest("strength must be between 0.0 and 1.0".into()));
+        }
+        if upload.steps.is_some_and(|steps| steps < 1) {
+            return Err(AppCoreError::BadRequest("steps must be at least 1".into()));
+        }
+        if upload.image.is_empty() {
+            return Err(AppCoreError::BadRequest("image must not be empty".into()));
+        }
+
+        let (data, width, height) = crate::schemas::decode_init_image_bytes(&upload.image)?;
+
+        Ok(Self {
+            model_id: upload.model_id,
+            model: upload.model,
+            prompt: upload.prompt,
+            negative_prompt: upload.negative_prompt,
+            n,
+            width,
+            height,
+            cfg_scale: None,
+            guidance: None,
+            steps: upload.steps,
+            seed: upload.seed,
+            sample_method: None,
+            scheduler: None,
+            clip_skip: None,
+            eta: None,
+            strength: upload.strength,
+            init_image: Some(DecodedImageInput { data, width, height, channels: 3 }),
+            mode: ImageGenerationMode::Img2Img,
+            preview_interval: None,
+        })
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct ImageGenerationRequestData {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -374,3 +457,48 @@ impl From<DomainImageGenerationResultData> for ImageGenerationResultData {
         Self { primary_image_path: value.primary_image_path, artifact_paths: value.artifact_paths }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::ImageEditUpload;
+    use crate::domain::models::{ImageGenerationCommand, ImageGenerationMode};
+
+    fn tiny_png() -> Vec<u8> {
+        let image = image::RgbImage::from_pixel(4, 3, image::Rgb([200, 10, 10]));
+        let mut bytes = std::io::Cursor::new(Vec::new());
+        image.write_to(&mut bytes, image::ImageFormat::Png).expect("encode png");
+        bytes.into_inner()
+    }
+
+    #[test]
+    fn image_edit_upload_becomes_img2img_command() {
+        let command = ImageGenerationCommand::try_from(ImageEditUpload {
+            model: "sd-turbo".to_owned(),
+            prompt: "make it blue".to_owned(),
+            strength: Some(0.6),
+            image: tiny_png(),
+            ..Default::default()
+        })
+        .expect("valid upload");
+
+        assert_eq!(command.mode, ImageGenerationMode::Img2Img);
+        assert_eq!((command.width, command.height), (4, 3));
+        assert_eq!(command.n, 1);
+        assert_eq!(command.strength, Some(0.6));
+        let init_image = command.init_image.expect("init image");
+        assert_eq!(init_image.data.len(), 4 * 3 * 3);
+    }
+
+    #[test]
+    fn image_edit_upload_rejects_non_image_bytes() {
+        let error = ImageGenerationCommand::try_from(ImageEditUpload {
+            model: "sd-turbo".to_owned(),
+            prompt: "make it blue".to_owned(),
+            image: b"not an image".to_vec(),
+            ..Default::default()
+        })
+        .expect_err("non-image upload");
+
+        assert!(error.to_string().contains("PNG or JPEG"), "{error}");
+    }
+}
@@ -60,6 +60,14 @@ pub(crate) fn decode_base64_init_image(
         crate::error::AppCoreError::BadRequest(format!("init_image base64 decode failed: {error}"))
     })?;
 
+    decode_init_image_bytes(&bytes)
+}
+
+/// Decode uploaded PNG or JPEG bytes into raw RGB pixels, enforcing the same
+/// size and dimension limits as [`decode_base64_init_image`].
+pub(crate) fn decode_init_image_bytes(
+    bytes: &[u8],
+) -> Result<(Vec<u8>, u32, u32), crate::error::AppCoreError> {
     if bytes.len() > MAX_INIT_IMAGE_BYTES {
         return Err(crate::error::AppCoreError::BadRequest(format!(
             "init_image decoded size ({} bytes) exceeds the maximum of {} bytes",
@@ -68,7 +76,16 @@ pub(crate) fn decode_base64_init_image(
         )));
     }
 
-    let image = image::load_from_memory(&bytes).map_err(|error| {
+    match image::guess_format(bytes) {
+        Ok(image::ImageFormat::Png | image::ImageFormat::Jpeg) => {}
+        _ => {
+            return Err(crate::error::AppCoreError::BadRequest(
+                "init_image must be a PNG or JPEG image".into(),
+            ));
+        }
+    }
+
+    let image = image::load_from_memory(bytes).map_err(|error| {
         crate::error::AppCoreError::BadRequest(format!("init_image decode failed: {error}"))
     })?;
 
@@ -259,6 +259,9 @@ pub struct ModelStatusResponse {
     /// Training context window length reported by the loaded model.
     #[serde(skip_serializing_if = "Option::is_none", default)]
     pub training_context_length: Option<u32>,
+    /// Native library build serving the model, when the backend reports one.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub version: Option<String>,
 }
 
 /// Runtime lifecycle state for a local catalog model.
@@ -618,6 +621,7 @@ impl From<DomainModelStatus> for ModelStatusResponse {
             status: status.status,
             context_length: status.context_length,
             training_context_length: status.training_context_length,
+            version: status.version,
         }
     }
 }
@@ -1184,13 +1188,13 @@ fn validation_error(code: &'static str, message: &'static str) -> ValidationErro
 #[cfg(test)]
 mod tests {
     use super::{
-        CreateModelRequest, LoadModelRequest, ModelKind, SwitchModelRequest, UnifiedModelResponse,
-        UnloadModelRequest, UpdateModelRequest,
+        CreateModelRequest, LoadModelRequest, ModelKind, ModelStatusResponse, SwitchModelRequest,
+        UnifiedModelResponse, UnloadModelRequest, UpdateModelRequest,
     };
     use crate::domain::models::{
         CreateModelCommand as DomainCreateModelCommand, ManagedModelBackendId, ModelSpec,
-        UnifiedModel as DomainUnifiedModel, UnifiedModelKind, UnifiedModelStatus,
-        UpdateModelCommand as DomainUpdateModelCommand,
+        ModelStatus as DomainModelStatus, UnifiedModel as DomainUnifiedModel, UnifiedModelKind,
+        UnifiedModelStatus, UpdateModelCommand as DomainUpdateModelCommand,
     };
     use chrono::Utc;
     use serde_json::json;
@@ -1342,6 +1346,23 @@ mod tests {
         assert!(request.validate().is_err());
     }
 
+    #[test]
+    fn model_status_response_reports_backend_version_when_known() {
+        let loaded = ModelStatusResponse::from(DomainModelStatus {
+            backend: "ggml.diffusion".to_owned(),
+            status: "loaded".to_owned(),
+            context_length: None,
+            training_context_length: None,
+            version: Some("master-abc1234".to_owned()),
+        });
+        let json = serde_json::to_value(&loaded).expect("serialize status");
+        assert_eq!(json["version"], "master-abc1234");
+
+        let unversioned = ModelStatusResponse { version: None, ..loaded };
+        let json = serde_json::to_value(&unversioned).expect("serialize status");
+        assert!(json.get("version").is_none());
+    }
+
     #[test]
     fn unified_model_response_projects_local_artifact_size_once() {
         let root = tempfile::tempdir().expect("tempdir");
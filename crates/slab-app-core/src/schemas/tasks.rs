@@ -30,6 +30,10 @@ pub struct TaskResultPayload {
     /// Array of base64-encoded PNG data URIs for multi-image task results.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub images: Option<Vec<String>>,
+    /// Seeds actually used for each image in `images`, in the same order.
+    /// Omitted when the backend does not report them.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub seeds: Option<Vec<i64>>,
     /// Absolute path to the assembled MP4 video file for video task results.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub video_path: Option<String>,
@@ -122,6 +126,7 @@ impl From<TaskResult> for TaskResultPayload {
         Self {
             image: result.image,
             images: result.images,
+            seeds: result.seeds,
             video_path: result.video_path,
             output_path: result.output_path,
             text: result.text,
@@ -23,10 +23,10 @@ use crate::domain::models::{
     UnifiedModelStatus,
 };
 use crate::domain::ports::{
-    RuntimeBackendStatus, RuntimeDiffusionImageRequest, RuntimeDiffusionImageResult,
-    RuntimeDiffusionVideoRequest, RuntimeDiffusionVideoResult, RuntimeInferenceGateway,
-    RuntimeTextGenerationChunk, RuntimeTextGenerationRequest, RuntimeTextGenerationResponse,
-    RuntimeTranscriptionRequest, RuntimeTranscriptionResult,
+    RuntimeBackendStatus, RuntimeDiffusionImageChunk, RuntimeDiffusionImageRequest,
+    RuntimeDiffusionImageResult, RuntimeDiffusionVideoRequest, RuntimeDiffusionVideoResult,
+    RuntimeInferenceGateway, RuntimeTextGenerationChunk, RuntimeTextGenerationRequest,
+    RuntimeTextGenerationResponse, RuntimeTranscriptionRequest, RuntimeTranscriptionResult,
 };
 use crate::domain::services::{ModelService, PmidService};
 use crate::error::AppCoreError;
@@ -125,6 +125,14 @@ impl RuntimeInferenceGateway for RecordingRuntimeGateway {
         Err(Self::unavailable())
     }
 
+    async fn generate_image_stream(
+        &self,
+        _request: RuntimeDiffusionImageRequest,
+    ) -> Result<BoxStream<'static, Result<RuntimeDiffusionImageChunk, AppCoreError>>, AppCoreError>
+    {
+        Err(Self::unavailable())
+    }
+
     async fn generate_video(
         &self,
         _request: RuntimeDiffusionVideoRequest,
@@ -142,6 +150,7 @@ impl RuntimeInferenceGateway for RecordingRuntimeGateway {
             status: "ready".to_owned(),
             context_length: None,
             training_context_length: None,
+            version: None,
         })
     }
 
@@ -155,6 +164,7 @@ impl RuntimeInferenceGateway for RecordingRuntimeGateway {
             status: "unloaded".to_owned(),
             context_length: None,
             training_context_length: None,
+            version: None,
         })
     }
 }
@@ -166,6 +176,7 @@ pub(crate) struct TestAppCore {
     pub(crate) store: Arc<AnyStore>,
     pub(crate) runtime: Arc<RecordingRuntimeGateway>,
     pub(crate) auto_unload: Arc<ModelAutoUnloadManager>,
+    pub(crate) worker_state: WorkerState,
     pub(crate) model: ModelService,
     pub(crate) model_config_dir: PathBuf,
     pub(crate) model_cache_dir: PathBuf,
@@ -213,6 +224,7 @@ impl TestAppCore {
             cloud_http_trace: false,
             queue_capacity: 64,
             backend_capacity: 4,
+            max_completion_tokens: None,
             enable_swagger: false,
             cors_allowed_origins: None,
             admin_api_token: None,
@@ -263,7 +275,7 @@ impl TestAppCore {
             Arc::clone(&auto_unload),
             Arc::new(OperationManager::new()),
         );
-        let model = ModelService::new(model_state, worker_state);
+        let model = ModelService::new(model_state, worker_state.clone());
 
         Self {
             _temp_dir: temp_dir,
@@ -272,6 +284,7 @@ impl TestAppCore {
             store,
             runtime,
             auto_unload,
+            worker_state,
             model,
             model_config_dir,
             model_cache_dir,
@@ -62,6 +62,11 @@ pub struct Config {
     /// Maximum concurrent in-flight requests per AI backend.
     pub backend_capacity: usize,
 
+    /// Optional server-side ceiling for a request's `max_tokens`. Larger
+    /// requests are clamped to this value and answered with a `Warning`
+    /// header; unset means no cap.
+    pub max_completion_tokens: Option<u32>,
+
     /// When `true`, serve the Swagger UI at `/swagger-ui` and the OpenAPI spec
     /// at `/api-docs/openapi.json`.  Set `SLAB_ENABLE_SWAGGER=false` to
     /// disable in production if you don't want the API structure exposed.
@@ -164,6 +169,7 @@ impl Config {
             cloud_http_trace: parse_bool_env(source, "SLAB_CLOUD_HTTP_TRACE", false),
             queue_capacity: parse_env(source, "SLAB_QUEUE_CAPACITY", 64),
             backend_capacity: parse_env(source, "SLAB_BACKEND_CAPACITY", 4),
+            max_completion_tokens: parse_optional_env(source, "SLAB_MAX_COMPLETION_TOKENS"),
             enable_swagger: parse_bool_env(source, "SLAB_ENABLE_SWAGGER", true),
             cors_allowed_origins: source.var("SLAB_CORS_ORIGINS"),
             admin_api_token: source.var("SLAB_ADMIN_TOKEN"),
@@ -222,6 +228,22 @@ where
     }
 }
 
+fn parse_optional_env<T>(source: &impl EnvSource, key: &str) -> Option<T>
+where
+    T: std::str::FromStr,
+    T::Err: Display,
+{
+    let raw = source.var(key)?;
+
+    match raw.parse() {
+        Ok(value) => Some(value),
+        Err(error) => {
+            warn!(env_var = key, value = %raw, %error, "invalid environment value; ignoring it");
+            None
+        }
+    }
+}
+
 fn parse_bool_env(source: &impl EnvSource, key: &str, default: bool) -> bool {
     let Some(raw) = source.var(key) else {
         return default;
@@ -470,6 +492,7 @@ mod tests {
             ("SLAB_ENABLE_SWAGGER", "false"),
             ("SLAB_QUEUE_CAPACITY", "0"),
             ("SLAB_BACKEND_CAPACITY", "12"),
+            ("SLAB_MAX_COMPLETION_TOKENS", "2048"),
             ("SLAB_ADMIN_TOKEN", "test-admin-token"),
             ("SLAB_CORS_ORIGINS", "https://app.example.com,https://admin.example.com"),
             ("SLAB_TRANSPORT", "ipc"),
@@ -482,6 +505,7 @@ mod tests {
         assert!(!config.enable_swagger);
         assert_eq!(config.queue_capacity, 0);
         assert_eq!(config.backend_capacity, 12);
+        assert_eq!(config.max_completion_tokens, Some(2048));
         assert_eq!(config.admin_api_token.as_deref(), Some("test-admin-token"));
         assert_eq!(
             config.cors_allowed_origins.as_deref(),
@@ -498,6 +522,7 @@ mod tests {
             ("SLAB_ENABLE_SWAGGER", "maybe"),
             ("SLAB_QUEUE_CAPACITY", "not-a-number"),
             ("SLAB_BACKEND_CAPACITY", "999999999999999999999999999999999999"),
+            ("SLAB_MAX_COMPLETION_TOKENS", "-1"),
         ]);
         let config = Config::from_env_source(&env);
 
@@ -506,6 +531,7 @@ mod tests {
         assert!(config.enable_swagger);
         assert_eq!(config.queue_capacity, 64);
         assert_eq!(config.backend_capacity, 4);
+        assert_eq!(config.max_completion_tokens, None);
     }
 
     #[test]
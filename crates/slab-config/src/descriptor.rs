@@ -184,6 +184,14 @@ pub(crate) fn setting_descriptor(pmid: &str) -> Option<SettingDescriptor> {
             "runtime.ggml.backends.llama.flash_attn",
             runtime.ggml.backends.llama.flash_attn
         ),
+        "runtime.ggml.backends.llama.least_loaded_sessions" => descriptor!(
+            "runtime.ggml.backends.llama.least_loaded_sessions",
+            runtime.ggml.backends.llama.least_loaded_sessions
+        ),
+        "runtime.ggml.backends.llama.stop_on_context_full" => descriptor!(
+            "runtime.ggml.backends.llama.stop_on_context_full",
+            runtime.ggml.backends.llama.stop_on_context_full
+        ),
         "runtime.ggml.backends.llama.source.version" => descriptor!(
             "runtime.ggml.backends.llama.source.version",
             runtime.ggml.backends.llama.source.version
@@ -147,6 +147,8 @@ fn load_config(settings: &SettingsDocument) -> PmidConfig {
                 num_workers: resolve_backend_concurrency(settings, RuntimeBackend::Llama),
                 context_length: settings.runtime.ggml.backends.llama.context_length,
                 flash_attn: settings.runtime.ggml.backends.llama.flash_attn,
+                least_loaded_sessions: settings.runtime.ggml.backends.llama.least_loaded_sessions,
+                stop_on_context_full: settings.runtime.ggml.backends.llama.stop_on_context_full,
             },
             whisper: RuntimeWhisperConfig {
                 num_workers: resolve_backend_concurrency(settings, RuntimeBackend::Whisper),
@@ -1056,6 +1058,8 @@ pub fn change_effect_for(path: &str) -> SettingChangeEffect {
 
     if path.starts_with("runtime.ggml.backends.llama.context_length")
         || path.starts_with("runtime.ggml.backends.llama.flash_attn")
+        || path.starts_with("runtime.ggml.backends.llama.least_loaded_sessions")
+        || path.starts_with("runtime.ggml.backends.llama.stop_on_context_full")
         || path.starts_with("runtime.ggml.backends.whisper.flash_attn")
         || path.starts_with("runtime.ggml.backends.diffusion.flash_attn")
     {
@@ -1604,6 +1608,8 @@ fn property_description(path: &str) -> String {
         "server.cors.allowed_origins" => "List of allowed browser origins for API requests.".to_owned(),
         "server.swagger.enabled" => "Expose the OpenAPI document and Swagger UI.".to_owned(),
         "server.cloud_http_trace" => "Log redacted cloud request and response payloads for debugging.".to_owned(),
+        "runtime.ggml.backends.llama.least_loaded_sessions" => "Place new sessions on the llama worker with the fewest active sessions instead of round-robin.".to_owned(),
+        "runtime.ggml.backends.llama.stop_on_context_full" => "End generation with a context-full error instead of dropping the oldest context when a llama session runs out of room.".to_owned(),
         _ if path.ends_with(".enabled") => "Enable or disable this component-specific override.".to_owned(),
         _ if path.ends_with(".flash_attn") => {
             "Enable Flash Attention when the backend supports it.".to_owned()
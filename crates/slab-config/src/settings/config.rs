@@ -101,11 +101,23 @@ pub struct RuntimeLlamaConfig {
     pub context_length: Option<u32>,
     #[serde(default = "defaults::flash_attn_enabled")]
     pub flash_attn: bool,
+    /// Place new sessions on the least-loaded worker instead of round-robin.
+    #[serde(default)]
+    pub least_loaded_sessions: bool,
+    /// End generation with a context-full error instead of shifting the KV cache.
+    #[serde(default)]
+    pub stop_on_context_full: bool,
 }
 
 impl Default for RuntimeLlamaConfig {
     fn default() -> Self {
-        Self { num_workers: 0, context_length: None, flash_attn: defaults::flash_attn_enabled() }
+        Self {
+            num_workers: 0,
+            context_length: None,
+            flash_attn: defaults::flash_attn_enabled(),
+            least_loaded_sessions: false,
+            stop_on_context_full: false,
+        }
     }
 }
 
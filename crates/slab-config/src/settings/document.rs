@@ -956,6 +956,12 @@ pub struct LlamaRuntimeLeafConfig {
     /// Whether Flash Attention is enabled for llama contexts.
     #[serde(default = "defaults::flash_attn_enabled")]
     pub flash_attn: bool,
+    /// Place new sessions on the worker with the fewest active sessions instead of round-robin.
+    #[serde(default)]
+    pub least_loaded_sessions: bool,
+    /// End generation with a context-full error instead of shifting out the oldest KV cache entries.
+    #[serde(default)]
+    pub stop_on_context_full: bool,
     #[serde(default)]
     pub source: SourceConfig,
     #[serde(default)]
@@ -972,6 +978,8 @@ impl Default for LlamaRuntimeLeafConfig {
             enabled: true,
             context_length: Some(2048),
             flash_attn: defaults::flash_attn_enabled(),
+            least_loaded_sessions: false,
+            stop_on_context_full: false,
             source: SourceConfig::default(),
             logging: LoggingOverrideConfig::default(),
             capacity: CapacityOverrideConfig::default(),
@@ -1868,6 +1876,8 @@ mod tests {
         assert!(settings.runtime.ggml.backends.llama.flash_attn);
         assert!(settings.runtime.ggml.backends.whisper.flash_attn);
         assert!(settings.runtime.ggml.backends.diffusion.flash_attn);
+        assert!(!settings.runtime.ggml.backends.llama.least_loaded_sessions);
+        assert!(!settings.runtime.ggml.backends.llama.stop_on_context_full);
         assert!(!settings.runtime.candle.enabled);
         assert!(settings.runtime.ggml.backends.llama.capacity.concurrent_requests.is_none());
         assert_eq!(settings.runtime.capacity.concurrent_requests, 4);
@@ -132,6 +132,8 @@ impl SettingsPmidCatalog {
             self.runtime.ggml.backends.llama.enabled(),
             self.runtime.ggml.backends.llama.context_length(),
             self.runtime.ggml.backends.llama.flash_attn(),
+            self.runtime.ggml.backends.llama.least_loaded_sessions(),
+            self.runtime.ggml.backends.llama.stop_on_context_full(),
             self.runtime.ggml.backends.llama.source.version(),
             self.runtime.ggml.backends.llama.source.artifact(),
             self.runtime.ggml.backends.llama.logging.level(),
@@ -768,6 +770,14 @@ impl LlamaRuntimePmids {
     pub fn flash_attn(self) -> SettingPmid {
         SettingPmid::from_path(format!("{}.flash_attn", self.prefix))
     }
+
+    pub fn least_loaded_sessions(self) -> SettingPmid {
+        SettingPmid::from_path(format!("{}.least_loaded_sessions", self.prefix))
+    }
+
+    pub fn stop_on_context_full(self) -> SettingPmid {
+        SettingPmid::from_path(format!("{}.stop_on_context_full", self.prefix))
+    }
 }
 
 impl Default for LlamaRuntimePmids {
@@ -1007,6 +1017,8 @@ mod tests {
         assert!(unique.contains("runtime.launch.desktop.bind_host"));
         assert!(unique.contains("runtime.launch.desktop.base_port"));
         assert!(unique.contains("runtime.ggml.backends.llama.flash_attn"));
+        assert!(unique.contains("runtime.ggml.backends.llama.least_loaded_sessions"));
+        assert!(unique.contains("runtime.ggml.backends.llama.stop_on_context_full"));
         assert!(unique.contains("runtime.ggml.backends.whisper.flash_attn"));
         assert!(unique.contains("runtime.ggml.backends.diffusion.flash_attn"));
         assert!(unique.contains("agent.hooks.enabled"));
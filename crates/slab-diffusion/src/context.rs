@@ -1,8 +1,9 @@
 use crate::SharedDiffusionLib;
+use crate::UpscalerContext;
 use crate::error::DiffusionError;
 use crate::params::{
-    ContextParams, Image, ImgParams, InnerImgParams, InnerVideoParams, SampleMethod, Scheduler,
-    Video, VideoParams, owned_image_from_raw,
+    ContextParams, Image, ImgParams, InnerImgParams, InnerVideoParams, InpaintParams, SampleMethod,
+    Scheduler, SeededImage, Video, VideoParams, owned_image_from_raw,
 };
 use std::slice;
 use std::sync::Arc;
@@ -14,7 +15,8 @@ use std::sync::Arc;
 pub struct Context {
     pub(crate) ctx: *mut slab_diffusion_sys::sd_ctx_t,
     pub(crate) lib: Arc<SharedDiffusionLib>,
-    pub(crate) _params: ContextParams,
+    pub(crate) upscaler: Option<UpscalerContext>,
+    pub(crate) params: ContextParams,
 }
 
 impl Context {
@@ -57,13 +59,36 @@ impl Context {
 
     /// Generate one or more images from the supplied parameters.
     ///
+    /// When `params.vae_tiling_params` is unset, the context's
+    /// [`ContextParams::vae_tiling`] setting is used instead.
+    ///
     /// The returned `Vec` contains exactly the effective batch count sent to
-    /// the native layer.
+    /// the native layer. Use [`Context::generate_image_with_seed`] to learn
+    /// which seed each image was sampled from.
     ///
     /// # Errors
     /// Returns [`DiffusionError::GenerationFailed`] when the native library
     /// returns a null pointer (e.g. out of memory or bad parameters).
     pub fn generate_image(&self, params: ImgParams) -> Result<Vec<Image>, DiffusionError> {
+        Ok(self.generate_image_with_seed(params)?.into_iter().map(|seeded| seeded.image).collect())
+    }
+
+    /// Generate images like [`Context::generate_image`], pairing each with
+    /// the seed it was sampled from.
+    ///
+    /// A missing or negative seed is resolved to a concrete random seed
+    /// first; batch entries use `seed + index`.
+    ///
+    /// # Errors
+    /// Same as [`Context::generate_image`].
+    pub fn generate_image_with_seed(
+        &self,
+        mut params: ImgParams,
+    ) -> Result<Vec<SeededImage>, DiffusionError> {
+        let seed = params.resolve_seed();
+        if params.vae_tiling_params.is_none() {
+            params.vae_tiling_params = self.params.default_vae_tiling();
+        }
         let inner: InnerImgParams = InnerImgParams::from_canonical(self.lib.as_ref(), &params)
             .map_err(DiffusionError::InvalidParameters)?;
 
@@ -79,10 +104,39 @@ impl Context {
 
         let batch = usize::try_from(inner.get_batch_count())
             .map_err(|_| DiffusionError::GenerationFailed)?;
-        Ok(Self::collect_images(self.lib.as_ref(), images_ptr, batch))
+        Ok(Self::collect_images(self.lib.as_ref(), images_ptr, batch)
+            .into_iter()
+            .zip(seed..)
+            .map(|(image, seed)| SeededImage { seed, image })
+            .collect())
+    }
+
+    /// Regenerate the masked region of an image.
+    ///
+    /// # Errors
+    /// Returns [`DiffusionError::InvalidParameters`] when the mask is not a
+    /// single-channel image matching the base image size, or when either
+    /// buffer does not hold `width * height * channel` bytes.
+    pub fn inpaint(&self, params: InpaintParams) -> Result<Image, DiffusionError> {
+        let params = params.into_img_params().map_err(DiffusionError::InvalidParameters)?;
+        self.generate_image(params)?.into_iter().next().ok_or(DiffusionError::GenerationFailed)
+    }
+
+    /// Enlarge `image` by `factor` with the ESRGAN model from
+    /// [`ContextParams::upscaler_path`].
+    ///
+    /// # Errors
+    /// Returns [`DiffusionError::UpscalerNotLoaded`] when the context was
+    /// created without an upscaler.
+    pub fn upscale(&self, image: Image, factor: u32) -> Result<Image, DiffusionError> {
+        let upscaler = self.upscaler.as_ref().ok_or(DiffusionError::UpscalerNotLoaded)?;
+        upscaler.upscale(image, factor)
     }
 
-    pub fn generate_video(&self, params: VideoParams) -> Result<Video, DiffusionError> {
+    pub fn generate_video(&self, mut params: VideoParams) -> Result<Video, DiffusionError> {
+        if params.vae_tiling_params.is_none() {
+            params.vae_tiling_params = self.params.default_vae_tiling();
+        }
         let inner = InnerVideoParams::from_canonical(self.lib.as_ref(), self.ctx, &params)
             .map_err(DiffusionError::InvalidParameters)?;
         let mut num_frames_out: i32 = 0;
@@ -14,10 +14,15 @@ pub enum DiffusionError {
     #[error("Invalid diffusion parameters: {0}")]
     InvalidParameters(String),
 
-    /// Reserved for future upscaling support (ESRGAN / RealESRGAN).
     #[error("Upscaling failed (NULL data in upscaled image)")]
     UpscalerFailed,
 
+    #[error("No upscaler loaded; set ContextParams::upscaler_path to an ESRGAN model")]
+    UpscalerNotLoaded,
+
+    #[error("No TAESD model loaded; set ContextParams::taesd_path to enable previews")]
+    TaesdNotLoaded,
+
     #[error("Backend list is unavailable")]
     BackendListUnavailable,
 }
@@ -2,6 +2,8 @@ mod context;
 mod error;
 mod logging;
 mod params;
+mod preview;
+mod progress;
 mod upscaler;
 
 use crate::params::InnerContextParams;
@@ -19,6 +21,9 @@ pub use logging::DiffusionLogLevel;
 pub use params::*;
 pub use upscaler::UpscalerContext;
 
+/// Tile size stable-diffusion.cpp uses for ESRGAN upscaling by default.
+const UPSCALER_TILE_SIZE: i32 = 128;
+
 pub(crate) struct SharedDiffusionLib(pub(crate) slab_diffusion_sys::DiffusionLib);
 
 // SAFETY: The loaded stable-diffusion symbol table is treated as immutable and
@@ -152,14 +157,28 @@ impl Diffusion {
     ///
     /// # Errors
     /// Returns [`DiffusionError::ContextCreationFailed`] when the native
-    /// `new_sd_ctx` call returns a null pointer (e.g. invalid model path).
+    /// `new_sd_ctx` call returns a null pointer (e.g. invalid model path), or
+    /// when [`ContextParams::upscaler_path`] is set but the ESRGAN model fails
+    /// to load.
     pub fn new_context(&self, params: ContextParams) -> Result<Context, DiffusionError> {
         let inner = InnerContextParams::from_canonical(self.lib.as_ref(), &params);
         let ctx = unsafe { self.lib.new_sd_ctx(&*inner.fp) };
         if ctx.is_null() {
             return Err(DiffusionError::ContextCreationFailed);
         }
-        Ok(Context { ctx, lib: self.lib.clone(), _params: params })
+        // Build the Context first so the sd_ctx_t is freed if the upscaler fails to load.
+        let mut context = Context { ctx, lib: self.lib.clone(), upscaler: None, params };
+        if let Some(upscaler_path) = context.params.upscaler_path.as_ref() {
+            context.upscaler = Some(self.new_upscaler_context(
+                &upscaler_path.to_string_lossy(),
+                context.params.offload_params_to_cpu.unwrap_or(false),
+                false,
+                context.params.n_threads.unwrap_or_else(|| self.get_num_physical_cores()),
+                UPSCALER_TILE_SIZE,
+                context.params.main_device.as_deref(),
+            )?);
+        }
+        Ok(context)
     }
 
     pub fn backend_list_size(&self) -> Result<usize, DiffusionError> {
@@ -7,7 +7,7 @@ use slab_diffusion_sys::{sd_ctx_params_t, sd_embedding_t};
 
 use crate::Diffusion;
 use crate::params::support::{c_string_ptr, new_c_string, sync_embedding_views};
-use crate::params::{Embedding, LoraApplyMode, Prediction, RngType, WeightType};
+use crate::params::{Embedding, LoraApplyMode, Prediction, RngType, TilingParams, WeightType};
 
 const fn default_flash_attn_enabled_option() -> Option<bool> {
     Some(true)
@@ -44,6 +44,9 @@ pub struct ContextParams {
     pub embeddings: Option<Vec<Embedding>>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub photo_maker_path: Option<PathBuf>,
+    /// ESRGAN model loaded next to the diffusion model for [`crate::Context::upscale`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub upscaler_path: Option<PathBuf>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub tensor_type_rules: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -52,6 +55,14 @@ pub struct ContextParams {
     pub free_params_immediately: Option<bool>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub n_threads: Option<i32>,
+    /// Decode latents in tiles so large images fit in limited VRAM. Applied to
+    /// every generation that does not set its own `vae_tiling_params`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vae_tiling: Option<bool>,
+    /// Tile edge in latent pixels when [`Self::vae_tiling`] is on; the native
+    /// default is used when unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vae_tile_size: Option<i32>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub wtype: Option<WeightType>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -218,6 +229,69 @@ impl std::fmt::Debug for InnerContextParams {
     }
 }
 
+impl ContextParams {
+    /// Wrap convolutions around the horizontal and/or vertical edges so the
+    /// generated image tiles seamlessly along that axis.
+    pub fn tileable(mut self, x: bool, y: bool) -> Self {
+        self.circular_x = Some(x);
+        self.circular_y = Some(y);
+        self
+    }
+
+    /// RNG used for the initial latent noise; [`RngType::Cuda`] reproduces
+    /// GPU-generated images across hardware.
+    pub fn with_rng_type(mut self, rng_type: RngType) -> Self {
+        self.rng_type = Some(rng_type);
+        self
+    }
+
+    /// RNG used by the sampler; follows [`Self::with_rng_type`] when unset.
+    pub fn with_sampler_rng_type(mut self, rng_type: RngType) -> Self {
+        self.sampler_rng_type = Some(rng_type);
+        self
+    }
+
+    /// Apply Chroma's DiT attention mask (native default: on).
+    pub fn with_chroma_use_dit_mask(mut self, enabled: bool) -> Self {
+        self.chroma_use_dit_mask = Some(enabled);
+        self
+    }
+
+    /// Mask T5 padding tokens for Chroma (native default: off).
+    pub fn with_chroma_use_t5_mask(mut self, enabled: bool) -> Self {
+        self.chroma_use_t5_mask = Some(enabled);
+        self
+    }
+
+    /// Padding tokens left unmasked after the T5 prompt (native default: 1).
+    pub fn with_chroma_t5_mask_pad(mut self, pad: i32) -> Self {
+        self.chroma_t5_mask_pad = Some(pad);
+        self
+    }
+
+    /// Enable Qwen-Image's `zero_cond_t` conditioning (native default: off).
+    pub fn with_qwen_image_zero_cond_t(mut self, enabled: bool) -> Self {
+        self.qwen_image_zero_cond_t = Some(enabled);
+        self
+    }
+
+    /// VAE tiling applied to generations that leave `vae_tiling_params` unset.
+    pub fn default_vae_tiling(&self) -> Option<TilingParams> {
+        if !self.vae_tiling.unwrap_or(false) {
+            return None;
+        }
+        let tile_size = self.vae_tile_size.unwrap_or(0);
+        Some(TilingParams {
+            enabled: true,
+            tile_size_x: tile_size,
+            tile_size_y: tile_size,
+            target_overlap: 0.5,
+            rel_size_x: 0.0,
+            rel_size_y: 0.0,
+        })
+    }
+}
+
 impl Diffusion {
     pub fn context_params_to_str(&self, params: &ContextParams) -> Option<String> {
         Some(format!("{params:#?}"))
@@ -240,6 +314,43 @@ impl InnerContextParams {
         self.fp.tae_preview_only = value.tae_preview_only.unwrap_or(false);
     }
 
+    /// `sd_ctx_params_init` leaves the sampler RNG at `RNG_TYPE_COUNT`, which
+    /// makes the sampler follow `rng_type`; only explicit choices override it.
+    fn apply_rng_types(&mut self, value: &ContextParams) {
+        if let Some(rng_type) = value.rng_type {
+            self.fp.rng_type = rng_type.into();
+        }
+        if let Some(sampler_rng_type) = value.sampler_rng_type {
+            self.fp.sampler_rng_type = sampler_rng_type.into();
+        }
+    }
+
+    fn apply_circular_padding(&mut self, value: &ContextParams) {
+        if let Some(circular_x) = value.circular_x {
+            self.fp.circular_x = circular_x;
+        }
+        if let Some(circular_y) = value.circular_y {
+            self.fp.circular_y = circular_y;
+        }
+    }
+
+    /// Chroma and Qwen-Image conditioning flags. Unset values keep the
+    /// `sd_ctx_params_init` defaults (DiT mask on, T5 mask off, pad 1).
+    fn apply_model_family_flags(&mut self, value: &ContextParams) {
+        if let Some(chroma_use_dit_mask) = value.chroma_use_dit_mask {
+            self.fp.chroma_use_dit_mask = chroma_use_dit_mask;
+        }
+        if let Some(chroma_use_t5_mask) = value.chroma_use_t5_mask {
+            self.fp.chroma_use_t5_mask = chroma_use_t5_mask;
+        }
+        if let Some(chroma_t5_mask_pad) = value.chroma_t5_mask_pad {
+            self.fp.chroma_t5_mask_pad = chroma_t5_mask_pad;
+        }
+        if let Some(qwen_image_zero_cond_t) = value.qwen_image_zero_cond_t {
+            self.fp.qwen_image_zero_cond_t = qwen_image_zero_cond_t;
+        }
+    }
+
     pub(crate) fn from_canonical(
         lib: &slab_diffusion_sys::DiffusionLib,
         value: &ContextParams,
@@ -347,12 +458,7 @@ impl InnerContextParams {
         if let Some(wtype) = value.wtype {
             inner.fp.wtype = wtype.into();
         }
-        if let Some(rng_type) = value.rng_type {
-            inner.fp.rng_type = rng_type.into();
-        }
-        if let Some(sampler_rng_type) = value.sampler_rng_type {
-            inner.fp.sampler_rng_type = sampler_rng_type.into();
-        }
+        inner.apply_rng_types(value);
         if let Some(prediction) = value.prediction {
             inner.fp.prediction = prediction.into();
         }
@@ -377,27 +483,11 @@ impl InnerContextParams {
         if let Some(vae_conv_direct) = value.vae_conv_direct {
             inner.fp.vae_conv_direct = vae_conv_direct;
         }
-        if let Some(circular_x) = value.circular_x {
-            inner.fp.circular_x = circular_x;
-        }
-        if let Some(circular_y) = value.circular_y {
-            inner.fp.circular_y = circular_y;
-        }
+        inner.apply_circular_padding(value);
         if let Some(force_sdxl_vae_conv_scale) = value.force_sdxl_vae_conv_scale {
             inner.fp.force_sdxl_vae_conv_scale = force_sdxl_vae_conv_scale;
         }
-        if let Some(chroma_use_dit_mask) = value.chroma_use_dit_mask {
-            inner.fp.chroma_use_dit_mask = chroma_use_dit_mask;
-        }
-        if let Some(chroma_use_t5_mask) = value.chroma_use_t5_mask {
-            inner.fp.chroma_use_t5_mask = chroma_use_t5_mask;
-        }
-        if let Some(chroma_t5_mask_pad) = value.chroma_t5_mask_pad {
-            inner.fp.chroma_t5_mask_pad = chroma_t5_mask_pad;
-        }
-        if let Some(qwen_image_zero_cond_t) = value.qwen_image_zero_cond_t {
-            inner.fp.qwen_image_zero_cond_t = qwen_image_zero_cond_t;
-        }
+        inner.apply_model_family_flags(value);
         if value.main_device.is_some() {
             Self::set_c_string(
                 &mut inner.main_device,
@@ -526,6 +616,79 @@ impl InnerContextParams {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use slab_diffusion_sys::rng_type_t;
+
+    #[test]
+    fn tileable_sets_both_circular_axes() {
+        let params = ContextParams::default().tileable(true, false);
+        assert_eq!((params.circular_x, params.circular_y), (Some(true), Some(false)));
+
+        let mut inner = InnerContextParams::default();
+        inner.apply_circular_padding(&params.tileable(true, true));
+        assert!(inner.fp.circular_x);
+        assert!(inner.fp.circular_y);
+    }
+
+    #[test]
+    fn vae_tiling_builds_default_tiling_params_only_when_enabled() {
+        assert_eq!(ContextParams::default().default_vae_tiling(), None);
+        assert_eq!(
+            ContextParams {
+                vae_tiling: Some(false),
+                vae_tile_size: Some(64),
+                ..Default::default()
+            }
+            .default_vae_tiling(),
+            None
+        );
+
+        let tiling =
+            ContextParams { vae_tiling: Some(true), vae_tile_size: Some(64), ..Default::default() }
+                .default_vae_tiling()
+                .expect("tiling should be enabled");
+        assert!(tiling.enabled);
+        assert_eq!((tiling.tile_size_x, tiling.tile_size_y), (64, 64));
+        assert_eq!(tiling.target_overlap, 0.5);
+    }
+
+    #[test]
+    fn explicit_rng_types_reach_native_context_params() {
+        let mut inner = InnerContextParams::default();
+        inner.fp.sampler_rng_type = RngType::Unknown.into();
+
+        inner.apply_rng_types(&ContextParams::default().with_rng_type(RngType::Cuda));
+        assert_eq!(inner.fp.rng_type, rng_type_t::from(RngType::Cuda));
+        assert_eq!(inner.fp.sampler_rng_type, rng_type_t::from(RngType::Unknown));
+
+        inner.apply_rng_types(&ContextParams::default().with_sampler_rng_type(RngType::Cpu));
+        assert_eq!(inner.fp.rng_type, rng_type_t::from(RngType::Cuda));
+        assert_eq!(inner.fp.sampler_rng_type, rng_type_t::from(RngType::Cpu));
+    }
+
+    #[test]
+    fn chroma_flags_override_only_what_is_set() {
+        let mut inner = InnerContextParams::default();
+        inner.fp.chroma_use_dit_mask = true;
+        inner.fp.chroma_t5_mask_pad = 1;
+
+        inner.apply_model_family_flags(&ContextParams::default().with_chroma_use_t5_mask(true));
+
+        assert!(inner.fp.chroma_use_t5_mask);
+        assert!(inner.fp.chroma_use_dit_mask);
+        assert_eq!(inner.fp.chroma_t5_mask_pad, 1);
+        assert!(!inner.fp.qwen_image_zero_cond_t);
+
+        inner.apply_model_family_flags(
+            &ContextParams::default()
+                .with_chroma_use_dit_mask(false)
+                .with_chroma_t5_mask_pad(0)
+                .with_qwen_image_zero_cond_t(true),
+        );
+
+        assert!(!inner.fp.chroma_use_dit_mask);
+        assert_eq!(inner.fp.chroma_t5_mask_pad, 0);
+        assert!(inner.fp.qwen_image_zero_cond_t);
+    }
 
     #[test]
     fn unset_context_flags_use_server_compatible_defaults() {
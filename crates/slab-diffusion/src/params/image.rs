@@ -99,6 +99,8 @@ pub struct ImgParams {
     pub sample_params: Option<SampleParams>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub strength: Option<f32>,
+    /// RNG seed; negative values pick a random seed. Image `i` of a batch is
+    /// generated with `seed + i`, so a fixed seed reproduces the whole batch.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub seed: Option<i64>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -115,6 +117,107 @@ pub struct ImgParams {
     pub cache: Option<CacheParams>,
 }
 
+/// A generated image together with the RNG seed that produced it.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SeededImage {
+    pub seed: i64,
+    pub image: Image,
+}
+
+impl ImgParams {
+    /// Skip the last `layers` CLIP layers when encoding the prompt; `-1` keeps
+    /// the model's own default.
+    pub fn clip_skip(mut self, layers: i32) -> Self {
+        self.clip_skip = Some(layers);
+        self
+    }
+
+    /// Number of denoising steps; unset keeps the library default.
+    pub fn sample_steps(mut self, steps: i32) -> Self {
+        self.sample_params.get_or_insert_with(SampleParams::default).sample_steps = Some(steps);
+        self
+    }
+
+    /// Replace a missing or negative seed with a concrete random one so the
+    /// caller can report it, and return the seed that will be used.
+    pub(crate) fn resolve_seed(&mut self) -> i64 {
+        let seed = match self.seed {
+            Some(seed) if seed >= 0 => seed,
+            _ => random_seed(),
+        };
+        self.seed = Some(seed);
+        seed
+    }
+}
+
+fn random_seed() -> i64 {
+    use std::collections::hash_map::RandomState;
+    use std::hash::BuildHasher;
+
+    let entropy = RandomState::new().hash_one(std::time::SystemTime::now());
+    i64::from((entropy >> 33) as u32)
+}
+
+/// Masked inpainting request for [`crate::Context::inpaint`].
+///
+/// `mask` must be a single-channel image with the same size as `image`; white
+/// pixels are regenerated and black pixels are kept. Remaining generation
+/// options come from `params`, whose image, mask, prompt, size and strength
+/// fields are overridden.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct InpaintParams {
+    pub image: Image,
+    pub mask: Image,
+    pub prompt: String,
+    pub strength: f32,
+    #[serde(default)]
+    pub params: ImgParams,
+}
+
+impl InpaintParams {
+    pub(crate) fn into_img_params(self) -> Result<ImgParams, String> {
+        let Self { image, mask, prompt, strength, params } = self;
+        if mask.channel != 1 {
+            return Err(format!("inpaint mask must have 1 channel, got {}", mask.channel));
+        }
+        if (mask.width, mask.height) != (image.width, image.height) {
+            return Err(format!(
+                "inpaint mask is {}x{} but the image is {}x{}",
+                mask.width, mask.height, image.width, image.height
+            ));
+        }
+        check_pixel_data("inpaint image", &image)?;
+        check_pixel_data("inpaint mask", &mask)?;
+
+        Ok(ImgParams {
+            prompt: Some(prompt),
+            width: Some(image.width),
+            height: Some(image.height),
+            strength: Some(strength),
+            batch_count: Some(1),
+            init_image: Some(image),
+            mask_image: Some(mask),
+            ..params
+        })
+    }
+}
+
+/// Reject images whose buffer does not hold exactly `width * height * channel`
+/// bytes, since the native side reads that many without checking.
+fn check_pixel_data(what: &str, image: &Image) -> Result<(), String> {
+    let expected = image.width as usize * image.height as usize * image.channel as usize;
+    if image.data.len() != expected {
+        return Err(format!(
+            "{what} is {}x{}x{} and needs {expected} bytes, got {}",
+            image.width,
+            image.height,
+            image.channel,
+            image.data.len()
+        ));
+    }
+    Ok(())
+}
+
 /// FFI-only image inference parameter backing struct.
 pub(crate) struct InnerImgParams {
     pub(crate) fp: Box<sd_img_gen_params_t>,
@@ -453,6 +556,25 @@ mod tests {
         Image { width: 2, height: 1, channel: 3, data: vec![value; 6] }
     }
 
+    #[test]
+    fn clip_skip_and_sample_steps_builders_keep_other_sample_params() {
+        let params = ImgParams {
+            sample_params: Some(SampleParams { eta: Some(0.5), ..Default::default() }),
+            ..Default::default()
+        }
+        .clip_skip(2)
+        .sample_steps(4);
+
+        assert_eq!(params.clip_skip, Some(2));
+        let sample_params = params.sample_params.expect("sample params should be set");
+        assert_eq!(sample_params.sample_steps, Some(4));
+        assert_eq!(sample_params.eta, Some(0.5));
+        assert_eq!(
+            ImgParams::default().sample_steps(20).sample_params.unwrap().sample_steps,
+            Some(20)
+        );
+    }
+
     #[test]
     fn image_from_raw_copies_pixels_and_handles_empty_images() {
         let raw = sd_image_t {
@@ -479,6 +601,59 @@ mod tests {
         assert!(empty.data.is_empty());
     }
 
+    #[test]
+    fn resolve_seed_keeps_fixed_seeds_and_replaces_random_ones() {
+        let mut fixed = ImgParams { seed: Some(42), ..Default::default() };
+        assert_eq!(fixed.resolve_seed(), 42);
+
+        for seed in [None, Some(-1)] {
+            let mut params = ImgParams { seed, ..Default::default() };
+            let resolved = params.resolve_seed();
+            assert!(resolved >= 0);
+            assert_eq!(params.seed, Some(resolved));
+        }
+    }
+
+    #[test]
+    fn inpaint_params_require_matching_single_channel_mask() {
+        let params = InpaintParams {
+            image: sample_image(3),
+            mask: Image { width: 2, height: 1, channel: 1, data: vec![0, 255] },
+            prompt: "a red hat".to_owned(),
+            strength: 0.75,
+            params: ImgParams { seed: Some(7), batch_count: Some(4), ..Default::default() },
+        };
+
+        let img = params.clone().into_img_params().expect("valid inpaint params");
+        assert_eq!(img.init_image, Some(sample_image(3)));
+        assert_eq!(img.mask_image.as_ref().map(|mask| mask.channel), Some(1));
+        assert_eq!((img.width, img.height), (Some(2), Some(1)));
+        assert_eq!(img.strength, Some(0.75));
+        assert_eq!(img.seed, Some(7));
+        assert_eq!(img.batch_count, Some(1));
+
+        let wrong_size = InpaintParams {
+            mask: Image { width: 1, height: 1, channel: 1, data: vec![255] },
+            ..params.clone()
+        };
+        assert!(wrong_size.into_img_params().unwrap_err().contains("1x1 but the image is 2x1"));
+
+        let short_mask = InpaintParams {
+            mask: Image { width: 2, height: 1, channel: 1, data: vec![255] },
+            ..params.clone()
+        };
+        assert!(short_mask.into_img_params().unwrap_err().contains("needs 2 bytes, got 1"));
+
+        let short_image = InpaintParams {
+            image: Image { width: 2, height: 1, channel: 3, data: vec![0; 5] },
+            ..params.clone()
+        };
+        assert!(short_image.into_img_params().unwrap_err().contains("needs 6 bytes, got 5"));
+
+        let wrong_channels = InpaintParams { mask: sample_image(255), ..params };
+        assert!(wrong_channels.into_img_params().is_err());
+    }
+
     #[test]
     fn clone_resyncs_owned_prompt_and_ref_images() {
         let params = ImgParams {
@@ -28,7 +28,7 @@ pub use cache::CacheParams;
 pub use context::ContextParams;
 pub use embedding::Embedding;
 pub use guidance::GuidanceParams;
-pub use image::{Image, ImgParams};
+pub use image::{Image, ImgParams, InpaintParams, SeededImage};
 pub use lora::{Lora, LoraApplyMode};
 pub use pm::PmParams;
 pub use prediction::Prediction;
@@ -66,7 +66,7 @@ impl From<LogLevel> for sd_log_level_t {
 }
 
 /// RNG type for noise generation.
-use slab_diffusion_sys::rng_type_t;
+pub use slab_diffusion_sys::rng_type_t;
 // RNG type parameters must keep code order
 #[rustfmt::skip]
 use slab_diffusion_sys::{
@@ -0,0 +1,179 @@
+use core::ffi::{c_int, c_void};
+use std::cell::Cell;
+use std::panic::{AssertUnwindSafe, catch_unwind};
+use std::slice;
+use std::sync::Mutex;
+
+use crate::error::DiffusionError;
+use crate::params::{Image, ImgParams, SeededImage, owned_image_from_raw};
+use crate::{Context, SharedDiffusionLib};
+
+/// Type-erased pointer to the caller's preview closure plus its monomorphized
+/// entry point.
+type PreviewSink = (*mut c_void, unsafe fn(*mut c_void, i32, Image));
+
+thread_local! {
+    // Like the progress callback, stable-diffusion.cpp keeps a single
+    // process-wide preview callback that runs on the sampling thread.
+    static PREVIEW_SINK: Cell<Option<PreviewSink>> = const { Cell::new(None) };
+}
+
+/// Serializes preview generations. The native preview mode is shared by every
+/// context of the library (workers fork the same handle), so without this a
+/// finishing generation would switch previews off for another one mid-run.
+static PREVIEW_LOCK: Mutex<()> = Mutex::new(());
+
+impl Context {
+    /// Returns `true` when the context was created with a TAESD model, which
+    /// [`Context::generate_image_with_preview`] needs to decode previews.
+    pub fn has_taesd(&self) -> bool {
+        self.params.taesd_path.is_some()
+    }
+
+    /// Generate images like [`Context::generate_image_with_seed_and_progress`], also
+    /// decoding the denoised latent with TAESD every `interval` steps and
+    /// handing the low-resolution preview to `on_preview(step, image)`.
+    ///
+    /// Panics raised by either callback are caught and discarded instead of
+    /// unwinding into C.
+    ///
+    /// Preview generations run one at a time across the process. Plain
+    /// generations on other workers are not blocked, but while a preview is
+    /// active they also pay for TAESD decoding; their frames find no sink on
+    /// their thread and are dropped.
+    ///
+    /// # Errors
+    /// Returns [`DiffusionError::TaesdNotLoaded`] when the context was created
+    /// without [`ContextParams::taesd_path`](crate::ContextParams::taesd_path).
+    pub fn generate_image_with_preview<P, V>(
+        &self,
+        params: ImgParams,
+        interval: i32,
+        on_progress: P,
+        mut on_preview: V,
+    ) -> Result<Vec<SeededImage>, DiffusionError>
+    where
+        P: FnMut(i32, i32),
+        V: FnMut(i32, Image),
+    {
+        if !self.has_taesd() {
+            return Err(DiffusionError::TaesdNotLoaded);
+        }
+
+        let _lock = PREVIEW_LOCK.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        let _guard = PreviewGuard {
+            lib: self.lib.as_ref(),
+            previous: PREVIEW_SINK.replace(Some(sink_for(&mut on_preview))),
+        };
+        install_preview_trampoline(self.lib.as_ref(), interval.max(1));
+        self.generate_image_with_seed_and_progress(params, on_progress)
+    }
+}
+
+/// Restores the previous sink and turns native previews back off even if
+/// generation unwinds, so later generations don't pay for TAESD decoding.
+struct PreviewGuard<'a> {
+    lib: &'a SharedDiffusionLib,
+    previous: Option<PreviewSink>,
+}
+
+impl Drop for PreviewGuard<'_> {
+    fn drop(&mut self) {
+        PREVIEW_SINK.set(self.previous.take());
+        unsafe {
+            self.lib.sd_set_preview_callback(
+                None,
+                slab_diffusion_sys::preview_t_PREVIEW_NONE,
+                1,
+                true,
+                false,
+                std::ptr::null_mut(),
+            )
+        };
+    }
+}
+
+fn install_preview_trampoline(lib: &SharedDiffusionLib, interval: i32) {
+    unsafe {
+        lib.sd_set_preview_callback(
+            Some(preview_trampoline),
+            slab_diffusion_sys::preview_t_PREVIEW_TAE,
+            interval,
+            true,
+            false,
+            std::ptr::null_mut(),
+        )
+    };
+}
+
+fn sink_for<F>(on_preview: &mut F) -> PreviewSink
+where
+    F: FnMut(i32, Image),
+{
+    ((on_preview as *mut F).cast::<c_void>(), call_sink::<F>)
+}
+
+/// # Safety
+/// `data` must point at a live `F` that is not otherwise borrowed.
+unsafe fn call_sink<F>(data: *mut c_void, step: i32, image: Image)
+where
+    F: FnMut(i32, Image),
+{
+    let on_preview = unsafe { &mut *data.cast::<F>() };
+    let _ = catch_unwind(AssertUnwindSafe(|| on_preview(step, image)));
+}
+
+unsafe extern "C" fn preview_trampoline(
+    step: c_int,
+    frame_count: c_int,
+    frames: *mut slab_diffusion_sys::sd_image_t,
+    _is_noisy: bool,
+    _: *mut c_void,
+) {
+    let Some((data, call)) = PREVIEW_SINK.get() else {
+        return;
+    };
+    let Ok(frame_count) = usize::try_from(frame_count) else {
+        return;
+    };
+    if frames.is_null() {
+        return;
+    }
+
+    // The frames stay owned by stable-diffusion.cpp; copy them out before the
+    // callback returns.
+    for raw in unsafe { slice::from_raw_parts(frames, frame_count) } {
+        // SAFETY: the sink is only installed for the duration of
+        // `generate_image_with_preview` on this thread.
+        unsafe { call(data, step, owned_image_from_raw(*raw)) };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn preview_trampoline_copies_frames_into_the_installed_sink() {
+        let mut pixels = [1u8, 2, 3, 4, 5, 6];
+        let mut frames = [slab_diffusion_sys::sd_image_t {
+            width: 2,
+            height: 1,
+            channel: 3,
+            data: pixels.as_mut_ptr(),
+        }];
+        let mut seen = Vec::new();
+        let mut on_preview = |step: i32, image: Image| seen.push((step, image));
+
+        let previous = PREVIEW_SINK.replace(Some(sink_for(&mut on_preview)));
+        unsafe { preview_trampoline(3, 1, frames.as_mut_ptr(), false, std::ptr::null_mut()) };
+        unsafe { preview_trampoline(4, 1, std::ptr::null_mut(), false, std::ptr::null_mut()) };
+        PREVIEW_SINK.set(previous);
+
+        assert_eq!(seen.len(), 1);
+        let (step, image) = &seen[0];
+        assert_eq!(*step, 3);
+        assert_eq!((image.width, image.height, image.channel), (2, 1, 3));
+        assert_eq!(image.data, pixels);
+    }
+}
@@ -0,0 +1,90 @@
+use core::ffi::{c_int, c_void};
+use std::cell::Cell;
+use std::panic::{AssertUnwindSafe, catch_unwind};
+
+use crate::error::DiffusionError;
+use crate::params::{Image, ImgParams, SeededImage};
+use crate::{Context, SharedDiffusionLib};
+
+/// Type-erased pointer to the caller's progress closure plus its monomorphized
+/// entry point.
+type ProgressSink = (*mut c_void, unsafe fn(*mut c_void, i32, i32));
+
+thread_local! {
+    // stable-diffusion.cpp only keeps one process-wide progress callback, but it
+    // invokes it on the thread running the sampler. Routing through a
+    // thread-local sink keeps concurrent workers from seeing each other's steps.
+    static PROGRESS_SINK: Cell<Option<ProgressSink>> = const { Cell::new(None) };
+}
+
+impl Context {
+    /// Generate images like [`Context::generate_image`], reporting every
+    /// denoising step to `on_progress(step, steps)` on the calling thread.
+    ///
+    /// Panics raised by `on_progress` are caught and discarded instead of
+    /// unwinding into C.
+    pub fn generate_image_with_progress<F>(
+        &self,
+        params: ImgParams,
+        on_progress: F,
+    ) -> Result<Vec<Image>, DiffusionError>
+    where
+        F: FnMut(i32, i32),
+    {
+        Ok(self
+            .generate_image_with_seed_and_progress(params, on_progress)?
+            .into_iter()
+            .map(|seeded| seeded.image)
+            .collect())
+    }
+
+    /// Generate images like [`Context::generate_image_with_seed`], reporting
+    /// every denoising step like [`Context::generate_image_with_progress`].
+    pub fn generate_image_with_seed_and_progress<F>(
+        &self,
+        params: ImgParams,
+        mut on_progress: F,
+    ) -> Result<Vec<SeededImage>, DiffusionError>
+    where
+        F: FnMut(i32, i32),
+    {
+        install_progress_trampoline(self.lib.as_ref());
+
+        let sink: ProgressSink = ((&mut on_progress as *mut F).cast::<c_void>(), call_sink::<F>);
+        let _guard = SinkGuard { previous: PROGRESS_SINK.replace(Some(sink)) };
+        self.generate_image_with_seed(params)
+    }
+}
+
+/// Restores the previous sink even if generation unwinds.
+struct SinkGuard {
+    previous: Option<ProgressSink>,
+}
+
+impl Drop for SinkGuard {
+    fn drop(&mut self) {
+        PROGRESS_SINK.set(self.previous.take());
+    }
+}
+
+fn install_progress_trampoline(lib: &SharedDiffusionLib) {
+    unsafe { lib.sd_set_progress_callback(Some(progress_trampoline), std::ptr::null_mut()) };
+}
+
+/// # Safety
+/// `data` must point at a live `F` that is not otherwise borrowed.
+unsafe fn call_sink<F>(data: *mut c_void, step: i32, steps: i32)
+where
+    F: FnMut(i32, i32),
+{
+    let on_progress = unsafe { &mut *data.cast::<F>() };
+    let _ = catch_unwind(AssertUnwindSafe(|| on_progress(step, steps)));
+}
+
+unsafe extern "C" fn progress_trampoline(step: c_int, steps: c_int, _time: f32, _: *mut c_void) {
+    if let Some((data, call)) = PROGRESS_SINK.get() {
+        // SAFETY: the sink is only installed for the duration of
+        // `generate_image_with_progress` on this thread.
+        unsafe { call(data, step, steps) };
+    }
+}
@@ -1,6 +1,8 @@
 use hf_hub::api::sync::Api;
 use hf_hub::{Repo, RepoType};
-use slab_diffusion::{ContextParams, Diffusion, ImgParams, SampleMethod, SampleParams};
+use slab_diffusion::{
+    ContextParams, Diffusion, Image, ImgParams, InpaintParams, SampleMethod, SampleParams,
+};
 use std::path::{Path, PathBuf};
 #[cfg(windows)]
 use std::sync::OnceLock;
@@ -146,3 +148,170 @@ fn minisd_generates_small_image_twice_from_same_context() {
         assert!(images[0].data.iter().any(|value| *value != 0));
     }
 }
+
+#[test]
+#[ignore = "requires vendored diffusion runtime and cached miniSD model"]
+fn minisd_fixed_seed_reproduces_identical_batches() {
+    let diffusion = load_vendored_diffusion();
+    let model_path = resolve_minisd_model_path();
+    let ctx = diffusion
+        .new_context(ContextParams { model_path: Some(model_path), ..Default::default() })
+        .unwrap_or_else(|error| panic!("failed to create miniSD context: {error}"));
+
+    let image_params = ImgParams {
+        prompt: Some("a tiny orange cat".to_owned()),
+        width: Some(128),
+        height: Some(128),
+        seed: Some(1234),
+        batch_count: Some(2),
+        sample_params: Some(SampleParams {
+            sample_steps: Some(2),
+            sample_method: Some(SampleMethod::Euler),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+
+    let first = ctx.generate_image_with_seed(image_params.clone()).expect("first generation");
+    let second = ctx.generate_image_with_seed(image_params).expect("second generation");
+
+    assert_eq!(first.len(), 2);
+    assert_eq!(first, second);
+    // Batch entries use consecutive seeds, so they must not collapse into one image.
+    assert_ne!(first[0].image.data, first[1].image.data);
+    assert_eq!((first[0].seed, first[1].seed), (1234, 1235));
+}
+
+#[test]
+#[ignore = "requires vendored diffusion runtime and cached miniSD model"]
+fn minisd_random_seed_is_reported() {
+    let diffusion = load_vendored_diffusion();
+    let model_path = resolve_minisd_model_path();
+    let ctx = diffusion
+        .new_context(ContextParams { model_path: Some(model_path), ..Default::default() })
+        .unwrap_or_else(|error| panic!("failed to create miniSD context: {error}"));
+
+    let images = ctx
+        .generate_image_with_seed(ImgParams {
+            prompt: Some("a tiny orange cat".to_owned()),
+            width: Some(128),
+            height: Some(128),
+            seed: Some(-1),
+            sample_params: Some(SampleParams {
+                sample_steps: Some(1),
+                sample_method: Some(SampleMethod::Euler),
+                ..Default::default()
+            }),
+            ..Default::default()
+        })
+        .expect("generation with a random seed");
+
+    assert_eq!(images.len(), 1);
+    assert!(images[0].seed >= 0);
+}
+
+#[test]
+#[ignore = "requires vendored diffusion runtime and cached miniSD model"]
+fn minisd_sample_steps_are_forwarded() {
+    let diffusion = load_vendored_diffusion();
+    let model_path = resolve_minisd_model_path();
+    let ctx = diffusion
+        .new_context(ContextParams { model_path: Some(model_path), ..Default::default() })
+        .unwrap_or_else(|error| panic!("failed to create miniSD context: {error}"));
+
+    let image_params = ImgParams {
+        prompt: Some("a tiny orange cat".to_owned()),
+        width: Some(128),
+        height: Some(128),
+        seed: Some(7),
+        ..Default::default()
+    }
+    .clip_skip(1);
+
+    let few = ctx.generate_image(image_params.clone().sample_steps(4)).expect("4-step generation");
+    let many = ctx.generate_image(image_params.sample_steps(20)).expect("20-step generation");
+
+    assert_eq!((few.len(), many.len()), (1, 1));
+    assert_ne!(few[0].data, many[0].data);
+}
+
+#[test]
+#[ignore = "requires vendored diffusion runtime and cached miniSD model"]
+fn minisd_inpaint_keeps_unmasked_pixels() {
+    let diffusion = load_vendored_diffusion();
+    let model_path = resolve_minisd_model_path();
+    let ctx = diffusion
+        .new_context(ContextParams { model_path: Some(model_path), ..Default::default() })
+        .unwrap_or_else(|error| panic!("failed to create miniSD context: {error}"));
+
+    let (width, height) = (256_u32, 256_u32);
+    let image = Image { width, height, channel: 3, data: vec![128; (width * height * 3) as usize] };
+    // Regenerate only the left half.
+    let mask_data = (0..width * height)
+        .map(|index| if index % width < width / 2 { 255 } else { 0 })
+        .collect::<Vec<u8>>();
+    let mask = Image { width, height, channel: 1, data: mask_data };
+
+    let output = ctx
+        .inpaint(InpaintParams {
+            image: image.clone(),
+            mask,
+            prompt: "a tiny orange cat".to_owned(),
+            strength: 0.9,
+            params: ImgParams {
+                seed: Some(42),
+                sample_params: Some(SampleParams {
+                    sample_steps: Some(2),
+                    sample_method: Some(SampleMethod::Euler),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            },
+        })
+        .unwrap_or_else(|error| panic!("failed to inpaint miniSD test image: {error}"));
+
+    assert_eq!((output.width, output.height, output.channel), (width, height, 3));
+    let right_half_diff = output
+        .data
+        .chunks_exact(3)
+        .zip(image.data.chunks_exact(3))
+        .enumerate()
+        .filter(|(index, _)| *index as u32 % width >= width / 2 + 8)
+        .flat_map(|(_, (out, base))| out.iter().zip(base).map(|(a, b)| a.abs_diff(*b) as u64))
+        .collect::<Vec<_>>();
+    let mean_diff = right_half_diff.iter().sum::<u64>() / right_half_diff.len() as u64;
+    // Unmasked pixels only go through a VAE round-trip, so they stay close to the input.
+    assert!(mean_diff < 16, "unmasked pixels drifted by {mean_diff} on average");
+}
+
+#[test]
+#[ignore = "requires vendored diffusion runtime, cached miniSD model and SLAB_TEST_ESRGAN_MODEL"]
+fn minisd_context_upscales_with_esrgan_model() {
+    let diffusion = load_vendored_diffusion();
+    let model_path = resolve_minisd_model_path();
+    let upscaler_path = std::env::var_os("SLAB_TEST_ESRGAN_MODEL")
+        .map(PathBuf::from)
+        .expect("SLAB_TEST_ESRGAN_MODEL must point at an ESRGAN x4 model");
+
+    let plain = diffusion
+        .new_context(ContextParams { model_path: Some(model_path.clone()), ..Default::default() })
+        .unwrap_or_else(|error| panic!("failed to create miniSD context: {error}"));
+    let image = Image { width: 64, height: 64, channel: 3, data: vec![96; 64 * 64 * 3] };
+    assert!(matches!(
+        plain.upscale(image.clone(), 4),
+        Err(slab_diffusion::DiffusionError::UpscalerNotLoaded)
+    ));
+
+    let ctx = diffusion
+        .new_context(ContextParams {
+            model_path: Some(model_path),
+            upscaler_path: Some(upscaler_path),
+            ..Default::default()
+        })
+        .unwrap_or_else(|error| panic!("failed to create miniSD context with upscaler: {error}"));
+    let upscaled =
+        ctx.upscale(image, 4).unwrap_or_else(|error| panic!("failed to upscale image: {error}"));
+
+    assert_eq!((upscaled.width, upscaled.height, upscaled.channel), (256, 256, 3));
+    assert_eq!(upscaled.data.len(), 256 * 256 * 3);
+}
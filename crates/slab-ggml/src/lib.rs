@@ -147,6 +147,25 @@ impl GGML {
         }
         Ok(unsafe { CStr::from_ptr(name_ptr) }.to_str()?)
     }
+
+    pub fn ggml_backend_dev_description(
+        &self,
+        device: GGMLBackendDevice,
+    ) -> Result<&str, GGMLError> {
+        let description_ptr = unsafe { self.lib.base.ggml_backend_dev_description(device.device) };
+        if description_ptr.is_null() {
+            return Err(GGMLError::NullPointer);
+        }
+        Ok(unsafe { CStr::from_ptr(description_ptr) }.to_str()?)
+    }
+
+    /// Free and total memory of `device` in bytes, as `(free, total)`.
+    pub fn ggml_backend_dev_memory(&self, device: GGMLBackendDevice) -> (usize, usize) {
+        let mut free = 0usize;
+        let mut total = 0usize;
+        unsafe { self.lib.base.ggml_backend_dev_memory(device.device, &mut free, &mut total) };
+        (free, total)
+    }
 }
 
 impl fmt::Debug for GGML {
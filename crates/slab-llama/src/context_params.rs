@@ -1,3 +1,69 @@
+/// RoPE frequency scaling strategy.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LlamaRopeScalingType {
+    /// Use the scaling type stored in the model.
+    #[default]
+    Unspecified,
+    None,
+    Linear,
+    Yarn,
+    LongRope,
+}
+
+impl LlamaRopeScalingType {
+    fn to_c(self) -> slab_llama_sys::llama_rope_scaling_type {
+        match self {
+            Self::Unspecified => {
+                slab_llama_sys::llama_rope_scaling_type_LLAMA_ROPE_SCALING_TYPE_UNSPECIFIED
+            }
+            Self::None => slab_llama_sys::llama_rope_scaling_type_LLAMA_ROPE_SCALING_TYPE_NONE,
+            Self::Linear => slab_llama_sys::llama_rope_scaling_type_LLAMA_ROPE_SCALING_TYPE_LINEAR,
+            Self::Yarn => slab_llama_sys::llama_rope_scaling_type_LLAMA_ROPE_SCALING_TYPE_YARN,
+            Self::LongRope => {
+                slab_llama_sys::llama_rope_scaling_type_LLAMA_ROPE_SCALING_TYPE_LONGROPE
+            }
+        }
+    }
+}
+
+/// Tensor element type used for the K and V caches.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum GgmlType {
+    F32,
+    #[default]
+    F16,
+    Bf16,
+    Q8_0,
+    Q5_0,
+    Q5_1,
+    Q4_0,
+    Q4_1,
+    Iq4Nl,
+}
+
+impl GgmlType {
+    /// Whether the type is block-quantized rather than a plain float.
+    pub fn is_quantized(self) -> bool {
+        !matches!(self, Self::F32 | Self::F16 | Self::Bf16)
+    }
+
+    fn to_c(self) -> slab_llama_sys::ggml_type {
+        match self {
+            Self::F32 => slab_llama_sys::ggml_type_GGML_TYPE_F32,
+            Self::F16 => slab_llama_sys::ggml_type_GGML_TYPE_F16,
+            Self::Bf16 => slab_llama_sys::ggml_type_GGML_TYPE_BF16,
+            Self::Q8_0 => slab_llama_sys::ggml_type_GGML_TYPE_Q8_0,
+            Self::Q5_0 => slab_llama_sys::ggml_type_GGML_TYPE_Q5_0,
+            Self::Q5_1 => slab_llama_sys::ggml_type_GGML_TYPE_Q5_1,
+            Self::Q4_0 => slab_llama_sys::ggml_type_GGML_TYPE_Q4_0,
+            Self::Q4_1 => slab_llama_sys::ggml_type_GGML_TYPE_Q4_1,
+            Self::Iq4Nl => slab_llama_sys::ggml_type_GGML_TYPE_IQ4_NL,
+        }
+    }
+}
+
+use crate::LlamaError;
+
 /// Parameters for creating a llama inference context.
 #[derive(Debug, Clone)]
 pub struct LlamaContextParams {
@@ -13,14 +79,25 @@ pub struct LlamaContextParams {
     pub n_threads: i32,
     /// Number of threads for batch processing.
     pub n_threads_batch: i32,
+    /// RoPE scaling type.
+    pub rope_scaling_type: LlamaRopeScalingType,
+    /// RoPE base frequency (0 = use model default).
+    pub rope_freq_base: f32,
+    /// RoPE frequency scaling factor (0 = use model default).
+    pub rope_freq_scale: f32,
     /// Offload KV cache to GPU.
     pub offload_kqv: bool,
-    /// Enable flash attention.
+    /// Enable flash attention; `false` disables it instead of deferring to
+    /// the backend's automatic choice.
     pub flash_attn: bool,
     /// Disable performance metrics.
     pub no_perf: bool,
     /// Use a unified KV buffer across input sequences.
     pub kv_unified: bool,
+    /// Element type of the K cache.
+    pub type_k: GgmlType,
+    /// Element type of the V cache; quantized types require flash attention.
+    pub type_v: GgmlType,
 }
 
 impl Default for LlamaContextParams {
@@ -32,12 +109,17 @@ impl Default for LlamaContextParams {
             n_seq_max: 64,
             n_threads: 4,
             n_threads_batch: 4,
+            rope_scaling_type: LlamaRopeScalingType::default(),
+            rope_freq_base: 0.0,
+            rope_freq_scale: 0.0,
             offload_kqv: true,
             flash_attn: true,
             no_perf: false,
             // Our runtime batches multiple seq_ids inside one context and expects
             // `n_ctx` to behave like the per-sequence window.
             kv_unified: true,
+            type_k: GgmlType::F16,
+            type_v: GgmlType::F16,
         }
     }
 }
@@ -47,6 +129,10 @@ impl LlamaContextParams {
         Self::default()
     }
 
+    /// Context window in tokens; `0` uses the model's training context.
+    ///
+    /// Windows larger than the model's `n_ctx_train` only stay coherent when
+    /// RoPE scaling is configured (see [`Self::rope_scaling_type`]).
     pub fn n_ctx(mut self, v: u32) -> Self {
         self.n_ctx = v;
         self
@@ -77,6 +163,21 @@ impl LlamaContextParams {
         self
     }
 
+    pub fn rope_scaling_type(mut self, v: LlamaRopeScalingType) -> Self {
+        self.rope_scaling_type = v;
+        self
+    }
+
+    pub fn rope_freq_base(mut self, v: f32) -> Self {
+        self.rope_freq_base = v;
+        self
+    }
+
+    pub fn rope_freq_scale(mut self, v: f32) -> Self {
+        self.rope_freq_scale = v;
+        self
+    }
+
     pub fn offload_kqv(mut self, v: bool) -> Self {
         self.offload_kqv = v;
         self
@@ -97,11 +198,44 @@ impl LlamaContextParams {
         self
     }
 
+    /// KV cache element types. Quantized types (e.g. `Q8_0`) trade some
+    /// quality for a smaller cache, allowing longer contexts in the same VRAM.
+    pub fn kv_type(mut self, k: GgmlType, v: GgmlType) -> Self {
+        self.type_k = k;
+        self.type_v = v;
+        self
+    }
+
+    /// Check that the batch sizes and KV cache types are consistent.
+    pub fn validate(&self) -> Result<(), LlamaError> {
+        if self.n_batch == 0 {
+            return Err(LlamaError::InvalidContextParams("n_batch must be greater than 0".into()));
+        }
+        if self.n_ubatch > self.n_batch {
+            return Err(LlamaError::InvalidContextParams(format!(
+                "n_ubatch ({}) must not exceed n_batch ({})",
+                self.n_ubatch, self.n_batch
+            )));
+        }
+        if self.type_v.is_quantized() && !self.flash_attn {
+            return Err(LlamaError::InvalidContextParams(format!(
+                "quantized V cache type {:?} requires flash attention",
+                self.type_v
+            )));
+        }
+        Ok(())
+    }
+
     pub(crate) fn to_c_params(
         &self,
         lib: &slab_llama_sys::LlamaLib,
     ) -> slab_llama_sys::llama_context_params {
         let mut params = unsafe { lib.llama_context_default_params() };
+        self.apply(&mut params);
+        params
+    }
+
+    fn apply(&self, params: &mut slab_llama_sys::llama_context_params) {
         params.n_ctx = self.n_ctx;
         params.n_batch = self.n_batch;
         if self.n_ubatch > 0 {
@@ -110,14 +244,152 @@ impl LlamaContextParams {
         params.n_seq_max = self.n_seq_max.max(1);
         params.n_threads = self.n_threads;
         params.n_threads_batch = self.n_threads_batch;
+        params.rope_scaling_type = self.rope_scaling_type.to_c();
+        params.rope_freq_base = self.rope_freq_base;
+        params.rope_freq_scale = self.rope_freq_scale;
         params.offload_kqv = self.offload_kqv;
         params.no_perf = self.no_perf;
         params.kv_unified = self.kv_unified;
+        params.type_k = self.type_k.to_c();
+        params.type_v = self.type_v.to_c();
         // flash_attn is controlled via flash_attn_type field
-        if self.flash_attn {
-            params.flash_attn_type =
-                slab_llama_sys::llama_flash_attn_type_LLAMA_FLASH_ATTN_TYPE_ENABLED;
-        }
-        params
+        params.flash_attn_type = if self.flash_attn {
+            slab_llama_sys::llama_flash_attn_type_LLAMA_FLASH_ATTN_TYPE_ENABLED
+        } else {
+            slab_llama_sys::llama_flash_attn_type_LLAMA_FLASH_ATTN_TYPE_DISABLED
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{GgmlType, LlamaContextParams, LlamaRopeScalingType};
+
+    fn zeroed_c_params() -> slab_llama_sys::llama_context_params {
+        // SAFETY: `llama_context_params` is a plain C struct; all-zero is a
+        // valid (null callbacks, disabled flags) value.
+        unsafe { std::mem::zeroed() }
+    }
+
+    #[test]
+    fn rope_scaling_is_forwarded() {
+        let mut params = zeroed_c_params();
+
+        LlamaContextParams::new()
+            .rope_scaling_type(LlamaRopeScalingType::Yarn)
+            .rope_freq_base(1_000_000.0)
+            .rope_freq_scale(0.25)
+            .apply(&mut params);
+
+        assert_eq!(
+            params.rope_scaling_type,
+            slab_llama_sys::llama_rope_scaling_type_LLAMA_ROPE_SCALING_TYPE_YARN
+        );
+        assert_eq!(params.rope_freq_base, 1_000_000.0);
+        assert_eq!(params.rope_freq_scale, 0.25);
+    }
+
+    #[test]
+    fn thread_counts_are_forwarded() {
+        let mut params = zeroed_c_params();
+
+        LlamaContextParams::new().n_threads(6).n_threads_batch(24).apply(&mut params);
+
+        assert_eq!(params.n_threads, 6);
+        assert_eq!(params.n_threads_batch, 24);
+    }
+
+    #[test]
+    fn context_window_is_forwarded() {
+        let mut params = zeroed_c_params();
+
+        LlamaContextParams::new().n_ctx(32_768).apply(&mut params);
+        assert_eq!(params.n_ctx, 32_768);
+
+        LlamaContextParams::new().n_ctx(0).apply(&mut params);
+        assert_eq!(params.n_ctx, 0);
+    }
+
+    #[test]
+    fn batch_sizes_are_forwarded() {
+        let mut params = zeroed_c_params();
+
+        LlamaContextParams::new().n_batch(2048).n_ubatch(512).apply(&mut params);
+
+        assert_eq!(params.n_batch, 2048);
+        assert_eq!(params.n_ubatch, 512);
+    }
+
+    #[test]
+    fn validate_rejects_ubatch_larger_than_batch() {
+        assert!(LlamaContextParams::default().validate().is_ok());
+        assert!(LlamaContextParams::new().n_batch(1024).n_ubatch(1024).validate().is_ok());
+
+        let error = LlamaContextParams::new()
+            .n_batch(256)
+            .n_ubatch(512)
+            .validate()
+            .expect_err("n_ubatch above n_batch must be rejected");
+        assert!(error.to_string().contains("n_ubatch (512) must not exceed n_batch (256)"));
+        assert!(LlamaContextParams::new().n_batch(0).validate().is_err());
+    }
+
+    #[test]
+    fn kv_cache_types_are_forwarded_and_validated() {
+        let mut params = zeroed_c_params();
+
+        LlamaContextParams::default().apply(&mut params);
+        assert_eq!(params.type_k, slab_llama_sys::ggml_type_GGML_TYPE_F16);
+        assert_eq!(params.type_v, slab_llama_sys::ggml_type_GGML_TYPE_F16);
+
+        let quantized = LlamaContextParams::new().kv_type(GgmlType::Q8_0, GgmlType::Q4_0);
+        quantized.apply(&mut params);
+        assert_eq!(params.type_k, slab_llama_sys::ggml_type_GGML_TYPE_Q8_0);
+        assert_eq!(params.type_v, slab_llama_sys::ggml_type_GGML_TYPE_Q4_0);
+        assert!(quantized.validate().is_ok());
+
+        let error = quantized
+            .flash_attn(false)
+            .validate()
+            .expect_err("quantized V cache needs flash attention");
+        assert!(error.to_string().contains("requires flash attention"), "{error}");
+        assert!(
+            LlamaContextParams::new()
+                .kv_type(GgmlType::Q8_0, GgmlType::F16)
+                .flash_attn(false)
+                .validate()
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn flash_attn_toggle_is_forwarded() {
+        let mut params = zeroed_c_params();
+
+        LlamaContextParams::default().apply(&mut params);
+        assert_eq!(
+            params.flash_attn_type,
+            slab_llama_sys::llama_flash_attn_type_LLAMA_FLASH_ATTN_TYPE_ENABLED
+        );
+
+        LlamaContextParams::default().flash_attn(false).apply(&mut params);
+        assert_eq!(
+            params.flash_attn_type,
+            slab_llama_sys::llama_flash_attn_type_LLAMA_FLASH_ATTN_TYPE_DISABLED
+        );
+    }
+
+    #[test]
+    fn rope_scaling_defaults_to_model_values() {
+        let mut params = zeroed_c_params();
+
+        LlamaContextParams::default().apply(&mut params);
+
+        assert_eq!(
+            params.rope_scaling_type,
+            slab_llama_sys::llama_rope_scaling_type_LLAMA_ROPE_SCALING_TYPE_UNSPECIFIED
+        );
+        assert_eq!(params.rope_freq_base, 0.0);
+        assert_eq!(params.rope_freq_scale, 0.0);
     }
 }
@@ -1,6 +1,8 @@
 use std::ffi::NulError;
 use std::str::Utf8Error;
 
+use crate::token::{LlamaSeqId, LlamaToken};
+
 /// Errors that can occur when using the llama API.
 #[derive(Debug, thiserror::Error)]
 pub enum LlamaError {
@@ -20,6 +22,18 @@ pub enum LlamaError {
     #[error("failed to load the llama model")]
     ModelLoadFailed,
 
+    /// The shard list passed to `load_model_from_splits` is unusable.
+    #[error("invalid model splits: {0}")]
+    InvalidModelSplits(String),
+
+    /// Failed to stage an in-memory model where llama.cpp can read it.
+    #[error("failed to stage in-memory model: {0}")]
+    ModelBufferFailed(std::io::Error),
+
+    /// No ggml backend device exists at the requested index.
+    #[error("no ggml backend device at index {0}")]
+    DeviceNotFound(usize),
+
     /// Failed to create a context (llama returned null).
     #[error("failed to create llama context")]
     ContextCreateFailed,
@@ -44,6 +58,14 @@ pub enum LlamaError {
     #[error("batch is full, cannot add more tokens")]
     BatchFull,
 
+    /// A batch token references a sequence id outside `0..n_seq_max`.
+    #[error("sequence id {seq_id} is out of range for a context with n_seq_max = {n_seq_max}")]
+    SeqIdOutOfRange { seq_id: LlamaSeqId, n_seq_max: u32 },
+
+    /// A token id is outside the model's vocabulary.
+    #[error("token id {token} is out of range for a vocabulary of {n_vocab} tokens")]
+    TokenOutOfRange { token: LlamaToken, n_vocab: i32 },
+
     /// Failed to load a LoRA adapter (llama returned null).
     #[error("failed to load LoRA adapter")]
     LoraAdapterLoadFailed,
@@ -59,4 +81,12 @@ pub enum LlamaError {
     /// A LoRA adapter metadata lookup failed (key not found or index out of range).
     #[error("LoRA adapter metadata lookup failed")]
     AdapterMetaFailed,
+
+    /// Context parameters are inconsistent.
+    #[error("invalid context parameters: {0}")]
+    InvalidContextParams(String),
+
+    /// Sampler chain options are mutually exclusive.
+    #[error("invalid sampler configuration: {0}")]
+    InvalidSamplerConfig(String),
 }
@@ -6,7 +6,6 @@
 //! use slab_llama::{Llama, LlamaModelParams, LlamaContextParams, LlamaBatch, SamplerChainBuilder};
 //!
 //! let llama = Llama::new("/path/to/runtime/libs").unwrap();
-//! llama.backend_init();
 //!
 //! let model = llama
 //!     .load_model_from_file("/path/to/model.gguf", LlamaModelParams::default())
@@ -25,17 +24,15 @@
 //!
 //! ctx.decode(&mut batch).unwrap();
 //!
-//! let mut sampler = SamplerChainBuilder::new(llama.lib_arc()).build();
+//! let mut sampler = SamplerChainBuilder::new(llama.lib_arc()).build().unwrap();
 //! let next_token = sampler.sample(&mut ctx, (tokens.len() - 1) as i32);
 //! let piece = model.token_to_piece(next_token, true).unwrap();
 //! println!("{}", piece);
-//!
-//! llama.backend_free();
 //! ```
 
 use std::fmt;
 use std::path::Path;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex, PoisonError};
 
 mod context_params;
 mod error;
@@ -49,21 +46,24 @@ mod model_params;
 pub mod runtime;
 mod token;
 
-pub use context_params::LlamaContextParams;
+pub use context_params::{GgmlType, LlamaContextParams, LlamaRopeScalingType};
 pub use error::LlamaError;
 pub use llama_adapter::LlamaLoraAdapter;
 pub use llama_batch::LlamaBatch;
-pub use llama_context::LlamaContext;
+pub use llama_context::{LlamaContext, LlamaPerfContextData};
 pub use llama_model::LlamaModel;
 pub use llama_sampler::{LlamaSampler, SamplerChainBuilder};
 pub use logging::GgmlLogLevel;
-pub use model_params::LlamaModelParams;
+pub use model_params::{LlamaModelParams, LlamaSplitMode};
 pub use runtime::{
-    LlamaInferenceOutput, LlamaInferenceParams, LlamaLoadConfig, LlamaLogitBias, LlamaRuntime,
-    LlamaRuntimeError, LlamaSamplingOptions, LlamaSessionSnapshot, LlamaStopInfo, SessionId,
-    StreamChunk, StreamHandle,
+    LlamaContextOverflow, LlamaInferenceOutput, LlamaInferenceParams, LlamaLoadConfig,
+    LlamaLogitBias, LlamaRuntime, LlamaRuntimeError, LlamaRuntimeOptions, LlamaSamplingOptions,
+    LlamaSessionAssignment, LlamaSessionSnapshot, LlamaStopInfo, SessionId, StreamChunk,
+    StreamHandle,
+};
+pub use token::{
+    LLAMA_TOKEN_NULL, LlamaPos, LlamaSeqId, LlamaToken, LlamaTokenAttr, LlamaVocabType,
 };
-pub use token::{LlamaPos, LlamaSeqId, LlamaToken};
 
 /// The type alias for per-sequence state flags (used in `state_seq_*_ext` methods).
 pub type LlamaStateSeqFlags = slab_llama_sys::llama_state_seq_flags;
@@ -73,15 +73,86 @@ pub const LLAMA_DEFAULT_SEED: u32 = slab_llama_sys::LLAMA_DEFAULT_SEED;
 
 use slab_ggml::GGML;
 use slab_ggml::load_runtime_with_ggml_sidecar;
+/// Number of live [`Llama`] instances keeping the llama.cpp backend initialised.
+///
+/// The backend is process-global, so this is shared by every [`Llama`]
+/// regardless of which library instance it came from.
+static BACKEND: BackendState = BackendState::new();
+
+struct BackendState {
+    refs: Mutex<usize>,
+}
+
+impl BackendState {
+    const fn new() -> Self {
+        Self { refs: Mutex::new(0) }
+    }
+
+    /// Take a reference, running `init` if this is the first one.
+    fn acquire(&self, init: impl FnOnce()) {
+        let mut refs = self.refs.lock().unwrap_or_else(PoisonError::into_inner);
+        if *refs == 0 {
+            init();
+        }
+        *refs += 1;
+    }
+
+    /// Drop a reference, running `free` if it was the last one.
+    fn release(&self, free: impl FnOnce()) {
+        let mut refs = self.refs.lock().unwrap_or_else(PoisonError::into_inner);
+        if *refs == 0 {
+            return;
+        }
+        *refs -= 1;
+        if *refs == 0 {
+            free();
+        }
+    }
+}
+
+/// Holds one backend reference for a [`Llama`] and all of its clones, and for
+/// every model and context created from it.
+pub(crate) struct BackendGuard {
+    lib: Arc<slab_llama_sys::LlamaLib>,
+}
+
+impl BackendGuard {
+    fn acquire(lib: Arc<slab_llama_sys::LlamaLib>) -> Self {
+        BACKEND.acquire(|| unsafe { lib.llama_backend_init() });
+        Self { lib }
+    }
+}
+
+impl Drop for BackendGuard {
+    fn drop(&mut self) {
+        BACKEND.release(|| unsafe { self.lib.llama_backend_free() });
+    }
+}
+
 /// Entry point for the llama.cpp dynamic library.
 ///
-/// Load the shared library directory with [`Llama::new`],
-/// then use it to initialise the backend, load models and create contexts.
+/// Load the shared library directory with [`Llama::new`], then use it to load
+/// models and create contexts. The backend is initialised by the first
+/// `Llama` and freed once the last one (including clones) and every model and
+/// context created from them are dropped.
 #[derive(Clone)]
 pub struct Llama {
     pub(crate) lib: Arc<slab_llama_sys::LlamaLib>,
-    // Keep ggml.dll loaded when backend symbols are resolved from it.
-    _ggml_lib: Option<Arc<GGML>>,
+    pub(crate) backend: Arc<BackendGuard>,
+    // Keep ggml.dll loaded when backend symbols are resolved from it; also
+    // used for device enumeration.
+    ggml_lib: Option<Arc<GGML>>,
+}
+
+/// A ggml backend device (CPU, GPU, ...) visible to llama.cpp.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeviceInfo {
+    pub name: String,
+    pub description: String,
+    /// Total device memory in bytes.
+    pub total_mem: usize,
+    /// Currently free device memory in bytes.
+    pub free_mem: usize,
 }
 
 impl Llama {
@@ -95,25 +166,20 @@ impl Llama {
         let (llama_lib, ggml_lib) =
             load_runtime_with_ggml_sidecar(lib_dir, "llama", load_llama_lib)?;
 
-        let llama = Self { lib: Arc::new(llama_lib), _ggml_lib: ggml_lib };
+        let lib = Arc::new(llama_lib);
+        let backend = Arc::new(BackendGuard::acquire(Arc::clone(&lib)));
+        let llama = Self { lib, backend, ggml_lib };
         llama.install_logging_hooks();
         Ok(llama)
     }
 
-    /// Initialise the llama.cpp backend.
-    ///
-    /// Must be called once before loading any model.  It is idempotent on most
-    /// back-ends but should still only be called once per process.
-    pub fn backend_init(&self) {
-        unsafe { self.lib.llama_backend_init() }
-    }
+    /// Formerly initialised the llama.cpp backend; [`Llama::new`] now does so.
+    #[deprecated(note = "the backend is initialised by `Llama::new`")]
+    pub fn backend_init(&self) {}
 
-    /// Free the llama.cpp backend resources.
-    ///
-    /// Should be called after all models and contexts have been dropped.
-    pub fn backend_free(&self) {
-        unsafe { self.lib.llama_backend_free() }
-    }
+    /// Formerly freed the llama.cpp backend; dropping the last [`Llama`] now does so.
+    #[deprecated(note = "the backend is freed when the last `Llama` is dropped")]
+    pub fn backend_free(&self) {}
 
     /// Enable NUMA-aware memory allocation.
     ///
@@ -139,6 +205,33 @@ impl Llama {
         cstr.to_str().unwrap_or("")
     }
 
+    /// Number of ggml backend devices registered from the runtime directory.
+    ///
+    /// Zero when the ggml sidecar library could not be loaded.
+    pub fn device_count(&self) -> usize {
+        self.ggml_lib.as_ref().map_or(0, |ggml| ggml.ggml_backend_dev_count())
+    }
+
+    /// Name and memory of the backend device at `index`.
+    ///
+    /// # Errors
+    /// Returns [`LlamaError::DeviceNotFound`] if `index` is out of range.
+    pub fn device_info(&self, index: usize) -> Result<DeviceInfo, LlamaError> {
+        let ggml = self.ggml_lib.as_ref().ok_or(LlamaError::DeviceNotFound(index))?;
+        if index >= ggml.ggml_backend_dev_count() {
+            return Err(LlamaError::DeviceNotFound(index));
+        }
+        let device =
+            ggml.ggml_backend_dev_get(index).map_err(|_| LlamaError::DeviceNotFound(index))?;
+        let (free_mem, total_mem) = ggml.ggml_backend_dev_memory(device);
+        Ok(DeviceInfo {
+            name: ggml.ggml_backend_dev_name(device).unwrap_or_default().to_owned(),
+            description: ggml.ggml_backend_dev_description(device).unwrap_or_default().to_owned(),
+            total_mem,
+            free_mem,
+        })
+    }
+
     /// Returns `true` if the library was compiled with mmap support.
     pub fn supports_mmap(&self) -> bool {
         unsafe { self.lib.llama_supports_mmap() }
@@ -177,3 +270,65 @@ fn load_llama_lib(
         unsafe { slab_llama_sys::LlamaLib::new(path) }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+    use std::path::PathBuf;
+
+    use super::{BackendState, Llama};
+
+    #[test]
+    fn backend_state_inits_on_first_reference_and_frees_on_last() {
+        let state = BackendState::new();
+        let (inits, frees) = (Cell::new(0), Cell::new(0));
+        let init = || inits.set(inits.get() + 1);
+        let free = || frees.set(frees.get() + 1);
+
+        state.acquire(init);
+        state.acquire(init);
+        assert_eq!((inits.get(), frees.get()), (1, 0));
+
+        state.release(free);
+        assert_eq!(frees.get(), 0);
+        state.release(free);
+        assert_eq!(frees.get(), 1);
+        state.release(free);
+        assert_eq!(frees.get(), 1);
+
+        state.acquire(init);
+        assert_eq!(inits.get(), 2);
+    }
+
+    #[test]
+    #[ignore = "requires local llama runtime libraries"]
+    fn backend_stays_usable_until_the_last_llama_is_dropped() {
+        let lib_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../../bin/testdata/llama");
+        let llama = Llama::new(&lib_dir).expect("llama runtime libraries");
+        let clone = llama.clone();
+        let second = Llama::new(&lib_dir).expect("second llama handle");
+
+        drop(llama);
+        assert!(clone.device_count() >= 1);
+        drop(clone);
+        assert!(second.device_count() >= 1);
+        drop(second);
+
+        let fresh = Llama::new(&lib_dir).expect("backend re-initialises after the last drop");
+        assert!(fresh.device_count() >= 1);
+    }
+
+    #[test]
+    #[ignore = "requires local llama runtime libraries"]
+    fn device_enumeration_includes_at_least_the_cpu() {
+        let lib_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../../bin/testdata/llama");
+        let llama = Llama::new(&lib_dir).expect("llama runtime libraries");
+
+        assert!(llama.device_count() >= 1);
+        let devices: Vec<_> = (0..llama.device_count())
+            .map(|index| llama.device_info(index).expect("device info"))
+            .collect();
+        assert!(devices.iter().any(|device| device.name.to_ascii_lowercase().contains("cpu")));
+        assert!(llama.device_info(llama.device_count()).is_err());
+    }
+}
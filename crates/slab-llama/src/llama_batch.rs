@@ -96,6 +96,22 @@ impl LlamaBatch {
         self.logits.clear();
     }
 
+    /// Check that every token's sequence ids fall within `0..n_seq_max`.
+    ///
+    /// # Errors
+    /// Returns [`LlamaError::SeqIdOutOfRange`] for the first offending id.
+    pub fn validate_seq_ids(&self, n_seq_max: u32) -> Result<(), LlamaError> {
+        let out_of_range = self
+            .seq_ids
+            .iter()
+            .flatten()
+            .find(|&&seq_id| !u32::try_from(seq_id).is_ok_and(|seq_id| seq_id < n_seq_max));
+        match out_of_range {
+            Some(&seq_id) => Err(LlamaError::SeqIdOutOfRange { seq_id, n_seq_max }),
+            None => Ok(()),
+        }
+    }
+
     /// Build the raw `llama_batch` struct for passing to `llama_decode`.
     ///
     /// # Safety
@@ -118,3 +134,29 @@ impl LlamaBatch {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::LlamaBatch;
+    use crate::error::LlamaError;
+
+    #[test]
+    fn validate_seq_ids_rejects_ids_beyond_n_seq_max() {
+        let mut batch = LlamaBatch::new(4);
+        batch.add(1, 0, &[0], false).unwrap();
+        batch.add(2, 0, &[1, 3], true).unwrap();
+
+        assert!(batch.validate_seq_ids(4).is_ok());
+        assert!(matches!(
+            batch.validate_seq_ids(2),
+            Err(LlamaError::SeqIdOutOfRange { seq_id: 3, n_seq_max: 2 })
+        ));
+
+        batch.clear();
+        batch.add(1, 0, &[-1], true).unwrap();
+        assert!(matches!(
+            batch.validate_seq_ids(4),
+            Err(LlamaError::SeqIdOutOfRange { seq_id: -1, n_seq_max: 4 })
+        ));
+    }
+}
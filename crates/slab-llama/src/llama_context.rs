@@ -1,6 +1,7 @@
 use std::ffi::CString;
 use std::sync::Arc;
 
+use crate::BackendGuard;
 use crate::LlamaStateSeqFlags;
 use crate::error::LlamaError;
 use crate::llama_adapter::LlamaLoraAdapter;
@@ -8,6 +9,26 @@ use crate::llama_batch::LlamaBatch;
 use crate::llama_model::LlamaModelInner;
 use crate::token::{LlamaSeqId, LlamaToken};
 
+/// Cumulative timings and token counts reported by `llama_perf_context`.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct LlamaPerfContextData {
+    pub t_start_ms: f64,
+    pub t_load_ms: f64,
+    /// Time spent evaluating prompt batches.
+    pub t_p_eval_ms: f64,
+    /// Time spent evaluating single generated tokens.
+    pub t_eval_ms: f64,
+    pub n_p_eval: i32,
+    pub n_eval: i32,
+}
+
+impl LlamaPerfContextData {
+    /// Evaluation time spent since `earlier`, across prompt and generation batches.
+    pub fn eval_ms_since(&self, earlier: &Self) -> f64 {
+        ((self.t_p_eval_ms - earlier.t_p_eval_ms) + (self.t_eval_ms - earlier.t_eval_ms)).max(0.0)
+    }
+}
+
 /// A safe wrapper around a llama inference context.
 ///
 /// Created via [`crate::llama_model::LlamaModel::new_context`].
@@ -15,6 +36,8 @@ pub struct LlamaContext {
     pub(crate) ctx: Option<std::ptr::NonNull<slab_llama_sys::llama_context>>,
     /// Keep the model alive as long as the context exists.
     pub(crate) model: Arc<LlamaModelInner>,
+    /// Keeps the backend initialised until the context has been freed.
+    pub(crate) _backend: Arc<BackendGuard>,
 }
 
 // SAFETY: The context pointer is only accessed through `&mut self` methods,
@@ -79,21 +102,33 @@ impl LlamaContext {
     /// * `batch` – the batch to decode.
     ///
     /// # Errors
-    /// Returns [`LlamaError::DecodeFailed`] if llama.cpp reports an error.
+    /// Returns [`LlamaError::SeqIdOutOfRange`] if a token targets a sequence
+    /// beyond [`Self::n_seq_max`], or [`LlamaError::DecodeFailed`] if
+    /// llama.cpp reports an error.
     pub fn decode(&mut self, batch: &mut LlamaBatch) -> Result<(), LlamaError> {
+        batch.validate_seq_ids(self.n_seq_max())?;
         let raw_batch = batch.as_llama_batch();
         let ret = unsafe { self.model.lib.llama_decode(self.as_ptr(), raw_batch) };
         if ret != 0 { Err(LlamaError::DecodeFailed(ret)) } else { Ok(()) }
     }
 
+    /// Block until all work queued by [`Self::decode`] has finished.
+    ///
+    /// `decode` can return while GPU backends are still computing, and
+    /// llama.cpp only adds the batch to its eval timings once synchronized.
+    pub fn synchronize(&mut self) {
+        unsafe { self.model.lib.llama_synchronize(self.as_ptr()) }
+    }
+
     // ── Internal helpers ─────────────────────────────────────────────────────
 
     fn n_vocab(&self) -> usize {
-        unsafe {
+        let n_vocab = unsafe {
             self.model.lib.llama_vocab_n_tokens(
                 self.model.lib.llama_model_get_vocab(self.model.model.unwrap().as_ptr()),
-            ) as usize
-        }
+            )
+        };
+        usize::try_from(n_vocab).unwrap_or(0)
     }
 
     // ── Logits ───────────────────────────────────────────────────────────────
@@ -108,24 +143,27 @@ impl LlamaContext {
     /// Panics if the returned pointer is null.
     pub fn get_logits_ith(&self, i: i32) -> &[f32] {
         let n_vocab = self.n_vocab();
+        assert!(n_vocab > 0, "model vocabulary is empty");
         let ptr = unsafe { self.model.lib.llama_get_logits_ith(self.as_ptr(), i) };
         assert!(!ptr.is_null(), "llama_get_logits_ith returned null");
         unsafe { std::slice::from_raw_parts(ptr, n_vocab) }
     }
 
-    /// Return the logits for a single output token from the last decoded batch.
+    /// Return the logits for the last output token of the last decoded batch.
     ///
-    /// This returns a slice of length `n_vocab` corresponding to one token's
-    /// logits (typically the last token processed by the most recent decode).
+    /// Equivalent to `get_logits_ith(-1)`; the slice has length `n_vocab`.
     /// For individual token logits by index use [`Self::get_logits_ith`].
     ///
     /// # Panics
     /// Panics if the returned pointer is null.
     pub fn get_logits(&self) -> &[f32] {
-        let n_vocab = self.n_vocab();
-        let ptr = unsafe { self.model.lib.llama_get_logits(self.as_ptr()) };
-        assert!(!ptr.is_null(), "llama_get_logits returned null");
-        unsafe { std::slice::from_raw_parts(ptr, n_vocab) }
+        self.get_logits_ith(-1)
+    }
+
+    /// Return the `n` most likely next tokens at the last output position
+    /// with their log-probabilities, most likely first.
+    pub fn top_logprobs(&self, n: usize) -> Vec<(LlamaToken, f32)> {
+        top_logprobs_from_logits(self.get_logits(), n)
     }
 
     // ── Thread control ────────────────────────────────────────────────────────
@@ -147,6 +185,21 @@ impl LlamaContext {
         unsafe { self.model.lib.llama_perf_context_reset(self.as_ptr()) }
     }
 
+    /// Read the context's cumulative performance counters.
+    ///
+    /// All zero when the context was created with `no_perf`.
+    pub fn perf_data(&self) -> LlamaPerfContextData {
+        let data = unsafe { self.model.lib.llama_perf_context(self.as_ptr()) };
+        LlamaPerfContextData {
+            t_start_ms: data.t_start_ms,
+            t_load_ms: data.t_load_ms,
+            t_p_eval_ms: data.t_p_eval_ms,
+            t_eval_ms: data.t_eval_ms,
+            n_p_eval: data.n_p_eval,
+            n_eval: data.n_eval,
+        }
+    }
+
     // ── KV-cache management ──────────────────────────────────────────────────
 
     /// Clear all tokens from all sequences in the KV cache.
@@ -166,6 +219,18 @@ impl LlamaContext {
         unsafe { self.model.lib.llama_memory_seq_rm(mem, seq_id, p0, p1) }
     }
 
+    /// Copy the KV entries of `[p0, p1)` from `src_seq` into `dst_seq`.
+    ///
+    /// Lets a prefix decoded once (e.g. a shared system prompt) seed new
+    /// sequences without decoding it again. Negative bounds mean "open".
+    pub fn kv_cache_seq_cp(&mut self, src_seq: i32, dst_seq: i32, p0: i32, p1: i32) {
+        let mem = unsafe { self.model.lib.llama_get_memory(self.as_ptr()) };
+        if mem.is_null() {
+            return;
+        }
+        unsafe { self.model.lib.llama_memory_seq_cp(mem, src_seq, dst_seq, p0, p1) };
+    }
+
     /// Add `delta` to token positions in `[p0, p1)` for `seq_id`.
     pub fn kv_cache_seq_add(&mut self, seq_id: i32, p0: i32, p1: i32, delta: i32) {
         let mem = unsafe { self.model.lib.llama_get_memory(self.as_ptr()) };
@@ -175,6 +240,15 @@ impl LlamaContext {
         unsafe { self.model.lib.llama_memory_seq_add(mem, seq_id, p0, p1, delta) };
     }
 
+    /// Integer-divide token positions in `[p0, p1)` for `seq_id` by `d`.
+    pub fn kv_cache_seq_div(&mut self, seq_id: i32, p0: i32, p1: i32, d: i32) {
+        let mem = unsafe { self.model.lib.llama_get_memory(self.as_ptr()) };
+        if mem.is_null() {
+            return;
+        }
+        unsafe { self.model.lib.llama_memory_seq_div(mem, seq_id, p0, p1, d) };
+    }
+
     /// Returns whether the KV cache implementation supports position shifting.
     pub fn kv_cache_can_shift(&self) -> bool {
         let mem = unsafe { self.model.lib.llama_get_memory(self.as_ptr()) };
@@ -184,6 +258,39 @@ impl LlamaContext {
         unsafe { self.model.lib.llama_memory_can_shift(mem) }
     }
 
+    /// Smallest position stored for `seq_id`, or `-1` when the sequence is empty.
+    pub fn kv_cache_seq_pos_min(&self, seq_id: i32) -> i32 {
+        let mem = unsafe { self.model.lib.llama_get_memory(self.as_ptr()) };
+        if mem.is_null() {
+            return -1;
+        }
+        unsafe { self.model.lib.llama_memory_seq_pos_min(mem, seq_id) }
+    }
+
+    /// Largest position stored for `seq_id`, or `-1` when the sequence is empty.
+    pub fn kv_cache_seq_pos_max(&self, seq_id: i32) -> i32 {
+        let mem = unsafe { self.model.lib.llama_get_memory(self.as_ptr()) };
+        if mem.is_null() {
+            return -1;
+        }
+        unsafe { self.model.lib.llama_memory_seq_pos_max(mem, seq_id) }
+    }
+
+    /// Number of token positions held for `seq_id` in the KV cache.
+    pub fn kv_cache_seq_token_count(&self, seq_id: i32) -> i32 {
+        seq_token_span(self.kv_cache_seq_pos_min(seq_id), self.kv_cache_seq_pos_max(seq_id))
+    }
+
+    /// Number of token positions held across all sequences.
+    ///
+    /// llama.cpp no longer exposes a used-cell counter, so this sums the
+    /// per-sequence spans; prefixes shared through [`Self::kv_cache_seq_cp`]
+    /// are counted once per sequence.
+    pub fn kv_cache_token_count(&self) -> i32 {
+        let n_seq = i32::try_from(self.n_seq_max()).unwrap_or(i32::MAX);
+        (0..n_seq).map(|seq_id| self.kv_cache_seq_token_count(seq_id)).sum()
+    }
+
     // ── LoRA adapters ────────────────────────────────────────────────────────
 
     /// Apply LoRA adapters to this context.
@@ -506,3 +613,58 @@ impl std::fmt::Debug for LlamaContext {
         f.debug_struct("LlamaContext").finish()
     }
 }
+
+/// Log-softmax `logits` and return the `n` highest-scoring token ids.
+fn top_logprobs_from_logits(logits: &[f32], n: usize) -> Vec<(LlamaToken, f32)> {
+    let n = n.min(logits.len());
+    if n == 0 {
+        return Vec::new();
+    }
+
+    let max = logits.iter().copied().fold(f32::NEG_INFINITY, f32::max);
+    let log_sum = logits.iter().map(|&logit| (logit - max).exp()).sum::<f32>().ln() + max;
+
+    let mut ranked: Vec<(LlamaToken, f32)> =
+        logits.iter().enumerate().map(|(id, &logit)| (id as LlamaToken, logit)).collect();
+    ranked.sort_unstable_by(|a, b| b.1.total_cmp(&a.1));
+    ranked.truncate(n);
+    for (_, logit) in &mut ranked {
+        *logit -= log_sum;
+    }
+    ranked
+}
+
+/// Positions covered by `[pos_min, pos_max]`; llama.cpp reports `-1` for both
+/// bounds of an empty sequence.
+fn seq_token_span(pos_min: i32, pos_max: i32) -> i32 {
+    if pos_min < 0 || pos_max < pos_min { 0 } else { pos_max - pos_min + 1 }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn seq_token_span_counts_inclusive_positions() {
+        assert_eq!(seq_token_span(-1, -1), 0);
+        assert_eq!(seq_token_span(0, 0), 1);
+        assert_eq!(seq_token_span(0, 31), 32);
+        // After a context shift the window no longer starts at zero.
+        assert_eq!(seq_token_span(16, 47), 32);
+    }
+
+    #[test]
+    fn top_logprobs_are_sorted_normalized_log_probabilities() {
+        let logits = [1.0_f32, 3.0, 2.0, -1.0];
+        let top = top_logprobs_from_logits(&logits, 2);
+
+        assert_eq!(top.iter().map(|(id, _)| *id).collect::<Vec<_>>(), vec![1, 2]);
+        assert!(top[0].1 > top[1].1);
+
+        let all = top_logprobs_from_logits(&logits, 10);
+        assert_eq!(all.len(), logits.len());
+        let total: f32 = all.iter().map(|(_, logprob)| logprob.exp()).sum();
+        assert!((total - 1.0).abs() < 1e-5);
+        assert!(top_logprobs_from_logits(&logits, 0).is_empty());
+    }
+}
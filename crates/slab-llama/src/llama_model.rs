@@ -1,8 +1,10 @@
 use std::ffi::CString;
+use std::path::Path;
 use std::sync::Arc;
 
 use tracing::debug;
 
+use crate::BackendGuard;
 use crate::Llama;
 use crate::LlamaSampler;
 use crate::context_params::LlamaContextParams;
@@ -11,7 +13,7 @@ use crate::llama_adapter::LlamaLoraAdapter;
 use crate::llama_context::LlamaContext;
 use crate::llama_sampler::SamplerChainBuilder;
 use crate::runtime::{LlamaLogitBias, LlamaSamplingOptions};
-use crate::token::LlamaToken;
+use crate::token::{LlamaToken, LlamaTokenAttr, LlamaVocabType, check_tokens, defined_token};
 
 /// Inner (non-Clone) model data.  Wrapped in Arc so that LlamaContext can keep
 /// the model alive without copying the raw pointer.
@@ -20,6 +22,11 @@ pub(crate) struct LlamaModelInner {
     pub(crate) lib: Arc<slab_llama_sys::LlamaLib>,
     pub(crate) eog_tokens: Box<[LlamaToken]>,
     pub(crate) eog_logit_bias: Box<[slab_llama_sys::llama_logit_bias]>,
+    /// In-memory file backing a model loaded from a buffer. Dropped after
+    /// the model is freed, so mmapped tensors never outlive their data.
+    pub(crate) backing: Option<std::fs::File>,
+    /// Keeps the backend initialised until the model has been freed.
+    pub(crate) backend: Arc<BackendGuard>,
 }
 
 // SAFETY: The underlying `llama_model` pointer is only accessed through
@@ -43,7 +50,8 @@ impl Drop for LlamaModelInner {
 
 /// A safe wrapper around a loaded `llama_model`.
 ///
-/// Created via [`Llama::load_model_from_file`].
+/// Created via [`Llama::load_model_from_file`], [`Llama::load_model_from_splits`]
+/// or `Llama::load_model_from_buffer`.
 #[derive(Clone)]
 pub struct LlamaModel {
     pub(crate) inner: Arc<LlamaModelInner>,
@@ -62,10 +70,70 @@ impl Llama {
         &self,
         path: &str,
         params: crate::model_params::LlamaModelParams,
+    ) -> Result<LlamaModel, LlamaError> {
+        self.load_model_with_backing(path, params, None)
+    }
+
+    /// Load a model sharded across several GGUF files
+    /// (`model-00001-of-00003.gguf`, ...), given in shard order.
+    ///
+    /// # Errors
+    /// Returns [`LlamaError::InvalidModelSplits`] if the list is empty or a
+    /// shard is missing, or [`LlamaError::ModelLoadFailed`] if loading fails.
+    pub fn load_model_from_splits(
+        &self,
+        paths: &[&Path],
+        params: crate::model_params::LlamaModelParams,
+    ) -> Result<LlamaModel, LlamaError> {
+        let c_paths = split_c_paths(paths)?;
+        let path_ptrs: Vec<*const std::ffi::c_char> =
+            c_paths.iter().map(|path| path.as_ptr()).collect();
+        let c_params = params.to_c_params(&self.lib);
+        let model = unsafe {
+            self.lib.llama_model_load_from_splits(
+                path_ptrs.as_ptr().cast_mut(),
+                path_ptrs.len(),
+                c_params,
+            )
+        };
+        self.wrap_loaded_model(model, None)
+    }
+
+    /// Load a model from GGUF bytes held in memory, without touching disk.
+    ///
+    /// The bytes are copied into an anonymous `memfd` that the returned
+    /// [`LlamaModel`] keeps alive, so `data` may be dropped once this returns.
+    ///
+    /// # Errors
+    /// Returns [`LlamaError::ModelBufferFailed`] if the in-memory file cannot
+    /// be created, or [`LlamaError::ModelLoadFailed`] if loading fails.
+    #[cfg(target_os = "linux")]
+    pub fn load_model_from_buffer(
+        &self,
+        data: &[u8],
+        params: crate::model_params::LlamaModelParams,
+    ) -> Result<LlamaModel, LlamaError> {
+        let (file, path) = memfd_from_bytes(data).map_err(LlamaError::ModelBufferFailed)?;
+        self.load_model_with_backing(&path, params, Some(file))
+    }
+
+    fn load_model_with_backing(
+        &self,
+        path: &str,
+        params: crate::model_params::LlamaModelParams,
+        backing: Option<std::fs::File>,
     ) -> Result<LlamaModel, LlamaError> {
         let c_path = CString::new(path)?;
         let c_params = params.to_c_params(&self.lib);
         let model = unsafe { self.lib.llama_model_load_from_file(c_path.as_ptr(), c_params) };
+        self.wrap_loaded_model(model, backing)
+    }
+
+    fn wrap_loaded_model(
+        &self,
+        model: *mut slab_llama_sys::llama_model,
+        backing: Option<std::fs::File>,
+    ) -> Result<LlamaModel, LlamaError> {
         if model.is_null() {
             Err(LlamaError::ModelLoadFailed)
         } else {
@@ -77,12 +145,71 @@ impl Llama {
                     lib: Arc::clone(&self.lib),
                     eog_tokens,
                     eog_logit_bias,
+                    backing,
+                    backend: Arc::clone(&self.backend),
                 }),
             })
         }
     }
 }
 
+/// Copy a template returned by `llama_model_chat_template`, treating null and
+/// empty strings as "no template".
+///
+/// # Safety
+/// `template` must be null or point to a NUL-terminated string.
+unsafe fn chat_template_from_ptr(template: *const std::ffi::c_char) -> Option<String> {
+    if template.is_null() {
+        return None;
+    }
+    let template = unsafe { std::ffi::CStr::from_ptr(template) }.to_string_lossy();
+    (!template.trim().is_empty()).then(|| template.into_owned())
+}
+
+/// Check every shard exists before handing the list to llama.cpp, which would
+/// otherwise only log the missing file and return null.
+fn split_c_paths(paths: &[&Path]) -> Result<Vec<CString>, LlamaError> {
+    if paths.is_empty() {
+        return Err(LlamaError::InvalidModelSplits("no shard paths given".to_owned()));
+    }
+    paths
+        .iter()
+        .map(|path| {
+            if !path.is_file() {
+                return Err(LlamaError::InvalidModelSplits(format!(
+                    "shard '{}' does not exist",
+                    path.display()
+                )));
+            }
+            let path = path.to_str().ok_or_else(|| {
+                LlamaError::InvalidModelSplits(format!(
+                    "shard path '{}' is not valid UTF-8",
+                    path.display()
+                ))
+            })?;
+            Ok(CString::new(path)?)
+        })
+        .collect()
+}
+
+/// Copy `data` into an anonymous in-memory file and return it together with a
+/// path llama.cpp can open.
+#[cfg(target_os = "linux")]
+fn memfd_from_bytes(data: &[u8]) -> std::io::Result<(std::fs::File, String)> {
+    use std::io::Write;
+    use std::os::fd::{AsRawFd, FromRawFd};
+
+    let fd = unsafe { libc::memfd_create(c"slab-llama-model".as_ptr(), libc::MFD_CLOEXEC) };
+    if fd < 0 {
+        return Err(std::io::Error::last_os_error());
+    }
+    // SAFETY: `fd` was just returned by `memfd_create` and is owned by nobody else.
+    let mut file = unsafe { std::fs::File::from_raw_fd(fd) };
+    file.write_all(data)?;
+    let path = format!("/proc/self/fd/{}", file.as_raw_fd());
+    Ok((file, path))
+}
+
 fn collect_eog_bias(
     lib: &Arc<slab_llama_sys::LlamaLib>,
     vocab: *const slab_llama_sys::llama_vocab,
@@ -142,8 +269,10 @@ impl LlamaModel {
     /// * `params` – context creation parameters.
     ///
     /// # Errors
-    /// Returns [`LlamaError::ContextCreateFailed`] if context creation fails.
+    /// Returns [`LlamaError::InvalidContextParams`] if `params` are inconsistent
+    /// and [`LlamaError::ContextCreateFailed`] if context creation fails.
     pub fn new_context(&self, params: LlamaContextParams) -> Result<LlamaContext, LlamaError> {
+        params.validate()?;
         let c_params = params.to_c_params(&self.inner.lib);
         let ctx = unsafe {
             self.inner.lib.llama_init_from_model(self.inner.model.unwrap().as_ptr(), c_params)
@@ -154,6 +283,7 @@ impl LlamaModel {
             Ok(LlamaContext {
                 ctx: Some(unsafe { std::ptr::NonNull::new_unchecked(ctx) }),
                 model: Arc::clone(&self.inner),
+                _backend: Arc::clone(&self.inner.backend),
             })
         }
     }
@@ -188,6 +318,10 @@ impl LlamaModel {
 
     /// Tokenize a UTF-8 string.
     ///
+    /// Tokenization is synchronous and CPU-bound; long prompts should be
+    /// tokenized from `tokio::task::spawn_blocking` (or an equivalent) rather
+    /// than directly on an async task.
+    ///
     /// # Arguments
     /// * `text`          – the text to tokenize.
     /// * `add_special`   – whether to add BOS/EOS special tokens.
@@ -201,43 +335,39 @@ impl LlamaModel {
         add_special: bool,
         parse_special: bool,
     ) -> Result<Vec<LlamaToken>, LlamaError> {
+        let mut tokens = Vec::new();
+        self.tokenize_into(text, add_special, parse_special, &mut tokens)?;
+        Ok(tokens)
+    }
+
+    /// Tokenize a UTF-8 string into `buf`, replacing its contents.
+    ///
+    /// The existing capacity of `buf` is tried first, so reusing one buffer
+    /// across calls avoids both the sizing pass and reallocation once it has
+    /// grown to fit. Returns the number of tokens written.
+    ///
+    /// # Errors
+    /// Returns [`LlamaError::TokenizeFailed`] if tokenization fails.
+    pub fn tokenize_into(
+        &self,
+        text: &str,
+        add_special: bool,
+        parse_special: bool,
+        buf: &mut Vec<LlamaToken>,
+    ) -> Result<usize, LlamaError> {
         let vocab = self.vocab();
         let text_bytes = text.as_bytes();
-        // First call with zero-sized buffer to get the required token count.
-        let n = unsafe {
+        fill_tokens(buf, |tokens, capacity| unsafe {
             self.inner.lib.llama_tokenize(
                 vocab,
                 text_bytes.as_ptr() as *const std::os::raw::c_char,
                 text_bytes.len() as i32,
-                std::ptr::null_mut(),
-                0,
+                tokens,
+                capacity,
                 add_special,
                 parse_special,
             )
-        };
-        // n is negative when the buffer is too small; its absolute value is the
-        // required size.
-        let required = if n < 0 { (-n) as usize } else { n as usize };
-        if required == 0 {
-            return Ok(Vec::new());
-        }
-        let mut tokens: Vec<LlamaToken> = vec![0; required];
-        let n2 = unsafe {
-            self.inner.lib.llama_tokenize(
-                vocab,
-                text_bytes.as_ptr() as *const std::os::raw::c_char,
-                text_bytes.len() as i32,
-                tokens.as_mut_ptr(),
-                required as i32,
-                add_special,
-                parse_special,
-            )
-        };
-        if n2 < 0 {
-            return Err(LlamaError::TokenizeFailed(n2));
-        }
-        tokens.truncate(n2 as usize);
-        Ok(tokens)
+        })
     }
 
     /// Convert a token id to its raw byte representation (piece).
@@ -253,10 +383,33 @@ impl LlamaModel {
         token: LlamaToken,
         special: bool,
     ) -> Result<Vec<u8>, LlamaError> {
+        self.token_to_piece_bytes_ext(token, 0, special)
+    }
+
+    /// Like [`Self::token_to_piece_bytes`], but strips up to `lstrip` leading
+    /// spaces from the piece.
+    ///
+    /// # Errors
+    /// Returns [`LlamaError::TokenOutOfRange`] for an id outside the vocabulary
+    /// and [`LlamaError::TokenToPieceFailed`] on failure.
+    pub fn token_to_piece_bytes_ext(
+        &self,
+        token: LlamaToken,
+        lstrip: i32,
+        special: bool,
+    ) -> Result<Vec<u8>, LlamaError> {
+        check_tokens(&[token], self.n_vocab())?;
         let vocab = self.vocab();
         // First call to get required buffer length.
         let n = unsafe {
-            self.inner.lib.llama_token_to_piece(vocab, token, std::ptr::null_mut(), 0, 0, special)
+            self.inner.lib.llama_token_to_piece(
+                vocab,
+                token,
+                std::ptr::null_mut(),
+                0,
+                lstrip,
+                special,
+            )
         };
         // Like llama_tokenize/llama_detokenize, negative means the buffer was too
         // small and abs(n) is the required byte length.
@@ -275,7 +428,7 @@ impl LlamaModel {
                 token,
                 buf.as_mut_ptr() as *mut std::os::raw::c_char,
                 required as i32,
-                0,
+                lstrip,
                 special,
             )
         };
@@ -299,7 +452,30 @@ impl LlamaModel {
         String::from_utf8(bytes).map_err(|e| LlamaError::from(e.utf8_error()))
     }
 
-    /// Detokenize a list of token ids into a string.
+    /// Convert a token id to its string representation with explicit control
+    /// over leading-space stripping.
+    ///
+    /// Streaming callers should keep `lstrip` at `0` so SentencePiece-style
+    /// pieces retain their leading space; pass `1` to drop it, e.g. for the
+    /// first piece of a response.
+    ///
+    /// # Errors
+    /// Returns [`LlamaError::TokenToPieceFailed`] or [`LlamaError::InvalidUtf8`] on failure.
+    pub fn token_to_piece_ext(
+        &self,
+        token: LlamaToken,
+        lstrip: i32,
+        special: bool,
+    ) -> Result<String, LlamaError> {
+        let bytes = self.token_to_piece_bytes_ext(token, lstrip, special)?;
+        String::from_utf8(bytes).map_err(|e| LlamaError::from(e.utf8_error()))
+    }
+
+    /// Detokenize a list of token ids into a string in a single FFI round trip.
+    ///
+    /// Unlike concatenating [`Self::token_to_piece`] results, this lets llama.cpp
+    /// merge multi-byte characters split across tokens. An empty slice yields an
+    /// empty string.
     ///
     /// # Arguments
     /// * `tokens`        – slice of token ids.
@@ -307,13 +483,19 @@ impl LlamaModel {
     /// * `unparse_special` – render special tokens as their text forms.
     ///
     /// # Errors
-    /// Returns [`LlamaError::TokenToPieceFailed`] or [`LlamaError::InvalidUtf8`] on failure.
-    pub fn tokens_to_str(
+    /// Returns [`LlamaError::TokenOutOfRange`] for the first id outside the
+    /// vocabulary, and [`LlamaError::TokenToPieceFailed`] or
+    /// [`LlamaError::InvalidUtf8`] on failure.
+    pub fn detokenize(
         &self,
         tokens: &[LlamaToken],
         remove_special: bool,
         unparse_special: bool,
     ) -> Result<String, LlamaError> {
+        if tokens.is_empty() {
+            return Ok(String::new());
+        }
+        check_tokens(tokens, self.n_vocab())?;
         let vocab = self.vocab();
         // Determine required buffer size.
         let n = unsafe {
@@ -363,29 +545,60 @@ impl LlamaModel {
         unsafe { self.inner.lib.llama_vocab_n_tokens(self.vocab()) }
     }
 
-    /// BOS (beginning-of-sentence) token id.
-    pub fn token_bos(&self) -> LlamaToken {
-        unsafe { self.inner.lib.llama_vocab_bos(self.vocab()) }
+    /// BOS (beginning-of-sentence) token id, if the vocabulary defines one.
+    pub fn token_bos(&self) -> Option<LlamaToken> {
+        defined_token(unsafe { self.inner.lib.llama_vocab_bos(self.vocab()) })
+    }
+
+    /// EOS (end-of-sentence) token id, if the vocabulary defines one.
+    pub fn token_eos(&self) -> Option<LlamaToken> {
+        defined_token(unsafe { self.inner.lib.llama_vocab_eos(self.vocab()) })
+    }
+
+    /// EOT (end-of-turn) token id, if the vocabulary defines one.
+    pub fn token_eot(&self) -> Option<LlamaToken> {
+        defined_token(unsafe { self.inner.lib.llama_vocab_eot(self.vocab()) })
     }
 
-    /// EOS (end-of-sentence) token id.
-    pub fn token_eos(&self) -> LlamaToken {
-        unsafe { self.inner.lib.llama_vocab_eos(self.vocab()) }
+    /// SEP (sentence separator) token id, if the vocabulary defines one.
+    pub fn token_sep(&self) -> Option<LlamaToken> {
+        defined_token(unsafe { self.inner.lib.llama_vocab_sep(self.vocab()) })
     }
 
-    /// EOT (end-of-turn) token id.
-    pub fn token_eot(&self) -> LlamaToken {
-        unsafe { self.inner.lib.llama_vocab_eot(self.vocab()) }
+    /// NL (newline) token id, if the vocabulary defines one.
+    pub fn token_nl(&self) -> Option<LlamaToken> {
+        defined_token(unsafe { self.inner.lib.llama_vocab_nl(self.vocab()) })
     }
 
-    /// NL (newline) token id.
-    pub fn token_nl(&self) -> LlamaToken {
-        unsafe { self.inner.lib.llama_vocab_nl(self.vocab()) }
+    /// Padding token id, if the vocabulary defines one.
+    pub fn token_pad(&self) -> Option<LlamaToken> {
+        defined_token(unsafe { self.inner.lib.llama_vocab_pad(self.vocab()) })
     }
 
-    /// Padding token id.
-    pub fn token_pad(&self) -> LlamaToken {
-        unsafe { self.inner.lib.llama_vocab_pad(self.vocab()) }
+    /// Tokenizer family of the model's vocabulary.
+    pub fn vocab_type(&self) -> LlamaVocabType {
+        LlamaVocabType::from_c(unsafe { self.inner.lib.llama_vocab_type(self.vocab()) })
+    }
+
+    /// Attribute flags of `token` (control, byte, user-defined, ...).
+    ///
+    /// # Errors
+    /// Returns [`LlamaError::TokenOutOfRange`] for an id outside the vocabulary.
+    pub fn token_get_attr(&self, token: LlamaToken) -> Result<LlamaTokenAttr, LlamaError> {
+        check_tokens(&[token], self.n_vocab())?;
+        Ok(LlamaTokenAttr::from_c(unsafe {
+            self.inner.lib.llama_vocab_get_attr(self.vocab(), token)
+        }))
+    }
+
+    /// Returns `true` if `token` is a control token that templates emit but
+    /// streaming output normally hides.
+    ///
+    /// # Errors
+    /// Returns [`LlamaError::TokenOutOfRange`] for an id outside the vocabulary.
+    pub fn token_is_control(&self, token: LlamaToken) -> Result<bool, LlamaError> {
+        check_tokens(&[token], self.n_vocab())?;
+        Ok(unsafe { self.inner.lib.llama_vocab_is_control(self.vocab(), token) })
     }
 
     /// Returns `true` if `token` is an end-of-generation token.
@@ -405,9 +618,9 @@ impl LlamaModel {
 
     /// Classify a stop token into the most specific llama.cpp category we know.
     pub fn token_stop_kind(&self, token: LlamaToken) -> Option<&'static str> {
-        if token == self.token_eos() {
+        if self.token_eos() == Some(token) {
             Some("eos")
-        } else if token == self.token_eot() {
+        } else if self.token_eot() == Some(token) {
             Some("eot")
         } else if self.inner.eog_tokens.contains(&token) {
             Some("eog")
@@ -493,7 +706,9 @@ impl LlamaModel {
     /// Convenience helper so callers do not need direct access to the
     /// underlying `LlamaLib` handle.
     pub fn new_sampler(&self) -> LlamaSampler {
-        SamplerChainBuilder::new(Arc::clone(&self.inner.lib)).build()
+        SamplerChainBuilder::new(Arc::clone(&self.inner.lib))
+            .build()
+            .expect("default sampler chain configuration is valid")
     }
 
     /// Create a sampler chain with an optional raw GBNF constraint.
@@ -520,9 +735,7 @@ impl LlamaModel {
     /// When an override is `None`, the builder default is used.
     pub fn new_sampler_with_options(&self, options: &LlamaSamplingOptions) -> LlamaSampler {
         let mut builder = SamplerChainBuilder::new(Arc::clone(&self.inner.lib));
-        if let Some(t) = options.temperature {
-            builder.temperature = t;
-        }
+        builder.temperature = options.temperature;
         if let Some(p) = options.top_p {
             builder.top_p = p;
         }
@@ -532,11 +745,14 @@ impl LlamaModel {
         if let Some(p) = options.min_p {
             builder.min_p = p;
         }
-        if let Some(penalty) = options.repetition_penalty {
-            builder.repeat_penalty = penalty;
-        }
-        if let Some(penalty) = options.presence_penalty {
-            builder.presence_penalty = penalty;
+        builder = builder.penalties(
+            options.repeat_last_n.unwrap_or(builder.repeat_last_n),
+            options.repetition_penalty.unwrap_or(builder.repeat_penalty),
+            options.frequency_penalty.unwrap_or(builder.frequency_penalty),
+            options.presence_penalty.unwrap_or(builder.presence_penalty),
+        );
+        if let Some(seed) = options.seed {
+            builder = builder.seed(seed);
         }
         let raw_logit_bias = collect_sampler_logit_bias(
             &options.logit_bias,
@@ -546,10 +762,27 @@ impl LlamaModel {
         if !raw_logit_bias.is_empty() {
             builder.set_logit_bias(self.n_vocab(), raw_logit_bias);
         }
-        match options.gbnf.as_deref() {
-            None | Some("") => builder.build(),
-            Some(gbnf_str) => builder.build_with_grammar(self.vocab(), gbnf_str),
-        }
+        builder
+            .build_with_grammar(self.vocab(), options.gbnf.as_deref().unwrap_or_default())
+            .expect("sampling options never enable greedy selection")
+    }
+
+    /// The model's built-in chat template (`tokenizer.chat_template`), if any.
+    ///
+    /// Base models usually ship without one; callers should fall back to a
+    /// default template in that case.
+    pub fn chat_template(&self) -> Option<String> {
+        let template = unsafe {
+            self.inner
+                .lib
+                .llama_model_chat_template(self.inner.model.unwrap().as_ptr(), std::ptr::null())
+        };
+        unsafe { chat_template_from_ptr(template) }
+    }
+
+    /// Whether the model carries a built-in chat template.
+    pub fn has_chat_template(&self) -> bool {
+        self.chat_template().is_some()
     }
 
     /// Retrieve a metadata value by key.
@@ -580,3 +813,177 @@ impl std::fmt::Debug for LlamaModel {
         f.debug_struct("LlamaModel").finish()
     }
 }
+
+/// Run llama.cpp's two-pass tokenize protocol against `buf`'s spare capacity.
+///
+/// `tokenize(ptr, capacity)` returns the token count, or its negation when
+/// `capacity` is too small.
+fn fill_tokens<F>(buf: &mut Vec<LlamaToken>, mut tokenize: F) -> Result<usize, LlamaError>
+where
+    F: FnMut(*mut LlamaToken, i32) -> i32,
+{
+    buf.clear();
+    let capacity = i32::try_from(buf.capacity()).unwrap_or(i32::MAX);
+    let mut n = tokenize(buf.as_mut_ptr(), capacity);
+    if n < 0 {
+        let required = n.checked_abs().ok_or(LlamaError::TokenizeFailed(n))? as usize;
+        buf.reserve_exact(required);
+        n = tokenize(buf.as_mut_ptr(), required as i32);
+        if n < 0 {
+            return Err(LlamaError::TokenizeFailed(n));
+        }
+    }
+    let written = n as usize;
+    // SAFETY: llama_tokenize initialized `written` tokens within the capacity
+    // it was given.
+    unsafe { buf.set_len(written) };
+    Ok(written)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::{Path, PathBuf};
+
+    use super::{chat_template_from_ptr, fill_tokens, split_c_paths};
+    use crate::error::LlamaError;
+    use crate::{Llama, LlamaModelParams, LlamaVocabType};
+
+    #[test]
+    #[ignore = "requires local llama runtime libraries and a GGUF model"]
+    fn bos_token_is_classified_as_control() {
+        let testdata = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../../bin/testdata/llama");
+        let llama = Llama::new(&testdata).expect("llama runtime libraries");
+        let model_path = testdata.join("model.gguf");
+        let model = llama
+            .load_model_from_file(
+                model_path.to_str().expect("utf-8 path"),
+                LlamaModelParams::default().vocab_only(true),
+            )
+            .expect("load vocabulary");
+
+        assert_ne!(model.vocab_type(), LlamaVocabType::None);
+        let bos = model.token_bos().expect("model defines a BOS token");
+        assert!(model.token_is_control(bos).expect("BOS is in the vocabulary"));
+        assert!(model.token_get_attr(bos).expect("BOS is in the vocabulary").is_control());
+        assert!(matches!(
+            model.token_is_control(model.n_vocab()),
+            Err(LlamaError::TokenOutOfRange { .. })
+        ));
+        assert!(matches!(
+            model.detokenize(&[bos, -1], false, true),
+            Err(LlamaError::TokenOutOfRange { token: -1, .. })
+        ));
+    }
+
+    #[test]
+    #[ignore = "requires local llama runtime libraries and a GGUF model"]
+    fn token_to_piece_ext_strips_leading_space_on_request() {
+        let testdata = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../../bin/testdata/llama");
+        let llama = Llama::new(&testdata).expect("llama runtime libraries");
+        let model_path = testdata.join("model.gguf");
+        let model = llama
+            .load_model_from_file(
+                model_path.to_str().expect("utf-8 path"),
+                LlamaModelParams::default().vocab_only(true),
+            )
+            .expect("load vocabulary");
+
+        let tokens = model.tokenize("Hello world", false, false).expect("tokenize");
+        let token = tokens
+            .iter()
+            .copied()
+            .find(|&token| model.token_to_piece(token, false).is_ok_and(|p| p.starts_with(' ')))
+            .expect("a space-prefixed token");
+
+        let kept = model.token_to_piece_ext(token, 0, false).expect("piece with lstrip=0");
+        let stripped = model.token_to_piece_ext(token, 1, false).expect("piece with lstrip=1");
+        assert_eq!(kept, model.token_to_piece(token, false).expect("piece"));
+        assert!(kept.starts_with(' '));
+        assert_eq!(stripped, kept[1..]);
+    }
+
+    /// Stands in for `llama_tokenize`: one token per byte.
+    fn fake_tokenize(text: &str) -> impl FnMut(*mut i32, i32) -> i32 + '_ {
+        move |tokens, capacity| {
+            let needed = text.len() as i32;
+            if capacity < needed {
+                return -needed;
+            }
+            for (index, byte) in text.bytes().enumerate() {
+                unsafe { tokens.add(index).write(i32::from(byte)) };
+            }
+            needed
+        }
+    }
+
+    #[test]
+    fn tokenize_into_reuses_buffer_capacity() {
+        let mut buf = Vec::new();
+        assert_eq!(fill_tokens(&mut buf, fake_tokenize("hello world")).unwrap(), 11);
+        assert_eq!(buf[0], i32::from(b'h'));
+        let capacity = buf.capacity();
+        let ptr = buf.as_ptr();
+
+        for text in ["hello", "hello world", "", "hi there"] {
+            let mut calls = 0;
+            let mut tokenize = fake_tokenize(text);
+            let written = fill_tokens(&mut buf, |tokens, cap| {
+                calls += 1;
+                tokenize(tokens, cap)
+            })
+            .unwrap();
+            assert_eq!(written, text.len());
+            assert_eq!(buf.len(), text.len());
+            assert_eq!(calls, 1, "a buffer that already fits needs no sizing pass");
+            assert_eq!(buf.capacity(), capacity);
+            assert_eq!(buf.as_ptr(), ptr);
+        }
+    }
+
+    #[test]
+    fn chat_template_is_absent_for_null_or_blank_metadata() {
+        let instruct = c"{% for message in messages %}{{ message.content }}{% endfor %}";
+
+        assert_eq!(
+            unsafe { chat_template_from_ptr(instruct.as_ptr()) }.as_deref(),
+            Some("{% for message in messages %}{{ message.content }}{% endfor %}")
+        );
+        assert_eq!(unsafe { chat_template_from_ptr(std::ptr::null()) }, None);
+        assert_eq!(unsafe { chat_template_from_ptr(c"  ".as_ptr()) }, None);
+    }
+
+    #[test]
+    fn split_paths_reject_missing_shards_before_loading() {
+        let dir = std::env::temp_dir().join(format!("slab-llama-splits-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).expect("temp dir");
+        let first = dir.join("model-00001-of-00002.gguf");
+        let second = dir.join("model-00002-of-00002.gguf");
+        std::fs::write(&first, b"GGUF").expect("write first shard");
+
+        let error = split_c_paths(&[first.as_path(), second.as_path()])
+            .expect_err("missing second shard should be rejected");
+        let LlamaError::InvalidModelSplits(message) = error else {
+            panic!("unexpected error: {error}");
+        };
+        assert!(message.contains("model-00002-of-00002.gguf"));
+
+        std::fs::write(&second, b"GGUF").expect("write second shard");
+        let c_paths =
+            split_c_paths(&[first.as_path(), second.as_path()]).expect("both shards exist");
+        assert_eq!(c_paths.len(), 2);
+
+        assert!(matches!(split_c_paths(&[] as &[&Path]), Err(LlamaError::InvalidModelSplits(_))));
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn memfd_model_buffer_is_readable_through_its_proc_path() {
+        let (file, path) =
+            super::memfd_from_bytes(b"GGUF\x03\x00\x00\x00").expect("memfd should be created");
+
+        assert_eq!(std::fs::read(&path).expect("proc path readable"), b"GGUF\x03\x00\x00\x00");
+        drop(file);
+        assert!(std::fs::read(&path).is_err());
+    }
+}
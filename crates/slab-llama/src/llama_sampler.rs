@@ -1,6 +1,7 @@
 use std::sync::Arc;
 
 use crate::Llama;
+use crate::error::LlamaError;
 use crate::llama_context::LlamaContext;
 use crate::token::LlamaToken;
 
@@ -109,6 +110,27 @@ impl LlamaSampler {
         self
     }
 
+    /// Add a locally typical sampler.
+    ///
+    /// # Arguments
+    /// * `p`        – typicality mass to keep (1.0 = disabled).
+    /// * `min_keep` – minimum number of tokens to keep.
+    pub fn add_typical(self, p: f32, min_keep: usize) -> Self {
+        let s = unsafe { self.lib.llama_sampler_init_typical(p, min_keep) };
+        unsafe { self.lib.llama_sampler_chain_add(self.as_ptr(), s) };
+        self
+    }
+
+    /// Add a top-n-sigma sampler.
+    ///
+    /// Keeps tokens whose logit is within `n` standard deviations of the
+    /// maximum logit (`n <= 0.0` = disabled).
+    pub fn add_top_n_sigma(self, n: f32) -> Self {
+        let s = unsafe { self.lib.llama_sampler_init_top_n_sigma(n) };
+        unsafe { self.lib.llama_sampler_chain_add(self.as_ptr(), s) };
+        self
+    }
+
     /// Add a Mirostat v2 sampler.
     ///
     /// # Arguments
@@ -256,11 +278,17 @@ impl LlamaSampler {
     }
 }
 
+const DEFAULT_TEMPERATURE: f32 = 0.8;
+
 /// A convenience builder for common sampler chain configurations.
+///
+/// The builder only holds a declarative configuration, so it is cheap to
+/// clone and can [`build`](Self::build) any number of independent chains.
+#[derive(Clone)]
 pub struct SamplerChainBuilder {
     lib: Arc<slab_llama_sys::LlamaLib>,
-    /// Temperature (default 0.8).
-    pub temperature: f32,
+    /// Temperature (`None` = default 0.8).
+    pub temperature: Option<f32>,
     /// Top-K (default 40, 0 = disabled).
     pub top_k: i32,
     /// Top-P (default 0.9, 1.0 = disabled).
@@ -269,12 +297,17 @@ pub struct SamplerChainBuilder {
     pub min_p: f32,
     /// Repetition penalty (default 1.05).
     pub repeat_penalty: f32,
+    /// Frequency penalty (default 0.0).
+    pub frequency_penalty: f32,
     /// Presence penalty (default 0.0).
     pub presence_penalty: f32,
     /// Number of tokens to consider for repetition penalty (default 64).
     pub repeat_last_n: i32,
-    /// Random seed (default `LLAMA_DEFAULT_SEED` = 0xFFFF_FFFF).
-    pub seed: u32,
+    /// Random seed (`None` = `LLAMA_DEFAULT_SEED` = 0xFFFF_FFFF).
+    pub seed: Option<u32>,
+    greedy: bool,
+    typical: Option<(f32, usize)>,
+    top_n_sigma: Option<f32>,
     logit_bias_n_vocab: Option<i32>,
     logit_bias: Vec<slab_llama_sys::llama_logit_bias>,
 }
@@ -284,14 +317,18 @@ impl SamplerChainBuilder {
     pub fn new(lib: Arc<slab_llama_sys::LlamaLib>) -> Self {
         Self {
             lib,
-            temperature: 0.8,
+            temperature: None,
             top_k: 40,
             top_p: 0.9,
             min_p: 0.05,
             repeat_penalty: 1.05,
+            frequency_penalty: 0.0,
             presence_penalty: 0.0,
             repeat_last_n: 64,
-            seed: slab_llama_sys::LLAMA_DEFAULT_SEED,
+            seed: None,
+            greedy: false,
+            typical: None,
+            top_n_sigma: None,
             logit_bias_n_vocab: None,
             logit_bias: Vec::new(),
         }
@@ -306,37 +343,60 @@ impl SamplerChainBuilder {
         self.logit_bias = logit_bias;
     }
 
-    /// Build and return a [`LlamaSampler`] chain.
-    pub fn build(self) -> LlamaSampler {
-        let mut chain = LlamaSampler::chain_new(Arc::clone(&self.lib));
+    /// Switch the chain to pure argmax selection via `llama_sampler_init_greedy`.
+    ///
+    /// Greedy chains use no RNG, so the same prompt always yields the same
+    /// tokens. Unlike `temperature = 0.0`, no distribution sampler is added at
+    /// all. Top-K/Top-P/Min-P cannot change the argmax and are skipped;
+    /// penalties, logit bias and grammar still apply. Setting `temperature` or
+    /// `seed`, before or after this call, makes [`build`](Self::build) fail.
+    pub fn greedy(mut self) -> Self {
+        self.greedy = true;
+        self
+    }
 
-        // penalties first (they observe the logits before sampling).
-        if self.repeat_penalty != 1.0 || self.repeat_last_n != 0 || self.presence_penalty != 0.0 {
-            chain = chain.add_penalties(
-                self.repeat_last_n,
-                self.repeat_penalty,
-                0.0,
-                self.presence_penalty,
-            );
-        }
+    /// Seed the final distribution sampler so identical prompts and settings
+    /// reproduce the same tokens.
+    pub fn seed(mut self, seed: u32) -> Self {
+        self.seed = Some(seed);
+        self
+    }
 
-        if let Some(n_vocab) = self.logit_bias_n_vocab {
-            chain = chain.add_logit_bias(n_vocab, &self.logit_bias);
-        }
+    /// Configure the penalties stage that runs first in the chain.
+    ///
+    /// `freq` and `presence` map to OpenAI's `frequency_penalty` and
+    /// `presence_penalty`. Penalties only see history the caller feeds back
+    /// through [`LlamaSampler::accept`].
+    pub fn penalties(mut self, last_n: i32, repeat: f32, freq: f32, presence: f32) -> Self {
+        self.repeat_last_n = last_n;
+        self.repeat_penalty = repeat;
+        self.frequency_penalty = freq;
+        self.presence_penalty = presence;
+        self
+    }
 
-        if self.top_k > 0 {
-            chain = chain.add_top_k(self.top_k);
-        }
-        if self.top_p < 1.0 {
-            chain = chain.add_top_p(self.top_p, 1);
-        }
-        if self.min_p > 0.0 {
-            chain = chain.add_min_p(self.min_p, 1);
-        }
-        chain = chain.add_temp(self.temperature);
-        chain = chain.add_dist(self.seed);
+    /// Enable locally typical sampling with typicality mass `p`.
+    pub fn typical(mut self, p: f32, min_keep: usize) -> Self {
+        self.typical = Some((p, min_keep));
+        self
+    }
 
-        chain
+    /// Enable top-n-sigma filtering, keeping logits within `n` standard
+    /// deviations of the maximum.
+    pub fn top_n_sigma(mut self, n: f32) -> Self {
+        self.top_n_sigma = Some(n);
+        self
+    }
+
+    /// Check that greedy selection is not combined with temperature/dist sampling.
+    pub fn validate(&self) -> Result<(), LlamaError> {
+        validate_greedy_exclusive(self.greedy, self.temperature, self.seed)
+    }
+
+    /// Build a fresh [`LlamaSampler`] chain; the builder stays reusable.
+    pub fn build(&self) -> Result<LlamaSampler, LlamaError> {
+        self.validate()?;
+        Ok(self.assemble(None))
     }
 
     /// Build a [`LlamaSampler`] chain with an optional GBNF grammar constraint.
@@ -352,33 +412,55 @@ impl SamplerChainBuilder {
     /// # Safety
     /// `vocab` must be a valid, non-null pointer that outlives this sampler chain.
     pub(crate) fn build_with_grammar(
-        self,
+        &self,
         vocab: *const slab_llama_sys::llama_vocab,
         grammar_str: &str,
+    ) -> Result<LlamaSampler, LlamaError> {
+        self.validate()?;
+        Ok(self.assemble((!grammar_str.is_empty()).then_some((vocab, grammar_str))))
+    }
+
+    fn assemble(
+        &self,
+        grammar: Option<(*const slab_llama_sys::llama_vocab, &str)>,
     ) -> LlamaSampler {
         let mut chain = LlamaSampler::chain_new(Arc::clone(&self.lib));
 
-        if self.repeat_penalty != 1.0 || self.repeat_last_n != 0 || self.presence_penalty != 0.0 {
+        // penalties first (they observe the logits before sampling).
+        if penalties_enabled(
+            self.repeat_last_n,
+            self.repeat_penalty,
+            self.frequency_penalty,
+            self.presence_penalty,
+        ) {
             chain = chain.add_penalties(
                 self.repeat_last_n,
                 self.repeat_penalty,
-                0.0,
+                self.frequency_penalty,
                 self.presence_penalty,
             );
         }
         if let Some(n_vocab) = self.logit_bias_n_vocab {
             chain = chain.add_logit_bias(n_vocab, &self.logit_bias);
         }
-        if self.top_k > 0 {
+        if !self.greedy && self.top_k > 0 {
             chain = chain.add_top_k(self.top_k);
         }
-        if self.top_p < 1.0 {
+        if !self.greedy && self.top_p < 1.0 {
             chain = chain.add_top_p(self.top_p, 1);
         }
-        if self.min_p > 0.0 {
+        if !self.greedy && self.min_p > 0.0 {
             chain = chain.add_min_p(self.min_p, 1);
         }
-        chain = chain.add_temp(self.temperature);
+        if let Some((p, min_keep)) = self.typical {
+            chain = chain.add_typical(p, min_keep);
+        }
+        if let Some(n) = self.top_n_sigma {
+            chain = chain.add_top_n_sigma(n);
+        }
+        if !self.greedy {
+            chain = chain.add_temp(self.temperature.unwrap_or(DEFAULT_TEMPERATURE));
+        }
 
         // Grammar sampler: filters logits so only grammar-valid tokens survive,
         // placed after temperature shaping and before the final selection step.
@@ -386,7 +468,9 @@ impl SamplerChainBuilder {
         // NOTE: The grammar must define a `root` rule, which is used here as the
         // start symbol.  If the grammar does not contain `root ::= ...`, grammar
         // initialization will fail and we fall back to unconstrained sampling.
-        if !grammar_str.is_empty() && !chain.try_add_grammar(vocab, grammar_str, "root") {
+        if let Some((vocab, grammar_str)) = grammar
+            && !chain.try_add_grammar(vocab, grammar_str, "root")
+        {
             let grammar_len = grammar_str.chars().count();
             let grammar_preview: String = grammar_str.chars().take(200).collect();
             tracing::warn!(
@@ -397,7 +481,81 @@ impl SamplerChainBuilder {
             );
         }
 
-        chain = chain.add_dist(self.seed);
-        chain
+        if self.greedy {
+            chain.add_greedy()
+        } else {
+            chain.add_dist(self.seed.unwrap_or(slab_llama_sys::LLAMA_DEFAULT_SEED))
+        }
+    }
+}
+
+fn penalties_enabled(last_n: i32, repeat: f32, freq: f32, presence: f32) -> bool {
+    last_n != 0 && (repeat != 1.0 || freq != 0.0 || presence != 0.0)
+}
+
+fn validate_greedy_exclusive(
+    greedy: bool,
+    temperature: Option<f32>,
+    seed: Option<u32>,
+) -> Result<(), LlamaError> {
+    if !greedy {
+        return Ok(());
+    }
+    if let Some(temperature) = temperature {
+        return Err(LlamaError::InvalidSamplerConfig(format!(
+            "greedy sampling cannot be combined with temperature {temperature}"
+        )));
+    }
+    if seed.is_some() {
+        return Err(LlamaError::InvalidSamplerConfig(
+            "greedy sampling cannot be combined with a seeded dist sampler".into(),
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn greedy_rejects_temperature_and_seed() {
+        assert!(validate_greedy_exclusive(false, Some(0.8), Some(42)).is_ok());
+        assert!(validate_greedy_exclusive(true, None, None).is_ok());
+
+        let error = validate_greedy_exclusive(true, Some(0.7), None)
+            .expect_err("temperature must not combine with greedy");
+        assert!(error.to_string().contains("temperature 0.7"));
+        // An explicit temperature is rejected even at the neutral 1.0.
+        assert!(validate_greedy_exclusive(true, Some(1.0), None).is_err());
+        assert!(validate_greedy_exclusive(true, None, Some(42)).is_err());
+    }
+
+    #[test]
+    #[ignore = "requires local llama runtime libraries"]
+    fn cloned_builders_stamp_out_independent_equivalent_chains() {
+        let lib_dir =
+            std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../../bin/testdata/llama");
+        let llama = crate::Llama::new(&lib_dir).expect("llama runtime libraries");
+        let builder =
+            SamplerChainBuilder::new(llama.lib_arc()).seed(42).penalties(32, 1.1, 0.2, 0.1);
+
+        let first = builder.clone().build().expect("first chain");
+        let second = builder.build().expect("second chain");
+        let third = builder.build().expect("builder stays reusable");
+
+        assert_eq!(first.get_seed(), 42);
+        assert_eq!(second.get_seed(), first.get_seed());
+        assert_eq!(third.get_seed(), first.get_seed());
+        assert_ne!(first.as_ptr(), second.as_ptr());
+    }
+
+    #[test]
+    fn penalties_stage_is_added_only_when_a_penalty_is_active() {
+        assert!(!penalties_enabled(64, 1.0, 0.0, 0.0));
+        assert!(!penalties_enabled(0, 1.3, 0.5, 0.5));
+        assert!(penalties_enabled(64, 1.05, 0.0, 0.0));
+        assert!(penalties_enabled(64, 1.0, 0.5, 0.0));
+        assert!(penalties_enabled(-1, 1.0, 0.0, 0.5));
     }
 }
@@ -1,8 +1,35 @@
+/// How a model is split across multiple GPUs.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LlamaSplitMode {
+    /// Use a single GPU (`main_gpu`).
+    None,
+    /// Split layers and KV cache across GPUs.
+    #[default]
+    Layer,
+    /// Split layers and KV cache across GPUs, using tensor parallelism if supported.
+    Row,
+}
+
+impl LlamaSplitMode {
+    fn to_c(self) -> slab_llama_sys::llama_split_mode {
+        match self {
+            Self::None => slab_llama_sys::llama_split_mode_LLAMA_SPLIT_MODE_NONE,
+            Self::Layer => slab_llama_sys::llama_split_mode_LLAMA_SPLIT_MODE_LAYER,
+            Self::Row => slab_llama_sys::llama_split_mode_LLAMA_SPLIT_MODE_ROW,
+        }
+    }
+}
+
 /// Parameters for loading a llama model.
 #[derive(Debug, Clone)]
 pub struct LlamaModelParams {
     /// Number of GPU layers to offload (-1 = all).
     pub n_gpu_layers: i32,
+    /// GPU used for the whole model when `split_mode` is `None`, or for
+    /// intermediate results and KV cache when it is `Row`.
+    pub main_gpu: i32,
+    /// How to split the model across multiple GPUs.
+    pub split_mode: LlamaSplitMode,
     /// Load only the vocabulary, not the weights.
     pub vocab_only: bool,
     /// Use memory-mapped I/O if available.
@@ -13,7 +40,14 @@ pub struct LlamaModelParams {
 
 impl Default for LlamaModelParams {
     fn default() -> Self {
-        Self { n_gpu_layers: 0, vocab_only: false, use_mmap: true, use_mlock: false }
+        Self {
+            n_gpu_layers: 0,
+            main_gpu: 0,
+            split_mode: LlamaSplitMode::default(),
+            vocab_only: false,
+            use_mmap: true,
+            use_mlock: false,
+        }
     }
 }
 
@@ -27,6 +61,16 @@ impl LlamaModelParams {
         self
     }
 
+    pub fn main_gpu(mut self, index: i32) -> Self {
+        self.main_gpu = index;
+        self
+    }
+
+    pub fn split_mode(mut self, mode: LlamaSplitMode) -> Self {
+        self.split_mode = mode;
+        self
+    }
+
     pub fn vocab_only(mut self, v: bool) -> Self {
         self.vocab_only = v;
         self
@@ -47,10 +91,39 @@ impl LlamaModelParams {
         lib: &slab_llama_sys::LlamaLib,
     ) -> slab_llama_sys::llama_model_params {
         let mut params = unsafe { lib.llama_model_default_params() };
+        self.apply(&mut params);
+        params
+    }
+
+    fn apply(&self, params: &mut slab_llama_sys::llama_model_params) {
         params.n_gpu_layers = self.n_gpu_layers;
+        params.main_gpu = self.main_gpu;
+        params.split_mode = self.split_mode.to_c();
         params.vocab_only = self.vocab_only;
         params.use_mmap = self.use_mmap;
         params.use_mlock = self.use_mlock;
-        params
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{LlamaModelParams, LlamaSplitMode};
+
+    #[test]
+    fn gpu_offload_settings_are_forwarded() {
+        // SAFETY: `llama_model_params` is a plain C struct; all-zero is a valid
+        // (null pointers, disabled flags) value.
+        let mut params: slab_llama_sys::llama_model_params = unsafe { std::mem::zeroed() };
+
+        LlamaModelParams::new()
+            .n_gpu_layers(33)
+            .main_gpu(1)
+            .split_mode(LlamaSplitMode::Row)
+            .apply(&mut params);
+
+        assert_eq!(params.n_gpu_layers, 33);
+        assert_eq!(params.main_gpu, 1);
+        assert_eq!(params.split_mode, slab_llama_sys::llama_split_mode_LLAMA_SPLIT_MODE_ROW);
+        assert!(params.use_mmap);
     }
 }
@@ -1,11 +1,12 @@
 use std::collections::HashMap;
 use std::path::PathBuf;
 use std::sync::Arc;
+use std::time::Instant;
 
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use thiserror::Error;
-use tokio::sync::{mpsc, oneshot};
+use tokio::sync::{mpsc, oneshot, watch};
 use tracing::warn;
 
 use crate::{
@@ -18,6 +19,37 @@ const fn default_flash_attn_enabled() -> bool {
 
 pub type SessionId = u64;
 
+/// What a worker does when a session's next batch no longer fits in its context.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LlamaContextOverflow {
+    /// Evict the oldest KV entries of the sequence and keep generating.
+    #[default]
+    Shift,
+    /// End the stream with a [`LlamaRuntimeError::ContextFull`] error.
+    Stop,
+}
+
+/// How the runtime picks a worker for a new session.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LlamaSessionAssignment {
+    /// Rotate through workers regardless of how many sessions each holds.
+    #[default]
+    RoundRobin,
+    /// Pick the worker with the fewest active sessions (lowest index on ties).
+    LeastLoaded,
+}
+
+/// Worker behaviour chosen when a [`LlamaRuntime`] starts.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LlamaRuntimeOptions {
+    /// How workers react to a full context.
+    pub overflow: LlamaContextOverflow,
+    /// How new sessions are spread across workers.
+    pub assignment: LlamaSessionAssignment,
+}
+
 #[derive(Debug, Clone)]
 pub struct LlamaSessionSnapshot {
     pub worker_id: usize,
@@ -71,9 +103,16 @@ pub struct LlamaSamplingOptions {
     pub top_k: Option<i32>,
     pub min_p: Option<f32>,
     pub repetition_penalty: Option<f32>,
+    pub frequency_penalty: Option<f32>,
     pub presence_penalty: Option<f32>,
+    /// Tokens of history the penalties look back over; `None` keeps the builder default.
+    pub repeat_last_n: Option<i32>,
+    /// Seed for the distribution sampler; `None` keeps `LLAMA_DEFAULT_SEED` (random).
+    pub seed: Option<u32>,
     pub ignore_eos: bool,
     pub logit_bias: Vec<LlamaLogitBias>,
+    /// Leading tokens (e.g. a system prompt) preserved when the context window shifts.
+    pub n_keep: usize,
 }
 
 #[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
@@ -82,12 +121,18 @@ pub struct LlamaStopInfo {
     pub stop_token_id: Option<LlamaToken>,
     pub stop_token_text: Option<String>,
     pub stop_token_kind: Option<String>,
+    /// Tokens sampled for this generation, excluding the end-of-generation token.
+    #[serde(default)]
+    pub completion_tokens: u32,
 }
 
 #[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct LlamaInferenceOutput {
     pub text: String,
     pub stop: Option<LlamaStopInfo>,
+    /// Number of generated pieces received from the decode loop.
+    #[serde(default)]
+    pub completion_tokens: u32,
 }
 
 #[derive(Debug, Error)]
@@ -128,9 +173,17 @@ pub enum LlamaRuntimeError {
     #[error("Session capacity exceeded: max concurrent sessions per worker is {max_sessions}")]
     SessionCapacityExceeded { max_sessions: usize },
 
+    #[error(
+        "Context full: {n_past} cached + {needed_tokens} new tokens exceed context length {context_length}"
+    )]
+    ContextFull { context_length: usize, n_past: usize, needed_tokens: usize },
+
     #[error("Inference worker shut down unexpectedly")]
     WorkerShutdown,
 
+    #[error("Generation failed: {message}")]
+    GenerationFailed { message: String },
+
     #[error("Failed to spawn inference worker thread")]
     SpawnWorkerFailed {
         #[source]
@@ -249,6 +302,7 @@ enum GlobalCommand {
         session_id: SessionId,
         max_new_tokens: usize,
         stream_tx: mpsc::Sender<StreamChunk>,
+        cancel_rx: Option<watch::Receiver<bool>>,
         reply_tx: oneshot::Sender<Result<(), LlamaRuntimeError>>,
     },
     EndSession {
@@ -269,10 +323,37 @@ struct MasterWorkerState {
     global_rx: mpsc::Receiver<GlobalCommand>,
     worker_txs: Vec<mpsc::Sender<WorkerCommand>>,
     session_map: HashMap<SessionId, usize>,
+    assignment: LlamaSessionAssignment,
     next_worker: usize,
     next_session_id: u64,
 }
 
+fn assign_worker(
+    assignment: LlamaSessionAssignment,
+    next_worker: &mut usize,
+    num_workers: usize,
+    session_map: &HashMap<SessionId, usize>,
+) -> usize {
+    match assignment {
+        LlamaSessionAssignment::RoundRobin => {
+            let worker_id = *next_worker % num_workers;
+            *next_worker += 1;
+            worker_id
+        }
+        LlamaSessionAssignment::LeastLoaded => {
+            let mut loads = vec![0_usize; num_workers];
+            for &worker_id in session_map.values() {
+                loads[worker_id] += 1;
+            }
+            loads
+                .iter()
+                .enumerate()
+                .min_by_key(|&(worker_id, load)| (*load, worker_id))
+                .map_or(0, |(worker_id, _)| worker_id)
+        }
+    }
+}
+
 impl MasterWorkerState {
     async fn run(mut self) {
         while let Some(cmd) = self.global_rx.recv().await {
@@ -280,8 +361,12 @@ impl MasterWorkerState {
                 GlobalCommand::CreateSession { options, reply_tx } => {
                     let session_id = self.next_session_id;
                     self.next_session_id += 1;
-                    let worker_id = self.next_worker % self.worker_txs.len();
-                    self.next_worker += 1;
+                    let worker_id = assign_worker(
+                        self.assignment,
+                        &mut self.next_worker,
+                        self.worker_txs.len(),
+                        &self.session_map,
+                    );
 
                     let (ack_tx, ack_rx) = oneshot::channel();
                     if self.worker_txs[worker_id]
@@ -380,6 +465,7 @@ impl MasterWorkerState {
                     session_id,
                     max_new_tokens,
                     stream_tx,
+                    cancel_rx,
                     reply_tx,
                 } => match self.session_map.get(&session_id) {
                     None => {
@@ -393,6 +479,7 @@ impl MasterWorkerState {
                                 session_id,
                                 max_new_tokens,
                                 stream_tx,
+                                cancel_rx,
                                 reply_tx: ack_tx,
                             })
                             .await
@@ -523,6 +610,7 @@ pub(super) enum WorkerCommand {
         session_id: SessionId,
         max_new_tokens: usize,
         stream_tx: mpsc::Sender<StreamChunk>,
+        cancel_rx: Option<watch::Receiver<bool>>,
         reply_tx: oneshot::Sender<Result<(), LlamaRuntimeError>>,
     },
     EndSession {
@@ -546,9 +634,75 @@ struct SessionState {
     pending_output: Utf8PieceBuffer,
     sampler: Option<crate::LlamaSampler>,
     stream_tx: Option<mpsc::Sender<StreamChunk>>,
+    /// Caller-owned cancellation signal for the active generation, if any.
+    cancel_rx: Option<watch::Receiver<bool>>,
     remaining_tokens: usize,
     last_token: Option<LlamaToken>,
     cancelled: bool,
+    /// Leading positions never evicted by a window shift.
+    n_keep: usize,
+    /// Prefill/decode accounting for the active generation.
+    timings: GenerationTimings,
+}
+
+/// Token counts and evaluation time attributed to one generation.
+///
+/// llama's perf counters are context-wide and shared by every session batched
+/// on the worker, so each decode's elapsed time (see [`decode_timed`]) is
+/// charged to the sessions that took part in that batch.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+struct GenerationTimings {
+    prompt_tokens: usize,
+    eval_tokens: usize,
+    sampled_tokens: usize,
+    prefill_ms: f64,
+    decode_ms: f64,
+}
+
+impl GenerationTimings {
+    fn record_prefill(&mut self, tokens: usize, elapsed_ms: f64) {
+        self.prompt_tokens += tokens;
+        self.prefill_ms += elapsed_ms;
+    }
+
+    fn record_decode(&mut self, elapsed_ms: f64) {
+        self.eval_tokens += 1;
+        self.decode_ms += elapsed_ms;
+    }
+
+    fn record_sample(&mut self) {
+        self.sampled_tokens += 1;
+    }
+
+    fn span(&self, seq_id: LlamaSeqId) -> tracing::Span {
+        tracing::debug_span!(
+            "llama_generation",
+            seq_id,
+            prompt_tokens = self.prompt_tokens,
+            eval_tokens = self.eval_tokens,
+            sampled_tokens = self.sampled_tokens,
+            prefill_ms = self.prefill_ms,
+            decode_ms = self.decode_ms,
+        )
+    }
+}
+
+/// Decode `batch` and wait for the backend to finish, returning the wall time
+/// in milliseconds.
+///
+/// Sampling reads the batch's logits right afterwards, which synchronizes
+/// anyway, so waiting here costs nothing and keeps the time with its batch.
+fn decode_timed(ctx: &mut LlamaContext, batch: &mut LlamaBatch) -> Result<f64, LlamaError> {
+    let started = Instant::now();
+    ctx.decode(batch)?;
+    ctx.synchronize();
+    Ok(started.elapsed().as_secs_f64() * 1000.0)
+}
+
+impl SessionState {
+    fn cancel_requested(&self) -> bool {
+        self.cancelled || self.cancel_rx.as_ref().is_some_and(|cancel_rx| *cancel_rx.borrow())
+    }
 }
 
 struct InferenceWorkerState {
@@ -563,6 +717,7 @@ struct InferenceWorkerState {
     context_length: usize,
     kv_cache_can_shift: bool,
     window_drop_chunk: usize,
+    overflow: LlamaContextOverflow,
     cmd_rx: mpsc::Receiver<WorkerCommand>,
 }
 
@@ -571,6 +726,7 @@ impl InferenceWorkerState {
         worker_id: usize,
         model: Arc<LlamaModel>,
         ctx: LlamaContext,
+        overflow: LlamaContextOverflow,
         cmd_rx: mpsc::Receiver<WorkerCommand>,
     ) -> Self {
         let context_length = ctx.n_ctx_seq() as usize;
@@ -589,6 +745,7 @@ impl InferenceWorkerState {
             context_length,
             kv_cache_can_shift,
             window_drop_chunk,
+            overflow,
             cmd_rx,
         }
     }
@@ -600,6 +757,7 @@ impl InferenceWorkerState {
         session.pending_output.clear();
         session.remaining_tokens = 0;
         session.last_token = None;
+        session.timings = GenerationTimings::default();
     }
 
     fn build_stop_info(
@@ -622,6 +780,7 @@ impl InferenceWorkerState {
             stop_token_id: token,
             stop_token_text,
             stop_token_kind,
+            completion_tokens: 0,
         }
     }
 
@@ -632,11 +791,13 @@ impl InferenceWorkerState {
     ) -> Result<(), mpsc::error::SendError<StreamChunk>> {
         session.last_token = None;
         session.remaining_tokens = 0;
+        let timings = std::mem::take(&mut session.timings);
 
         let Some(tx) = session.stream_tx.take() else {
             session.pending_output.clear();
             return Ok(());
         };
+        timings.span(session.seq_id).in_scope(|| tracing::debug!("llama generation finished"));
 
         if let Some(text) = final_text
             && !text.is_empty()
@@ -644,7 +805,8 @@ impl InferenceWorkerState {
             tx.blocking_send(StreamChunk::Token(text))?;
         }
 
-        if let Some(stop) = stop {
+        if let Some(mut stop) = stop {
+            stop.completion_tokens = u32::try_from(timings.sampled_tokens).unwrap_or(u32::MAX);
             tx.blocking_send(StreamChunk::Stop(stop))?;
         }
 
@@ -666,9 +828,55 @@ impl InferenceWorkerState {
         }
     }
 
+    /// Number of cached tokens that must be evicted before `needed_tokens` fit,
+    /// or [`LlamaRuntimeError::ContextFull`] when `overflow` forbids eviction.
+    fn context_overflow(
+        overflow: LlamaContextOverflow,
+        context_length: usize,
+        n_past: usize,
+        needed_tokens: usize,
+    ) -> Result<usize, LlamaRuntimeError> {
+        if n_past + needed_tokens <= context_length {
+            return Ok(0);
+        }
+        match overflow {
+            LlamaContextOverflow::Shift => Ok(n_past + needed_tokens - context_length),
+            LlamaContextOverflow::Stop => {
+                Err(LlamaRuntimeError::ContextFull { context_length, n_past, needed_tokens })
+            }
+        }
+    }
+
+    /// Plan a sliding-window shift as `(keep, drop)`: evict `drop` positions
+    /// right after the first `keep` ones so `needed_tokens` fit again.
+    ///
+    /// At least `window_drop_chunk` positions are dropped to amortise shifts.
+    /// The kept prefix is abandoned when it leaves no room for the new tokens.
+    fn plan_window_shift(
+        context_length: usize,
+        window_drop_chunk: usize,
+        n_past: usize,
+        n_keep: usize,
+        needed_tokens: usize,
+    ) -> (usize, usize) {
+        let overflow = (n_past + needed_tokens).saturating_sub(context_length);
+        let mut keep = n_keep.min(n_past);
+        if overflow == 0 {
+            return (keep, 0);
+        }
+        if overflow > n_past - keep {
+            keep = 0;
+        }
+
+        let drop = overflow.max(window_drop_chunk).min(n_past - keep);
+        (keep, drop)
+    }
+
+    #[allow(clippy::too_many_arguments)]
     fn ensure_window_capacity(
         ctx: &mut LlamaContext,
         can_shift: bool,
+        overflow_policy: LlamaContextOverflow,
         context_length: usize,
         window_drop_chunk: usize,
         session: &mut SessionState,
@@ -685,12 +893,13 @@ impl InferenceWorkerState {
         }
 
         let n_past = session.n_past.max(0) as usize;
-        if n_past + needed_tokens <= context_length {
+        let overflow =
+            Self::context_overflow(overflow_policy, context_length, n_past, needed_tokens)
+                .map_err(|error| error.to_string())?;
+        if overflow == 0 {
             return Ok(());
         }
 
-        let overflow = n_past + needed_tokens - context_length;
-
         if !can_shift {
             warn!(
                 seq_id = session.seq_id,
@@ -702,25 +911,31 @@ impl InferenceWorkerState {
             return Ok(());
         }
 
-        let mut drop = overflow.max(window_drop_chunk).min(n_past);
-        if n_past.saturating_sub(drop) + needed_tokens > context_length {
-            drop = n_past + needed_tokens - context_length;
-        }
+        let (keep, drop) = Self::plan_window_shift(
+            context_length,
+            window_drop_chunk,
+            n_past,
+            session.n_keep,
+            needed_tokens,
+        );
         if drop == 0 {
             return Ok(());
         }
 
-        let drop_i32 = i32::try_from(drop).map_err(|_| {
-            format!("window shift overflow: drop count {drop} does not fit into i32")
-        })?;
+        let (Ok(keep_i32), Ok(drop_i32)) = (i32::try_from(keep), i32::try_from(drop)) else {
+            return Err(format!(
+                "window shift overflow: keep {keep} / drop {drop} does not fit into i32"
+            ));
+        };
+        let shifted_from = keep_i32 + drop_i32;
 
-        if !ctx.kv_cache_seq_rm(session.seq_id, 0, drop_i32) {
+        if !ctx.kv_cache_seq_rm(session.seq_id, keep_i32, shifted_from) {
             return Err(format!(
-                "failed to evict KV range [0, {drop_i32}) for seq_id={}",
+                "failed to evict KV range [{keep_i32}, {shifted_from}) for seq_id={}",
                 session.seq_id
             ));
         }
-        ctx.kv_cache_seq_add(session.seq_id, drop_i32, -1, -drop_i32);
+        ctx.kv_cache_seq_add(session.seq_id, shifted_from, -1, -drop_i32);
         session.n_past = session.n_past.saturating_sub(drop_i32);
 
         Ok(())
@@ -750,9 +965,12 @@ impl InferenceWorkerState {
                     pending_output: Utf8PieceBuffer::default(),
                     sampler: Some(sampler),
                     stream_tx: None,
+                    cancel_rx: None,
                     remaining_tokens: 0,
                     last_token: None,
                     cancelled: false,
+                    n_keep: options.n_keep,
+                    timings: GenerationTimings::default(),
                 };
 
                 if let Some(snapshot) = snapshot {
@@ -789,20 +1007,25 @@ impl InferenceWorkerState {
                 }
             }
 
-            WorkerCommand::GenerateStream { session_id, max_new_tokens, stream_tx, reply_tx } => {
-                match self.sessions.get_mut(&session_id) {
-                    None => {
-                        let _ =
-                            reply_tx.send(Err(LlamaRuntimeError::SessionNotFound { session_id }));
-                    }
-                    Some(session) => {
-                        session.stream_tx = Some(stream_tx);
-                        session.remaining_tokens = max_new_tokens;
-                        session.cancelled = false;
-                        let _ = reply_tx.send(Ok(()));
-                    }
+            WorkerCommand::GenerateStream {
+                session_id,
+                max_new_tokens,
+                stream_tx,
+                cancel_rx,
+                reply_tx,
+            } => match self.sessions.get_mut(&session_id) {
+                None => {
+                    let _ = reply_tx.send(Err(LlamaRuntimeError::SessionNotFound { session_id }));
                 }
-            }
+                Some(session) => {
+                    session.stream_tx = Some(stream_tx);
+                    session.cancel_rx = cancel_rx;
+                    session.remaining_tokens = max_new_tokens;
+                    session.cancelled = false;
+                    session.timings = GenerationTimings::default();
+                    let _ = reply_tx.send(Ok(()));
+                }
+            },
 
             WorkerCommand::EndSession { session_id, reply_tx } => {
                 match self.sessions.remove(&session_id) {
@@ -869,12 +1092,23 @@ impl InferenceWorkerState {
         }
     }
 
+    /// Stop a cancelled generation: discard unprocessed prompt tokens and close
+    /// the stream with `Done`. Tokens already decoded stay in the KV cache.
+    fn cancel_session_stream(session: &mut SessionState) {
+        session.cancelled = true;
+        session.cancel_rx = None;
+        session.pending_tokens.clear();
+        if Self::finish_session_stream(session, None, None).is_err() {
+            session.stream_tx = None;
+        }
+    }
+
     fn has_work(&self) -> bool {
         self.sessions.values().any(|session| {
-            !session.cancelled
-                && session.stream_tx.is_some()
-                && session.remaining_tokens > 0
-                && (!session.pending_tokens.is_empty() || session.last_token.is_some())
+            session.stream_tx.is_some()
+                && (session.cancel_requested()
+                    || (session.remaining_tokens > 0
+                        && (!session.pending_tokens.is_empty() || session.last_token.is_some())))
         })
     }
 
@@ -883,6 +1117,7 @@ impl InferenceWorkerState {
         let mut batch = LlamaBatch::new(batch_capacity);
         let context_length = self.context_length;
         let kv_cache_can_shift = self.kv_cache_can_shift;
+        let overflow = self.overflow;
         let window_drop_chunk = self.window_drop_chunk;
         let mut logit_owners: Vec<(SessionId, i32)> = Vec::new();
         let mut prefill_counts: HashMap<SessionId, usize> = HashMap::new();
@@ -893,9 +1128,9 @@ impl InferenceWorkerState {
         for &session_id in &session_ids {
             let session = self.sessions.get_mut(&session_id).expect("session id from map keys");
 
-            if session.cancelled {
-                if Self::finish_session_stream(session, None, None).is_err() {
-                    session.stream_tx = None;
+            if session.cancel_requested() {
+                if session.stream_tx.is_some() {
+                    Self::cancel_session_stream(session);
                 }
                 continue;
             }
@@ -922,6 +1157,7 @@ impl InferenceWorkerState {
                 if let Err(error) = Self::ensure_window_capacity(
                     &mut self.ctx,
                     kv_cache_can_shift,
+                    overflow,
                     context_length,
                     window_drop_chunk,
                     session,
@@ -951,6 +1187,7 @@ impl InferenceWorkerState {
                 if let Err(error) = Self::ensure_window_capacity(
                     &mut self.ctx,
                     kv_cache_can_shift,
+                    overflow,
                     context_length,
                     window_drop_chunk,
                     session,
@@ -973,32 +1210,37 @@ impl InferenceWorkerState {
             return;
         }
 
-        if let Err(error) = self.ctx.decode(&mut batch) {
-            let message = self.describe_stream_error(&error, batch.n_tokens() as usize);
-            for session_id in session_ids {
-                if let Some(session) = self.sessions.get_mut(&session_id)
-                    && session.stream_tx.is_some()
-                {
-                    if let Some(tx) = session.stream_tx.take() {
-                        let _ = tx.blocking_send(StreamChunk::Error(message.clone()));
+        let batch_ms = match decode_timed(&mut self.ctx, &mut batch) {
+            Ok(batch_ms) => batch_ms,
+            Err(error) => {
+                let message = self.describe_stream_error(&error, batch.n_tokens() as usize);
+                for session_id in session_ids {
+                    if let Some(session) = self.sessions.get_mut(&session_id)
+                        && session.stream_tx.is_some()
+                    {
+                        if let Some(tx) = session.stream_tx.take() {
+                            let _ = tx.blocking_send(StreamChunk::Error(message.clone()));
+                        }
+                        session.pending_output.clear();
+                        session.remaining_tokens = 0;
+                        session.last_token = None;
                     }
-                    session.pending_output.clear();
-                    session.remaining_tokens = 0;
-                    session.last_token = None;
                 }
+                return;
             }
-            return;
-        }
+        };
 
         for (session_id, count) in prefill_counts {
             if let Some(session) = self.sessions.get_mut(&session_id) {
                 session.pending_tokens.drain(..count);
                 session.n_past += i32::try_from(count).unwrap_or(i32::MAX);
+                session.timings.record_prefill(count, batch_ms);
             }
         }
         for session_id in gen_sessions {
             if let Some(session) = self.sessions.get_mut(&session_id) {
                 session.n_past = session.n_past.saturating_add(1);
+                session.timings.record_decode(batch_ms);
             }
         }
 
@@ -1061,6 +1303,7 @@ impl InferenceWorkerState {
                     }
 
                     session.last_token = Some(token);
+                    session.timings.record_sample();
                     session.remaining_tokens = session.remaining_tokens.saturating_sub(1);
                     if session.remaining_tokens == 0 {
                         let flush = match session.pending_output.finish() {
@@ -1118,6 +1361,7 @@ impl InferenceWorkerState {
 pub struct LlamaRuntime {
     global_tx: mpsc::Sender<GlobalCommand>,
     context_length: u32,
+    num_workers: usize,
 }
 
 impl LlamaRuntime {
@@ -1126,6 +1370,17 @@ impl LlamaRuntime {
         model: Arc<LlamaModel>,
         ctx_params: LlamaContextParams,
     ) -> Result<Self, LlamaRuntimeError> {
+        Self::start_with_options(num_workers, model, ctx_params, LlamaRuntimeOptions::default())
+    }
+
+    /// Like [`LlamaRuntime::start`], with non-default worker behaviour.
+    pub fn start_with_options(
+        num_workers: usize,
+        model: Arc<LlamaModel>,
+        ctx_params: LlamaContextParams,
+        options: LlamaRuntimeOptions,
+    ) -> Result<Self, LlamaRuntimeError> {
+        let LlamaRuntimeOptions { overflow, assignment } = options;
         if num_workers == 0 {
             return Err(LlamaRuntimeError::InvalidWorkerCount { num_workers });
         }
@@ -1143,7 +1398,7 @@ impl LlamaRuntime {
             context_length.get_or_insert_with(|| ctx.n_ctx_seq());
 
             let worker_state =
-                InferenceWorkerState::new(worker_id, Arc::clone(&model), ctx, cmd_rx);
+                InferenceWorkerState::new(worker_id, Arc::clone(&model), ctx, overflow, cmd_rx);
 
             std::thread::Builder::new()
                 .name(format!("llama-worker-{worker_id}"))
@@ -1156,19 +1411,29 @@ impl LlamaRuntime {
             global_rx,
             worker_txs,
             session_map: HashMap::new(),
+            assignment,
             next_worker: 0,
             next_session_id: 0,
         };
 
         tokio::spawn(master.run());
 
-        Ok(Self { global_tx, context_length: context_length.unwrap_or(ctx_params.n_ctx) })
+        Ok(Self {
+            global_tx,
+            context_length: context_length.unwrap_or(ctx_params.n_ctx),
+            num_workers,
+        })
     }
 
     pub fn context_length(&self) -> u32 {
         self.context_length
     }
 
+    /// Number of inference worker threads.
+    pub fn num_workers(&self) -> usize {
+        self.num_workers
+    }
+
     #[cfg_attr(not(test), allow(dead_code))]
     pub async fn create_session(&self) -> Result<SessionId, LlamaRuntimeError> {
         self.create_session_with_options(LlamaSamplingOptions::default()).await
@@ -1185,6 +1450,11 @@ impl LlamaRuntime {
         .await
     }
 
+    /// Create a session whose sampler chain is built from `options`.
+    ///
+    /// Each session owns its sampler, so concurrent sessions can use different
+    /// temperature, top-p and penalty settings. Unset fields keep the
+    /// [`SamplerChainBuilder`](crate::SamplerChainBuilder) defaults.
     pub async fn create_session_with_options(
         &self,
         options: LlamaSamplingOptions,
@@ -1227,11 +1497,52 @@ impl LlamaRuntime {
         &self,
         session_id: SessionId,
         max_new_tokens: usize,
+    ) -> Result<StreamHandle, LlamaRuntimeError> {
+        self.start_generate_stream(session_id, max_new_tokens, None).await
+    }
+
+    /// Generate up to `max_new_tokens` and return the full text once the stream completes.
+    ///
+    /// This is the unary form of [`LlamaRuntime::generate_stream`]; the session
+    /// keeps its KV cache, so follow-up turns only decode their new input.
+    pub async fn generate(
+        &self,
+        session_id: SessionId,
+        max_new_tokens: usize,
+    ) -> Result<String, LlamaRuntimeError> {
+        let stream = self.generate_stream(session_id, max_new_tokens).await?;
+        collect_stream_text(stream).await
+    }
+
+    /// Start generation that the worker stops on its own once `cancel_rx` flips to `true`.
+    ///
+    /// The session is dropped from the decode batch at the next step and the
+    /// stream is closed with [`StreamChunk::Done`]; pending prompt tokens are discarded.
+    pub async fn generate_stream_with_cancel(
+        &self,
+        session_id: SessionId,
+        max_new_tokens: usize,
+        cancel_rx: watch::Receiver<bool>,
+    ) -> Result<StreamHandle, LlamaRuntimeError> {
+        self.start_generate_stream(session_id, max_new_tokens, Some(cancel_rx)).await
+    }
+
+    async fn start_generate_stream(
+        &self,
+        session_id: SessionId,
+        max_new_tokens: usize,
+        cancel_rx: Option<watch::Receiver<bool>>,
     ) -> Result<StreamHandle, LlamaRuntimeError> {
         let (stream_tx, stream_rx) = mpsc::channel::<StreamChunk>(64);
         let (reply_tx, reply_rx) = oneshot::channel();
         self.global_tx
-            .send(GlobalCommand::GenerateStream { session_id, max_new_tokens, stream_tx, reply_tx })
+            .send(GlobalCommand::GenerateStream {
+                session_id,
+                max_new_tokens,
+                stream_tx,
+                cancel_rx,
+                reply_tx,
+            })
             .await
             .map_err(|_| LlamaRuntimeError::WorkerShutdown)?;
         reply_rx.await.map_err(|_| LlamaRuntimeError::WorkerShutdown)??;
@@ -1269,9 +1580,147 @@ impl LlamaRuntime {
     }
 }
 
+async fn collect_stream_text(mut stream: StreamHandle) -> Result<String, LlamaRuntimeError> {
+    let mut text = String::new();
+    while let Some(chunk) = stream.recv().await {
+        match chunk {
+            StreamChunk::Token(piece) => text.push_str(&piece),
+            StreamChunk::Stop(_) => {}
+            StreamChunk::Done => return Ok(text),
+            StreamChunk::Error(message) => {
+                return Err(LlamaRuntimeError::GenerationFailed { message });
+            }
+        }
+    }
+    Err(LlamaRuntimeError::WorkerShutdown)
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{Utf8FlushResult, Utf8PieceBuffer};
+    use tokio::sync::{mpsc, watch};
+
+    use std::collections::HashMap;
+    use std::path::PathBuf;
+    use std::sync::Arc;
+
+    use super::{
+        GenerationTimings, InferenceWorkerState, LlamaContextOverflow, LlamaRuntime,
+        LlamaRuntimeError, LlamaSamplingOptions, LlamaSessionAssignment, LlamaStopInfo, SessionId,
+        SessionState, StreamChunk, Utf8FlushResult, Utf8PieceBuffer, assign_worker,
+        collect_stream_text, decode_timed,
+    };
+    use crate::{Llama, LlamaBatch, LlamaContextParams, LlamaModelParams};
+
+    fn streaming_session(
+        pending_tokens: Vec<i32>,
+    ) -> (SessionState, mpsc::Receiver<StreamChunk>, watch::Sender<bool>) {
+        let (stream_tx, stream_rx) = mpsc::channel(8);
+        let (cancel_tx, cancel_rx) = watch::channel(false);
+        let session = SessionState {
+            seq_id: 0,
+            n_past: 0,
+            pending_tokens,
+            pending_output: Utf8PieceBuffer::default(),
+            sampler: None,
+            stream_tx: Some(stream_tx),
+            cancel_rx: Some(cancel_rx),
+            remaining_tokens: 16,
+            last_token: None,
+            cancelled: false,
+            n_keep: 0,
+            timings: GenerationTimings::default(),
+        };
+        (session, stream_rx, cancel_tx)
+    }
+
+    #[test]
+    fn cancel_signal_discards_pending_prefill_and_closes_stream_with_done() {
+        let (mut session, mut stream_rx, cancel_tx) = streaming_session(vec![1, 2, 3, 4]);
+        assert!(!session.cancel_requested());
+
+        cancel_tx.send(true).expect("cancel receiver alive");
+        assert!(session.cancel_requested());
+        InferenceWorkerState::cancel_session_stream(&mut session);
+
+        assert!(session.pending_tokens.is_empty());
+        assert!(session.stream_tx.is_none());
+        assert_eq!(session.remaining_tokens, 0);
+        assert!(matches!(stream_rx.try_recv(), Ok(StreamChunk::Done)));
+        assert!(stream_rx.try_recv().is_err(), "no chunks may follow Done");
+    }
+
+    #[test]
+    fn cancel_after_streamed_tokens_stops_further_tokens() {
+        let (mut session, mut stream_rx, cancel_tx) = streaming_session(Vec::new());
+        let tx = session.stream_tx.clone().expect("stream sender");
+        for piece in ["a", "b", "c"] {
+            tx.try_send(StreamChunk::Token(piece.to_owned())).expect("queue token");
+        }
+        drop(tx);
+        session.last_token = Some(7);
+
+        cancel_tx.send(true).expect("cancel receiver alive");
+        InferenceWorkerState::cancel_session_stream(&mut session);
+
+        let mut tokens = Vec::new();
+        while let Ok(chunk) = stream_rx.try_recv() {
+            match chunk {
+                StreamChunk::Token(text) => tokens.push(text),
+                StreamChunk::Done => break,
+                other => panic!("unexpected chunk: {other:?}"),
+            }
+        }
+        assert_eq!(tokens, vec!["a", "b", "c"]);
+        assert!(stream_rx.try_recv().is_err(), "no tokens may arrive after cancellation");
+        assert!(session.last_token.is_none());
+    }
+
+    #[test]
+    fn context_overflow_stops_with_context_full_instead_of_shifting() {
+        assert_eq!(
+            InferenceWorkerState::context_overflow(LlamaContextOverflow::Stop, 64, 60, 4)
+                .expect("exact fit needs no eviction"),
+            0
+        );
+        assert_eq!(
+            InferenceWorkerState::context_overflow(LlamaContextOverflow::Shift, 64, 62, 4)
+                .expect("shift policy evicts the overflow"),
+            2
+        );
+
+        let error = InferenceWorkerState::context_overflow(LlamaContextOverflow::Stop, 64, 62, 4)
+            .expect_err("stop policy must reject overflow");
+        assert!(matches!(
+            error,
+            LlamaRuntimeError::ContextFull { context_length: 64, n_past: 62, needed_tokens: 4 }
+        ));
+        assert!(error.to_string().starts_with("Context full"));
+    }
+
+    #[test]
+    fn window_shift_preserves_kept_prefix_while_it_fits() {
+        // 60 cached + 8 new in a 64-token window: evict a 16-token chunk after
+        // the 10-token system prompt.
+        assert_eq!(InferenceWorkerState::plan_window_shift(64, 16, 60, 10, 8), (10, 16));
+        // Without a kept prefix the oldest positions go first.
+        assert_eq!(InferenceWorkerState::plan_window_shift(64, 16, 60, 0, 8), (0, 16));
+        // The drop never reaches into the kept prefix.
+        assert_eq!(InferenceWorkerState::plan_window_shift(64, 16, 60, 50, 8), (50, 10));
+        // A prefix too long to leave room for the new tokens is abandoned.
+        assert_eq!(InferenceWorkerState::plan_window_shift(64, 16, 60, 58, 8), (0, 16));
+
+        // Repeated shifts keep room for generation indefinitely.
+        let mut n_past = 0;
+        let mut shifts = 0;
+        for _ in 0..1000 {
+            let (keep, drop) = InferenceWorkerState::plan_window_shift(64, 16, n_past, 10, 1);
+            assert_eq!(keep, 10.min(n_past));
+            shifts += usize::from(drop > 0);
+            n_past = n_past - drop + 1;
+            assert!(n_past <= 64);
+        }
+        assert!(shifts > 0);
+    }
 
     #[test]
     fn utf8_piece_buffer_waits_for_multibyte_sequence_completion() {
@@ -1284,6 +1733,38 @@ mod tests {
         );
     }
 
+    #[test]
+    fn generation_timings_reset_when_stream_finishes() {
+        let (mut session, mut stream_rx, _cancel_tx) = streaming_session(vec![1, 2, 3, 4]);
+
+        session.timings.record_prefill(4, 1.0);
+        session.timings.record_decode(1.0);
+        assert_eq!((session.timings.prompt_tokens, session.timings.eval_tokens), (4, 1));
+
+        InferenceWorkerState::finish_session_stream(&mut session, None, None)
+            .expect("stream receiver alive");
+        assert!(matches!(stream_rx.try_recv(), Ok(StreamChunk::Done)));
+        assert_eq!(session.timings, GenerationTimings::default());
+    }
+
+    #[test]
+    fn stop_info_reports_sampled_token_count() {
+        let (mut session, mut stream_rx, _cancel_tx) = streaming_session(Vec::new());
+        for _ in 0..3 {
+            session.timings.record_sample();
+        }
+
+        let stop = LlamaStopInfo { finish_reason: "length".to_owned(), ..LlamaStopInfo::default() };
+        InferenceWorkerState::finish_session_stream(&mut session, None, Some(stop))
+            .expect("stream receiver alive");
+
+        match stream_rx.try_recv() {
+            Ok(StreamChunk::Stop(stop)) => assert_eq!(stop.completion_tokens, 3),
+            other => panic!("expected stop chunk, got {other:?}"),
+        }
+        assert!(matches!(stream_rx.try_recv(), Ok(StreamChunk::Done)));
+    }
+
     #[test]
     fn utf8_piece_buffer_emits_valid_prefix_and_keeps_partial_tail() {
         let mut buffer = Utf8PieceBuffer::default();
@@ -1312,4 +1793,143 @@ mod tests {
             Utf8FlushResult { text: None, dropped_incomplete_tail: true }
         );
     }
+
+    /// Alternate long-lived and immediately ended sessions and return how many
+    /// long-lived sessions each worker ends up holding.
+    fn long_lived_loads(assignment: LlamaSessionAssignment, num_workers: usize) -> Vec<usize> {
+        let mut session_map = HashMap::new();
+        let mut next_worker = 0;
+        for session_id in 0..24 {
+            let worker_id = assign_worker(assignment, &mut next_worker, num_workers, &session_map);
+            if session_id % num_workers == 0 {
+                session_map.insert(session_id as SessionId, worker_id);
+            }
+        }
+        let mut loads = vec![0; num_workers];
+        for worker_id in session_map.into_values() {
+            loads[worker_id] += 1;
+        }
+        loads
+    }
+
+    #[test]
+    fn least_loaded_assignment_balances_uneven_session_lifetimes() {
+        let spread = |loads: Vec<usize>| {
+            loads.iter().max().copied().unwrap_or(0) - loads.iter().min().copied().unwrap_or(0)
+        };
+
+        assert_eq!(long_lived_loads(LlamaSessionAssignment::RoundRobin, 2), vec![12, 0]);
+        let least_loaded = long_lived_loads(LlamaSessionAssignment::LeastLoaded, 2);
+        assert_eq!(least_loaded.iter().sum::<usize>(), 12);
+        assert!(spread(least_loaded) <= 1);
+    }
+
+    #[tokio::test]
+    async fn unary_generate_concatenates_streamed_pieces() {
+        let (tx, rx) = mpsc::channel(8);
+        let pieces = ["Hel", "lo", ",", " world"];
+        for piece in pieces {
+            tx.send(StreamChunk::Token(piece.to_owned())).await.expect("send piece");
+        }
+        tx.send(StreamChunk::Stop(LlamaStopInfo::default())).await.expect("send stop");
+        tx.send(StreamChunk::Done).await.expect("send done");
+
+        let text = collect_stream_text(rx).await.expect("stream should complete");
+
+        assert_eq!(text, pieces.concat());
+    }
+
+    #[tokio::test]
+    async fn unary_generate_surfaces_stream_errors_and_early_close() {
+        let (tx, rx) = mpsc::channel(8);
+        tx.send(StreamChunk::Token("partial".to_owned())).await.expect("send piece");
+        tx.send(StreamChunk::Error("decode failed".to_owned())).await.expect("send error");
+        assert!(matches!(
+            collect_stream_text(rx).await,
+            Err(LlamaRuntimeError::GenerationFailed { message }) if message == "decode failed"
+        ));
+
+        let (tx, rx) = mpsc::channel(8);
+        tx.send(StreamChunk::Token("partial".to_owned())).await.expect("send piece");
+        drop(tx);
+        assert!(matches!(collect_stream_text(rx).await, Err(LlamaRuntimeError::WorkerShutdown)));
+    }
+
+    async fn generate_text(runtime: &LlamaRuntime, session_id: SessionId, prompt: &str) -> String {
+        runtime.append_input(session_id, prompt.to_owned()).await.expect("append prompt");
+        let mut stream = runtime.generate_stream(session_id, 48).await.expect("start generation");
+        let mut text = String::new();
+        while let Some(chunk) = stream.recv().await {
+            match chunk {
+                StreamChunk::Token(piece) => text.push_str(&piece),
+                StreamChunk::Error(error) => panic!("generation failed: {error}"),
+                StreamChunk::Stop(_) | StreamChunk::Done => break,
+            }
+        }
+        text
+    }
+
+    #[tokio::test]
+    #[ignore = "requires local llama runtime libraries and a GGUF model"]
+    async fn sessions_keep_their_own_sampler_configuration() {
+        let testdata = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../../bin/testdata/llama");
+        let llama = Llama::new(&testdata).expect("llama runtime libraries");
+        let model_path = testdata.join("model.gguf");
+        let model = llama
+            .load_model_from_file(
+                model_path.to_str().expect("utf-8 path"),
+                LlamaModelParams::default(),
+            )
+            .expect("load model");
+        let runtime = LlamaRuntime::start(1, Arc::new(model), LlamaContextParams::default())
+            .expect("start runtime");
+
+        let sampler = |temperature| LlamaSamplingOptions {
+            temperature: Some(temperature),
+            top_k: Some(0),
+            top_p: Some(1.0),
+            min_p: Some(0.0),
+            seed: Some(7),
+            ..LlamaSamplingOptions::default()
+        };
+        let cold = runtime.create_session_with_options(sampler(0.01)).await.expect("cold session");
+        let hot = runtime.create_session_with_options(sampler(2.5)).await.expect("hot session");
+
+        let prompt = "Write a short sentence about the sea.";
+        let cold_text = generate_text(&runtime, cold, prompt).await;
+        let hot_text = generate_text(&runtime, hot, prompt).await;
+
+        assert!(!cold_text.is_empty());
+        assert_ne!(cold_text, hot_text);
+    }
+
+    #[test]
+    #[ignore = "requires local llama runtime libraries and a GGUF model"]
+    fn decode_timed_covers_the_synchronized_eval_time() {
+        let testdata = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../../bin/testdata/llama");
+        let llama = Llama::new(&testdata).expect("llama runtime libraries");
+        let model_path = testdata.join("model.gguf");
+        let model = llama
+            .load_model_from_file(
+                model_path.to_str().expect("utf-8 path"),
+                LlamaModelParams::default(),
+            )
+            .expect("load model");
+        let mut ctx = model.new_context(LlamaContextParams::default()).expect("create context");
+
+        let tokens = model.tokenize("The quick brown fox", true, false).expect("tokenize");
+        let mut batch = LlamaBatch::new(tokens.len());
+        for (pos, &token) in tokens.iter().enumerate() {
+            batch.add(token, pos as i32, &[0], pos + 1 == tokens.len()).expect("batch capacity");
+        }
+
+        let perf_before = ctx.perf_data();
+        let elapsed_ms = decode_timed(&mut ctx, &mut batch).expect("decode prompt");
+        let eval_ms = ctx.perf_data().eval_ms_since(&perf_before);
+
+        // llama has already accounted for the batch once decode_timed returns,
+        // and the measured wall time covers it.
+        assert!(eval_ms > 0.0, "synchronize should flush the batch into perf data");
+        assert!(elapsed_ms >= eval_ms);
+    }
 }
@@ -1,8 +1,155 @@
+use crate::error::LlamaError;
+
 /// A token identifier in the llama vocabulary.
 pub type LlamaToken = slab_llama_sys::llama_token;
 
+/// Sentinel llama.cpp returns for special tokens the vocabulary does not define.
+pub const LLAMA_TOKEN_NULL: LlamaToken = -1;
+
+/// Map llama.cpp's `LLAMA_TOKEN_NULL` sentinel to `None`.
+pub(crate) fn defined_token(token: LlamaToken) -> Option<LlamaToken> {
+    (token != LLAMA_TOKEN_NULL).then_some(token)
+}
+
+/// Check that every id in `tokens` lies within `0..n_vocab`.
+///
+/// llama.cpp indexes its vocabulary with `std::vector::at`, so an unknown id
+/// throws across the C boundary and aborts the process instead of failing.
+pub(crate) fn check_tokens(tokens: &[LlamaToken], n_vocab: i32) -> Result<(), LlamaError> {
+    match tokens.iter().find(|&&token| !(0..n_vocab).contains(&token)) {
+        Some(&token) => Err(LlamaError::TokenOutOfRange { token, n_vocab }),
+        None => Ok(()),
+    }
+}
+
+/// Tokenizer family of a llama vocabulary.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LlamaVocabType {
+    /// The model has no vocabulary.
+    None,
+    /// LLaMA-style SentencePiece with byte fallback.
+    Spm,
+    /// GPT-2-style byte-level BPE.
+    Bpe,
+    /// BERT-style WordPiece.
+    Wpm,
+    /// T5-style Unigram.
+    Ugm,
+    /// RWKV greedy tokenizer.
+    Rwkv,
+    /// A type this binding does not know about yet.
+    Unknown,
+}
+
+impl LlamaVocabType {
+    pub(crate) fn from_c(raw: slab_llama_sys::llama_vocab_type) -> Self {
+        match raw {
+            slab_llama_sys::llama_vocab_type_LLAMA_VOCAB_TYPE_NONE => Self::None,
+            slab_llama_sys::llama_vocab_type_LLAMA_VOCAB_TYPE_SPM => Self::Spm,
+            slab_llama_sys::llama_vocab_type_LLAMA_VOCAB_TYPE_BPE => Self::Bpe,
+            slab_llama_sys::llama_vocab_type_LLAMA_VOCAB_TYPE_WPM => Self::Wpm,
+            slab_llama_sys::llama_vocab_type_LLAMA_VOCAB_TYPE_UGM => Self::Ugm,
+            slab_llama_sys::llama_vocab_type_LLAMA_VOCAB_TYPE_RWKV => Self::Rwkv,
+            _ => Self::Unknown,
+        }
+    }
+}
+
+/// Attribute flags llama.cpp attaches to each vocabulary token.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LlamaTokenAttr(slab_llama_sys::llama_token_attr);
+
+impl LlamaTokenAttr {
+    pub(crate) fn from_c(raw: slab_llama_sys::llama_token_attr) -> Self {
+        Self(raw)
+    }
+
+    /// Raw `llama_token_attr` bit set.
+    pub fn bits(self) -> slab_llama_sys::llama_token_attr {
+        self.0
+    }
+
+    fn has(self, flag: slab_llama_sys::llama_token_attr) -> bool {
+        self.0 & flag != 0
+    }
+
+    /// Ordinary text token.
+    pub fn is_normal(self) -> bool {
+        self.has(slab_llama_sys::llama_token_attr_LLAMA_TOKEN_ATTR_NORMAL)
+    }
+
+    /// Control token such as BOS/EOS or chat-template markers; usually not rendered.
+    pub fn is_control(self) -> bool {
+        self.has(slab_llama_sys::llama_token_attr_LLAMA_TOKEN_ATTR_CONTROL)
+    }
+
+    /// Special token added by the model author rather than learned by the tokenizer.
+    pub fn is_user_defined(self) -> bool {
+        self.has(slab_llama_sys::llama_token_attr_LLAMA_TOKEN_ATTR_USER_DEFINED)
+    }
+
+    /// The unknown-token placeholder.
+    pub fn is_unknown(self) -> bool {
+        self.has(slab_llama_sys::llama_token_attr_LLAMA_TOKEN_ATTR_UNKNOWN)
+    }
+
+    /// Raw byte token; its piece may be an incomplete UTF-8 sequence.
+    pub fn is_byte(self) -> bool {
+        self.has(slab_llama_sys::llama_token_attr_LLAMA_TOKEN_ATTR_BYTE)
+    }
+}
+
 /// A position in a sequence.
 pub type LlamaPos = slab_llama_sys::llama_pos;
 
 /// A sequence identifier.
 pub type LlamaSeqId = slab_llama_sys::llama_seq_id;
+
+#[cfg(test)]
+mod tests {
+    use super::{LLAMA_TOKEN_NULL, LlamaTokenAttr, LlamaVocabType, check_tokens, defined_token};
+    use crate::error::LlamaError;
+
+    #[test]
+    fn check_tokens_rejects_ids_outside_the_vocabulary() {
+        assert!(check_tokens(&[], 0).is_ok());
+        assert!(check_tokens(&[0, 1, 31_999], 32_000).is_ok());
+        assert!(matches!(
+            check_tokens(&[5, 32_000, -1], 32_000),
+            Err(LlamaError::TokenOutOfRange { token: 32_000, n_vocab: 32_000 })
+        ));
+        assert!(matches!(
+            check_tokens(&[-1], 32_000),
+            Err(LlamaError::TokenOutOfRange { token: -1, .. })
+        ));
+    }
+
+    #[test]
+    fn defined_token_maps_null_sentinel_to_none() {
+        assert_eq!(defined_token(LLAMA_TOKEN_NULL), None);
+        assert_eq!(defined_token(0), Some(0));
+        assert_eq!(defined_token(128_000), Some(128_000));
+    }
+
+    #[test]
+    fn vocab_type_and_token_attr_decode_llama_constants() {
+        assert_eq!(
+            LlamaVocabType::from_c(slab_llama_sys::llama_vocab_type_LLAMA_VOCAB_TYPE_BPE),
+            LlamaVocabType::Bpe
+        );
+        assert_eq!(LlamaVocabType::from_c(1000), LlamaVocabType::Unknown);
+
+        let control = LlamaTokenAttr::from_c(
+            slab_llama_sys::llama_token_attr_LLAMA_TOKEN_ATTR_CONTROL
+                | slab_llama_sys::llama_token_attr_LLAMA_TOKEN_ATTR_RSTRIP,
+        );
+        assert!(control.is_control());
+        assert!(!control.is_normal());
+        assert!(!control.is_byte());
+
+        let normal =
+            LlamaTokenAttr::from_c(slab_llama_sys::llama_token_attr_LLAMA_TOKEN_ATTR_NORMAL);
+        assert!(normal.is_normal());
+        assert!(!normal.is_control());
+    }
+}
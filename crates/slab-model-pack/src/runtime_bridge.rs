@@ -181,6 +181,8 @@ impl ModelPackEngineLoadSpec {
                 flash_attn: true,
                 chat_template: self.load_defaults.chat_template_source.clone(),
                 gbnf: self.load_defaults.gbnf_source.clone(),
+                least_loaded_sessions: false,
+                stop_on_context_full: false,
             }),
             RuntimeBackendId::GgmlWhisper => {
                 RuntimeBackendLoadSpec::GgmlWhisper(GgmlWhisperLoadConfig {
@@ -1,5 +1,7 @@
 pub use crate::base::types::{Payload, StreamChunk, StreamHandle};
-pub use crate::internal::scheduler::backend::admission::{ResourceManager, ResourceManagerConfig};
+pub use crate::internal::scheduler::backend::admission::{
+    BackendStats, InferenceLease, InferenceReservation, ResourceManager, ResourceManagerConfig,
+};
 pub use crate::internal::scheduler::backend::handler::{
     BackendHandlerError, BroadcastSeq, CancelRx, ControlOpId, Input, IntoBackendReply, Json,
     Options, Typed, backend_reply_from_event_result, extract_event_broadcast_seq,
@@ -13,9 +15,9 @@ pub use crate::internal::scheduler::backend::handler::{
 #[cfg(test)]
 pub use crate::internal::scheduler::backend::protocol::DriverRequestKind;
 pub use crate::internal::scheduler::backend::protocol::{
-    BackendOp, BackendReply, BackendRequest, BackendRequestKind, DeploymentSnapshot,
-    ManagementEvent, PeerControlBus, PeerWorkerCommand, PeerWorkerCommandKind, RequestRoute,
-    RuntimeControlSignal, SyncMessage, WorkerCommand,
+    BackendError, BackendErrorKind, BackendOp, BackendReply, BackendRequest, BackendRequestKind,
+    DeploymentSnapshot, ManagementEvent, PeerControlBus, PeerWorkerCommand, PeerWorkerCommandKind,
+    Priority, RequestRoute, RuntimeControlSignal, SyncMessage, WorkerCommand,
 };
 pub use crate::internal::scheduler::backend::runner::{
     HandlerFuture, LaggedDispatchFn, PeerDispatchFn, PeerRoute, RequestDispatchFn,
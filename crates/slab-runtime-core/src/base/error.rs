@@ -118,3 +118,45 @@ impl CoreError {
         }
     }
 }
+
+/// Implement the kind-specific constructors and `From<$error> for BackendError`
+/// for a backend worker error enum.
+///
+/// The enum must declare `InvalidArgument`, `ModelNotLoaded` and
+/// `LibraryNotLoaded` variants with a `message: String` field. Those map to
+/// the matching [`BackendErrorKind`](crate::backend::BackendErrorKind); every
+/// other variant maps to `Failed`.
+#[macro_export]
+macro_rules! impl_backend_error_kinds {
+    ($error:ident) => {
+        #[allow(dead_code)]
+        impl $error {
+            pub(crate) fn invalid_argument(message: impl Into<String>) -> Self {
+                Self::InvalidArgument { message: message.into() }
+            }
+
+            pub(crate) fn model_not_loaded(message: impl Into<String>) -> Self {
+                Self::ModelNotLoaded { message: message.into() }
+            }
+
+            pub(crate) fn library_not_loaded(message: impl Into<String>) -> Self {
+                Self::LibraryNotLoaded { message: message.into() }
+            }
+        }
+
+        impl From<$error> for $crate::backend::BackendError {
+            fn from(error: $error) -> Self {
+                use $crate::backend::BackendErrorKind;
+
+                let kind = match &error {
+                    $error::InvalidArgument { .. } => BackendErrorKind::InvalidArgument,
+                    $error::ModelNotLoaded { .. } => BackendErrorKind::ModelNotLoaded,
+                    $error::LibraryNotLoaded { .. } => BackendErrorKind::LibraryNotLoaded,
+                    #[allow(unreachable_patterns)]
+                    _ => BackendErrorKind::Failed,
+                };
+                Self::new(kind, error.to_string())
+            }
+        }
+    };
+}
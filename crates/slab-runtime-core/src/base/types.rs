@@ -20,6 +20,8 @@ pub enum Payload {
     Json(serde_json::Value),
     /// Type-erased in-process payload for typed internal handoff.
     Typed(TypedPayload),
+    /// Raw decoded pixels (e.g. a generated image) with their dimensions.
+    Image { width: u32, height: u32, channels: u32, data: Arc<[u8]> },
 }
 
 /// Type-erased payload that preserves the original Rust type for later downcast.
@@ -67,6 +69,13 @@ impl fmt::Debug for Payload {
             Self::Text(text) => f.debug_tuple("Text").field(text).finish(),
             Self::Json(value) => f.debug_tuple("Json").field(value).finish(),
             Self::Typed(payload) => f.debug_tuple("Typed").field(payload).finish(),
+            Self::Image { width, height, channels, data } => f
+                .debug_struct("Image")
+                .field("width", width)
+                .field("height", height)
+                .field("channels", channels)
+                .field("len", &data.len())
+                .finish(),
         }
     }
 }
@@ -306,6 +315,14 @@ mod tests {
         assert!(format!("{:?}", StreamChunk::Error("bad".to_owned())).contains("Error"));
         assert!(format!("{:?}", StreamChunk::Json(serde_json::json!({"a": 1}))).contains("Json"));
     }
+
+    #[test]
+    fn image_payload_debug_reports_dimensions_without_pixels() {
+        let image =
+            Payload::Image { width: 2, height: 1, channels: 3, data: Arc::from(vec![7_u8; 6]) };
+
+        assert_eq!(format!("{image:?}"), "Image { width: 2, height: 1, channels: 3, len: 6 }");
+    }
 }
 
 /// A single chunk emitted by a streaming backend.
@@ -322,9 +339,9 @@ pub enum StreamChunk {
     Error(String),
     /// Structured stream metadata emitted before terminal completion.
     Json(serde_json::Value),
-    /// A generated image (placeholder for now).
-    #[allow(dead_code)]
-    Image(bytes::Bytes),
+    /// A generated image; always a [`Payload::Image`] so its dimensions
+    /// travel with the pixels.
+    Image(Payload),
 }
 
 /// A handle to a streaming inference response.
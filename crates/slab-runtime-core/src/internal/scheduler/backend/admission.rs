@@ -1,15 +1,13 @@
-use std::collections::HashMap;
+use std::collections::{BinaryHeap, HashMap};
 use std::sync::atomic::{AtomicU64, Ordering};
-use std::sync::{Arc, RwLock};
+use std::sync::{Arc, Mutex, PoisonError, RwLock};
 use std::time::Duration;
 
 use flume::Sender;
-use tokio::sync::{
-    OwnedRwLockReadGuard, OwnedRwLockWriteGuard, OwnedSemaphorePermit, Semaphore, broadcast,
-};
+use tokio::sync::{OwnedRwLockReadGuard, OwnedRwLockWriteGuard, broadcast, oneshot};
 
 use crate::base::error::CoreError;
-use crate::internal::scheduler::backend::protocol::{BackendRequest, WorkerCommand};
+use crate::internal::scheduler::backend::protocol::{BackendRequest, Priority, WorkerCommand};
 use crate::internal::scheduler::backend::runner::{SharedIngressRx, shared_ingress};
 
 /// Inference lease: blocks management mutations and holds compute quota.
@@ -17,7 +15,7 @@ pub struct InferenceLease {
     #[allow(dead_code)]
     mgmt_guard: OwnedRwLockReadGuard<()>,
     #[allow(dead_code)]
-    compute_permit: OwnedSemaphorePermit,
+    compute_permit: AdmissionPermit,
 }
 
 impl std::fmt::Debug for InferenceLease {
@@ -26,6 +24,27 @@ impl std::fmt::Debug for InferenceLease {
     }
 }
 
+/// Compute quota taken without waiting, to be turned into an [`InferenceLease`]
+/// when the request actually runs. Dropping it returns the quota.
+pub struct InferenceReservation {
+    compute_permit: AdmissionPermit,
+    management_lock: Arc<tokio::sync::RwLock<()>>,
+}
+
+impl InferenceReservation {
+    /// Wait for the management read lock and hold the reserved quota as a lease.
+    pub async fn into_lease(self) -> InferenceLease {
+        let mgmt_guard = self.management_lock.read_owned().await;
+        InferenceLease { mgmt_guard, compute_permit: self.compute_permit }
+    }
+}
+
+impl std::fmt::Debug for InferenceReservation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("InferenceReservation").finish()
+    }
+}
+
 /// Management lease: exclusive configuration lock for initialize/load/unload.
 pub struct ManagementLease {
     #[allow(dead_code)]
@@ -38,9 +57,159 @@ impl std::fmt::Debug for ManagementLease {
     }
 }
 
+/// Compute quota handed out by [`AdmissionGate`]; returned to the gate on drop.
+struct AdmissionPermit {
+    gate: Option<Arc<AdmissionGate>>,
+}
+
+impl Drop for AdmissionPermit {
+    fn drop(&mut self) {
+        if let Some(gate) = self.gate.take() {
+            gate.release();
+        }
+    }
+}
+
+struct AdmissionWaiter {
+    priority: Priority,
+    seq: u64,
+    permit_tx: oneshot::Sender<AdmissionPermit>,
+}
+
+impl PartialEq for AdmissionWaiter {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority && self.seq == other.seq
+    }
+}
+
+impl Eq for AdmissionWaiter {}
+
+impl PartialOrd for AdmissionWaiter {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for AdmissionWaiter {
+    /// Higher priority first; earlier arrivals first within the same priority.
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.priority.cmp(&other.priority).then_with(|| other.seq.cmp(&self.seq))
+    }
+}
+
+#[derive(Default)]
+struct AdmissionState {
+    capacity: usize,
+    available: usize,
+    next_seq: u64,
+    waiters: BinaryHeap<AdmissionWaiter>,
+}
+
+/// Priority-aware counting semaphore guarding backend compute capacity.
+///
+/// Released permits are handed directly to the highest-priority waiter, so a
+/// late high-priority request cannot be starved by earlier low-priority ones.
+struct AdmissionGate {
+    state: Mutex<AdmissionState>,
+}
+
+impl std::fmt::Debug for AdmissionGate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AdmissionGate").finish()
+    }
+}
+
+impl AdmissionGate {
+    fn new(capacity: usize) -> Self {
+        Self {
+            state: Mutex::new(AdmissionState {
+                capacity,
+                available: capacity,
+                ..Default::default()
+            }),
+        }
+    }
+
+    fn stats(&self) -> BackendStats {
+        let state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        BackendStats {
+            in_flight: state.capacity.saturating_sub(state.available),
+            capacity: state.capacity,
+            // Waiters that already timed out stay in the heap until the next
+            // release skips them, so only count the ones still listening.
+            queued: state.waiters.iter().filter(|waiter| !waiter.permit_tx.is_closed()).count(),
+        }
+    }
+
+    async fn acquire(
+        self: &Arc<Self>,
+        priority: Priority,
+        timeout: Duration,
+    ) -> Result<AdmissionPermit, CoreError> {
+        let permit_rx = {
+            let mut state = self
+                .state
+                .lock()
+                .map_err(|_| CoreError::InternalPoisoned { lock_name: "admission".to_owned() })?;
+            if state.available > 0 {
+                state.available -= 1;
+                return Ok(AdmissionPermit { gate: Some(Arc::clone(self)) });
+            }
+
+            let (permit_tx, permit_rx) = oneshot::channel();
+            let seq = state.next_seq;
+            state.next_seq += 1;
+            state.waiters.push(AdmissionWaiter { priority, seq, permit_tx });
+            permit_rx
+        };
+
+        // Dropping the receiver on timeout also drops any permit that raced in,
+        // which returns it to the gate.
+        tokio::time::timeout(timeout, permit_rx)
+            .await
+            .map_err(|_| CoreError::Timeout)?
+            .map_err(|_| CoreError::InternalPoisoned { lock_name: "admission".to_owned() })
+    }
+
+    /// Take a permit only if one is free right now; never joins the waiters.
+    fn try_acquire(self: &Arc<Self>) -> Option<AdmissionPermit> {
+        let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        if state.available == 0 {
+            return None;
+        }
+        state.available -= 1;
+        Some(AdmissionPermit { gate: Some(Arc::clone(self)) })
+    }
+
+    fn release(self: Arc<Self>) {
+        let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        while let Some(waiter) = state.waiters.pop() {
+            match waiter.permit_tx.send(AdmissionPermit { gate: Some(Arc::clone(&self)) }) {
+                Ok(()) => return,
+                Err(mut permit) => {
+                    // Waiter already gave up; reclaim without re-entering release.
+                    permit.gate = None;
+                }
+            }
+        }
+        state.available += 1;
+    }
+}
+
+/// Point-in-time admission counters for one backend.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BackendStats {
+    /// Inference leases currently held.
+    pub in_flight: usize,
+    /// Maximum number of concurrent inference leases.
+    pub capacity: usize,
+    /// Requests waiting for a lease to free up.
+    pub queued: usize,
+}
+
 #[derive(Debug, Clone)]
 struct BackendHandle {
-    semaphore: Arc<Semaphore>,
+    admission: Arc<AdmissionGate>,
     ingress_tx: Option<Sender<BackendRequest>>,
     #[cfg_attr(not(test), allow(dead_code))]
     control_tx: Option<broadcast::Sender<WorkerCommand>>,
@@ -55,7 +224,7 @@ impl BackendHandle {
         control_tx: Option<broadcast::Sender<WorkerCommand>>,
     ) -> Self {
         Self {
-            semaphore: Arc::new(Semaphore::new(capacity)),
+            admission: Arc::new(AdmissionGate::new(capacity)),
             ingress_tx,
             control_tx,
             management_lock: Arc::new(tokio::sync::RwLock::new(())),
@@ -98,6 +267,20 @@ impl ResourceManager {
     pub fn register_backend<F>(&mut self, backend_id: impl Into<String>, spawn_backend: F)
     where
         F: FnOnce(SharedIngressRx, broadcast::Sender<WorkerCommand>),
+    {
+        let capacity = self.config.backend_capacity;
+        self.register_backend_with_capacity(backend_id, capacity, spawn_backend);
+    }
+
+    /// Like [`Self::register_backend`], but admits up to `capacity` concurrent
+    /// inference leases instead of [`ResourceManagerConfig::backend_capacity`].
+    pub fn register_backend_with_capacity<F>(
+        &mut self,
+        backend_id: impl Into<String>,
+        capacity: usize,
+        spawn_backend: F,
+    ) where
+        F: FnOnce(SharedIngressRx, broadcast::Sender<WorkerCommand>),
     {
         let (ingress_tx, ingress_rx) =
             flume::bounded::<BackendRequest>(self.config.ingress_channel_capacity);
@@ -109,14 +292,8 @@ impl ResourceManager {
         let key = backend_id.into();
         match self.backends.write() {
             Ok(mut backends) => {
-                backends.insert(
-                    key,
-                    BackendHandle::new(
-                        self.config.backend_capacity,
-                        Some(ingress_tx),
-                        Some(control_tx),
-                    ),
-                );
+                backends
+                    .insert(key, BackendHandle::new(capacity, Some(ingress_tx), Some(control_tx)));
             }
             Err(_) => {
                 tracing::error!("backend map poisoned during registration");
@@ -138,7 +315,6 @@ impl ResourceManager {
     }
 
     /// List registered backends in deterministic order.
-    #[cfg_attr(not(test), allow(dead_code))]
     pub fn backend_ids(&self) -> Vec<String> {
         let mut ids: Vec<String> = match self.backends.read() {
             Ok(backends) => backends.keys().cloned().collect(),
@@ -153,6 +329,11 @@ impl ResourceManager {
         ids
     }
 
+    /// Live admission counters for a registered backend.
+    pub fn backend_stats(&self, backend_id: &str) -> Result<BackendStats, CoreError> {
+        Ok(self.handle(backend_id)?.admission.stats())
+    }
+
     /// Clone backend ingress sender.
     pub fn ingress_tx(&self, backend_id: &str) -> Result<Sender<BackendRequest>, CoreError> {
         let handle = self.handle(backend_id)?;
@@ -175,31 +356,43 @@ impl ResourceManager {
         Ok(handle.next_seq.fetch_add(1, Ordering::Relaxed))
     }
 
-    async fn acquire_compute_permit(
+    /// Acquire inference lease: read management lock + compute quota.
+    pub async fn acquire_inference_lease(
         &self,
-        handle: &BackendHandle,
         backend_id: &str,
         timeout: Duration,
-    ) -> Result<OwnedSemaphorePermit, CoreError> {
-        tokio::time::timeout(timeout, Arc::clone(&handle.semaphore).acquire_owned())
-            .await
-            .map_err(|_| CoreError::Timeout)?
-            .map_err(|_| CoreError::Busy { backend_id: backend_id.to_owned() })
+    ) -> Result<InferenceLease, CoreError> {
+        self.acquire_inference_lease_with_priority(backend_id, Priority::default(), timeout).await
     }
 
-    /// Acquire inference lease: read management lock + compute quota.
-    pub async fn acquire_inference_lease(
+    /// Acquire inference lease, preferring higher-priority waiters when capacity frees up.
+    pub async fn acquire_inference_lease_with_priority(
         &self,
         backend_id: &str,
+        priority: Priority,
         timeout: Duration,
     ) -> Result<InferenceLease, CoreError> {
         let handle = self.handle(backend_id)?;
-        let compute_permit = self.acquire_compute_permit(&handle, backend_id, timeout).await?;
+        let compute_permit = handle.admission.acquire(priority, timeout).await?;
         let mgmt_guard = Arc::clone(&handle.management_lock).read_owned().await;
 
         Ok(InferenceLease { mgmt_guard, compute_permit })
     }
 
+    /// Reserve compute quota without waiting, failing with [`CoreError::Busy`]
+    /// when every slot of the backend is taken.
+    pub fn try_reserve_inference(
+        &self,
+        backend_id: &str,
+    ) -> Result<InferenceReservation, CoreError> {
+        let handle = self.handle(backend_id)?;
+        let compute_permit = handle
+            .admission
+            .try_acquire()
+            .ok_or_else(|| CoreError::Busy { backend_id: backend_id.to_owned() })?;
+        Ok(InferenceReservation { compute_permit, management_lock: handle.management_lock })
+    }
+
     /// Acquire exclusive management lease for initialize/load/unload operations.
     pub async fn acquire_management_lease(
         &self,
@@ -209,6 +402,29 @@ impl ResourceManager {
         let mgmt_guard = Arc::clone(&handle.management_lock).write_owned().await;
         Ok(ManagementLease { mgmt_guard })
     }
+
+    /// Drain and unregister every backend, returning the ids that were removed.
+    ///
+    /// Each backend is removed once its in-flight leases are released, which
+    /// drops the ingress sender so its workers exit after draining the queue.
+    /// Backends can be registered again afterwards with a new configuration.
+    pub async fn shutdown(&self) -> Vec<String> {
+        let backend_ids = self.backend_ids();
+        for backend_id in &backend_ids {
+            let Ok(_drained) = self.acquire_management_lease(backend_id).await else {
+                continue;
+            };
+            match self.backends.write() {
+                Ok(mut backends) => {
+                    backends.remove(backend_id);
+                }
+                Err(_) => {
+                    tracing::error!(backend_id, "backend map poisoned during shutdown");
+                }
+            }
+        }
+        backend_ids
+    }
 }
 
 impl Default for ResourceManager {
@@ -219,8 +435,34 @@ impl Default for ResourceManager {
 
 #[cfg(test)]
 mod tests {
-    use super::{ResourceManager, ResourceManagerConfig};
+    use std::sync::{Arc, Mutex};
+
+    use super::{BackendStats, ResourceManager, ResourceManagerConfig};
     use crate::base::error::CoreError;
+    use crate::internal::scheduler::backend::protocol::Priority;
+
+    #[tokio::test]
+    async fn try_reserve_inference_fails_fast_and_returns_quota_on_drop() {
+        let mut manager = ResourceManager::with_config(ResourceManagerConfig {
+            backend_capacity: 1,
+            ..ResourceManagerConfig::default()
+        });
+        manager.register_backend("serial-backend", |_shared_rx, _control_tx| {});
+
+        let reservation =
+            manager.try_reserve_inference("serial-backend").expect("free slot should be reserved");
+        assert!(matches!(
+            manager.try_reserve_inference("serial-backend"),
+            Err(CoreError::Busy { ref backend_id }) if backend_id == "serial-backend"
+        ));
+        assert_eq!(manager.backend_stats("serial-backend").unwrap().in_flight, 1);
+
+        let lease = reservation.into_lease().await;
+        assert_eq!(manager.backend_stats("serial-backend").unwrap().in_flight, 1);
+        drop(lease);
+        assert_eq!(manager.backend_stats("serial-backend").unwrap().in_flight, 0);
+        drop(manager.try_reserve_inference("serial-backend").expect("slot returned"));
+    }
 
     #[tokio::test]
     async fn inference_lease_waits_for_available_capacity() {
@@ -250,6 +492,111 @@ mod tests {
         drop(second);
     }
 
+    #[tokio::test]
+    async fn high_priority_waiter_is_admitted_before_earlier_low_priority_waiter() {
+        let mut manager = ResourceManager::with_config(ResourceManagerConfig {
+            backend_capacity: 1,
+            ..ResourceManagerConfig::default()
+        });
+        manager.register_backend("serial-backend", |_shared_rx, _control_tx| {});
+
+        let lease = manager
+            .acquire_inference_lease("serial-backend", std::time::Duration::from_secs(1))
+            .await
+            .expect("first lease should succeed");
+
+        let order = Arc::new(Mutex::new(Vec::new()));
+        let mut waiters = Vec::new();
+        for (label, priority) in [("low", Priority::Low), ("high", Priority::High)] {
+            let clone = manager.clone();
+            let order = Arc::clone(&order);
+            waiters.push(tokio::spawn(async move {
+                let lease = clone
+                    .acquire_inference_lease_with_priority(
+                        "serial-backend",
+                        priority,
+                        std::time::Duration::from_secs(1),
+                    )
+                    .await
+                    .expect("queued lease should succeed");
+                order.lock().expect("order lock").push(label);
+                tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+                drop(lease);
+            }));
+            tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+        }
+
+        drop(lease);
+        for waiter in waiters {
+            waiter.await.expect("waiter task should not panic");
+        }
+
+        assert_eq!(*order.lock().expect("order lock"), vec!["high", "low"]);
+    }
+
+    #[tokio::test]
+    async fn equal_priority_waiters_keep_fifo_order() {
+        let mut manager = ResourceManager::with_config(ResourceManagerConfig {
+            backend_capacity: 1,
+            ..ResourceManagerConfig::default()
+        });
+        manager.register_backend("serial-backend", |_shared_rx, _control_tx| {});
+
+        let lease = manager
+            .acquire_inference_lease("serial-backend", std::time::Duration::from_secs(1))
+            .await
+            .expect("first lease should succeed");
+
+        let order = Arc::new(Mutex::new(Vec::new()));
+        let mut waiters = Vec::new();
+        for label in ["first", "second", "third"] {
+            let clone = manager.clone();
+            let order = Arc::clone(&order);
+            waiters.push(tokio::spawn(async move {
+                let lease = clone
+                    .acquire_inference_lease("serial-backend", std::time::Duration::from_secs(1))
+                    .await
+                    .expect("queued lease should succeed");
+                order.lock().expect("order lock").push(label);
+                drop(lease);
+            }));
+            tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+        }
+
+        drop(lease);
+        for waiter in waiters {
+            waiter.await.expect("waiter task should not panic");
+        }
+
+        assert_eq!(*order.lock().expect("order lock"), vec!["first", "second", "third"]);
+    }
+
+    #[tokio::test]
+    async fn timed_out_waiter_does_not_leak_capacity() {
+        let mut manager = ResourceManager::with_config(ResourceManagerConfig {
+            backend_capacity: 1,
+            ..ResourceManagerConfig::default()
+        });
+        manager.register_backend("serial-backend", |_shared_rx, _control_tx| {});
+
+        let lease = manager
+            .acquire_inference_lease("serial-backend", std::time::Duration::from_secs(1))
+            .await
+            .expect("first lease should succeed");
+        let err = manager
+            .acquire_inference_lease("serial-backend", std::time::Duration::from_millis(5))
+            .await
+            .expect_err("waiter should time out");
+        assert!(matches!(err, CoreError::Timeout));
+
+        drop(lease);
+        let next = manager
+            .acquire_inference_lease("serial-backend", std::time::Duration::from_millis(50))
+            .await
+            .expect("capacity should be returned after the stale waiter is skipped");
+        drop(next);
+    }
+
     #[tokio::test]
     async fn unknown_backend_returns_driver_not_registered() {
         let manager = ResourceManager::new();
@@ -335,6 +682,108 @@ mod tests {
         drop(management);
     }
 
+    #[tokio::test]
+    async fn shutdown_drains_leases_and_stops_workers_before_reregistering() {
+        let mut manager = ResourceManager::with_config(ResourceManagerConfig {
+            backend_capacity: 1,
+            ..ResourceManagerConfig::default()
+        });
+        let (exited_tx, exited_rx) = tokio::sync::oneshot::channel();
+        manager.register_backend("worker-backend", move |shared_rx, _control_tx| {
+            tokio::spawn(async move {
+                while shared_rx.recv_async().await.is_ok() {}
+                let _ = exited_tx.send(());
+            });
+        });
+
+        let lease = manager
+            .acquire_inference_lease("worker-backend", std::time::Duration::from_secs(1))
+            .await
+            .expect("lease should succeed");
+        let clone = manager.clone();
+        let shutdown = tokio::spawn(async move { clone.shutdown().await });
+        tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+        assert!(!shutdown.is_finished(), "shutdown should wait for the in-flight lease");
+
+        drop(lease);
+        let removed = shutdown.await.expect("shutdown task should not panic");
+        assert_eq!(removed, ["worker-backend"]);
+        tokio::time::timeout(std::time::Duration::from_secs(1), exited_rx)
+            .await
+            .expect("worker should exit once the ingress sender is dropped")
+            .expect("worker should report its exit");
+        assert!(manager.backend_ids().is_empty());
+        assert!(matches!(
+            manager.backend_stats("worker-backend"),
+            Err(CoreError::DriverNotRegistered { .. })
+        ));
+
+        manager.register_backend_with_capacity("worker-backend", 3, |_shared_rx, _control_tx| {});
+        assert_eq!(manager.backend_stats("worker-backend").expect("stats").capacity, 3);
+    }
+
+    #[test]
+    fn backends_registered_with_capacity_override_the_default() {
+        let mut manager = ResourceManager::with_config(ResourceManagerConfig {
+            backend_capacity: 4,
+            ..ResourceManagerConfig::default()
+        });
+        manager.register_backend("default-backend", |_shared_rx, _control_tx| {});
+        manager.register_backend_with_capacity("light-backend", 8, |_shared_rx, _control_tx| {});
+        manager.register_backend_with_capacity("heavy-backend", 1, |_shared_rx, _control_tx| {});
+
+        let capacity = |id: &str| manager.backend_stats(id).expect("stats").capacity;
+        assert_eq!(capacity("default-backend"), 4);
+        assert_eq!(capacity("light-backend"), 8);
+        assert_eq!(capacity("heavy-backend"), 1);
+    }
+
+    #[tokio::test]
+    async fn backend_stats_track_held_and_waiting_leases() {
+        let mut manager = ResourceManager::with_config(ResourceManagerConfig {
+            backend_capacity: 2,
+            ..ResourceManagerConfig::default()
+        });
+        manager.register_backend("stats-backend", |_shared_rx, _control_tx| {});
+
+        let idle = manager.backend_stats("stats-backend").expect("stats");
+        assert_eq!(idle, BackendStats { in_flight: 0, capacity: 2, queued: 0 });
+
+        let first = manager
+            .acquire_inference_lease("stats-backend", std::time::Duration::from_secs(1))
+            .await
+            .expect("first lease should succeed");
+        let second = manager
+            .acquire_inference_lease("stats-backend", std::time::Duration::from_secs(1))
+            .await
+            .expect("second lease should succeed");
+        let clone = manager.clone();
+        let waiter = tokio::spawn(async move {
+            clone.acquire_inference_lease("stats-backend", std::time::Duration::from_secs(1)).await
+        });
+        tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+
+        let busy = manager.backend_stats("stats-backend").expect("stats");
+        assert_eq!(busy, BackendStats { in_flight: 2, capacity: 2, queued: 1 });
+
+        drop(first);
+        let third = waiter
+            .await
+            .expect("waiter task should not panic")
+            .expect("queued lease should succeed");
+        let handed_off = manager.backend_stats("stats-backend").expect("stats");
+        assert_eq!(handed_off, BackendStats { in_flight: 2, capacity: 2, queued: 0 });
+
+        drop(second);
+        drop(third);
+        let drained = manager.backend_stats("stats-backend").expect("stats");
+        assert_eq!(drained.in_flight, 0);
+        assert!(matches!(
+            manager.backend_stats("missing-backend"),
+            Err(CoreError::DriverNotRegistered { .. })
+        ));
+    }
+
     #[test]
     fn next_seq_is_monotonic_per_backend() {
         let mut manager = ResourceManager::new();
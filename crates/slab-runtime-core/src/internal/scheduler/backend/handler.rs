@@ -7,7 +7,8 @@ use thiserror::Error;
 use crate::base::types::Payload;
 
 use super::protocol::{
-    BackendReply, BackendRequest, PeerWorkerCommand, RuntimeControlSignal, StreamHandle,
+    BackendError, BackendReply, BackendRequest, PeerWorkerCommand, RuntimeControlSignal,
+    StreamHandle,
 };
 
 /// Typed input extracted by macro-generated worker handlers.
@@ -80,6 +81,12 @@ impl From<BackendHandlerError> for String {
     }
 }
 
+impl From<BackendHandlerError> for BackendError {
+    fn from(value: BackendHandlerError) -> Self {
+        Self::from(value.0)
+    }
+}
+
 /// Convert a typed handler success value into a backend reply.
 pub trait IntoBackendReply {
     fn into_backend_reply(self) -> Result<BackendReply, BackendHandlerError>;
@@ -173,14 +180,14 @@ where
 pub fn backend_reply_from_event_result<T, E>(result: Result<T, E>) -> BackendReply
 where
     T: IntoBackendReply,
-    E: fmt::Display,
+    E: Into<BackendError>,
 {
     match result {
         Ok(value) => match value.into_backend_reply() {
             Ok(reply) => reply,
-            Err(error) => BackendReply::error(error.to_string()),
+            Err(error) => BackendReply::error(error),
         },
-        Err(error) => BackendReply::error(error.to_string()),
+        Err(error) => BackendReply::error(error),
     }
 }
 
@@ -476,11 +483,12 @@ mod tests {
         ));
         assert!(matches!(
             backend_reply_from_event_result::<String, _>(Err("handler failed")),
-            BackendReply::Error(message) if message == "handler failed"
+            BackendReply::Error(error) if error.message == "handler failed"
         ));
         assert!(matches!(
             backend_reply_from_event_result::<_, &str>(Ok(Json(BrokenSerialize))),
-            BackendReply::Error(message) if message.contains("failed to serialize backend json response")
+            BackendReply::Error(error)
+                if error.message.contains("failed to serialize backend json response")
         ));
     }
 
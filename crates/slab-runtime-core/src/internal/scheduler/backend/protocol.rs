@@ -17,6 +17,10 @@ pub enum RequestRoute {
     Inference,
     InferenceStream,
     InferenceImage,
+    IsReady,
+    Warmup,
+    Tokenize,
+    Detokenize,
 }
 
 impl RequestRoute {
@@ -27,6 +31,10 @@ impl RequestRoute {
             Self::Inference => "inference",
             Self::InferenceStream => "inference.stream",
             Self::InferenceImage => "inference.image",
+            Self::IsReady => "is_ready",
+            Self::Warmup => "model.warmup",
+            Self::Tokenize => "tokenize",
+            Self::Detokenize => "detokenize",
         }
     }
 }
@@ -41,6 +49,10 @@ impl FromStr for RequestRoute {
             "inference" => Ok(Self::Inference),
             "inference.stream" => Ok(Self::InferenceStream),
             "inference.image" => Ok(Self::InferenceImage),
+            "is_ready" => Ok(Self::IsReady),
+            "model.warmup" => Ok(Self::Warmup),
+            "tokenize" => Ok(Self::Tokenize),
+            "detokenize" => Ok(Self::Detokenize),
             other => Err(format!("unknown backend op: {other}")),
         }
     }
@@ -306,6 +318,41 @@ impl BackendOp {
     }
 }
 
+/// Admission priority for inference requests competing for backend capacity.
+///
+/// Higher-priority requests are admitted first when a compute permit frees up;
+/// requests at the same priority keep FIFO order.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Priority {
+    High,
+    #[default]
+    Normal,
+    Low,
+}
+
+impl Priority {
+    /// Numeric rank used for ordering; larger values are admitted first.
+    pub const fn rank(self) -> u8 {
+        match self {
+            Self::High => 2,
+            Self::Normal => 1,
+            Self::Low => 0,
+        }
+    }
+}
+
+impl PartialOrd for Priority {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Priority {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.rank().cmp(&other.rank())
+    }
+}
+
 /// Request type used by runtime dispatch to separate management from inference.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum BackendRequestKind {
@@ -334,6 +381,8 @@ pub struct BackendRequest {
     pub cancel_rx: tokio::sync::watch::Receiver<bool>,
     /// Optional sequence id assigned by the resource manager for management ops.
     pub broadcast_seq: Option<u64>,
+    /// Admission priority the request was scheduled with.
+    pub priority: Priority,
     /// Channel on which the backend sends its single reply.
     pub reply_tx: oneshot::Sender<BackendReply>,
 }
@@ -347,7 +396,12 @@ impl BackendRequest {
         broadcast_seq: Option<u64>,
         reply_tx: oneshot::Sender<BackendReply>,
     ) -> Self {
-        Self { kind, op, input, cancel_rx, broadcast_seq, reply_tx }
+        Self { kind, op, input, cancel_rx, broadcast_seq, priority: Priority::default(), reply_tx }
+    }
+
+    pub fn with_priority(mut self, priority: Priority) -> Self {
+        self.priority = priority;
+        self
     }
 
     pub fn inference(
@@ -409,6 +463,51 @@ impl BackendRequest {
     }
 }
 
+/// Category of a [`BackendError`], so callers can react without parsing text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BackendErrorKind {
+    /// Any failure without a more specific category.
+    #[default]
+    Failed,
+    /// The request itself is invalid, e.g. it references an unknown token id.
+    InvalidArgument,
+    /// No model is loaded to serve the request.
+    ModelNotLoaded,
+    /// The backend's native library could not be loaded.
+    LibraryNotLoaded,
+}
+
+/// Error reply from a backend worker.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BackendError {
+    pub kind: BackendErrorKind,
+    pub message: String,
+}
+
+impl BackendError {
+    pub fn new(kind: BackendErrorKind, message: impl Into<String>) -> Self {
+        Self { kind, message: message.into() }
+    }
+}
+
+impl std::fmt::Display for BackendError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl From<String> for BackendError {
+    fn from(message: String) -> Self {
+        Self::new(BackendErrorKind::Failed, message)
+    }
+}
+
+impl From<&str> for BackendError {
+    fn from(message: &str) -> Self {
+        Self::new(BackendErrorKind::Failed, message)
+    }
+}
+
 /// Reply sent back from a backend worker to the orchestrator.
 #[derive(Debug)]
 pub enum BackendReply {
@@ -419,7 +518,7 @@ pub enum BackendReply {
     /// A streaming output handle (terminal stage only).
     Stream(StreamHandle),
     /// The backend encountered an error.
-    Error(String),
+    Error(BackendError),
 }
 
 impl BackendReply {
@@ -435,8 +534,8 @@ impl BackendReply {
         Self::Stream(handle)
     }
 
-    pub fn error(message: impl Into<String>) -> Self {
-        Self::Error(message.into())
+    pub fn error(error: impl Into<BackendError>) -> Self {
+        Self::Error(error.into())
     }
 }
 
@@ -448,9 +547,10 @@ mod tests {
     use crate::base::types::Payload;
 
     use super::{
-        BackendOp, BackendReply, BackendRequest, BackendRequestKind, DeploymentSnapshot,
-        DriverRequestKind, ManagementEvent, PeerControlBus, PeerWorkerCommand,
-        PeerWorkerCommandKind, RequestRoute, RuntimeControlSignal, SyncMessage, WorkerCommand,
+        BackendError, BackendErrorKind, BackendOp, BackendReply, BackendRequest,
+        BackendRequestKind, DeploymentSnapshot, DriverRequestKind, ManagementEvent, PeerControlBus,
+        PeerWorkerCommand, PeerWorkerCommandKind, Priority, RequestRoute, RuntimeControlSignal,
+        SyncMessage, WorkerCommand,
     };
     use std::str::FromStr;
 
@@ -467,6 +567,10 @@ mod tests {
             RequestRoute::Inference,
             RequestRoute::InferenceStream,
             RequestRoute::InferenceImage,
+            RequestRoute::IsReady,
+            RequestRoute::Warmup,
+            RequestRoute::Tokenize,
+            RequestRoute::Detokenize,
         ] {
             assert_eq!(RequestRoute::from_str(route.as_str()), Ok(route));
         }
@@ -545,6 +649,7 @@ mod tests {
         drop(cancel_tx);
 
         assert_eq!(inference.kind, BackendRequestKind::Inference);
+        assert_eq!(inference.priority, Priority::Normal);
         assert_eq!(inference.route(), Ok(RequestRoute::InferenceStream));
         let DriverRequestKind::Inference(invocation) =
             inference.driver_kind().expect("inference driver kind")
@@ -574,6 +679,24 @@ mod tests {
         assert_eq!(event, ManagementEvent::LoadModel);
     }
 
+    #[test]
+    fn priority_orders_high_above_normal_above_low() {
+        assert!(Priority::High > Priority::Normal);
+        assert!(Priority::Normal > Priority::Low);
+        assert_eq!(Priority::default(), Priority::Normal);
+
+        let (_cancel_tx, cancel_rx) = watch::channel(false);
+        let (reply_tx, _reply_rx) = oneshot::channel();
+        let request = BackendRequest::inference(
+            BackendOp::new("inference", Payload::default()),
+            Payload::default(),
+            cancel_rx,
+            reply_tx,
+        )
+        .with_priority(Priority::High);
+        assert_eq!(request.priority, Priority::High);
+    }
+
     #[test]
     fn backend_request_reports_unknown_route_for_driver_views() {
         let (_cancel_tx, cancel_rx) = watch::channel(false);
@@ -593,15 +716,50 @@ mod tests {
     #[tokio::test]
     async fn backend_reply_constructors_wrap_payloads_and_streams() {
         assert!(matches!(BackendReply::ack(), BackendReply::Ack));
-        assert!(
-            matches!(BackendReply::error("bad"), BackendReply::Error(message) if message == "bad")
-        );
+        assert!(matches!(
+            BackendReply::error("bad"),
+            BackendReply::Error(BackendError { kind: BackendErrorKind::Failed, message })
+                if message == "bad"
+        ));
+        assert!(matches!(
+            BackendReply::error(BackendError::new(BackendErrorKind::ModelNotLoaded, "no model")),
+            BackendReply::Error(BackendError { kind: BackendErrorKind::ModelNotLoaded, .. })
+        ));
         assert!(matches!(BackendReply::value(Payload::from("ok")), BackendReply::Value(_)));
 
         let (_tx, rx) = tokio::sync::mpsc::channel(1);
         assert!(matches!(BackendReply::stream(rx), BackendReply::Stream(_)));
     }
 
+    #[test]
+    fn worker_errors_map_to_backend_error_kinds() {
+        #[derive(Debug, thiserror::Error)]
+        enum WorkerError {
+            #[error("load failed: {message}")]
+            Load { message: String },
+            #[error("invalid argument: {message}")]
+            InvalidArgument { message: String },
+            #[error("model not loaded: {message}")]
+            ModelNotLoaded { message: String },
+            #[error("library not loaded: {message}")]
+            LibraryNotLoaded { message: String },
+        }
+        crate::impl_backend_error_kinds!(WorkerError);
+
+        let cases = [
+            (WorkerError::Load { message: "x".into() }, BackendErrorKind::Failed),
+            (WorkerError::invalid_argument("x"), BackendErrorKind::InvalidArgument),
+            (WorkerError::model_not_loaded("x"), BackendErrorKind::ModelNotLoaded),
+            (WorkerError::library_not_loaded("x"), BackendErrorKind::LibraryNotLoaded),
+        ];
+        for (error, kind) in cases {
+            let message = error.to_string();
+            let backend_error = BackendError::from(error);
+            assert_eq!(backend_error.kind, kind);
+            assert_eq!(backend_error.message, message);
+        }
+    }
+
     #[tokio::test]
     async fn peer_control_bus_broadcasts_peer_commands_when_receivers_exist() {
         let (tx, mut rx) = broadcast::channel(4);
@@ -18,6 +18,12 @@ pub struct GgmlLlamaLoadConfig {
     pub chat_template: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub gbnf: Option<String>,
+    /// Place new sessions on the least-loaded worker instead of round-robin.
+    #[serde(default)]
+    pub least_loaded_sessions: bool,
+    /// End generation with a context-full error instead of shifting the KV cache.
+    #[serde(default)]
+    pub stop_on_context_full: bool,
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
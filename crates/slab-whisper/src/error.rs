@@ -80,6 +80,9 @@ pub enum WhisperError {
     /// `enable_vad(true)` was called before a VAD model path was set.
     #[error("VAD model path must be set via set_vad_model_path before enabling VAD")]
     VadModelPathNotSet,
+    /// The grammar start rule index does not name one of the supplied rules.
+    #[error("Grammar start rule {root} is out of range for {n_rules} rules")]
+    InvalidGrammarStartRule { root: usize, n_rules: usize },
 }
 
 impl From<Utf8Error> for WhisperError {
@@ -27,7 +27,7 @@ pub use whisper_ctx::WhisperTimings;
 
 pub use whisper_ctx_wrapper::WhisperContext;
 pub use whisper_grammar::{WhisperGrammarElement, WhisperGrammarElementType};
-pub use whisper_params::{FullParams, SamplingStrategy, SegmentCallbackData};
+pub use whisper_params::{FullParams, FullParamsCallback, SamplingStrategy, SegmentCallbackData};
 
 pub use whisper_state::{WhisperSegment, WhisperState, WhisperStateSegmentIterator, WhisperToken};
 pub use whisper_vad::*;
@@ -167,6 +167,22 @@ impl WhisperInnerContext {
         unsafe { self.instance.lib.whisper_is_multilingual(self.ctx) != 0 }
     }
 
+    /// Number of languages the model can transcribe; English-only models report 1.
+    pub fn n_languages(&self) -> c_int {
+        languages_in_vocab(self.model_n_vocab(), self.is_multilingual())
+    }
+
+    /// Id of the language `code` (e.g. `"de"`), or `None` when the code is
+    /// unknown or this model cannot transcribe it.
+    ///
+    /// # C++ equivalent
+    /// `int whisper_lang_id(const char * lang)`
+    pub fn language_id(&self, code: &str) -> Option<c_int> {
+        let c_code = CString::new(code).ok()?;
+        let id = unsafe { self.instance.lib.whisper_lang_id(c_code.as_ptr()) };
+        (0..self.n_languages()).contains(&id).then_some(id)
+    }
+
     /// Get model_n_vocab.
     ///
     /// # Returns
@@ -785,6 +801,24 @@ impl DtwModelPreset {
     }
 }
 
+/// Language tokens follow the 51765 base text tokens; multilingual vocabularies
+/// also carry one extra special token (mirrors whisper.cpp's `num_languages`).
+fn languages_in_vocab(n_vocab: c_int, multilingual: bool) -> c_int {
+    if multilingual { (n_vocab - 51765 - 1).max(1) } else { 1 }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::languages_in_vocab;
+
+    #[test]
+    fn language_count_follows_vocab_size() {
+        assert_eq!(languages_in_vocab(51865, true), 99);
+        assert_eq!(languages_in_vocab(51866, true), 100);
+        assert_eq!(languages_in_vocab(51864, false), 1);
+    }
+}
+
 #[cfg(test)]
 #[cfg(feature = "test-with-tiny-model")]
 mod test_with_tiny_model {
@@ -803,4 +837,21 @@ mod test_with_tiny_model {
             tokens.into_iter().map(|t| ctx.token_to_str(t).unwrap()).collect::<Vec<_>>().join("");
         assert_eq!(text_in, text_out);
     }
+
+    #[test]
+    fn test_english_only_model_reports_single_language() {
+        let ctx = WhisperInnerContext::new(MODEL_PATH).expect("Download the ggml-tiny.en model using 'sys/whisper.cpp/models/download-ggml-model.sh tiny.en'");
+        assert!(!ctx.is_multilingual());
+        assert_eq!(ctx.n_languages(), 1);
+        assert_eq!(ctx.language_id("en"), Some(0));
+        assert_eq!(ctx.language_id("de"), None);
+    }
+
+    #[test]
+    fn test_model_shape_is_reported() {
+        let ctx = WhisperInnerContext::new(MODEL_PATH).expect("Download the ggml-tiny.en model using 'sys/whisper.cpp/models/download-ggml-model.sh tiny.en'");
+        assert_eq!(ctx.model_type_readable_str().unwrap(), "tiny");
+        assert_eq!(ctx.model_n_mels(), 80);
+        assert_eq!(ctx.model_n_audio_ctx(), 1500);
+    }
 }
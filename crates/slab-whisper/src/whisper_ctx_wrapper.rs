@@ -2,7 +2,11 @@ use std::borrow::Cow;
 use std::ffi::c_int;
 use std::sync::Arc;
 
-use crate::{ContextParams, WhisperError, WhisperInnerContext, WhisperState, WhisperTokenId};
+use crate::whisper_vad::{SAMPLES_PER_CENTISECOND, speech_sample_ranges};
+use crate::{
+    ContextParams, FullParams, VadTranscriptSegment, WhisperError, WhisperInnerContext,
+    WhisperState, WhisperTokenId, WhisperVadParams,
+};
 
 #[derive(Clone, Debug)]
 pub struct WhisperContext {
@@ -111,6 +115,20 @@ impl WhisperContext {
         self.ctx.is_multilingual()
     }
 
+    /// Number of languages the model can transcribe; English-only models report 1.
+    pub fn n_languages(&self) -> c_int {
+        self.ctx.n_languages()
+    }
+
+    /// Id of the language `code` (e.g. `"de"`), or `None` when the code is
+    /// unknown or this model cannot transcribe it.
+    ///
+    /// # C++ equivalent
+    /// `int whisper_lang_id(const char * lang)`
+    pub fn language_id(&self, code: &str) -> Option<c_int> {
+        self.ctx.language_id(code)
+    }
+
     /// Get model_n_vocab.
     ///
     /// # Returns
@@ -243,6 +261,23 @@ impl WhisperContext {
         self.ctx.model_type()
     }
 
+    /// Number of mel bins the model expects (80, or 128 for large-v3).
+    ///
+    /// # C++ equivalent
+    /// `int whisper_model_n_mels       (struct whisper_context * ctx);`
+    pub fn n_mels(&self) -> c_int {
+        self.ctx.model_n_mels()
+    }
+
+    /// Readable model size class (e.g. `"base"`, `"large"`), or `"unknown"`
+    /// when whisper.cpp does not report one.
+    ///
+    /// # C++ equivalent
+    /// `const char * whisper_model_type_readable(struct whisper_context * ctx);`
+    pub fn model_type_str(&self) -> &str {
+        self.ctx.model_type_readable_str().unwrap_or("unknown")
+    }
+
     // --- begin model_type_readable ---
     /// Undocumented but exposed function in the C++ API.
     ///
@@ -473,4 +508,50 @@ impl WhisperContext {
             Ok(unsafe { WhisperState::new(self.ctx.clone(), state) })
         }
     }
+
+    /// Run VAD over `samples` and transcribe only the detected speech regions.
+    ///
+    /// The VAD model is loaded from `full_params.vad_model_path`. Each speech
+    /// region is decoded on its own and segment timestamps are shifted back
+    /// onto the timeline of the full input. Audio without detected speech
+    /// yields an empty transcript.
+    pub fn transcribe_with_vad(
+        &self,
+        samples: &[f32],
+        vad_params: WhisperVadParams,
+        full_params: FullParams,
+    ) -> Result<Vec<VadTranscriptSegment>, WhisperError> {
+        if samples.is_empty() {
+            return Err(WhisperError::NoSamples);
+        }
+        let Some(vad_model_path) = full_params.vad_model_path.as_ref() else {
+            return Err(WhisperError::VadModelPathNotSet);
+        };
+
+        let instance = &self.ctx.instance;
+        let mut vad =
+            instance.new_vad_context(vad_model_path, instance.default_vad_context_params())?;
+        let speech =
+            speech_sample_ranges(vad.segments_from_samples(vad_params, samples)?, samples.len());
+        if speech.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        // The regions are already speech-only, so whisper's built-in VAD pass is skipped.
+        let params = FullParams { vad: Some(false), ..full_params };
+        let mut state = self.create_state()?;
+        let mut transcript = Vec::new();
+        for range in speech {
+            let offset_cs = (range.start as f32 / SAMPLES_PER_CENTISECOND) as i64;
+            state.full(params.clone(), &samples[range])?;
+            for segment in state.as_iter() {
+                transcript.push(VadTranscriptSegment {
+                    start_timestamp: segment.start_timestamp() + offset_cs,
+                    end_timestamp: segment.end_timestamp() + offset_cs,
+                    text: segment.to_str_lossy()?.into_owned(),
+                });
+            }
+        }
+        Ok(transcript)
+    }
 }
@@ -3,9 +3,12 @@ use crate::whisper_vad::WhisperVadParams;
 use crate::{Whisper, WhisperError};
 use serde::{Deserialize, Serialize};
 use slab_whisper_sys::whisper_token;
-use std::ffi::{CStr, CString, c_char, c_float, c_int};
+use std::ffi::{CStr, CString, c_char, c_float, c_int, c_void};
+use std::fmt;
+use std::panic::{AssertUnwindSafe, catch_unwind};
 use std::path::PathBuf;
 use std::ptr;
+use std::sync::{Arc, Mutex};
 
 /// The sampling strategy to use to pick tokens from a list of likely possibilities.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -72,6 +75,9 @@ impl SamplingStrategy {
     }
 }
 
+/// A finalized segment reported through [`crate::WhisperState::full_with_segment_callback`].
+///
+/// Timestamps are in centiseconds, matching [`crate::WhisperSegment::start_timestamp`].
 #[derive(Debug, Clone)]
 pub struct SegmentCallbackData {
     pub segment: i32,
@@ -80,6 +86,40 @@ pub struct SegmentCallbackData {
     pub text: String,
 }
 
+/// A user callback installed on [`FullParams`].
+///
+/// Clones share the same callback and compare equal; the callback itself is
+/// never serialized. whisper.cpp may invoke it from its worker threads, so
+/// calls are serialized through a mutex.
+pub struct FullParamsCallback<F: ?Sized>(Arc<Mutex<Box<F>>>);
+
+impl<F: ?Sized> FullParamsCallback<F> {
+    fn user_data(&self) -> *mut c_void {
+        Arc::as_ptr(&self.0).cast_mut().cast::<c_void>()
+    }
+}
+
+impl<F: ?Sized> Clone for FullParamsCallback<F> {
+    fn clone(&self) -> Self {
+        Self(Arc::clone(&self.0))
+    }
+}
+
+impl<F: ?Sized> PartialEq for FullParamsCallback<F> {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl<F: ?Sized> fmt::Debug for FullParamsCallback<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("FullParamsCallback")
+    }
+}
+
+type AbortFn = dyn FnMut() -> bool + Send;
+type ProgressFn = dyn FnMut(c_int) + Send;
+
 /// Stable Rust-native full inference parameters shared across the runtime chain.
 #[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
 pub struct FullParams {
@@ -169,6 +209,10 @@ pub struct FullParams {
     pub vad_model_path: Option<PathBuf>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub vad_params: Option<WhisperVadParams>,
+    #[serde(skip)]
+    pub abort_callback: Option<FullParamsCallback<AbortFn>>,
+    #[serde(skip)]
+    pub progress_callback: Option<FullParamsCallback<ProgressFn>>,
 }
 
 impl FullParams {
@@ -176,6 +220,104 @@ impl FullParams {
         Self { strategy, ..Self::default() }
     }
 
+    /// Number of threads used for inference.
+    ///
+    /// Unset keeps the whisper.cpp default of `min(4, hardware_concurrency)`.
+    pub fn set_n_threads(&mut self, n_threads: c_int) {
+        self.n_threads = Some(n_threads);
+    }
+
+    /// Start decoding `offset_ms` milliseconds into the audio (default 0).
+    pub fn set_offset_ms(&mut self, offset_ms: c_int) {
+        self.offset_ms = Some(offset_ms);
+    }
+
+    /// Only decode `duration_ms` milliseconds of audio after the offset
+    /// (default 0, meaning until the end of the input).
+    pub fn set_duration_ms(&mut self, duration_ms: c_int) {
+        self.duration_ms = Some(duration_ms);
+    }
+
+    /// Translate the transcript into English instead of transcribing in the
+    /// spoken language.
+    pub fn set_translate(&mut self, translate: bool) {
+        self.translate = Some(translate);
+    }
+
+    /// Decode without conditioning on text from previous decode windows, so
+    /// independently transcribed chunks cannot leak context into each other.
+    pub fn set_no_context(&mut self, no_context: bool) {
+        self.no_context = Some(no_context);
+    }
+
+    /// Force the whole input into a single segment, which suits short
+    /// real-time chunks.
+    pub fn set_single_segment(&mut self, single_segment: bool) {
+        self.single_segment = Some(single_segment);
+    }
+
+    /// Split segments on word boundaries rather than tokens when
+    /// [`Self::set_max_segment_len`] wraps a segment.
+    pub fn set_split_on_word(&mut self, split_on_word: bool) {
+        self.split_on_word = Some(split_on_word);
+    }
+
+    /// Wrap segments longer than `max_len` characters (0 disables wrapping).
+    ///
+    /// whisper.cpp only wraps segments when token timestamps are computed, so a
+    /// positive length also enables them unless they were set explicitly.
+    pub fn set_max_segment_len(&mut self, max_len: c_int) {
+        self.max_len = Some(max_len);
+        if max_len > 0 && self.token_timestamps.is_none() {
+            self.token_timestamps = Some(true);
+        }
+    }
+
+    /// Cap the number of tokens per segment (0 means no limit).
+    pub fn set_max_tokens_per_segment(&mut self, max_tokens: c_int) {
+        self.max_tokens = Some(max_tokens);
+    }
+
+    /// Constrain decoding with a whisper grammar.
+    ///
+    /// `rules` is the flat element list of every rule, each terminated by
+    /// [`WhisperGrammarElementType::End`]; `root` indexes the start rule and
+    /// `penalty` scales the logits of tokens the grammar rejects.
+    pub fn set_grammar(
+        &mut self,
+        rules: &[WhisperGrammarElement],
+        root: usize,
+        penalty: f32,
+    ) -> Result<(), WhisperError> {
+        let rules = split_grammar_rules(rules);
+        if root >= rules.len() {
+            return Err(WhisperError::InvalidGrammarStartRule { root, n_rules: rules.len() });
+        }
+
+        self.grammar = Some(rules);
+        self.i_start_rule = Some(root);
+        self.grammar_penalty = Some(penalty);
+        Ok(())
+    }
+
+    /// Install a callback polled during encoding and decoding; returning `true`
+    /// aborts the transcription, which then fails with an encode/decode error.
+    ///
+    /// Panics inside the callback are caught and treated as an abort request.
+    pub fn set_abort_callback(&mut self, abort: impl FnMut() -> bool + Send + 'static) {
+        self.abort_callback = Some(FullParamsCallback(Arc::new(Mutex::new(Box::new(abort)))));
+    }
+
+    /// Install a callback receiving the transcription progress as a 0–100
+    /// percentage.
+    ///
+    /// whisper.cpp may report progress from a worker thread; panics inside the
+    /// callback are caught and discarded.
+    pub fn set_progress_callback(&mut self, on_progress: impl FnMut(c_int) + Send + 'static) {
+        self.progress_callback =
+            Some(FullParamsCallback(Arc::new(Mutex::new(Box::new(on_progress)))));
+    }
+
     pub fn try_enable_vad(&mut self, vad: bool) -> Result<(), WhisperError> {
         if vad && self.vad_model_path.is_none() {
             return Err(WhisperError::VadModelPathNotSet);
@@ -230,6 +372,8 @@ impl FullParams {
             vad: Some(fp.vad),
             vad_model_path: copy_c_string(fp.vad_model_path).map(PathBuf::from),
             vad_params: Some(WhisperVadParams::from_native(fp.vad_params)),
+            abort_callback: None,
+            progress_callback: None,
         }
     }
 }
@@ -243,6 +387,9 @@ pub(crate) struct InnerFullParams {
     prompt_tokens: Vec<whisper_token>,
     grammar_rules: Vec<Vec<slab_whisper_sys::whisper_grammar_element>>,
     grammar_rule_ptrs: Vec<*const slab_whisper_sys::whisper_grammar_element>,
+    // Keep the allocations behind the native callback user data pointers alive.
+    abort_callback: Option<FullParamsCallback<AbortFn>>,
+    progress_callback: Option<FullParamsCallback<ProgressFn>>,
 }
 
 impl Clone for InnerFullParams {
@@ -256,6 +403,8 @@ impl Clone for InnerFullParams {
             prompt_tokens: self.prompt_tokens.clone(),
             grammar_rules: self.grammar_rules.clone(),
             grammar_rule_ptrs: Vec::new(),
+            abort_callback: self.abort_callback.clone(),
+            progress_callback: self.progress_callback.clone(),
         };
         cloned.sync_backing();
         cloned
@@ -290,6 +439,8 @@ impl InnerFullParams {
             prompt_tokens: value.prompt_tokens.clone(),
             grammar_rules: Vec::new(),
             grammar_rule_ptrs: Vec::new(),
+            abort_callback: value.abort_callback.clone(),
+            progress_callback: value.progress_callback.clone(),
         };
 
         value.strategy.apply_to_native(&mut inner.fp);
@@ -423,6 +574,14 @@ impl InnerFullParams {
         if let Some(i_start_rule) = value.i_start_rule {
             inner.fp.i_start_rule = i_start_rule;
         }
+        if let Some(abort_callback) = inner.abort_callback.as_ref() {
+            inner.fp.abort_callback = Some(abort_trampoline);
+            inner.fp.abort_callback_user_data = abort_callback.user_data();
+        }
+        if let Some(progress_callback) = inner.progress_callback.as_ref() {
+            inner.fp.progress_callback = Some(progress_trampoline);
+            inner.fp.progress_callback_user_data = progress_callback.user_data();
+        }
 
         inner.sync_backing();
 
@@ -457,6 +616,56 @@ impl InnerFullParams {
     }
 }
 
+unsafe extern "C" fn abort_trampoline(user_data: *mut c_void) -> bool {
+    if user_data.is_null() {
+        return false;
+    }
+
+    // SAFETY: `user_data` comes from `FullParamsCallback::user_data`, and the
+    // `InnerFullParams` holding that callback outlives the native call.
+    let abort = unsafe { &*user_data.cast::<Mutex<Box<AbortFn>>>() };
+    catch_unwind(AssertUnwindSafe(|| match abort.lock() {
+        Ok(mut abort) => abort(),
+        Err(_) => true,
+    }))
+    .unwrap_or(true)
+}
+
+unsafe extern "C" fn progress_trampoline(
+    _ctx: *mut slab_whisper_sys::whisper_context,
+    _state: *mut slab_whisper_sys::whisper_state,
+    progress: c_int,
+    user_data: *mut c_void,
+) {
+    if user_data.is_null() {
+        return;
+    }
+
+    // SAFETY: see `abort_trampoline`.
+    let on_progress = unsafe { &*user_data.cast::<Mutex<Box<ProgressFn>>>() };
+    let _ = catch_unwind(AssertUnwindSafe(|| {
+        if let Ok(mut on_progress) = on_progress.lock() {
+            on_progress(progress);
+        }
+    }));
+}
+
+fn split_grammar_rules(elements: &[WhisperGrammarElement]) -> Vec<Vec<WhisperGrammarElement>> {
+    let mut rules = Vec::new();
+    let mut rule = Vec::new();
+    for &element in elements {
+        rule.push(element);
+        if element.element_type == WhisperGrammarElementType::End {
+            rules.push(std::mem::take(&mut rule));
+        }
+    }
+    if !rule.is_empty() {
+        rule.push(WhisperGrammarElement::new(WhisperGrammarElementType::End, 0));
+        rules.push(rule);
+    }
+    rules
+}
+
 fn copy_c_string(ptr: *const c_char) -> Option<String> {
     (!ptr.is_null()).then(|| unsafe { CStr::from_ptr(ptr) }.to_string_lossy().into_owned())
 }
@@ -508,3 +717,100 @@ fn copy_grammar_rules(
 // concurrent usage is prevented by &mut self on methods that modify the struct
 unsafe impl Send for InnerFullParams {}
 unsafe impl Sync for InnerFullParams {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn element(element_type: WhisperGrammarElementType, value: u32) -> WhisperGrammarElement {
+        WhisperGrammarElement::new(element_type, value)
+    }
+
+    #[test]
+    fn abort_trampoline_forwards_and_treats_panics_as_abort() {
+        let mut calls = 0;
+        let mut params = FullParams::default();
+        params.set_abort_callback(move || {
+            calls += 1;
+            calls >= 2
+        });
+        let callback = params.abort_callback.clone().expect("callback set");
+        assert_eq!(params.clone().abort_callback, Some(callback.clone()));
+
+        assert!(!unsafe { abort_trampoline(callback.user_data()) });
+        assert!(unsafe { abort_trampoline(callback.user_data()) });
+        assert!(!unsafe { abort_trampoline(ptr::null_mut()) });
+
+        params.set_abort_callback(|| panic!("callback bug"));
+        let callback = params.abort_callback.expect("callback set");
+        assert!(unsafe { abort_trampoline(callback.user_data()) });
+    }
+
+    #[test]
+    fn progress_trampoline_forwards_percentages() {
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let mut params = FullParams::default();
+        let sink = Arc::clone(&seen);
+        params.set_progress_callback(move |progress| sink.lock().unwrap().push(progress));
+        let callback = params.progress_callback.expect("callback set");
+
+        for progress in [0, 50, 100] {
+            unsafe {
+                progress_trampoline(
+                    ptr::null_mut(),
+                    ptr::null_mut(),
+                    progress,
+                    callback.user_data(),
+                )
+            };
+        }
+
+        assert_eq!(*seen.lock().unwrap(), [0, 50, 100]);
+    }
+
+    #[test]
+    fn max_segment_len_enables_token_timestamps_unless_set() {
+        let mut params = FullParams::default();
+        params.set_split_on_word(true);
+        params.set_max_segment_len(16);
+        params.set_max_tokens_per_segment(8);
+        assert_eq!(params.split_on_word, Some(true));
+        assert_eq!(params.max_len, Some(16));
+        assert_eq!(params.max_tokens, Some(8));
+        assert_eq!(params.token_timestamps, Some(true));
+
+        let mut params = FullParams { token_timestamps: Some(false), ..FullParams::default() };
+        params.set_max_segment_len(16);
+        assert_eq!(params.token_timestamps, Some(false));
+
+        let mut params = FullParams::default();
+        params.set_max_segment_len(0);
+        assert_eq!(params.token_timestamps, None);
+    }
+
+    #[test]
+    fn set_grammar_splits_rules_on_end_markers() {
+        use WhisperGrammarElementType::{Character, CharacterRangeUpper, End, RuleReference};
+
+        // root ::= digit ; digit ::= [0-9]
+        let rules = [
+            element(RuleReference, 1),
+            element(End, 0),
+            element(Character, u32::from('0')),
+            element(CharacterRangeUpper, u32::from('9')),
+        ];
+        let mut params = FullParams::default();
+        params.set_grammar(&rules, 0, 100.0).expect("valid grammar");
+
+        let grammar = params.grammar.expect("grammar set");
+        assert_eq!(grammar.len(), 2);
+        assert_eq!(grammar[0], rules[..2].to_vec());
+        assert_eq!(grammar[1].last(), Some(&element(End, 0)));
+        assert_eq!(params.i_start_rule, Some(0));
+        assert_eq!(params.grammar_penalty, Some(100.0));
+
+        let error =
+            FullParams::default().set_grammar(&rules, 2, 100.0).expect_err("root out of range");
+        assert!(matches!(error, WhisperError::InvalidGrammarStartRule { root: 2, n_rules: 2 }));
+    }
+}
@@ -1,8 +1,9 @@
-use std::ffi::c_int;
+use std::ffi::{CStr, c_int, c_void};
+use std::panic::{AssertUnwindSafe, catch_unwind};
 use std::sync::Arc;
 
 use crate::whisper_params::InnerFullParams;
-use crate::{FullParams, WhisperError, WhisperInnerContext, WhisperTokenId};
+use crate::{FullParams, SegmentCallbackData, WhisperError, WhisperInnerContext, WhisperTokenId};
 
 mod iterator;
 mod segment;
@@ -297,6 +298,46 @@ impl WhisperState {
         }
 
         let params = InnerFullParams::from_canonical(self.ctx.instance.lib.as_ref(), &params)?;
+        self.full_with_inner_params(&params, data)
+    }
+
+    /// Run the entire model like [`Self::full`], invoking `on_segment` for every
+    /// segment as soon as whisper.cpp finalizes it.
+    ///
+    /// The callback runs synchronously on the calling thread from inside the
+    /// decode loop, so it should hand the data off quickly. Panics raised by the
+    /// callback are caught and discarded instead of unwinding into C.
+    ///
+    /// # C++ equivalent
+    /// `whisper_full_with_state` with `params.new_segment_callback` set.
+    pub fn full_with_segment_callback<F>(
+        &mut self,
+        params: FullParams,
+        data: &[f32],
+        on_segment: F,
+    ) -> Result<(), WhisperError>
+    where
+        F: FnMut(SegmentCallbackData),
+    {
+        if data.is_empty() {
+            return Err(WhisperError::NoSamples);
+        }
+
+        let lib = Arc::clone(&self.ctx.instance.lib);
+        let mut params = InnerFullParams::from_canonical(lib.as_ref(), &params)?;
+        let mut bridge = SegmentCallbackBridge { lib: lib.as_ref(), on_segment };
+        params.fp.new_segment_callback = Some(new_segment_trampoline::<F>);
+        params.fp.new_segment_callback_user_data =
+            (&mut bridge as *mut SegmentCallbackBridge<'_, F>).cast::<c_void>();
+
+        self.full_with_inner_params(&params, data)
+    }
+
+    fn full_with_inner_params(
+        &mut self,
+        params: &InnerFullParams,
+        data: &[f32],
+    ) -> Result<(), WhisperError> {
         let ret = unsafe {
             self.ctx.instance.lib.whisper_full_with_state(
                 self.ctx.ctx,
@@ -400,3 +441,49 @@ impl WhisperState {
         WhisperStateSegmentIterator::new(self)
     }
 }
+
+struct SegmentCallbackBridge<'a, F> {
+    lib: &'a slab_whisper_sys::WhisperLib,
+    on_segment: F,
+}
+
+unsafe extern "C" fn new_segment_trampoline<F>(
+    _ctx: *mut slab_whisper_sys::whisper_context,
+    state: *mut slab_whisper_sys::whisper_state,
+    n_new: c_int,
+    user_data: *mut c_void,
+) where
+    F: FnMut(SegmentCallbackData),
+{
+    if state.is_null() || user_data.is_null() {
+        return;
+    }
+
+    // SAFETY: `user_data` points at the bridge owned by `full_with_segment_callback`,
+    // which outlives the native call that invokes this trampoline.
+    let bridge = unsafe { &mut *user_data.cast::<SegmentCallbackBridge<'_, F>>() };
+    let n_segments = unsafe { bridge.lib.whisper_full_n_segments_from_state(state) };
+
+    for segment in (n_segments - n_new).max(0)..n_segments {
+        let text_ptr =
+            unsafe { bridge.lib.whisper_full_get_segment_text_from_state(state, segment) };
+        let text = if text_ptr.is_null() {
+            String::new()
+        } else {
+            unsafe { CStr::from_ptr(text_ptr) }.to_string_lossy().into_owned()
+        };
+        let data = SegmentCallbackData {
+            segment,
+            start_timestamp: unsafe {
+                bridge.lib.whisper_full_get_segment_t0_from_state(state, segment)
+            },
+            end_timestamp: unsafe {
+                bridge.lib.whisper_full_get_segment_t1_from_state(state, segment)
+            },
+            text,
+        };
+
+        let on_segment = &mut bridge.on_segment;
+        let _ = catch_unwind(AssertUnwindSafe(|| on_segment(data)));
+    }
+}
@@ -4,6 +4,7 @@ use slab_whisper_sys::{
     whisper_vad_context, whisper_vad_context_params, whisper_vad_params, whisper_vad_segments,
 };
 use std::ffi::CString;
+use std::ops::Range;
 use std::os::raw::c_int;
 use std::path::Path;
 
@@ -298,8 +299,56 @@ pub struct WhisperVadSegment {
     pub end: f32,
 }
 
+/// A transcript segment produced by [`crate::WhisperContext::transcribe_with_vad`].
+///
+/// Timestamps are in centiseconds relative to the start of the full input.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct VadTranscriptSegment {
+    pub start_timestamp: i64,
+    pub end_timestamp: i64,
+    pub text: String,
+}
+
+/// whisper.cpp always works on 16 kHz mono PCM.
+pub(crate) const SAMPLES_PER_CENTISECOND: f32 = 160.0;
+
+/// Convert VAD segments into non-empty sample ranges clamped to `n_samples`.
+pub(crate) fn speech_sample_ranges(
+    segments: impl IntoIterator<Item = WhisperVadSegment>,
+    n_samples: usize,
+) -> Vec<Range<usize>> {
+    segments
+        .into_iter()
+        .filter_map(|segment| {
+            let start =
+                ((segment.start.max(0.0) * SAMPLES_PER_CENTISECOND) as usize).min(n_samples);
+            let end = ((segment.end.max(0.0) * SAMPLES_PER_CENTISECOND) as usize).min(n_samples);
+            (start < end).then_some(start..end)
+        })
+        .collect()
+}
+
 impl Drop for WhisperVadSegments {
     fn drop(&mut self) {
         unsafe { self.instance.lib.whisper_vad_free_segments(self.ptr) }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn speech_sample_ranges_skip_silence_and_clamp_to_input() {
+        let segments = [
+            WhisperVadSegment { start: 500.0, end: 650.0 },
+            WhisperVadSegment { start: 700.0, end: 700.0 },
+            WhisperVadSegment { start: 900.0, end: 2_000.0 },
+        ];
+
+        let ranges = speech_sample_ranges(segments, 160_000);
+
+        assert_eq!(ranges, vec![80_000..104_000, 144_000..160_000]);
+        assert!(speech_sample_ranges([], 160_000).is_empty());
+    }
+}
@@ -20,6 +20,28 @@ export interface paths {
         patch?: never;
         trace?: never;
     };
+    "/health/ready": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        /**
+         * Readiness probe.
+         * @description Returns HTTP 200 once every enabled runtime backend is ready and at least
+         *     one model is loaded, and 503 otherwise. `/health` stays a pure liveness
+         *     probe.
+         */
+        get: operations["get_ready"];
+        put?: never;
+        post?: never;
+        delete?: never;
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
     "/v1/agents/migrate": {
         parameters: {
             query?: never;
@@ -68,6 +90,22 @@ export interface paths {
         patch?: never;
         trace?: never;
     };
+    "/v1/audio/transcriptions/batch": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        get?: never;
+        put?: never;
+        post: operations["transcribe_batch"];
+        delete?: never;
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
     "/v1/audio/transcriptions/{id}": {
         parameters: {
             query?: never;
@@ -152,6 +190,22 @@ export interface paths {
         patch?: never;
         trace?: never;
     };
+    "/v1/chat/ws": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        get: operations["chat_socket"];
+        put?: never;
+        post?: never;
+        delete?: never;
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
     "/v1/completions": {
         parameters: {
             query?: never;
@@ -184,6 +238,22 @@ export interface paths {
         patch?: never;
         trace?: never;
     };
+    "/v1/images/edits": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        get?: never;
+        put?: never;
+        post: operations["edit_images"];
+        delete?: never;
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
     "/v1/images/generations": {
         parameters: {
             query?: never;
@@ -232,6 +302,22 @@ export interface paths {
         patch?: never;
         trace?: never;
     };
+    "/v1/images/generations/{id}/preview": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        get: operations["get_image_generation_preview"];
+        put?: never;
+        post?: never;
+        delete?: never;
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
     "/v1/images/generations/{id}/reference": {
         parameters: {
             query?: never;
@@ -805,7 +891,7 @@ export interface paths {
         get: operations["get_task"];
         put?: never;
         post?: never;
-        delete?: never;
+        delete: operations["delete_task"];
         options?: never;
         head?: never;
         patch?: never;
@@ -1459,6 +1545,19 @@ export interface components {
             /** @enum {string} */
             type: "tool";
         };
+        AudioTranscriptionBatchItemResponse: {
+            /** @description Original name of the uploaded file. */
+            filename: string;
+            /** @description Transcription task created for the file. */
+            task_id: string;
+        };
+        AudioTranscriptionBatchMultipartRequest: {
+            /** @description One or more audio files, each sent as a `file` field. */
+            file: Blob[];
+            language?: string | null;
+            model_id?: string | null;
+            prompt?: string | null;
+        };
         AudioTranscriptionRequest: {
             decode?: null | components["schemas"]["TranscribeDecodeRequest"];
             /**
@@ -1549,6 +1648,11 @@ export interface components {
         ChatCompletionRequest: {
             /** @description When `true`, continue generating from the last assistant message instead of starting a new turn. */
             continue_generation?: boolean;
+            /**
+             * Format: float
+             * @description Frequency penalty for local llama backends.
+             */
+            frequency_penalty?: number | null;
             /** @description Raw GBNF passed through to the local llama backend. */
             gbnf?: string | null;
             /** @description Optional chat session ID for stateful conversations. */
@@ -1577,18 +1681,35 @@ export interface components {
              * @description Number of completions to generate.
              */
             n?: number | null;
+            /**
+             * Format: int32
+             * @description Leading prompt tokens (e.g. the system prompt) preserved when a local
+             *     llama session shifts its context window.
+             */
+            n_keep?: number | null;
             /**
              * Format: float
              * @description Presence penalty for local llama backends.
              */
             presence_penalty?: number | null;
             reasoning_effort?: null | components["schemas"]["ChatReasoningEffort"];
+            /**
+             * Format: int32
+             * @description Number of recent tokens the penalties look back over for local llama
+             *     backends; `-1` uses the whole context.
+             */
+            repeat_last_n?: number | null;
             /**
              * Format: float
              * @description Repetition penalty for local llama backends.
              */
             repetition_penalty?: number | null;
             response_format?: null | components["schemas"]["ChatResponseFormat"];
+            /**
+             * Format: int32
+             * @description Sampler seed for reproducible output from local llama backends.
+             */
+            seed?: number | null;
             stop?: null | components["schemas"]["StopSequences"];
             /** @description When `true`, the response is streamed token-by-token using SSE. */
             stream?: boolean;
@@ -1599,6 +1720,13 @@ export interface components {
              */
             temperature?: number | null;
             thinking?: null | components["schemas"]["ChatThinkingConfig"];
+            tool_choice?: null | components["schemas"]["ChatToolChoice"];
+            /**
+             * @description Functions a local model may call. Its output is constrained to plain
+             *     text or a single call, which is returned as `tool_calls`. Cloud models
+             *     reject requests that carry tools.
+             */
+            tools?: components["schemas"]["ChatTool"][];
             /**
              * Format: int32
              * @description Top-k sampling limit for local llama backends.
@@ -1672,6 +1800,14 @@ export interface components {
             /** @enum {string} */
             type: "refusal";
         };
+        /** @description Function definition offered to the model through `tools`. */
+        ChatFunctionDefinition: {
+            description?: string | null;
+            name: string;
+            /** @description JSON Schema for the call arguments. */
+            parameters?: unknown;
+            strict?: boolean | null;
+        };
         /** @description A single message in the conversation history. */
         ChatMessage: {
             content?: null | components["schemas"]["ChatMessageContent"];
@@ -1716,6 +1852,14 @@ export interface components {
          * @enum {string}
          */
         ChatModelSource: "local" | "cloud";
+        /** @description Forces a call of one specific function. */
+        ChatNamedToolChoice: {
+            function: components["schemas"]["ChatNamedToolChoiceFunction"];
+            type?: string;
+        };
+        ChatNamedToolChoiceFunction: {
+            name: string;
+        };
         ChatPromptTokensDetails: {
             /** Format: int32 */
             cached_tokens?: number;
@@ -1738,6 +1882,48 @@ export interface components {
             schema: unknown;
             strict?: boolean | null;
         };
+        /** @description Client frame accepted by the `GET /v1/chat/ws` WebSocket. */
+        ChatSocketClientMessage: {
+            content: string;
+            /** Format: int32 */
+            max_tokens?: number | null;
+            /** @description Unified model identifier from `/v1/models`. */
+            model?: string;
+            request_id?: string | null;
+            /** Format: float */
+            temperature?: number | null;
+            /** Format: float */
+            top_p?: number | null;
+            /** @enum {string} */
+            type: "chat.input";
+        } | {
+            request_id?: string | null;
+            /** @enum {string} */
+            type: "chat.cancel";
+        };
+        /** @description Server frame emitted on the `GET /v1/chat/ws` WebSocket. */
+        ChatSocketServerMessage: {
+            session_id: string;
+            /** @enum {string} */
+            type: "chat.session";
+        } | {
+            content: string;
+            request_id?: string | null;
+            /** @enum {string} */
+            type: "chat.token";
+        } | {
+            finish_reason?: string | null;
+            request_id?: string | null;
+            /** @enum {string} */
+            type: "chat.done";
+        } | {
+            code: string;
+            i18n?: null | components["schemas"]["I18nPayload"];
+            message: string;
+            request_id?: string | null;
+            /** @enum {string} */
+            type: "chat.error";
+        };
         /** @description Streaming controls accepted by `POST /v1/chat/completions`. */
         ChatStreamOptions: {
             /** @description Whether the final chunk should include a usage payload. */
@@ -1755,11 +1941,23 @@ export interface components {
          * @enum {string}
          */
         ChatThinkingType: "enabled" | "disabled";
+        /** @description OpenAI-style tool declaration accepted by `POST /v1/chat/completions`. */
+        ChatTool: {
+            function: components["schemas"]["ChatFunctionDefinition"];
+            type?: string;
+        };
         ChatToolCall: {
             function: components["schemas"]["ChatToolFunction"];
             id?: string | null;
             type?: string;
         };
+        /**
+         * @description Controls whether and which tool the model calls: `"none"`, `"auto"`
+         *     (the default when tools are given), `"required"`, or a named function.
+         */
+        ChatToolChoice: components["schemas"]["ChatToolChoiceMode"] | components["schemas"]["ChatNamedToolChoice"];
+        /** @enum {string} */
+        ChatToolChoiceMode: "none" | "auto" | "required";
         ChatToolFunction: {
             arguments?: string;
             name: string;
@@ -1788,6 +1986,11 @@ export interface components {
         };
         /** @description Request body for `POST /v1/completions`. */
         CompletionRequest: {
+            /**
+             * Format: float
+             * @description Frequency penalty for local llama backends.
+             */
+            frequency_penalty?: number | null;
             /** @description Raw GBNF passed through to the local llama backend. */
             gbnf?: string | null;
             /** @description Legacy llama.cpp-compatible top-level JSON schema field. */
@@ -1812,6 +2015,12 @@ export interface components {
              * @description Number of completions to generate.
              */
             n?: number | null;
+            /**
+             * Format: int32
+             * @description Leading prompt tokens (e.g. the system prompt) preserved when a local
+             *     llama session shifts its context window.
+             */
+            n_keep?: number | null;
             /**
              * Format: float
              * @description Presence penalty for local llama backends.
@@ -1819,12 +2028,23 @@ export interface components {
             presence_penalty?: number | null;
             /** @description Raw prompt for completion-style generation. */
             prompt: string;
+            /**
+             * Format: int32
+             * @description Number of recent tokens the penalties look back over for local llama
+             *     backends; `-1` uses the whole context.
+             */
+            repeat_last_n?: number | null;
             /**
              * Format: float
              * @description Repetition penalty for local llama backends.
              */
             repetition_penalty?: number | null;
             response_format?: null | components["schemas"]["ChatResponseFormat"];
+            /**
+             * Format: int32
+             * @description Sampler seed for reproducible output from local llama backends.
+             */
+            seed?: number | null;
             stop?: null | components["schemas"]["StopSequences"];
             /** @description Stream the result using SSE. */
             stream?: boolean;
@@ -1988,6 +2208,25 @@ export interface components {
         I18nPayload: {
             [key: string]: components["schemas"]["I18nMessageRef"];
         };
+        ImageEditMultipartRequest: {
+            /**
+             * Format: binary
+             * @description PNG or JPEG image to edit.
+             */
+            image: Blob;
+            model: string;
+            model_id?: string | null;
+            /** Format: int32 */
+            n?: number | null;
+            negative_prompt?: string | null;
+            prompt: string;
+            /** Format: int64 */
+            seed?: number | null;
+            /** Format: int32 */
+            steps?: number | null;
+            /** Format: float */
+            strength?: number | null;
+        };
         /** @description Request body for `POST /v1/images/generations`. */
         ImageGenerationRequest: {
             /**
@@ -2033,6 +2272,13 @@ export interface components {
             n?: number;
             /** @description Negative text prompt (things to avoid in the generated image). */
             negative_prompt?: string | null;
+            /**
+             * Format: int32
+             * @description Denoising steps between low-resolution previews, served from
+             *     `GET /v1/images/generations/{id}/preview` while the task runs. Requires a
+             *     model loaded with a TAESD; omit to disable previews.
+             */
+            preview_interval?: number | null;
             /** @description Text description of the desired image. */
             prompt: string;
             /** @description Sampling method (`"euler"`, `"euler_a"`, `"lcm"`, etc., `"auto"`). */
@@ -2321,6 +2567,8 @@ export interface components {
              * @description Training context window length reported by the loaded model.
              */
             training_context_length?: number | null;
+            /** @description Native library build serving the model, when the backend reports one. */
+            version?: string | null;
         };
         OpenAiError: {
             code?: string | null;
@@ -2538,6 +2786,14 @@ export interface components {
             /** Format: double */
             output: number;
         };
+        ReadinessResponse: {
+            /** @description Status of every enabled runtime backend. */
+            backends: components["schemas"]["BackendStatusResponse"][];
+            /** @description Backends that currently hold a loaded model. */
+            loaded_backends: string[];
+            /** @description `"ready"` or `"not_ready"`. */
+            status: string;
+        };
         RecentWorkspaceResponse: {
             /** Format: int64 */
             lastOpenedAt: number;
@@ -2799,6 +3055,11 @@ export interface components {
             images?: string[] | null;
             /** @description Absolute output path for file-producing utility tasks such as FFmpeg conversion. */
             output_path?: string | null;
+            /**
+             * @description Seeds actually used for each image in `images`, in the same order.
+             *     Omitted when the backend does not report them.
+             */
+            seeds?: number[] | null;
             /** @description Timed text segments, present for Whisper transcriptions with timestamps. */
             segments?: components["schemas"]["TimedTextSegmentResponse"][] | null;
             /** @description Text content, present for `whisper` and other text-producing task results. */
@@ -2843,6 +3104,7 @@ export interface components {
             /** Format: float */
             temperature_inc?: number | null;
             token_timestamps?: boolean | null;
+            translate?: boolean | null;
             /** Format: float */
             word_thold?: number | null;
         };
@@ -2904,6 +3166,8 @@ export interface components {
             temperature_inc?: number | null;
             /** @description Enable token-level timestamps. */
             token_timestamps?: boolean | null;
+            /** @description Translate the transcript into English. */
+            translate?: boolean | null;
             /**
              * Format: float
              * @description Word timestamp probability threshold.
@@ -3386,6 +3650,35 @@ export interface operations {
             };
         };
     };
+    get_ready: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description All enabled backends are ready and a model is loaded */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ReadinessResponse"];
+                };
+            };
+            /** @description A backend is not ready or no model is loaded */
+            503: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ReadinessResponse"];
+                };
+            };
+        };
+    };
     migrate_workspace: {
         parameters: {
             query?: never;
@@ -3575,6 +3868,45 @@ export interface operations {
             };
         };
     };
+    transcribe_batch: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        /** @description Upload several audio files as repeated `file` fields; one task is created per file. */
+        requestBody: {
+            content: {
+                "multipart/form-data": components["schemas"]["AudioTranscriptionBatchMultipartRequest"];
+            };
+        };
+        responses: {
+            /** @description Tasks accepted */
+            202: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["AudioTranscriptionBatchItemResponse"][];
+                };
+            };
+            /** @description Bad request (missing, empty or oversized files) */
+            400: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content?: never;
+            };
+            /** @description Backend error */
+            500: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content?: never;
+            };
+        };
+    };
     get_audio_transcription: {
         parameters: {
             query?: never;
@@ -3718,6 +4050,31 @@ export interface operations {
             };
         };
     };
+    chat_socket: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description WebSocket upgrade for bidirectional chat on a persistent session */
+            101: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content?: never;
+            };
+            /** @description Bad request */
+            400: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content?: never;
+            };
+        };
+    };
     list_chat_models: {
         parameters: {
             query?: never;
@@ -3825,6 +4182,45 @@ export interface operations {
             };
         };
     };
+    edit_images: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        /** @description Edit an uploaded image (img2img). The `image` file field is required. */
+        requestBody: {
+            content: {
+                "multipart/form-data": components["schemas"]["ImageEditMultipartRequest"];
+            };
+        };
+        responses: {
+            /** @description Task accepted */
+            202: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["OperationAcceptedResponse"];
+                };
+            };
+            /** @description Bad request (invalid parameters or image) */
+            400: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content?: never;
+            };
+            /** @description Backend error */
+            500: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content?: never;
+            };
+        };
+    };
     list_image_generations: {
         parameters: {
             query?: never;
@@ -3964,6 +4360,41 @@ export interface operations {
             };
         };
     };
+    get_image_generation_preview: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path: {
+                /** @description Image generation task ID */
+                id: string;
+            };
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description Latest preview image bytes */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content?: never;
+            };
+            /** @description Task or preview not found */
+            404: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content?: never;
+            };
+            /** @description Backend error */
+            500: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content?: never;
+            };
+        };
+    };
     get_image_generation_reference: {
         parameters: {
             query?: never;
@@ -5364,6 +5795,50 @@ export interface operations {
             };
         };
     };
+    delete_task: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path: {
+                /** @description ID of the task to cancel */
+                id: string;
+            };
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description Task cancelled */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["TaskResponse"];
+                };
+            };
+            /** @description Bad request */
+            400: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content?: never;
+            };
+            /** @description Task not found */
+            404: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content?: never;
+            };
+            /** @description Backend error */
+            500: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content?: never;
+            };
+        };
+    };
     cancel_task: {
         parameters: {
             query?: never;
@@ -0,0 +1,173 @@
+from http import HTTPStatus
+from typing import Any, cast
+
+import httpx
+
+from ... import errors
+from ...client import AuthenticatedClient, Client
+from ...models.audio_transcription_batch_item_response import (
+    AudioTranscriptionBatchItemResponse,
+)
+from ...models.audio_transcription_batch_multipart_request import (
+    AudioTranscriptionBatchMultipartRequest,
+)
+from ...types import Response
+
+
+def _get_kwargs(
+    *,
+    body: AudioTranscriptionBatchMultipartRequest,
+) -> dict[str, Any]:
+    headers: dict[str, Any] = {}
+
+    _kwargs: dict[str, Any] = {
+        "method": "post",
+        "url": "/v1/audio/transcriptions/batch",
+    }
+
+    _kwargs["files"] = body.to_multipart()
+
+    _kwargs["headers"] = headers
+    return _kwargs
+
+
+def _parse_response(
+    *, client: AuthenticatedClient | Client, response: httpx.Response
+) -> Any | list[AudioTranscriptionBatchItemResponse] | None:
+    if response.status_code == 202:
+        response_202 = []
+        _response_202 = response.json()
+        for response_202_item_data in _response_202:
+            response_202_item = AudioTranscriptionBatchItemResponse.from_dict(
+                response_202_item_data
+            )
+
+            response_202.append(response_202_item)
+
+        return response_202
+
+    if response.status_code == 400:
+        response_400 = cast(Any, None)
+        return response_400
+
+    if response.status_code == 500:
+        response_500 = cast(Any, None)
+        return response_500
+
+    if client.raise_on_unexpected_status:
+        raise errors.UnexpectedStatus(response.status_code, response.content)
+    else:
+        return None
+
+
+def _build_response(
+    *, client: AuthenticatedClient | Client, response: httpx.Response
+) -> Response[Any | list[AudioTranscriptionBatchItemResponse]]:
+    return Response(
+        status_code=HTTPStatus(response.status_code),
+        content=response.content,
+        headers=response.headers,
+        parsed=_parse_response(client=client, response=response),
+    )
+
+
+def sync_detailed(
+    *,
+    client: AuthenticatedClient | Client,
+    body: AudioTranscriptionBatchMultipartRequest,
+) -> Response[Any | list[AudioTranscriptionBatchItemResponse]]:
+    """
+    Args:
+        body (AudioTranscriptionBatchMultipartRequest):
+
+    Raises:
+        errors.UnexpectedStatus: If the server returns an undocumented status code and Client.raise_on_unexpected_status is True.
+        httpx.TimeoutException: If the request takes longer than Client.timeout.
+
+    Returns:
+        Response[Any | list[AudioTranscriptionBatchItemResponse]]
+    """
+
+    kwargs = _get_kwargs(
+        body=body,
+    )
+
+    response = client.get_httpx_client().request(
+        **kwargs,
+    )
+
+    return _build_response(client=client, response=response)
+
+
+def sync(
+    *,
+    client: AuthenticatedClient | Client,
+    body: AudioTranscriptionBatchMultipartRequest,
+) -> Any | list[AudioTranscriptionBatchItemResponse] | None:
+    """
+    Args:
+        body (AudioTranscriptionBatchMultipartRequest):
+
+    Raises:
+        errors.UnexpectedStatus: If the server returns an undocumented status code and Client.raise_on_unexpected_status is True.
+        httpx.TimeoutException: If the request takes longer than Client.timeout.
+
+    Returns:
+        Any | list[AudioTranscriptionBatchItemResponse]
+    """
+
+    return sync_detailed(
+        client=client,
+        body=body,
+    ).parsed
+
+
+async def asyncio_detailed(
+    *,
+    client: AuthenticatedClient | Client,
+    body: AudioTranscriptionBatchMultipartRequest,
+) -> Response[Any | list[AudioTranscriptionBatchItemResponse]]:
+    """
+    Args:
+        body (AudioTranscriptionBatchMultipartRequest):
+
+    Raises:
+        errors.UnexpectedStatus: If the server returns an undocumented status code and Client.raise_on_unexpected_status is True.
+        httpx.TimeoutException: If the request takes longer than Client.timeout.
+
+    Returns:
+        Response[Any | list[AudioTranscriptionBatchItemResponse]]
+    """
+
+    kwargs = _get_kwargs(
+        body=body,
+    )
+
+    response = await client.get_async_httpx_client().request(**kwargs)
+
+    return _build_response(client=client, response=response)
+
+
+async def asyncio(
+    *,
+    client: AuthenticatedClient | Client,
+    body: AudioTranscriptionBatchMultipartRequest,
+) -> Any | list[AudioTranscriptionBatchItemResponse] | None:
+    """
+    Args:
+        body (AudioTranscriptionBatchMultipartRequest):
+
+    Raises:
+        errors.UnexpectedStatus: If the server returns an undocumented status code and Client.raise_on_unexpected_status is True.
+        httpx.TimeoutException: If the request takes longer than Client.timeout.
+
+    Returns:
+        Any | list[AudioTranscriptionBatchItemResponse]
+    """
+
+    return (
+        await asyncio_detailed(
+            client=client,
+            body=body,
+        )
+    ).parsed
@@ -59,6 +59,8 @@ from .agent_tool_choice_input_type_2 import AgentToolChoiceInputType2
 from .agent_tool_choice_input_type_2_type import AgentToolChoiceInputType2Type
 from .agent_tool_choice_input_type_3 import AgentToolChoiceInputType3
 from .agent_tool_choice_input_type_3_type import AgentToolChoiceInputType3Type
+from .audio_transcription_batch_item_response import AudioTranscriptionBatchItemResponse
+from .audio_transcription_batch_multipart_request import AudioTranscriptionBatchMultipartRequest
 from .audio_transcription_request import AudioTranscriptionRequest
 from .audio_transcription_request_data import AudioTranscriptionRequestData
 from .audio_transcription_result_data import AudioTranscriptionResultData
@@ -85,10 +87,13 @@ from .chat_content_part_type_5 import ChatContentPartType5
 from .chat_content_part_type_5_type import ChatContentPartType5Type
 from .chat_content_part_type_6 import ChatContentPartType6
 from .chat_content_part_type_6_type import ChatContentPartType6Type
+from .chat_function_definition import ChatFunctionDefinition
 from .chat_message import ChatMessage
 from .chat_model_capabilities import ChatModelCapabilities
 from .chat_model_option import ChatModelOption
 from .chat_model_source import ChatModelSource
+from .chat_named_tool_choice import ChatNamedToolChoice
+from .chat_named_tool_choice_function import ChatNamedToolChoiceFunction
 from .chat_prompt_tokens_details import ChatPromptTokensDetails
 from .chat_reasoning_effort import ChatReasoningEffort
 from .chat_response_format import ChatResponseFormat
@@ -97,7 +102,9 @@ from .chat_response_json_schema import ChatResponseJsonSchema
 from .chat_stream_options import ChatStreamOptions
 from .chat_thinking_config import ChatThinkingConfig
 from .chat_thinking_type import ChatThinkingType
+from .chat_tool import ChatTool
 from .chat_tool_call import ChatToolCall
+from .chat_tool_choice_mode import ChatToolChoiceMode
 from .chat_tool_function import ChatToolFunction
 from .chat_verbosity import ChatVerbosity
 from .complete_setup_request import CompleteSetupRequest
@@ -119,6 +126,7 @@ from .health_response import HealthResponse
 from .i18n_message_ref import I18NMessageRef
 from .i18n_message_ref_params import I18NMessageRefParams
 from .i18n_payload import I18NPayload
+from .image_edit_multipart_request import ImageEditMultipartRequest
 from .image_generation_request import ImageGenerationRequest
 from .image_generation_request_data import ImageGenerationRequestData
 from .image_generation_result_data import ImageGenerationResultData
@@ -189,6 +197,7 @@ from .plugin_settings_contribution import PluginSettingsContribution
 from .plugin_sidebar_contribution import PluginSidebarContribution
 from .pricing_request import PricingRequest
 from .pricing_response import PricingResponse
+from .readiness_response import ReadinessResponse
 from .recent_workspace_response import RecentWorkspaceResponse
 from .render_subtitle_request import RenderSubtitleRequest
 from .render_subtitle_response import RenderSubtitleResponse
@@ -322,6 +331,8 @@ __all__ = (
     "AgentToolChoiceInputType2Type",
     "AgentToolChoiceInputType3",
     "AgentToolChoiceInputType3Type",
+    "AudioTranscriptionBatchItemResponse",
+    "AudioTranscriptionBatchMultipartRequest",
     "AudioTranscriptionRequest",
     "AudioTranscriptionRequestData",
     "AudioTranscriptionResultData",
@@ -348,10 +359,13 @@ __all__ = (
     "ChatContentPartType5Type",
     "ChatContentPartType6",
     "ChatContentPartType6Type",
+    "ChatFunctionDefinition",
     "ChatMessage",
     "ChatModelCapabilities",
     "ChatModelOption",
     "ChatModelSource",
+    "ChatNamedToolChoice",
+    "ChatNamedToolChoiceFunction",
     "ChatPromptTokensDetails",
     "ChatReasoningEffort",
     "ChatResponseFormat",
@@ -360,7 +374,9 @@ __all__ = (
     "ChatStreamOptions",
     "ChatThinkingConfig",
     "ChatThinkingType",
+    "ChatTool",
     "ChatToolCall",
+    "ChatToolChoiceMode",
     "ChatToolFunction",
     "ChatVerbosity",
     "CompleteSetupRequest",
@@ -382,6 +398,7 @@ __all__ = (
     "I18NMessageRef",
     "I18NMessageRefParams",
     "I18NPayload",
+    "ImageEditMultipartRequest",
     "ImageGenerationRequest",
     "ImageGenerationRequestData",
     "ImageGenerationResultData",
@@ -446,6 +463,7 @@ __all__ = (
     "PluginSidebarContribution",
     "PricingRequest",
     "PricingResponse",
+    "ReadinessResponse",
     "RecentWorkspaceResponse",
     "RenderSubtitleRequest",
     "RenderSubtitleResponse",
@@ -0,0 +1,154 @@
+from __future__ import annotations
+
+from collections.abc import Mapping
+from io import BytesIO
+from typing import Any, TypeVar, cast
+
+from attrs import define as _attrs_define
+from attrs import field as _attrs_field
+
+from .. import types
+from ..types import UNSET, File, Unset
+
+T = TypeVar("T", bound="AudioTranscriptionBatchMultipartRequest")
+
+
+@_attrs_define
+class AudioTranscriptionBatchMultipartRequest:
+    """
+    Attributes:
+        file (list[File]): One or more audio files, each sent as a `file` field.
+        language (None | str | Unset):
+        model_id (None | str | Unset):
+        prompt (None | str | Unset):
+    """
+
+    file: list[File]
+    language: None | str | Unset = UNSET
+    model_id: None | str | Unset = UNSET
+    prompt: None | str | Unset = UNSET
+    additional_properties: dict[str, Any] = _attrs_field(init=False, factory=dict)
+
+    def to_dict(self) -> dict[str, Any]:
+        file = []
+        for file_item_data in self.file:
+            file_item = file_item_data.to_tuple()
+
+            file.append(file_item)
+
+        language: None | str | Unset
+        if isinstance(self.language, Unset):
+            language = UNSET
+        else:
+            language = self.language
+
+        model_id: None | str | Unset
+        if isinstance(self.model_id, Unset):
+            model_id = UNSET
+        else:
+            model_id = self.model_id
+
+        prompt: None | str | Unset
+        if isinstance(self.prompt, Unset):
+            prompt = UNSET
+        else:
+            prompt = self.prompt
+
+        field_dict: dict[str, Any] = {}
+        field_dict.update(self.additional_properties)
+        field_dict.update(
+            {
+                "file": file,
+            }
+        )
+        if language is not UNSET:
+            field_dict["language"] = language
+        if model_id is not UNSET:
+            field_dict["model_id"] = model_id
+        if prompt is not UNSET:
+            field_dict["prompt"] = prompt
+
+        return field_dict
+
+    def to_multipart(self) -> types.RequestFiles:
+        files: types.RequestFiles = []
+
+        for file_item_element in self.file:
+            files.append(("file", file_item_element.to_tuple()))
+
+        if not isinstance(self.language, Unset) and self.language is not None:
+            files.append(("language", (None, str(self.language).encode(), "text/plain")))
+
+        if not isinstance(self.model_id, Unset) and self.model_id is not None:
+            files.append(("model_id", (None, str(self.model_id).encode(), "text/plain")))
+
+        if not isinstance(self.prompt, Unset) and self.prompt is not None:
+            files.append(("prompt", (None, str(self.prompt).encode(), "text/plain")))
+
+        for prop_name, prop in self.additional_properties.items():
+            files.append((prop_name, (None, str(prop).encode(), "text/plain")))
+
+        return files
+
+    @classmethod
+    def from_dict(cls: type[T], src_dict: Mapping[str, Any]) -> T:
+        d = dict(src_dict)
+        file = []
+        _file = d.pop("file")
+        for file_item_data in _file:
+            file_item = File(payload=BytesIO(file_item_data))
+
+            file.append(file_item)
+
+        def _parse_language(data: object) -> None | str | Unset:
+            if data is None:
+                return data
+            if isinstance(data, Unset):
+                return data
+            return cast(None | str | Unset, data)
+
+        language = _parse_language(d.pop("language", UNSET))
+
+        def _parse_model_id(data: object) -> None | str | Unset:
+            if data is None:
+                return data
+            if isinstance(data, Unset):
+                return data
+            return cast(None | str | Unset, data)
+
+        model_id = _parse_model_id(d.pop("model_id", UNSET))
+
+        def _parse_prompt(data: object) -> None | str | Unset:
+            if data is None:
+                return data
+            if isinstance(data, Unset):
+                return data
+            return cast(None | str | Unset, data)
+
+        prompt = _parse_prompt(d.pop("prompt", UNSET))
+
+        audio_transcription_batch_multipart_request = cls(
+            file=file,
+            language=language,
+            model_id=model_id,
+            prompt=prompt,
+        )
+
+        audio_transcription_batch_multipart_request.additional_properties = d
+        return audio_transcription_batch_multipart_request
+
+    @property
+    def additional_keys(self) -> list[str]:
+        return list(self.additional_properties.keys())
+
+    def __getitem__(self, key: str) -> Any:
+        return self.additional_properties[key]
+
+    def __setitem__(self, key: str, value: Any) -> None:
+        self.additional_properties[key] = value
+
+    def __delitem__(self, key: str) -> None:
+        del self.additional_properties[key]
+
+    def __contains__(self, key: str) -> bool:
+        return key in self.additional_properties
@@ -6,14 +6,17 @@ from typing import TYPE_CHECKING, Any, TypeVar, cast
 from attrs import define as _attrs_define
 
 from ..models.chat_reasoning_effort import ChatReasoningEffort
+from ..models.chat_tool_choice_mode import ChatToolChoiceMode
 from ..models.chat_verbosity import ChatVerbosity
 from ..types import UNSET, Unset
 
 if TYPE_CHECKING:
     from ..models.chat_message import ChatMessage
+    from ..models.chat_named_tool_choice import ChatNamedToolChoice
     from ..models.chat_response_format import ChatResponseFormat
     from ..models.chat_stream_options import ChatStreamOptions
     from ..models.chat_thinking_config import ChatThinkingConfig
+    from ..models.chat_tool import ChatTool
 
 
 T = TypeVar("T", bound="ChatCompletionRequest")
@@ -36,14 +39,24 @@ class ChatCompletionRequest:
             `GET /v1/chat/models` remains a compatibility wrapper that reuses the same ids.
         n (int | None | Unset): Number of completions to generate.
         presence_penalty (float | None | Unset): Presence penalty for local llama backends.
+        frequency_penalty (float | None | Unset): Frequency penalty for local llama backends.
         reasoning_effort (ChatReasoningEffort | None | Unset):
         repetition_penalty (float | None | Unset): Repetition penalty for local llama backends.
+        repeat_last_n (int | None | Unset): Number of recent tokens the penalties look back over for local llama
+            backends; `-1` uses the whole context.
+        n_keep (int | None | Unset): Leading prompt tokens (e.g. the system prompt) preserved when a local
+            llama session shifts its context window.
+        seed (int | None | Unset): Sampler seed for reproducible output from local llama backends.
         response_format (ChatResponseFormat | None | Unset):
         stop (list[str] | None | str | Unset):
         stream (bool | Unset): When `true`, the response is streamed token-by-token using SSE.
         stream_options (ChatStreamOptions | None | Unset):
         temperature (float | None | Unset): Sampling temperature in [0, 2].
         thinking (ChatThinkingConfig | None | Unset):
+        tool_choice (ChatNamedToolChoice | ChatToolChoiceMode | None | Unset):
+        tools (list[ChatTool] | Unset): Functions a local model may call. Its output is constrained to plain
+            text or a single call, which is returned as `tool_calls`. Cloud models
+            reject requests that carry tools.
         top_k (int | None | Unset): Top-k sampling limit for local llama backends.
         top_p (float | None | Unset): Nucleus sampling threshold in (0, 1].
         verbosity (ChatVerbosity | None | Unset):
@@ -59,19 +72,26 @@ class ChatCompletionRequest:
     model: str | Unset = UNSET
     n: int | None | Unset = UNSET
     presence_penalty: float | None | Unset = UNSET
+    frequency_penalty: float | None | Unset = UNSET
     reasoning_effort: ChatReasoningEffort | None | Unset = UNSET
     repetition_penalty: float | None | Unset = UNSET
+    repeat_last_n: int | None | Unset = UNSET
+    n_keep: int | None | Unset = UNSET
+    seed: int | None | Unset = UNSET
     response_format: ChatResponseFormat | None | Unset = UNSET
     stop: list[str] | None | str | Unset = UNSET
     stream: bool | Unset = UNSET
     stream_options: ChatStreamOptions | None | Unset = UNSET
     temperature: float | None | Unset = UNSET
     thinking: ChatThinkingConfig | None | Unset = UNSET
+    tool_choice: ChatNamedToolChoice | ChatToolChoiceMode | None | Unset = UNSET
+    tools: list[ChatTool] | Unset = UNSET
     top_k: int | None | Unset = UNSET
     top_p: float | None | Unset = UNSET
     verbosity: ChatVerbosity | None | Unset = UNSET
 
     def to_dict(self) -> dict[str, Any]:
+        from ..models.chat_named_tool_choice import ChatNamedToolChoice
         from ..models.chat_response_format import ChatResponseFormat
         from ..models.chat_stream_options import ChatStreamOptions
         from ..models.chat_thinking_config import ChatThinkingConfig
@@ -123,6 +143,12 @@ class ChatCompletionRequest:
         else:
             presence_penalty = self.presence_penalty
 
+        frequency_penalty: float | None | Unset
+        if isinstance(self.frequency_penalty, Unset):
+            frequency_penalty = UNSET
+        else:
+            frequency_penalty = self.frequency_penalty
+
         reasoning_effort: None | str | Unset
         if isinstance(self.reasoning_effort, Unset):
             reasoning_effort = UNSET
@@ -137,6 +163,24 @@ class ChatCompletionRequest:
         else:
             repetition_penalty = self.repetition_penalty
 
+        repeat_last_n: int | None | Unset
+        if isinstance(self.repeat_last_n, Unset):
+            repeat_last_n = UNSET
+        else:
+            repeat_last_n = self.repeat_last_n
+
+        n_keep: int | None | Unset
+        if isinstance(self.n_keep, Unset):
+            n_keep = UNSET
+        else:
+            n_keep = self.n_keep
+
+        seed: int | None | Unset
+        if isinstance(self.seed, Unset):
+            seed = UNSET
+        else:
+            seed = self.seed
+
         response_format: dict[str, Any] | None | Unset
         if isinstance(self.response_format, Unset):
             response_format = UNSET
@@ -178,6 +222,23 @@ class ChatCompletionRequest:
         else:
             thinking = self.thinking
 
+        tool_choice: dict[str, Any] | None | str | Unset
+        if isinstance(self.tool_choice, Unset):
+            tool_choice = UNSET
+        elif isinstance(self.tool_choice, ChatToolChoiceMode):
+            tool_choice = self.tool_choice.value
+        elif isinstance(self.tool_choice, ChatNamedToolChoice):
+            tool_choice = self.tool_choice.to_dict()
+        else:
+            tool_choice = self.tool_choice
+
+        tools: list[dict[str, Any]] | Unset = UNSET
+        if not isinstance(self.tools, Unset):
+            tools = []
+            for tools_item_data in self.tools:
+                tools_item = tools_item_data.to_dict()
+                tools.append(tools_item)
+
         top_k: int | None | Unset
         if isinstance(self.top_k, Unset):
             top_k = UNSET
@@ -223,10 +284,18 @@ class ChatCompletionRequest:
             field_dict["n"] = n
         if presence_penalty is not UNSET:
             field_dict["presence_penalty"] = presence_penalty
+        if frequency_penalty is not UNSET:
+            field_dict["frequency_penalty"] = frequency_penalty
         if reasoning_effort is not UNSET:
             field_dict["reasoning_effort"] = reasoning_effort
         if repetition_penalty is not UNSET:
             field_dict["repetition_penalty"] = repetition_penalty
+        if repeat_last_n is not UNSET:
+            field_dict["repeat_last_n"] = repeat_last_n
+        if n_keep is not UNSET:
+            field_dict["n_keep"] = n_keep
+        if seed is not UNSET:
+            field_dict["seed"] = seed
         if response_format is not UNSET:
             field_dict["response_format"] = response_format
         if stop is not UNSET:
@@ -239,6 +308,10 @@ class ChatCompletionRequest:
             field_dict["temperature"] = temperature
         if thinking is not UNSET:
             field_dict["thinking"] = thinking
+        if tool_choice is not UNSET:
+            field_dict["tool_choice"] = tool_choice
+        if tools is not UNSET:
+            field_dict["tools"] = tools
         if top_k is not UNSET:
             field_dict["top_k"] = top_k
         if top_p is not UNSET:
@@ -251,9 +324,11 @@ class ChatCompletionRequest:
     @classmethod
     def from_dict(cls: type[T], src_dict: Mapping[str, Any]) -> T:
         from ..models.chat_message import ChatMessage
+        from ..models.chat_named_tool_choice import ChatNamedToolChoice
         from ..models.chat_response_format import ChatResponseFormat
         from ..models.chat_stream_options import ChatStreamOptions
         from ..models.chat_thinking_config import ChatThinkingConfig
+        from ..models.chat_tool import ChatTool
 
         d = dict(src_dict)
         messages = []
@@ -323,6 +398,15 @@ class ChatCompletionRequest:
 
         presence_penalty = _parse_presence_penalty(d.pop("presence_penalty", UNSET))
 
+        def _parse_frequency_penalty(data: object) -> float | None | Unset:
+            if data is None:
+                return data
+            if isinstance(data, Unset):
+                return data
+            return cast(float | None | Unset, data)
+
+        frequency_penalty = _parse_frequency_penalty(d.pop("frequency_penalty", UNSET))
+
         def _parse_reasoning_effort(data: object) -> ChatReasoningEffort | None | Unset:
             if data is None:
                 return data
@@ -351,6 +435,33 @@ class ChatCompletionRequest:
             d.pop("repetition_penalty", UNSET)
         )
 
+        def _parse_repeat_last_n(data: object) -> int | None | Unset:
+            if data is None:
+                return data
+            if isinstance(data, Unset):
+                return data
+            return cast(int | None | Unset, data)
+
+        repeat_last_n = _parse_repeat_last_n(d.pop("repeat_last_n", UNSET))
+
+        def _parse_n_keep(data: object) -> int | None | Unset:
+            if data is None:
+                return data
+            if isinstance(data, Unset):
+                return data
+            return cast(int | None | Unset, data)
+
+        n_keep = _parse_n_keep(d.pop("n_keep", UNSET))
+
+        def _parse_seed(data: object) -> int | None | Unset:
+            if data is None:
+                return data
+            if isinstance(data, Unset):
+                return data
+            return cast(int | None | Unset, data)
+
+        seed = _parse_seed(d.pop("seed", UNSET))
+
         def _parse_response_format(data: object) -> ChatResponseFormat | None | Unset:
             if data is None:
                 return data
@@ -430,6 +541,40 @@ class ChatCompletionRequest:
 
         thinking = _parse_thinking(d.pop("thinking", UNSET))
 
+        def _parse_tool_choice(data: object) -> ChatNamedToolChoice | ChatToolChoiceMode | None | Unset:
+            if data is None:
+                return data
+            if isinstance(data, Unset):
+                return data
+            try:
+                if not isinstance(data, str):
+                    raise TypeError()
+                tool_choice_type_0 = ChatToolChoiceMode(data)
+
+                return tool_choice_type_0
+            except (TypeError, ValueError, AttributeError, KeyError):
+                pass
+            try:
+                if not isinstance(data, dict):
+                    raise TypeError()
+                tool_choice_type_1 = ChatNamedToolChoice.from_dict(data)
+
+                return tool_choice_type_1
+            except (TypeError, ValueError, AttributeError, KeyError):
+                pass
+            return cast(ChatNamedToolChoice | ChatToolChoiceMode | None | Unset, data)
+
+        tool_choice = _parse_tool_choice(d.pop("tool_choice", UNSET))
+
+        _tools = d.pop("tools", UNSET)
+        tools: list[ChatTool] | Unset = UNSET
+        if _tools is not UNSET:
+            tools = []
+            for tools_item_data in _tools:
+                tools_item = ChatTool.from_dict(tools_item_data)
+
+                tools.append(tools_item)
+
         def _parse_top_k(data: object) -> int | None | Unset:
             if data is None:
                 return data
@@ -476,14 +621,20 @@ class ChatCompletionRequest:
             model=model,
             n=n,
             presence_penalty=presence_penalty,
+            frequency_penalty=frequency_penalty,
             reasoning_effort=reasoning_effort,
             repetition_penalty=repetition_penalty,
+            repeat_last_n=repeat_last_n,
+            n_keep=n_keep,
+            seed=seed,
             response_format=response_format,
             stop=stop,
             stream=stream,
             stream_options=stream_options,
             temperature=temperature,
             thinking=thinking,
+            tool_choice=tool_choice,
+            tools=tools,
             top_k=top_k,
             top_p=top_p,
             verbosity=verbosity,
@@ -0,0 +1,10 @@
+from enum import Enum
+
+
+class ChatToolChoiceMode(str, Enum):
+    AUTO = "auto"
+    NONE = "none"
+    REQUIRED = "required"
+
+    def __str__(self) -> str:
+        return str(self.value)
@@ -28,7 +28,13 @@ class CompletionRequest:
             When omitted, the first available chat-compatible model is used.
         n (int | None | Unset): Number of completions to generate.
         presence_penalty (float | None | Unset): Presence penalty for local llama backends.
+        frequency_penalty (float | None | Unset): Frequency penalty for local llama backends.
         repetition_penalty (float | None | Unset): Repetition penalty for local llama backends.
+        repeat_last_n (int | None | Unset): Number of recent tokens the penalties look back over for local llama
+            backends; `-1` uses the whole context.
+        n_keep (int | None | Unset): Leading prompt tokens (e.g. the system prompt) preserved when a local
+            llama session shifts its context window.
+        seed (int | None | Unset): Sampler seed for reproducible output from local llama backends.
         response_format (ChatResponseFormat | None | Unset):
         stop (list[str] | None | str | Unset):
         stream (bool | Unset): Stream the result using SSE.
@@ -45,7 +51,11 @@ class CompletionRequest:
     model: str | Unset = UNSET
     n: int | None | Unset = UNSET
     presence_penalty: float | None | Unset = UNSET
+    frequency_penalty: float | None | Unset = UNSET
     repetition_penalty: float | None | Unset = UNSET
+    repeat_last_n: int | None | Unset = UNSET
+    n_keep: int | None | Unset = UNSET
+    seed: int | None | Unset = UNSET
     response_format: ChatResponseFormat | None | Unset = UNSET
     stop: list[str] | None | str | Unset = UNSET
     stream: bool | Unset = UNSET
@@ -92,12 +102,36 @@ class CompletionRequest:
         else:
             presence_penalty = self.presence_penalty
 
+        frequency_penalty: float | None | Unset
+        if isinstance(self.frequency_penalty, Unset):
+            frequency_penalty = UNSET
+        else:
+            frequency_penalty = self.frequency_penalty
+
         repetition_penalty: float | None | Unset
         if isinstance(self.repetition_penalty, Unset):
             repetition_penalty = UNSET
         else:
             repetition_penalty = self.repetition_penalty
 
+        repeat_last_n: int | None | Unset
+        if isinstance(self.repeat_last_n, Unset):
+            repeat_last_n = UNSET
+        else:
+            repeat_last_n = self.repeat_last_n
+
+        n_keep: int | None | Unset
+        if isinstance(self.n_keep, Unset):
+            n_keep = UNSET
+        else:
+            n_keep = self.n_keep
+
+        seed: int | None | Unset
+        if isinstance(self.seed, Unset):
+            seed = UNSET
+        else:
+            seed = self.seed
+
         response_format: dict[str, Any] | None | Unset
         if isinstance(self.response_format, Unset):
             response_format = UNSET
@@ -156,8 +190,16 @@ class CompletionRequest:
             field_dict["n"] = n
         if presence_penalty is not UNSET:
             field_dict["presence_penalty"] = presence_penalty
+        if frequency_penalty is not UNSET:
+            field_dict["frequency_penalty"] = frequency_penalty
         if repetition_penalty is not UNSET:
             field_dict["repetition_penalty"] = repetition_penalty
+        if repeat_last_n is not UNSET:
+            field_dict["repeat_last_n"] = repeat_last_n
+        if n_keep is not UNSET:
+            field_dict["n_keep"] = n_keep
+        if seed is not UNSET:
+            field_dict["seed"] = seed
         if response_format is not UNSET:
             field_dict["response_format"] = response_format
         if stop is not UNSET:
@@ -229,6 +271,15 @@ class CompletionRequest:
 
         presence_penalty = _parse_presence_penalty(d.pop("presence_penalty", UNSET))
 
+        def _parse_frequency_penalty(data: object) -> float | None | Unset:
+            if data is None:
+                return data
+            if isinstance(data, Unset):
+                return data
+            return cast(float | None | Unset, data)
+
+        frequency_penalty = _parse_frequency_penalty(d.pop("frequency_penalty", UNSET))
+
         def _parse_repetition_penalty(data: object) -> float | None | Unset:
             if data is None:
                 return data
@@ -240,6 +291,33 @@ class CompletionRequest:
             d.pop("repetition_penalty", UNSET)
         )
 
+        def _parse_repeat_last_n(data: object) -> int | None | Unset:
+            if data is None:
+                return data
+            if isinstance(data, Unset):
+                return data
+            return cast(int | None | Unset, data)
+
+        repeat_last_n = _parse_repeat_last_n(d.pop("repeat_last_n", UNSET))
+
+        def _parse_n_keep(data: object) -> int | None | Unset:
+            if data is None:
+                return data
+            if isinstance(data, Unset):
+                return data
+            return cast(int | None | Unset, data)
+
+        n_keep = _parse_n_keep(d.pop("n_keep", UNSET))
+
+        def _parse_seed(data: object) -> int | None | Unset:
+            if data is None:
+                return data
+            if isinstance(data, Unset):
+                return data
+            return cast(int | None | Unset, data)
+
+        seed = _parse_seed(d.pop("seed", UNSET))
+
         def _parse_response_format(data: object) -> ChatResponseFormat | None | Unset:
             if data is None:
                 return data
@@ -312,7 +390,11 @@ class CompletionRequest:
             model=model,
             n=n,
             presence_penalty=presence_penalty,
+            frequency_penalty=frequency_penalty,
             repetition_penalty=repetition_penalty,
+            repeat_last_n=repeat_last_n,
+            n_keep=n_keep,
+            seed=seed,
             response_format=response_format,
             stop=stop,
             stream=stream,
@@ -0,0 +1,233 @@
+from __future__ import annotations
+
+from collections.abc import Mapping
+from io import BytesIO
+from typing import Any, TypeVar, cast
+
+from attrs import define as _attrs_define
+from attrs import field as _attrs_field
+
+from .. import types
+from ..types import UNSET, File, Unset
+
+T = TypeVar("T", bound="ImageEditMultipartRequest")
+
+
+@_attrs_define
+class ImageEditMultipartRequest:
+    """
+    Attributes:
+        image (File): PNG or JPEG image to edit.
+        model (str):
+        prompt (str):
+        model_id (None | str | Unset):
+        n (int | None | Unset):
+        negative_prompt (None | str | Unset):
+        seed (int | None | Unset):
+        steps (int | None | Unset):
+        strength (float | None | Unset):
+    """
+
+    image: File
+    model: str
+    prompt: str
+    model_id: None | str | Unset = UNSET
+    n: int | None | Unset = UNSET
+    negative_prompt: None | str | Unset = UNSET
+    seed: int | None | Unset = UNSET
+    steps: int | None | Unset = UNSET
+    strength: float | None | Unset = UNSET
+    additional_properties: dict[str, Any] = _attrs_field(init=False, factory=dict)
+
+    def to_dict(self) -> dict[str, Any]:
+        image = self.image.to_tuple()
+
+        model = self.model
+
+        model_id: None | str | Unset
+        if isinstance(self.model_id, Unset):
+            model_id = UNSET
+        else:
+            model_id = self.model_id
+
+        n: int | None | Unset
+        if isinstance(self.n, Unset):
+            n = UNSET
+        else:
+            n = self.n
+
+        negative_prompt: None | str | Unset
+        if isinstance(self.negative_prompt, Unset):
+            negative_prompt = UNSET
+        else:
+            negative_prompt = self.negative_prompt
+
+        prompt = self.prompt
+
+        seed: int | None | Unset
+        if isinstance(self.seed, Unset):
+            seed = UNSET
+        else:
+            seed = self.seed
+
+        steps: int | None | Unset
+        if isinstance(self.steps, Unset):
+            steps = UNSET
+        else:
+            steps = self.steps
+
+        strength: float | None | Unset
+        if isinstance(self.strength, Unset):
+            strength = UNSET
+        else:
+            strength = self.strength
+
+        field_dict: dict[str, Any] = {}
+        field_dict.update(self.additional_properties)
+        field_dict.update(
+            {
+                "image": image,
+                "model": model,
+                "prompt": prompt,
+            }
+        )
+        if model_id is not UNSET:
+            field_dict["model_id"] = model_id
+        if n is not UNSET:
+            field_dict["n"] = n
+        if negative_prompt is not UNSET:
+            field_dict["negative_prompt"] = negative_prompt
+        if seed is not UNSET:
+            field_dict["seed"] = seed
+        if steps is not UNSET:
+            field_dict["steps"] = steps
+        if strength is not UNSET:
+            field_dict["strength"] = strength
+
+        return field_dict
+
+    def to_multipart(self) -> types.RequestFiles:
+        files: types.RequestFiles = []
+
+        files.append(("image", self.image.to_tuple()))
+
+        files.append(("model", (None, str(self.model).encode(), "text/plain")))
+
+        if not isinstance(self.model_id, Unset) and self.model_id is not None:
+            files.append(("model_id", (None, str(self.model_id).encode(), "text/plain")))
+
+        if not isinstance(self.n, Unset) and self.n is not None:
+            files.append(("n", (None, str(self.n).encode(), "text/plain")))
+
+        if not isinstance(self.negative_prompt, Unset) and self.negative_prompt is not None:
+            files.append(("negative_prompt", (None, str(self.negative_prompt).encode(), "text/plain")))
+
+        files.append(("prompt", (None, str(self.prompt).encode(), "text/plain")))
+
+        if not isinstance(self.seed, Unset) and self.seed is not None:
+            files.append(("seed", (None, str(self.seed).encode(), "text/plain")))
+
+        if not isinstance(self.steps, Unset) and self.steps is not None:
+            files.append(("steps", (None, str(self.steps).encode(), "text/plain")))
+
+        if not isinstance(self.strength, Unset) and self.strength is not None:
+            files.append(("strength", (None, str(self.strength).encode(), "text/plain")))
+
+        for prop_name, prop in self.additional_properties.items():
+            files.append((prop_name, (None, str(prop).encode(), "text/plain")))
+
+        return files
+
+    @classmethod
+    def from_dict(cls: type[T], src_dict: Mapping[str, Any]) -> T:
+        d = dict(src_dict)
+        image = File(payload=BytesIO(d.pop("image")))
+
+        model = d.pop("model")
+
+        def _parse_model_id(data: object) -> None | str | Unset:
+            if data is None:
+                return data
+            if isinstance(data, Unset):
+                return data
+            return cast(None | str | Unset, data)
+
+        model_id = _parse_model_id(d.pop("model_id", UNSET))
+
+        def _parse_n(data: object) -> int | None | Unset:
+            if data is None:
+                return data
+            if isinstance(data, Unset):
+                return data
+            return cast(int | None | Unset, data)
+
+        n = _parse_n(d.pop("n", UNSET))
+
+        def _parse_negative_prompt(data: object) -> None | str | Unset:
+            if data is None:
+                return data
+            if isinstance(data, Unset):
+                return data
+            return cast(None | str | Unset, data)
+
+        negative_prompt = _parse_negative_prompt(d.pop("negative_prompt", UNSET))
+
+        prompt = d.pop("prompt")
+
+        def _parse_seed(data: object) -> int | None | Unset:
+            if data is None:
+                return data
+            if isinstance(data, Unset):
+                return data
+            return cast(int | None | Unset, data)
+
+        seed = _parse_seed(d.pop("seed", UNSET))
+
+        def _parse_steps(data: object) -> int | None | Unset:
+            if data is None:
+                return data
+            if isinstance(data, Unset):
+                return data
+            return cast(int | None | Unset, data)
+
+        steps = _parse_steps(d.pop("steps", UNSET))
+
+        def _parse_strength(data: object) -> float | None | Unset:
+            if data is None:
+                return data
+            if isinstance(data, Unset):
+                return data
+            return cast(float | None | Unset, data)
+
+        strength = _parse_strength(d.pop("strength", UNSET))
+
+        image_edit_multipart_request = cls(
+            image=image,
+            model=model,
+            model_id=model_id,
+            n=n,
+            negative_prompt=negative_prompt,
+            prompt=prompt,
+            seed=seed,
+            steps=steps,
+            strength=strength,
+        )
+
+        image_edit_multipart_request.additional_properties = d
+        return image_edit_multipart_request
+
+    @property
+    def additional_keys(self) -> list[str]:
+        return list(self.additional_properties.keys())
+
+    def __getitem__(self, key: str) -> Any:
+        return self.additional_properties[key]
+
+    def __setitem__(self, key: str, value: Any) -> None:
+        self.additional_properties[key] = value
+
+    def __delitem__(self, key: str) -> None:
+        del self.additional_properties[key]
+
+    def __contains__(self, key: str) -> bool:
+        return key in self.additional_properties
@@ -30,6 +30,9 @@ class ImageGenerationRequest:
         model_id (None | str | Unset): Optional catalog model identifier used for history attribution.
         n (int | Unset): Number of images to generate (default `1`).
         negative_prompt (None | str | Unset): Negative text prompt (things to avoid in the generated image).
+        preview_interval (int | None | Unset): Denoising steps between low-resolution previews, served from
+            `GET /v1/images/generations/{id}/preview` while the task runs. Requires a
+            model loaded with a TAESD; omit to disable previews.
         sample_method (None | str | Unset): Sampling method (`"euler"`, `"euler_a"`, `"lcm"`, etc., `"auto"`).
         scheduler (None | str | Unset): Sigma schedule (`"discrete"`, `"karras"`, etc., `"auto"`).
         seed (int | None | Unset): RNG seed (`-1` = random, default `42`).
@@ -50,6 +53,7 @@ class ImageGenerationRequest:
     model_id: None | str | Unset = UNSET
     n: int | Unset = UNSET
     negative_prompt: None | str | Unset = UNSET
+    preview_interval: int | None | Unset = UNSET
     sample_method: None | str | Unset = UNSET
     scheduler: None | str | Unset = UNSET
     seed: int | None | Unset = UNSET
@@ -113,6 +117,12 @@ class ImageGenerationRequest:
         else:
             negative_prompt = self.negative_prompt
 
+        preview_interval: int | None | Unset
+        if isinstance(self.preview_interval, Unset):
+            preview_interval = UNSET
+        else:
+            preview_interval = self.preview_interval
+
         sample_method: None | str | Unset
         if isinstance(self.sample_method, Unset):
             sample_method = UNSET
@@ -173,6 +183,8 @@ class ImageGenerationRequest:
             field_dict["n"] = n
         if negative_prompt is not UNSET:
             field_dict["negative_prompt"] = negative_prompt
+        if preview_interval is not UNSET:
+            field_dict["preview_interval"] = preview_interval
         if sample_method is not UNSET:
             field_dict["sample_method"] = sample_method
         if scheduler is not UNSET:
@@ -269,6 +281,15 @@ class ImageGenerationRequest:
 
         negative_prompt = _parse_negative_prompt(d.pop("negative_prompt", UNSET))
 
+        def _parse_preview_interval(data: object) -> int | None | Unset:
+            if data is None:
+                return data
+            if isinstance(data, Unset):
+                return data
+            return cast(int | None | Unset, data)
+
+        preview_interval = _parse_preview_interval(d.pop("preview_interval", UNSET))
+
         def _parse_sample_method(data: object) -> None | str | Unset:
             if data is None:
                 return data
@@ -329,6 +350,7 @@ class ImageGenerationRequest:
             model_id=model_id,
             n=n,
             negative_prompt=negative_prompt,
+            preview_interval=preview_interval,
             sample_method=sample_method,
             scheduler=scheduler,
             seed=seed,
@@ -20,12 +20,14 @@ class ModelStatusResponse:
         status (str): Human-readable status string.
         context_length (int | None | Unset): Effective runtime context window length in tokens.
         training_context_length (int | None | Unset): Training context window length reported by the loaded model.
+        version (None | str | Unset): Native library build serving the model, when the backend reports one.
     """
 
     backend: str
     status: str
     context_length: int | None | Unset = UNSET
     training_context_length: int | None | Unset = UNSET
+    version: None | str | Unset = UNSET
     additional_properties: dict[str, Any] = _attrs_field(init=False, factory=dict)
 
     def to_dict(self) -> dict[str, Any]:
@@ -45,6 +47,12 @@ class ModelStatusResponse:
         else:
             training_context_length = self.training_context_length
 
+        version: None | str | Unset
+        if isinstance(self.version, Unset):
+            version = UNSET
+        else:
+            version = self.version
+
         field_dict: dict[str, Any] = {}
         field_dict.update(self.additional_properties)
         field_dict.update(
@@ -57,6 +65,8 @@ class ModelStatusResponse:
             field_dict["context_length"] = context_length
         if training_context_length is not UNSET:
             field_dict["training_context_length"] = training_context_length
+        if version is not UNSET:
+            field_dict["version"] = version
 
         return field_dict
 
@@ -87,11 +97,21 @@ class ModelStatusResponse:
             d.pop("training_context_length", UNSET)
         )
 
+        def _parse_version(data: object) -> None | str | Unset:
+            if data is None:
+                return data
+            if isinstance(data, Unset):
+                return data
+            return cast(None | str | Unset, data)
+
+        version = _parse_version(d.pop("version", UNSET))
+
         model_status_response = cls(
             backend=backend,
             status=status,
             context_length=context_length,
             training_context_length=training_context_length,
+            version=version,
         )
 
         model_status_response.additional_properties = d
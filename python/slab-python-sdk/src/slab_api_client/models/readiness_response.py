@@ -0,0 +1,91 @@
+from __future__ import annotations
+
+from collections.abc import Mapping
+from typing import TYPE_CHECKING, Any, TypeVar, cast
+
+from attrs import define as _attrs_define
+from attrs import field as _attrs_field
+
+if TYPE_CHECKING:
+    from ..models.backend_status_response import BackendStatusResponse
+
+
+T = TypeVar("T", bound="ReadinessResponse")
+
+
+@_attrs_define
+class ReadinessResponse:
+    """
+    Attributes:
+        backends (list[BackendStatusResponse]): Status of every enabled runtime backend.
+        loaded_backends (list[str]): Backends that currently hold a loaded model.
+        status (str): `"ready"` or `"not_ready"`.
+    """
+
+    backends: list[BackendStatusResponse]
+    loaded_backends: list[str]
+    status: str
+    additional_properties: dict[str, Any] = _attrs_field(init=False, factory=dict)
+
+    def to_dict(self) -> dict[str, Any]:
+        backends = []
+        for backends_item_data in self.backends:
+            backends_item = backends_item_data.to_dict()
+            backends.append(backends_item)
+
+        loaded_backends = self.loaded_backends
+
+        status = self.status
+
+        field_dict: dict[str, Any] = {}
+        field_dict.update(self.additional_properties)
+        field_dict.update(
+            {
+                "backends": backends,
+                "loaded_backends": loaded_backends,
+                "status": status,
+            }
+        )
+
+        return field_dict
+
+    @classmethod
+    def from_dict(cls: type[T], src_dict: Mapping[str, Any]) -> T:
+        from ..models.backend_status_response import BackendStatusResponse
+
+        d = dict(src_dict)
+        backends = []
+        _backends = d.pop("backends")
+        for backends_item_data in _backends:
+            backends_item = BackendStatusResponse.from_dict(backends_item_data)
+
+            backends.append(backends_item)
+
+        loaded_backends = cast(list[str], d.pop("loaded_backends"))
+
+        status = d.pop("status")
+
+        readiness_response = cls(
+            backends=backends,
+            loaded_backends=loaded_backends,
+            status=status,
+        )
+
+        readiness_response.additional_properties = d
+        return readiness_response
+
+    @property
+    def additional_keys(self) -> list[str]:
+        return list(self.additional_properties.keys())
+
+    def __getitem__(self, key: str) -> Any:
+        return self.additional_properties[key]
+
+    def __setitem__(self, key: str, value: Any) -> None:
+        self.additional_properties[key] = value
+
+    def __delitem__(self, key: str) -> None:
+        del self.additional_properties[key]
+
+    def __contains__(self, key: str) -> bool:
+        return key in self.additional_properties